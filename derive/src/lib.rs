@@ -37,7 +37,32 @@ use syn::{parse_macro_input, DeriveInput};
 ///     Ok(())
 /// }
 /// ```
-#[proc_macro_derive(Fetch)]
+///
+/// Fields holding a struct which derives `Fetch` itself, can be annotated with `#[odbc(flatten)]`.
+/// The columns of the nested struct are then bound in place of the field, so column groups can be
+/// reused between rows.
+///
+/// ```
+/// use odbc_api_derive::Fetch;
+/// use odbc_api::parameter::VarCharArray;
+///
+/// #[derive(Default, Clone, Copy, Fetch)]
+/// struct Address {
+///     street: VarCharArray<255>,
+///     city: VarCharArray<255>,
+///     zip: VarCharArray<10>,
+/// }
+///
+/// // Binds to `SELECT id, street, city, zip, phone FROM Customers`
+/// #[derive(Default, Clone, Copy, Fetch)]
+/// struct Customer {
+///     id: i64,
+///     #[odbc(flatten)]
+///     address: Address,
+///     phone: VarCharArray<30>,
+/// }
+/// ```
+#[proc_macro_derive(Fetch, attributes(odbc))]
 pub fn derive_fetch_row(item: TokenStream) -> TokenStream {
     let input = parse_macro_input!(item as DeriveInput);
 
@@ -50,18 +75,43 @@ pub fn derive_fetch_row(item: TokenStream) -> TokenStream {
 
     let fields = struct_data.fields;
 
+    // Number of columns bound by each field. Flattened fields bind all the columns of the nested
+    // struct, every other field binds exactly one.
+    let widths: Vec<_> = fields
+        .iter()
+        .map(|field| {
+            let field_type = &field.ty;
+            if is_flatten(field) {
+                quote! { <#field_type as odbc_api::buffers::FetchRowGroup>::NUM_COLUMNS }
+            } else {
+                quote! { 1u16 }
+            }
+        })
+        .collect();
+
     let bindings = fields.iter().enumerate().map(|(index, field)| {
         let field_name = field
             .ident
             .as_ref()
             .expect("All struct members must be named");
-        let col_index = (index + 1) as u16;
-        quote! {
-            odbc_api::buffers::FetchRowMember::bind_to_col(
-                &mut self.#field_name,
-                #col_index,
-                &mut cursor
-            )?;
+        let preceding = &widths[..index];
+        let col_index = quote! { first_column #(+ #preceding)* };
+        if is_flatten(field) {
+            quote! {
+                odbc_api::buffers::FetchRowGroup::bind_columns_starting_at(
+                    &mut self.#field_name,
+                    #col_index,
+                    cursor
+                )?;
+            }
+        } else {
+            quote! {
+                odbc_api::buffers::FetchRowMember::bind_to_col(
+                    &mut self.#field_name,
+                    #col_index,
+                    cursor
+                )?;
+            }
         }
     });
 
@@ -70,34 +120,131 @@ pub fn derive_fetch_row(item: TokenStream) -> TokenStream {
             .ident
             .as_ref()
             .expect("All struct members must be named");
+        let preceding = &widths[..index];
+        let buffer_index = quote! { first_buffer_index #(+ (#preceding) as usize)* };
+        let maybe_truncation = if is_flatten(field) {
+            quote! {
+                odbc_api::buffers::FetchRowGroup::find_truncation_starting_at(
+                    &self.#field_name,
+                    #buffer_index,
+                )
+            }
+        } else {
+            quote! {
+                odbc_api::buffers::FetchRowMember::find_truncation(
+                    &self.#field_name,
+                    #buffer_index,
+                )
+            }
+        };
         quote! {
-            let maybe_truncation = odbc_api::buffers::FetchRowMember::find_truncation(
-                &self.#field_name,
-                #index,
-            );
-            if let Some(truncation_info) = maybe_truncation {
+            if let Some(truncation_info) = #maybe_truncation {
                 return Some(truncation_info);
             }
         }
     });
 
+    let find_all_truncations = fields.iter().enumerate().map(|(index, field)| {
+        let field_name = field
+            .ident
+            .as_ref()
+            .expect("All struct members must be named");
+        let preceding = &widths[..index];
+        let buffer_index = quote! { first_buffer_index #(+ (#preceding) as usize)* };
+        if is_flatten(field) {
+            quote! {
+                odbc_api::buffers::FetchRowGroup::find_all_truncations_starting_at(
+                    &self.#field_name,
+                    #buffer_index,
+                    truncations,
+                );
+            }
+        } else {
+            quote! {
+                truncations.extend(odbc_api::buffers::FetchRowMember::find_truncation(
+                    &self.#field_name,
+                    #buffer_index,
+                ));
+            }
+        }
+    });
+
     let expanded = quote! {
-        unsafe impl odbc_api::buffers::FetchRow for #struct_name {
+        unsafe impl odbc_api::buffers::FetchRowGroup for #struct_name {
+            const NUM_COLUMNS: u16 = 0 #(+ #widths)*;
 
-            unsafe fn bind_columns_to_cursor(
+            unsafe fn bind_columns_starting_at(
                 &mut self,
-                mut cursor: odbc_api::handles::StatementRef<'_>
+                first_column: u16,
+                cursor: &mut odbc_api::handles::StatementRef<'_>
             ) -> std::result::Result<(), odbc_api::Error> {
                 #(#bindings)*
                 Ok(())
             }
 
-            fn find_truncation(&self) -> std::option::Option<odbc_api::TruncationInfo> {
+            fn find_truncation_starting_at(
+                &self,
+                first_buffer_index: usize
+            ) -> std::option::Option<odbc_api::TruncationInfo> {
                 #(#find_truncation)*
                 None
             }
+
+            fn find_all_truncations_starting_at(
+                &self,
+                first_buffer_index: usize,
+                truncations: &mut std::vec::Vec<odbc_api::TruncationInfo>,
+            ) {
+                #(#find_all_truncations)*
+            }
+        }
+
+        unsafe impl odbc_api::buffers::FetchRow for #struct_name {
+
+            unsafe fn bind_columns_to_cursor(
+                &mut self,
+                mut cursor: odbc_api::handles::StatementRef<'_>
+            ) -> std::result::Result<(), odbc_api::Error> {
+                odbc_api::buffers::FetchRowGroup::bind_columns_starting_at(self, 1, &mut cursor)
+            }
+
+            fn find_truncation(&self) -> std::option::Option<odbc_api::TruncationInfo> {
+                odbc_api::buffers::FetchRowGroup::find_truncation_starting_at(self, 0)
+            }
+
+            fn find_all_truncations(
+                &self,
+                truncations: &mut std::vec::Vec<odbc_api::TruncationInfo>,
+            ) {
+                odbc_api::buffers::FetchRowGroup::find_all_truncations_starting_at(
+                    self,
+                    0,
+                    truncations,
+                )
+            }
         }
     };
 
     expanded.into()
 }
+
+/// `true` if the field is annotated with `#[odbc(flatten)]`.
+fn is_flatten(field: &syn::Field) -> bool {
+    let mut flatten = false;
+    for attr in field
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("odbc"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("flatten") {
+                flatten = true;
+                Ok(())
+            } else {
+                Err(meta.error("Unsupported odbc attribute. Only `flatten` is supported."))
+            }
+        })
+        .unwrap_or_else(|error| panic!("{error}"));
+    }
+    flatten
+}
@@ -1,4 +1,4 @@
-use odbc_api::parameter::VarCharArray;
+use odbc_api::{buffers::FetchRowGroup, parameter::VarCharArray, Bit};
 use odbc_api_derive::Fetch;
 
 // A check, wether the derive syntax produces something that compiles. For a test actually fetching
@@ -10,4 +10,27 @@ use odbc_api_derive::Fetch;
 struct MyRow {
     a: i64,
     b: VarCharArray<50>,
+    c: f64,
+    d: f32,
+    e: Bit,
 }
+
+// Nested structs annotated with `flatten` contribute all of their columns to the parent.
+#[allow(dead_code)]
+#[derive(Fetch, Clone, Copy)]
+struct Address {
+    street: VarCharArray<50>,
+    city: VarCharArray<50>,
+    zip: VarCharArray<10>,
+}
+
+#[allow(dead_code)]
+#[derive(Fetch, Clone, Copy)]
+struct Customer {
+    id: i64,
+    #[odbc(flatten)]
+    address: Address,
+    phone: VarCharArray<20>,
+}
+
+const _: () = assert!(<Customer as FetchRowGroup>::NUM_COLUMNS == 5);
@@ -18,10 +18,15 @@ used in safe code:
 * [`crate::buffers::TextRowSet`]: Queries all data as text bound in columns. Since the columns are
   homogeneous, you can also use this, to iterate row wise over the buffer. Excellent if you want
   to print the contents of a table, or are for any reason only interessted in the text
-  representation of the values.
+  representation of the values. [`crate::buffers::WTextRowSet`] does the same, but binds the
+  columns as wide (utf-16) text.
 * [`crate::buffers::RowVec`]: A good choice if you know the schema at compile time and your
   application logic is build in a row by row fashion, rather than column by column.
 
+In addition [`crate::buffers::StringRowVec`] offers row wise access to owned `String`s without the
+need to choose a maximum string length. Values exceeding the bound text buffers are completed using
+`SQLGetData`.
+
 */
 
 mod any_buffer;
@@ -32,18 +37,24 @@ mod description;
 mod indicator;
 mod item;
 mod row_vec;
+mod string_row_vec;
 mod text_column;
+mod zeroed;
 
 pub use self::{
     any_buffer::{AnyBuffer, AnySlice, AnySliceMut, ColumnarAnyBuffer},
     bin_column::{BinColumn, BinColumnIt, BinColumnSliceMut, BinColumnView},
     column_with_indicator::{NullableSlice, NullableSliceMut},
-    columnar::{ColumnBuffer, ColumnarBuffer, TextRowSet},
+    columnar::{ColumnBuffer, ColumnarBuffer, TextRowSet, WTextRowSet},
     description::BufferDesc,
     indicator::Indicator,
     item::Item,
-    row_vec::{FetchRow, FetchRowMember, RowVec},
+    row_vec::{FetchRow, FetchRowGroup, FetchRowMember, RowVec},
+    string_row_vec::StringRowVec,
     text_column::{
         CharColumn, TextColumn, TextColumnIt, TextColumnSliceMut, TextColumnView, WCharColumn,
     },
 };
+
+#[cfg(feature = "chrono")]
+pub(crate) use self::column_with_indicator::{cast_slice, cast_slice_mut};
@@ -1,22 +1,30 @@
 use std::{collections::HashSet, ffi::c_void};
 
-use odbc_sys::{CDataType, Date, Time, Timestamp};
+use odbc_sys::{
+    CDataType, Date, Numeric, Operation, SqlDataType, StatementAttribute, Time, Timestamp,
+};
 
 use crate::{
     columnar_bulk_inserter::BoundInputSlice,
-    error::TooLargeBufferSize,
-    handles::{CData, CDataMut, HasDataType, StatementRef},
-    Bit, DataType, Error,
+    error::{ExtendResult, TooLargeBufferSize},
+    handles::{
+        AsStatementRef, CData, CDataMut, Descriptor, HasDataType, Record as DiagnosticRecord,
+        State, Statement, StatementRef,
+    },
+    Bit, CursorRow, DataType, Error,
 };
 
 use super::{
     bin_column::BinColumnSliceMut,
     column_with_indicator::{
         OptBitColumn, OptDateColumn, OptF32Column, OptF64Column, OptI16Column, OptI32Column,
-        OptI64Column, OptI8Column, OptTimeColumn, OptTimestampColumn, OptU8Column,
+        OptI64Column, OptI8Column, OptNumericColumn, OptTimeColumn, OptTimestampColumn,
+        OptU8Column,
     },
     columnar::ColumnBuffer,
+    description::LONG_DATA_CHUNK_SIZE,
     text_column::TextColumnSliceMut,
+    zeroed::zeroed_vec,
     BinColumn, BinColumnView, BufferDesc, CharColumn, ColumnarBuffer, Indicator, Item,
     NullableSlice, NullableSliceMut, TextColumn, TextColumnView, WCharColumn,
 };
@@ -37,9 +45,23 @@ pub enum AnyBuffer {
     Text(CharColumn),
     /// A buffer for holding both nullable and required text data. Uses UTF-16 encoding
     WText(WCharColumn),
+    /// Text in the system encoding of arbitrary length. See [`BufferDesc::LongText`].
+    LongText(CharColumn),
+    /// Binary data of arbitrary length. See [`BufferDesc::LongBinary`].
+    LongBinary(BinColumn),
+    /// Bound as `SQL_C_DEFAULT`. See [`BufferDesc::Default`].
+    Default(BinColumn),
     Date(Vec<Date>),
     Time(Vec<Time>),
     Timestamp(Vec<Timestamp>),
+    /// See [`BufferDesc::Numeric`].
+    Numeric {
+        values: Vec<Numeric>,
+        /// Declared precision, if bound as a parameter.
+        precision: usize,
+        /// Declared scale, if bound as a parameter.
+        scale: i16,
+    },
     F64(Vec<f64>),
     F32(Vec<f32>),
     I8(Vec<i8>),
@@ -51,6 +73,13 @@ pub enum AnyBuffer {
     NullableDate(OptDateColumn),
     NullableTime(OptTimeColumn),
     NullableTimestamp(OptTimestampColumn),
+    NullableNumeric {
+        values: OptNumericColumn,
+        /// Declared precision, if bound as a parameter.
+        precision: usize,
+        /// Declared scale, if bound as a parameter.
+        scale: i16,
+    },
     NullableF64(OptF64Column),
     NullableF32(OptF32Column),
     NullableI8(OptI8Column),
@@ -90,27 +119,51 @@ impl AnyBuffer {
             }
             BufferDesc::Text { max_str_len } => {
                 if fallible_allocations {
-                    AnyBuffer::Text(TextColumn::try_new(max_rows, max_str_len)?)
+                    AnyBuffer::Text(TextColumn::try_new_zeroed(max_rows, max_str_len)?)
                 } else {
-                    AnyBuffer::Text(TextColumn::new(max_rows, max_str_len))
+                    AnyBuffer::Text(TextColumn::new_zeroed(max_rows, max_str_len))
                 }
             }
             BufferDesc::WText { max_str_len } => {
                 if fallible_allocations {
-                    AnyBuffer::WText(TextColumn::try_new(max_rows, max_str_len)?)
+                    AnyBuffer::WText(TextColumn::try_new_zeroed(max_rows, max_str_len)?)
                 } else {
-                    AnyBuffer::WText(TextColumn::new(max_rows, max_str_len))
+                    AnyBuffer::WText(TextColumn::new_zeroed(max_rows, max_str_len))
                 }
             }
-            BufferDesc::Date { nullable: false } => {
-                AnyBuffer::Date(vec![Date::default(); max_rows])
+            BufferDesc::LongText => {
+                if fallible_allocations {
+                    AnyBuffer::LongText(TextColumn::try_new_zeroed(max_rows, LONG_DATA_CHUNK_SIZE)?)
+                } else {
+                    AnyBuffer::LongText(TextColumn::new_zeroed(max_rows, LONG_DATA_CHUNK_SIZE))
+                }
             }
-            BufferDesc::Time { nullable: false } => {
-                AnyBuffer::Time(vec![Time::default(); max_rows])
+            BufferDesc::LongBinary => {
+                if fallible_allocations {
+                    AnyBuffer::LongBinary(BinColumn::try_new(max_rows, LONG_DATA_CHUNK_SIZE)?)
+                } else {
+                    AnyBuffer::LongBinary(BinColumn::new(max_rows, LONG_DATA_CHUNK_SIZE))
+                }
             }
-            BufferDesc::Timestamp { nullable: false } => {
-                AnyBuffer::Timestamp(vec![Timestamp::default(); max_rows])
+            BufferDesc::Default { length } => {
+                if fallible_allocations {
+                    AnyBuffer::Default(BinColumn::try_new(max_rows, length)?)
+                } else {
+                    AnyBuffer::Default(BinColumn::new(max_rows, length))
+                }
             }
+            BufferDesc::Date { nullable: false } => AnyBuffer::Date(zeroed_vec(max_rows)),
+            BufferDesc::Time { nullable: false } => AnyBuffer::Time(zeroed_vec(max_rows)),
+            BufferDesc::Timestamp { nullable: false } => AnyBuffer::Timestamp(zeroed_vec(max_rows)),
+            BufferDesc::Numeric {
+                precision,
+                scale,
+                nullable: false,
+            } => AnyBuffer::Numeric {
+                values: zeroed_vec(max_rows),
+                precision,
+                scale,
+            },
             BufferDesc::F64 { nullable: false } => AnyBuffer::F64(vec![f64::default(); max_rows]),
             BufferDesc::F32 { nullable: false } => AnyBuffer::F32(vec![f32::default(); max_rows]),
             BufferDesc::I8 { nullable: false } => AnyBuffer::I8(vec![i8::default(); max_rows]),
@@ -118,7 +171,7 @@ impl AnyBuffer {
             BufferDesc::I32 { nullable: false } => AnyBuffer::I32(vec![i32::default(); max_rows]),
             BufferDesc::I64 { nullable: false } => AnyBuffer::I64(vec![i64::default(); max_rows]),
             BufferDesc::U8 { nullable: false } => AnyBuffer::U8(vec![u8::default(); max_rows]),
-            BufferDesc::Bit { nullable: false } => AnyBuffer::Bit(vec![Bit::default(); max_rows]),
+            BufferDesc::Bit { nullable: false } => AnyBuffer::Bit(zeroed_vec(max_rows)),
             BufferDesc::Date { nullable: true } => {
                 AnyBuffer::NullableDate(OptDateColumn::new(max_rows))
             }
@@ -128,6 +181,15 @@ impl AnyBuffer {
             BufferDesc::Timestamp { nullable: true } => {
                 AnyBuffer::NullableTimestamp(OptTimestampColumn::new(max_rows))
             }
+            BufferDesc::Numeric {
+                precision,
+                scale,
+                nullable: true,
+            } => AnyBuffer::NullableNumeric {
+                values: OptNumericColumn::new(max_rows),
+                precision,
+                scale,
+            },
             BufferDesc::F64 { nullable: true } => {
                 AnyBuffer::NullableF64(OptF64Column::new(max_rows))
             }
@@ -161,14 +223,15 @@ impl AnyBuffer {
 
     fn inner_cdata(&self) -> &dyn CData {
         match self {
-            AnyBuffer::Binary(col) => col,
-            AnyBuffer::Text(col) => col,
+            AnyBuffer::Binary(col) | AnyBuffer::LongBinary(col) | AnyBuffer::Default(col) => col,
+            AnyBuffer::Text(col) | AnyBuffer::LongText(col) => col,
             AnyBuffer::WText(col) => col,
             AnyBuffer::F64(col) => col,
             AnyBuffer::F32(col) => col,
             AnyBuffer::Date(col) => col,
             AnyBuffer::Time(col) => col,
             AnyBuffer::Timestamp(col) => col,
+            AnyBuffer::Numeric { values: col, .. } => col,
             AnyBuffer::I8(col) => col,
             AnyBuffer::I16(col) => col,
             AnyBuffer::I32(col) => col,
@@ -180,6 +243,7 @@ impl AnyBuffer {
             AnyBuffer::NullableDate(col) => col,
             AnyBuffer::NullableTime(col) => col,
             AnyBuffer::NullableTimestamp(col) => col,
+            AnyBuffer::NullableNumeric { values: col, .. } => col,
             AnyBuffer::NullableI8(col) => col,
             AnyBuffer::NullableI16(col) => col,
             AnyBuffer::NullableI32(col) => col,
@@ -191,14 +255,15 @@ impl AnyBuffer {
 
     fn inner_cdata_mut(&mut self) -> &mut dyn CDataMut {
         match self {
-            AnyBuffer::Binary(col) => col,
-            AnyBuffer::Text(col) => col,
+            AnyBuffer::Binary(col) | AnyBuffer::LongBinary(col) | AnyBuffer::Default(col) => col,
+            AnyBuffer::Text(col) | AnyBuffer::LongText(col) => col,
             AnyBuffer::WText(col) => col,
             AnyBuffer::F64(col) => col,
             AnyBuffer::F32(col) => col,
             AnyBuffer::Date(col) => col,
             AnyBuffer::Time(col) => col,
             AnyBuffer::Timestamp(col) => col,
+            AnyBuffer::Numeric { values: col, .. } => col,
             AnyBuffer::I8(col) => col,
             AnyBuffer::I16(col) => col,
             AnyBuffer::I32(col) => col,
@@ -210,6 +275,7 @@ impl AnyBuffer {
             AnyBuffer::NullableDate(col) => col,
             AnyBuffer::NullableTime(col) => col,
             AnyBuffer::NullableTimestamp(col) => col,
+            AnyBuffer::NullableNumeric { values: col, .. } => col,
             AnyBuffer::NullableI8(col) => col,
             AnyBuffer::NullableI16(col) => col,
             AnyBuffer::NullableI32(col) => col,
@@ -222,7 +288,11 @@ impl AnyBuffer {
 
 unsafe impl CData for AnyBuffer {
     fn cdata_type(&self) -> CDataType {
-        self.inner_cdata().cdata_type()
+        match self {
+            // Same memory layout as a binary column, but the driver decides what to put into it.
+            AnyBuffer::Default(_) => CDataType::Default,
+            _ => self.inner_cdata().cdata_type(),
+        }
     }
 
     fn indicator_ptr(&self) -> *const isize {
@@ -251,8 +321,10 @@ unsafe impl CDataMut for AnyBuffer {
 impl HasDataType for AnyBuffer {
     fn data_type(&self) -> DataType {
         match self {
-            AnyBuffer::Binary(col) => col.data_type(),
-            AnyBuffer::Text(col) => col.data_type(),
+            AnyBuffer::Binary(col) | AnyBuffer::LongBinary(col) | AnyBuffer::Default(col) => {
+                col.data_type()
+            }
+            AnyBuffer::Text(col) | AnyBuffer::LongText(col) => col.data_type(),
             AnyBuffer::WText(col) => col.data_type(),
             AnyBuffer::Date(_) | AnyBuffer::NullableDate(_) => DataType::Date,
             AnyBuffer::Time(_) | AnyBuffer::NullableTime(_) => DataType::Time {
@@ -261,6 +333,15 @@ impl HasDataType for AnyBuffer {
             AnyBuffer::Timestamp(_) | AnyBuffer::NullableTimestamp(_) => DataType::Timestamp {
                 precision: DEFAULT_TIME_PRECISION,
             },
+            AnyBuffer::Numeric {
+                precision, scale, ..
+            }
+            | AnyBuffer::NullableNumeric {
+                precision, scale, ..
+            } => DataType::Numeric {
+                precision: *precision,
+                scale: *scale,
+            },
             AnyBuffer::F64(_) | AnyBuffer::NullableF64(_) => DataType::Double,
             AnyBuffer::F32(_) | AnyBuffer::NullableF32(_) => DataType::Real,
             AnyBuffer::I8(_) | AnyBuffer::NullableI8(_) => DataType::TinyInt,
@@ -276,8 +357,69 @@ impl HasDataType for AnyBuffer {
     }
 }
 
+/// Caps the size of each element in bytes for variable sized buffers. The terminating zero of text
+/// is not accounted for.
+fn saturate_element_size(desc: BufferDesc, max_element_bytes: usize) -> BufferDesc {
+    match desc {
+        BufferDesc::Binary { length } => BufferDesc::Binary {
+            length: length.min(max_element_bytes),
+        },
+        BufferDesc::Text { max_str_len } => BufferDesc::Text {
+            max_str_len: max_str_len.min(max_element_bytes),
+        },
+        BufferDesc::WText { max_str_len } => BufferDesc::WText {
+            max_str_len: max_str_len.min(max_element_bytes / 2),
+        },
+        other => other,
+    }
+}
+
+/// Drivers lacking support for `SQLGetData` on bound columns or block cursors (see
+/// `SQL_GETDATA_EXTENSIONS`) report this with one of these states, if we try to fetch the remainder
+/// of a long value. Map them to an error telling the user why long columns do not work.
+pub(crate) fn get_data_extensions_not_supported(
+    record: DiagnosticRecord,
+    function: &'static str,
+) -> Error {
+    if [
+        State::INVALID_DESCRIPTOR_INDEX,
+        State::INVALID_CURSOR_POSITION,
+        State::OPTIONAL_FEATURE_NOT_IMPLEMENTED,
+    ]
+    .contains(&record.state)
+    {
+        Error::GetDataExtensionsNotSupported { record, function }
+    } else {
+        Error::Diagnostics { record, function }
+    }
+}
+
+/// Positions `cursor` on the row with the zero based `row_index` within the current row set, so
+/// `SQLGetData` retrieves values of that row. If the row set consists of a single row, the cursor
+/// is already positioned on it, and `SQLSetPos` is not called. This spares drivers which do not
+/// support `SQLGetData` for block cursors (`SQL_GD_BLOCK`) the need to.
+pub(crate) unsafe fn position_for_get_data(
+    cursor: &mut StatementRef<'_>,
+    row_index: usize,
+) -> Result<(), Error> {
+    let row_set_size = cursor
+        .attribute_u32(StatementAttribute::RowArraySize)
+        .into_result(cursor)?;
+    if row_set_size > 1 {
+        cursor
+            .set_pos(row_index + 1, Operation::POSITION)
+            .into_result(cursor)
+            .provide_context_for_diagnostic(get_data_extensions_not_supported)?;
+    }
+    Ok(())
+}
+
 /// Flexible columnar buffer implementation. Bind this to a cursor to fetch values in bulk, or pass
 /// this as a parameter to a statement, to submit many parameters at once.
+///
+/// The buffer owns the storage of its columns. In order to fetch fixed size values into storage
+/// owned by the caller, use a [`ColumnarBuffer`] of mutable slices or
+/// [`crate::buffers::NullableSliceMut`] instead.
 pub type ColumnarAnyBuffer = ColumnarBuffer<AnyBuffer>;
 
 impl ColumnarAnyBuffer {
@@ -297,8 +439,12 @@ impl ColumnarAnyBuffer {
 
     /// Allocates a [`ColumnarBuffer`] fitting the buffer descriptions. If not enough memory is
     /// available to allocate the buffers this function fails with
-    /// [`Error::TooLargeColumnBufferSize`]. This function is slower than [`Self::from_descs`]
-    /// which would just panic if not enough memory is available for allocation.
+    /// [`Error::TooLargeColumnBufferSize`], rather than panicking like [`Self::from_descs`].
+    ///
+    /// Memory for values is requested zeroed from the allocator, rather than being written
+    /// element by element. So allocating large buffers is cheap, as long as the operating system
+    /// lazily provides zeroed pages. This is why there is no constructor for uninitialized
+    /// buffers: each value must be valid before it can be viewed through a safe slice.
     pub fn try_from_descs(
         capacity: usize,
         descs: impl IntoIterator<Item = BufferDesc>,
@@ -316,6 +462,47 @@ impl ColumnarAnyBuffer {
         Ok(unsafe { ColumnarBuffer::new_unchecked(capacity, columns) })
     }
 
+    /// Like [`Self::try_from_descs`], but caps the size of each element of variable sized buffers
+    /// (`Text`, `WText` and `Binary`) to `max_element_bytes`, instead of failing. Useful if drivers
+    /// report absurdly large sizes for some columns, e.g. MariaDB with 16 MiB for `LONGTEXT`.
+    /// Values larger than the cap are truncated at fetch time, which can be detected using the
+    /// indicators, e.g. with [`crate::RowSetBuffer::find_truncation`].
+    ///
+    /// Besides the buffer, the zero based indices of all buffers whose element size has been capped
+    /// are returned, so applications can warn about possible truncation.
+    ///
+    /// ```
+    /// use odbc_api::buffers::{BufferDesc, ColumnarAnyBuffer};
+    ///
+    /// let descs = [
+    ///     BufferDesc::I32 { nullable: true },
+    ///     BufferDesc::Text { max_str_len: 16 * 1024 * 1024 },
+    /// ];
+    /// let (buffer, capped) = ColumnarAnyBuffer::try_from_descs_saturating(100, descs, 4096)?;
+    /// assert_eq!(vec![1], capped);
+    /// # Ok::<(), odbc_api::Error>(())
+    /// ```
+    pub fn try_from_descs_saturating(
+        capacity: usize,
+        descs: impl IntoIterator<Item = BufferDesc>,
+        max_element_bytes: usize,
+    ) -> Result<(Self, Vec<usize>), Error> {
+        let mut capped = Vec::new();
+        let descs: Vec<_> = descs
+            .into_iter()
+            .enumerate()
+            .map(|(buffer_index, desc)| {
+                let saturated = saturate_element_size(desc, max_element_bytes);
+                if saturated != desc {
+                    capped.push(buffer_index);
+                }
+                saturated
+            })
+            .collect();
+        let buffer = Self::try_from_descs(capacity, descs)?;
+        Ok((buffer, capped))
+    }
+
     /// Allows you to pass the buffer descriptions together with a one based column index referring
     /// the column, the buffer is supposed to bind to. This allows you also to ignore columns in a
     /// result set, by not binding them at all. There is no restriction on the order of column
@@ -358,6 +545,7 @@ pub enum AnySlice<'a> {
     Date(&'a [Date]),
     Time(&'a [Time]),
     Timestamp(&'a [Timestamp]),
+    Numeric(&'a [Numeric]),
     F64(&'a [f64]),
     F32(&'a [f32]),
     I8(&'a [i8]),
@@ -369,6 +557,7 @@ pub enum AnySlice<'a> {
     NullableDate(NullableSlice<'a, Date>),
     NullableTime(NullableSlice<'a, Time>),
     NullableTimestamp(NullableSlice<'a, Timestamp>),
+    NullableNumeric(NullableSlice<'a, Numeric>),
     NullableF64(NullableSlice<'a, f64>),
     NullableF32(NullableSlice<'a, f32>),
     NullableI8(NullableSlice<'a, i8>),
@@ -410,7 +599,10 @@ impl<'a> AnySlice<'a> {
         }
     }
 
-    /// Extract the array type from an [`AnySlice`].
+    /// Extract the array type from an [`AnySlice`]. Views obtained from a fetched buffer only
+    /// cover the rows of the last fetch, so the length of the slice is exactly the number of rows
+    /// fetched, rather than the capacity of the buffer. There is no need to truncate it using
+    /// [`super::ColumnarBuffer::num_rows`].
     pub fn as_slice<I: Item>(self) -> Option<&'a [I]> {
         I::as_slice(self)
     }
@@ -431,16 +623,21 @@ unsafe impl<'a> BoundInputSlice<'a> for AnyBuffer {
     ) -> Self::SliceMut {
         let num_rows = self.capacity();
         match self {
-            AnyBuffer::Binary(column) => {
+            AnyBuffer::Binary(column)
+            | AnyBuffer::LongBinary(column)
+            | AnyBuffer::Default(column) => {
                 AnySliceMut::Binary(column.as_view_mut(parameter_index, stmt))
             }
-            AnyBuffer::Text(column) => AnySliceMut::Text(column.as_view_mut(parameter_index, stmt)),
+            AnyBuffer::Text(column) | AnyBuffer::LongText(column) => {
+                AnySliceMut::Text(column.as_view_mut(parameter_index, stmt))
+            }
             AnyBuffer::WText(column) => {
                 AnySliceMut::WText(column.as_view_mut(parameter_index, stmt))
             }
             AnyBuffer::Date(column) => AnySliceMut::Date(column),
             AnyBuffer::Time(column) => AnySliceMut::Time(column),
             AnyBuffer::Timestamp(column) => AnySliceMut::Timestamp(column),
+            AnyBuffer::Numeric { values, .. } => AnySliceMut::Numeric(values),
             AnyBuffer::F64(column) => AnySliceMut::F64(column),
             AnyBuffer::F32(column) => AnySliceMut::F32(column),
             AnyBuffer::I8(column) => AnySliceMut::I8(column),
@@ -454,6 +651,9 @@ unsafe impl<'a> BoundInputSlice<'a> for AnyBuffer {
             AnyBuffer::NullableTimestamp(column) => {
                 AnySliceMut::NullableTimestamp(column.writer_n(num_rows))
             }
+            AnyBuffer::NullableNumeric { values, .. } => {
+                AnySliceMut::NullableNumeric(values.writer_n(num_rows))
+            }
             AnyBuffer::NullableF64(column) => AnySliceMut::NullableF64(column.writer_n(num_rows)),
             AnyBuffer::NullableF32(column) => AnySliceMut::NullableF32(column.writer_n(num_rows)),
             AnyBuffer::NullableI8(column) => AnySliceMut::NullableI8(column.writer_n(num_rows)),
@@ -476,6 +676,7 @@ pub enum AnySliceMut<'a> {
     Date(&'a mut [Date]),
     Time(&'a mut [Time]),
     Timestamp(&'a mut [Timestamp]),
+    Numeric(&'a mut [Numeric]),
     F64(&'a mut [f64]),
     F32(&'a mut [f32]),
     I8(&'a mut [i8]),
@@ -487,6 +688,7 @@ pub enum AnySliceMut<'a> {
     NullableDate(NullableSliceMut<'a, Date>),
     NullableTime(NullableSliceMut<'a, Time>),
     NullableTimestamp(NullableSliceMut<'a, Timestamp>),
+    NullableNumeric(NullableSliceMut<'a, Numeric>),
     NullableF64(NullableSliceMut<'a, f64>),
     NullableF32(NullableSliceMut<'a, f32>),
     NullableI8(NullableSliceMut<'a, i8>),
@@ -544,12 +746,15 @@ unsafe impl ColumnBuffer for AnyBuffer {
 
     fn capacity(&self) -> usize {
         match self {
-            AnyBuffer::Binary(col) => col.capacity(),
-            AnyBuffer::Text(col) => col.capacity(),
+            AnyBuffer::Binary(col) | AnyBuffer::LongBinary(col) | AnyBuffer::Default(col) => {
+                col.capacity()
+            }
+            AnyBuffer::Text(col) | AnyBuffer::LongText(col) => col.capacity(),
             AnyBuffer::WText(col) => col.capacity(),
             AnyBuffer::Date(col) => col.capacity(),
             AnyBuffer::Time(col) => col.capacity(),
             AnyBuffer::Timestamp(col) => col.capacity(),
+            AnyBuffer::Numeric { values, .. } => values.capacity(),
             AnyBuffer::F64(col) => col.capacity(),
             AnyBuffer::F32(col) => col.capacity(),
             AnyBuffer::I8(col) => col.capacity(),
@@ -561,6 +766,7 @@ unsafe impl ColumnBuffer for AnyBuffer {
             AnyBuffer::NullableDate(col) => col.capacity(),
             AnyBuffer::NullableTime(col) => col.capacity(),
             AnyBuffer::NullableTimestamp(col) => col.capacity(),
+            AnyBuffer::NullableNumeric { values, .. } => values.capacity(),
             AnyBuffer::NullableF64(col) => col.capacity(),
             AnyBuffer::NullableF32(col) => col.capacity(),
             AnyBuffer::NullableI8(col) => col.capacity(),
@@ -574,12 +780,15 @@ unsafe impl ColumnBuffer for AnyBuffer {
 
     fn view(&self, valid_rows: usize) -> AnySlice {
         match self {
-            AnyBuffer::Binary(col) => AnySlice::Binary(col.view(valid_rows)),
-            AnyBuffer::Text(col) => AnySlice::Text(col.view(valid_rows)),
+            AnyBuffer::Binary(col) | AnyBuffer::LongBinary(col) | AnyBuffer::Default(col) => {
+                AnySlice::Binary(col.view(valid_rows))
+            }
+            AnyBuffer::Text(col) | AnyBuffer::LongText(col) => AnySlice::Text(col.view(valid_rows)),
             AnyBuffer::WText(col) => AnySlice::WText(col.view(valid_rows)),
             AnyBuffer::Date(col) => AnySlice::Date(&col[0..valid_rows]),
             AnyBuffer::Time(col) => AnySlice::Time(&col[0..valid_rows]),
             AnyBuffer::Timestamp(col) => AnySlice::Timestamp(&col[0..valid_rows]),
+            AnyBuffer::Numeric { values, .. } => AnySlice::Numeric(&values[0..valid_rows]),
             AnyBuffer::F64(col) => AnySlice::F64(&col[0..valid_rows]),
             AnyBuffer::F32(col) => AnySlice::F32(&col[0..valid_rows]),
             AnyBuffer::I8(col) => AnySlice::I8(&col[0..valid_rows]),
@@ -591,6 +800,9 @@ unsafe impl ColumnBuffer for AnyBuffer {
             AnyBuffer::NullableDate(col) => AnySlice::NullableDate(col.iter(valid_rows)),
             AnyBuffer::NullableTime(col) => AnySlice::NullableTime(col.iter(valid_rows)),
             AnyBuffer::NullableTimestamp(col) => AnySlice::NullableTimestamp(col.iter(valid_rows)),
+            AnyBuffer::NullableNumeric { values, .. } => {
+                AnySlice::NullableNumeric(values.iter(valid_rows))
+            }
             AnyBuffer::NullableF64(col) => AnySlice::NullableF64(col.iter(valid_rows)),
             AnyBuffer::NullableF32(col) => AnySlice::NullableF32(col.iter(valid_rows)),
             AnyBuffer::NullableI8(col) => AnySlice::NullableI8(col.iter(valid_rows)),
@@ -605,12 +817,15 @@ unsafe impl ColumnBuffer for AnyBuffer {
     /// Fills the column with the default representation of values, between `from` and `to` index.
     fn fill_default(&mut self, from: usize, to: usize) {
         match self {
-            AnyBuffer::Binary(col) => col.fill_null(from, to),
-            AnyBuffer::Text(col) => col.fill_null(from, to),
+            AnyBuffer::Binary(col) | AnyBuffer::LongBinary(col) | AnyBuffer::Default(col) => {
+                col.fill_null(from, to)
+            }
+            AnyBuffer::Text(col) | AnyBuffer::LongText(col) => col.fill_null(from, to),
             AnyBuffer::WText(col) => col.fill_null(from, to),
             AnyBuffer::Date(col) => Self::fill_default_slice(&mut col[from..to]),
             AnyBuffer::Time(col) => Self::fill_default_slice(&mut col[from..to]),
             AnyBuffer::Timestamp(col) => Self::fill_default_slice(&mut col[from..to]),
+            AnyBuffer::Numeric { values, .. } => Self::fill_default_slice(&mut values[from..to]),
             AnyBuffer::F64(col) => Self::fill_default_slice(&mut col[from..to]),
             AnyBuffer::F32(col) => Self::fill_default_slice(&mut col[from..to]),
             AnyBuffer::I8(col) => Self::fill_default_slice(&mut col[from..to]),
@@ -622,6 +837,7 @@ unsafe impl ColumnBuffer for AnyBuffer {
             AnyBuffer::NullableDate(col) => col.fill_null(from, to),
             AnyBuffer::NullableTime(col) => col.fill_null(from, to),
             AnyBuffer::NullableTimestamp(col) => col.fill_null(from, to),
+            AnyBuffer::NullableNumeric { values, .. } => values.fill_null(from, to),
             AnyBuffer::NullableF64(col) => col.fill_null(from, to),
             AnyBuffer::NullableF32(col) => col.fill_null(from, to),
             AnyBuffer::NullableI8(col) => col.fill_null(from, to),
@@ -635,20 +851,195 @@ unsafe impl ColumnBuffer for AnyBuffer {
 
     fn has_truncated_values(&self, num_rows: usize) -> Option<Indicator> {
         match self {
-            AnyBuffer::Binary(col) => col.has_truncated_values(num_rows),
-            AnyBuffer::Text(col) => col.has_truncated_values(num_rows),
+            AnyBuffer::Binary(col) | AnyBuffer::LongBinary(col) | AnyBuffer::Default(col) => {
+                col.has_truncated_values(num_rows)
+            }
+            AnyBuffer::Text(col) | AnyBuffer::LongText(col) => col.has_truncated_values(num_rows),
             AnyBuffer::WText(col) => col.has_truncated_values(num_rows),
             _ => None,
         }
     }
+
+    unsafe fn fetch_truncated_values(
+        &mut self,
+        column_number: u16,
+        num_rows: usize,
+        mut cursor: StatementRef<'_>,
+    ) -> Result<bool, Error> {
+        // Remember truncated rows upfront. Growing the buffer changes the maximum length, and with
+        // it, which indicators would be considered truncated.
+        let truncated_rows: Vec<usize> = match self {
+            AnyBuffer::LongText(col) => (0..num_rows)
+                .filter(|&row_index| col.indicator_at(row_index).is_truncated(col.max_len()))
+                .collect(),
+            AnyBuffer::LongBinary(col) => (0..num_rows)
+                .filter(|&row_index| col.indicator_at(row_index).is_truncated(col.max_len()))
+                .collect(),
+            _ => return Ok(false),
+        };
+        let mut reallocated = false;
+        let mut value = Vec::new();
+        for row_index in truncated_rows {
+            // Always position the cursor explicitly. Fetching values of another long column may
+            // have moved it away from the first row of the row set.
+            position_for_get_data(&mut cursor, row_index)?;
+            let mut row = CursorRow::new(cursor.as_stmt_ref());
+            match self {
+                AnyBuffer::LongText(col) => {
+                    row.get_text(column_number, &mut value)
+                        .provide_context_for_diagnostic(get_data_extensions_not_supported)?;
+                    if value.len() > col.max_len() {
+                        col.resize_max_str(value.len(), num_rows);
+                        reallocated = true;
+                    }
+                    col.set_value(row_index, Some(&value));
+                }
+                AnyBuffer::LongBinary(col) => {
+                    row.get_binary(column_number, &mut value)
+                        .provide_context_for_diagnostic(get_data_extensions_not_supported)?;
+                    if value.len() > col.max_len() {
+                        col.resize_max_element_length(value.len(), num_rows);
+                        reallocated = true;
+                    }
+                    col.set_value(row_index, Some(&value));
+                }
+                _ => unreachable!("Only long columns have truncated rows to fetch"),
+            }
+        }
+        Ok(reallocated)
+    }
+
+    fn fetches_truncated_values(&self) -> bool {
+        matches!(self, AnyBuffer::LongText(_) | AnyBuffer::LongBinary(_))
+    }
+
+    unsafe fn bind_to_col(
+        &mut self,
+        column_number: u16,
+        cursor: &mut StatementRef<'_>,
+    ) -> Result<(), Error> {
+        if let AnyBuffer::Default(column) = self {
+            let sql_type = cursor.col_concise_type(column_number).into_result(cursor)?;
+            let required = default_c_type_min_len(sql_type);
+            if required.is_none_or(|required| column.max_len() < required) {
+                return Err(Error::DefaultCTypeBufferTooSmall {
+                    column_number,
+                    length: column.max_len(),
+                    required,
+                });
+            }
+        }
+        cursor.bind_col(column_number, self).into_result(cursor)?;
+        if !matches!(
+            self,
+            AnyBuffer::Numeric { .. } | AnyBuffer::NullableNumeric { .. }
+        ) {
+            return Ok(());
+        }
+        // Without adjusting the application row descriptor, drivers fill numeric structs using
+        // their default precision and a scale of zero, i.e. without any fractional digits.
+        let precision = cursor.col_precision(column_number).into_result(cursor)?;
+        let scale = cursor.col_scale(column_number).into_result(cursor)?;
+        let rec_number = column_number as i16;
+        let data_ptr = self.mut_value_ptr();
+        // Only keep the raw handle from the result, so the error can borrow the statement to
+        // fetch diagnostics.
+        let ard = cursor
+            .application_row_descriptor()
+            .map(|ard| ard.as_sys())
+            .into_result(cursor)?;
+        let mut ard = Descriptor::new(ard);
+        ard.set_type(rec_number, CDataType::Numeric)
+            .into_result(&ard)?;
+        ard.set_precision(rec_number, precision as i16)
+            .into_result(&ard)?;
+        ard.set_scale(rec_number, scale as i16).into_result(&ard)?;
+        // Setting any other field of the descriptor record unbinds the data pointer, so it is set
+        // last.
+        ard.set_data_ptr(rec_number, data_ptr).into_result(&ard)?;
+        Ok(())
+    }
+}
+
+/// Minimum number of bytes per value a buffer bound as `SQL_C_DEFAULT` must hold for a column of
+/// `sql_type`. Drivers write the fixed size default C types (e.g. `SQL_C_SLONG` for `INTEGER`)
+/// without regard for the buffer length. Character and binary types require no minimum, since
+/// their values are truncated to the buffer length. `None` if the default C type is not known.
+///
+/// See: <https://learn.microsoft.com/sql/odbc/reference/appendixes/c-data-types>
+fn default_c_type_min_len(sql_type: SqlDataType) -> Option<usize> {
+    let min_len = match sql_type {
+        SqlDataType::CHAR
+        | SqlDataType::VARCHAR
+        | SqlDataType::EXT_LONG_VARCHAR
+        | SqlDataType::EXT_W_CHAR
+        | SqlDataType::EXT_W_VARCHAR
+        | SqlDataType::EXT_W_LONG_VARCHAR
+        | SqlDataType::NUMERIC
+        | SqlDataType::DECIMAL
+        | SqlDataType::EXT_BINARY
+        | SqlDataType::EXT_VAR_BINARY
+        | SqlDataType::EXT_LONG_VAR_BINARY => 0,
+        SqlDataType::EXT_BIT | SqlDataType::EXT_TINY_INT => 1,
+        SqlDataType::SMALLINT => 2,
+        SqlDataType::INTEGER | SqlDataType::REAL => 4,
+        SqlDataType::EXT_BIG_INT | SqlDataType::FLOAT | SqlDataType::DOUBLE => 8,
+        SqlDataType::DATE => size_of::<Date>(),
+        SqlDataType::TIME => size_of::<Time>(),
+        SqlDataType::TIMESTAMP => size_of::<Timestamp>(),
+        // SQLGUID
+        SqlDataType::EXT_GUID => 16,
+        _ => return None,
+    };
+    Some(min_len)
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::buffers::{AnySlice, AnySliceMut, ColumnBuffer};
+    use crate::buffers::{AnySlice, AnySliceMut, BufferDesc, ColumnBuffer, ColumnarAnyBuffer};
 
     use super::AnyBuffer;
 
+    #[test]
+    fn views_yields_each_column_with_its_buffer_index() {
+        let buffer = ColumnarAnyBuffer::from_descs(
+            3,
+            [
+                BufferDesc::I32 { nullable: false },
+                BufferDesc::Text { max_str_len: 5 },
+                BufferDesc::F64 { nullable: true },
+            ],
+        );
+
+        let views: Vec<_> = buffer.views().collect();
+
+        assert_eq!(3, views.len());
+        assert!(matches!(views[0], (0, AnySlice::I32(_))));
+        assert!(matches!(views[1], (1, AnySlice::Text(_))));
+        assert!(matches!(views[2], (2, AnySlice::NullableF64(_))));
+    }
+
+    #[test]
+    fn saturating_buffer_caps_variable_sized_elements() {
+        let descs = [
+            BufferDesc::Text {
+                max_str_len: 1_000_000,
+            },
+            BufferDesc::I32 { nullable: false },
+            BufferDesc::WText { max_str_len: 10 },
+            BufferDesc::Binary { length: 500 },
+            BufferDesc::WText { max_str_len: 500 },
+        ];
+
+        let (buffer, capped) =
+            ColumnarAnyBuffer::try_from_descs_saturating(10, descs, 100).unwrap();
+
+        assert_eq!(vec![0, 3, 4], capped);
+        assert_eq!(100, buffer.column(0).as_text_view().unwrap().max_len());
+        assert_eq!(10, buffer.column(2).as_w_text_view().unwrap().max_len());
+        assert_eq!(50, buffer.column(4).as_w_text_view().unwrap().max_len());
+    }
+
     #[test]
     fn slice_should_only_contain_part_of_the_buffer() {
         let buffer = AnyBuffer::I32(vec![1, 2, 3]);
@@ -1,5 +1,8 @@
 use crate::{
-    buffers::Indicator,
+    buffers::{
+        zeroed::{try_zeroed_vec, zeroed_vec},
+        Indicator,
+    },
     columnar_bulk_inserter::BoundInputSlice,
     error::TooLargeBufferSize,
     handles::{CData, CDataMut, HasDataType, Statement, StatementRef},
@@ -30,18 +33,15 @@ impl BinColumn {
     /// have a maximum length of `element_size`. Uses a fallibale allocation for creating the
     /// buffer. In applications often the `element_size` of the buffer, might be directly inspired
     /// by the maximum size of the type, as reported, by ODBC. Which might get exceedingly large for
-    /// types like VARBINARY(MAX), or IMAGE. On the downside, this method is potentially slower than
-    /// new.
+    /// types like VARBINARY(MAX), or IMAGE.
     pub fn try_new(batch_size: usize, element_size: usize) -> Result<Self, TooLargeBufferSize> {
-        let len = element_size * batch_size;
-        let mut values = Vec::new();
-        values
-            .try_reserve_exact(len)
-            .map_err(|_| TooLargeBufferSize {
-                num_elements: batch_size,
-                element_size,
-            })?;
-        values.resize(len, 0);
+        let too_large = || TooLargeBufferSize {
+            num_elements: batch_size,
+            element_size,
+        };
+        let len = element_size.checked_mul(batch_size).ok_or_else(too_large)?;
+        // Requesting zeroed memory from the allocator is cheaper than writing each byte.
+        let values = try_zeroed_vec(len).ok_or_else(too_large)?;
         Ok(BinColumn {
             max_len: element_size,
             values,
@@ -53,9 +53,7 @@ impl BinColumn {
     /// have a maximum length of `max_len`.
     pub fn new(batch_size: usize, element_size: usize) -> Self {
         let len = element_size * batch_size;
-        let mut values = Vec::new();
-        values.reserve_exact(len);
-        values.resize(len, 0);
+        let values = zeroed_vec(len);
         BinColumn {
             max_len: element_size,
             values,
@@ -234,6 +232,28 @@ impl BinColumn {
         self.max_len = new_max_len;
     }
 
+    /// Reallocates the buffer, so the maximum element length matches the longest value within the
+    /// first `num_rows` rows. Use this to free up memory, if a batch of values much shorter than
+    /// the maximum element length is going to be kept around.
+    ///
+    /// Indicators are preserved. Truncated values fill the old buffer completely, so they keep it
+    /// from shrinking. Rows after `num_rows` are not preserved. The maximum element length does not
+    /// drop below one byte.
+    ///
+    /// # Parameters
+    ///
+    /// * `num_rows`: Number of valid rows currently stored in this buffer.
+    pub fn shrink_to_fit(&mut self, num_rows: usize) {
+        let new_max_len = (0..num_rows)
+            .filter_map(|row_index| self.content_length_at(row_index))
+            .max()
+            .unwrap_or(0)
+            .max(1);
+        if new_max_len < self.max_len {
+            self.resize_max_element_length(new_max_len, num_rows);
+        }
+    }
+
     /// Appends a new element to the column buffer. Rebinds the buffer to increase maximum element
     /// length should the input be too large.
     ///
@@ -448,4 +468,19 @@ mod test {
             }
         ))
     }
+
+    #[test]
+    fn shrink_to_fit_preserves_values() {
+        let mut column = BinColumn::new(3, 100);
+        column.set_value(0, Some(&[1, 2]));
+        column.set_value(1, None);
+        column.set_value(2, Some(&[3, 4, 5]));
+
+        column.shrink_to_fit(3);
+
+        assert_eq!(3, column.max_len());
+        assert_eq!(Some(&[1u8, 2][..]), column.value_at(0));
+        assert_eq!(None, column.value_at(1));
+        assert_eq!(Some(&[3u8, 4, 5][..]), column.value_at(2));
+    }
 }
@@ -1,8 +1,9 @@
 use crate::{
+    buffers::{ColumnBuffer, Indicator},
     fixed_sized::{Bit, Pod},
     handles::{CData, CDataMut},
 };
-use odbc_sys::{Date, Time, Timestamp, NULL_DATA};
+use odbc_sys::{Date, Numeric, Time, Timestamp, NULL_DATA};
 use std::{
     ffi::c_void,
     mem::size_of,
@@ -14,6 +15,7 @@ pub type OptF32Column = ColumnWithIndicator<f32>;
 pub type OptDateColumn = ColumnWithIndicator<Date>;
 pub type OptTimestampColumn = ColumnWithIndicator<Timestamp>;
 pub type OptTimeColumn = ColumnWithIndicator<Time>;
+pub type OptNumericColumn = ColumnWithIndicator<Numeric>;
 pub type OptI8Column = ColumnWithIndicator<i8>;
 pub type OptI16Column = ColumnWithIndicator<i16>;
 pub type OptI32Column = ColumnWithIndicator<i32>;
@@ -122,6 +124,19 @@ impl<'a, T> NullableSlice<'a, T> {
     pub fn raw_values(&self) -> (&'a [T], &'a [isize]) {
         (self.values, self.indicators)
     }
+
+    /// Reinterprets the values as elements of type `U`.
+    ///
+    /// # Safety
+    ///
+    /// `U` must have the same memory layout as `T`, e.g. be a `#[repr(transparent)]` wrapper of it.
+    #[cfg(feature = "chrono")]
+    pub(crate) unsafe fn cast<U>(self) -> NullableSlice<'a, U> {
+        NullableSlice {
+            indicators: self.indicators,
+            values: cast_slice(self.values),
+        }
+    }
 }
 
 impl<'a, T> Iterator for NullableSlice<'a, T> {
@@ -211,6 +226,40 @@ where
     }
 }
 
+unsafe impl<T> CData for &mut [T]
+where
+    T: Pod,
+{
+    fn cdata_type(&self) -> odbc_sys::CDataType {
+        T::C_DATA_TYPE
+    }
+
+    fn indicator_ptr(&self) -> *const isize {
+        null()
+    }
+
+    fn value_ptr(&self) -> *const c_void {
+        self.as_ptr() as *const c_void
+    }
+
+    fn buffer_length(&self) -> isize {
+        size_of::<T>().try_into().unwrap()
+    }
+}
+
+unsafe impl<T> CDataMut for &mut [T]
+where
+    T: Pod,
+{
+    fn mut_indicator_ptr(&mut self) -> *mut isize {
+        null_mut()
+    }
+
+    fn mut_value_ptr(&mut self) -> *mut c_void {
+        self.as_mut_ptr() as *mut c_void
+    }
+}
+
 /// Used to fill a column buffer with an iterator. Returned by
 /// [`crate::ColumnarBulkInserter::column_mut`] as part of an [`crate::buffers::AnySliceMut`].
 #[derive(Debug)]
@@ -276,12 +325,193 @@ impl<T> NullableSliceMut<'_, T> {
     }
 }
 
+impl<'a, T> NullableSliceMut<'a, T> {
+    /// Wraps storage owned by the caller, e.g. a region of an arena or a memory mapped file. This
+    /// allows binding it as a nullable column of a [`crate::buffers::ColumnarBuffer`]. All values
+    /// are initialized as `NULL`.
+    ///
+    /// ```
+    /// use odbc_api::{buffers::{ColumnarBuffer, NullableSliceMut}, Cursor, Error};
+    ///
+    /// fn fetch_into_arena(
+    ///     cursor: impl Cursor,
+    ///     values: &mut [i64],
+    ///     indicators: &mut [isize],
+    /// ) -> Result<(), Error> {
+    ///     let column = NullableSliceMut::new(values, indicators);
+    ///     let mut block_cursor = cursor.bind_buffer(ColumnarBuffer::new(vec![(1, column)]))?;
+    ///     while let Some(batch) = block_cursor.fetch()? {
+    ///         println!("{:?}", batch.column(0).collect::<Vec<_>>());
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If `values` and `indicators` differ in length.
+    pub fn new(values: &'a mut [T], indicators: &'a mut [isize]) -> Self {
+        assert_eq!(
+            values.len(),
+            indicators.len(),
+            "There must be exactly one indicator for each value."
+        );
+        indicators.fill(NULL_DATA);
+        Self { indicators, values }
+    }
+
+    /// Reinterprets the values as elements of type `U`.
+    ///
+    /// # Safety
+    ///
+    /// `U` must have the same memory layout as `T`, e.g. be a `#[repr(transparent)]` wrapper of it.
+    #[cfg(feature = "chrono")]
+    pub(crate) unsafe fn cast<U>(self) -> NullableSliceMut<'a, U> {
+        NullableSliceMut {
+            indicators: self.indicators,
+            values: cast_slice_mut(self.values),
+        }
+    }
+}
+
 impl<T> NullableSliceMut<'_, T> {
     /// Writes the elements returned by the iterator into the buffer, starting at the beginning.
     /// Writes elements until the iterator returns `None` or the buffer can not hold more elements.
-    pub fn write(&mut self, it: impl Iterator<Item = Option<T>>) {
+    /// `None` items are written as `NULL`.
+    ///
+    /// ```
+    /// use odbc_api::buffers::NullableSliceMut;
+    ///
+    /// fn fill_ages(column: &mut NullableSliceMut<i32>, ages: &[Option<i32>]) {
+    ///     column.write_opt(ages.iter().copied());
+    /// }
+    /// ```
+    pub fn write_opt(&mut self, it: impl Iterator<Item = Option<T>>) {
         for (index, item) in it.enumerate().take(self.values.len()) {
             self.set_cell(index, item)
         }
     }
+
+    /// Same as [`Self::write_opt`].
+    pub fn write(&mut self, it: impl Iterator<Item = Option<T>>) {
+        self.write_opt(it)
+    }
+}
+
+unsafe impl<T> CData for NullableSliceMut<'_, T>
+where
+    T: Pod,
+{
+    fn cdata_type(&self) -> odbc_sys::CDataType {
+        T::C_DATA_TYPE
+    }
+
+    fn indicator_ptr(&self) -> *const isize {
+        self.indicators.as_ptr()
+    }
+
+    fn value_ptr(&self) -> *const c_void {
+        self.values.as_ptr() as *const c_void
+    }
+
+    fn buffer_length(&self) -> isize {
+        size_of::<T>().try_into().unwrap()
+    }
+}
+
+unsafe impl<T> CDataMut for NullableSliceMut<'_, T>
+where
+    T: Pod,
+{
+    fn mut_indicator_ptr(&mut self) -> *mut isize {
+        self.indicators.as_mut_ptr()
+    }
+
+    fn mut_value_ptr(&mut self) -> *mut c_void {
+        self.values.as_mut_ptr() as *mut c_void
+    }
+}
+
+/// Binds nullable values to memory owned by the caller. See [`NullableSliceMut::new`].
+unsafe impl<T> ColumnBuffer for NullableSliceMut<'_, T>
+where
+    T: Pod,
+{
+    type View<'a>
+        = NullableSlice<'a, T>
+    where
+        Self: 'a;
+
+    fn view(&self, valid_rows: usize) -> NullableSlice<'_, T> {
+        NullableSlice {
+            indicators: &self.indicators[..valid_rows],
+            values: &self.values[..valid_rows],
+        }
+    }
+
+    fn fill_default(&mut self, from: usize, to: usize) {
+        self.indicators[from..to].fill(NULL_DATA);
+    }
+
+    fn capacity(&self) -> usize {
+        self.values.len()
+    }
+
+    fn has_truncated_values(&self, _num_rows: usize) -> Option<Indicator> {
+        None
+    }
+}
+
+/// Reinterprets a slice of `T` as a slice of `U`.
+///
+/// # Safety
+///
+/// `U` must have the same memory layout as `T`.
+#[cfg(feature = "chrono")]
+pub(crate) unsafe fn cast_slice<T, U>(values: &[T]) -> &[U] {
+    debug_assert_eq!(size_of::<T>(), size_of::<U>());
+    std::slice::from_raw_parts(values.as_ptr() as *const U, values.len())
+}
+
+/// Reinterprets a mutable slice of `T` as a mutable slice of `U`.
+///
+/// # Safety
+///
+/// `U` must have the same memory layout as `T`.
+#[cfg(feature = "chrono")]
+pub(crate) unsafe fn cast_slice_mut<T, U>(values: &mut [T]) -> &mut [U] {
+    debug_assert_eq!(size_of::<T>(), size_of::<U>());
+    std::slice::from_raw_parts_mut(values.as_mut_ptr() as *mut U, values.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::buffers::ColumnBuffer;
+
+    use super::{ColumnWithIndicator, NullableSliceMut};
+
+    #[test]
+    fn write_values_and_nulls_into_nullable_column() {
+        let mut column = ColumnWithIndicator::<i32>::new(4);
+
+        let mut writer = column.writer_n(4);
+        writer.write_opt([Some(1), None, Some(3), None].into_iter());
+        writer.set_cell(3, Some(4));
+
+        let actual: Vec<_> = column.iter(4).map(|v| v.copied()).collect();
+        assert_eq!(vec![Some(1), None, Some(3), Some(4)], actual);
+    }
+
+    #[test]
+    fn nullable_column_over_caller_provided_storage() {
+        let mut values = [0i32; 3];
+        let mut indicators = [0isize; 3];
+
+        let mut column = NullableSliceMut::new(&mut values, &mut indicators);
+        column.set_cell(1, Some(42));
+
+        let actual: Vec<_> = column.view(3).map(|v| v.copied()).collect();
+        assert_eq!(vec![None, Some(42), None], actual);
+        assert_eq!(3, column.capacity());
+    }
 }
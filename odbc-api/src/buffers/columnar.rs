@@ -4,17 +4,19 @@ use std::{
     str::{from_utf8, Utf8Error},
 };
 
+use widestring::U16Str;
+
 use crate::{
     columnar_bulk_inserter::BoundInputSlice,
     cursor::TruncationInfo,
     fixed_sized::Pod,
-    handles::{CDataMut, Statement, StatementRef},
+    handles::{AsStatementRef, CDataMut, Statement, StatementRef},
     parameter::WithDataType,
-    result_set_metadata::utf8_display_sizes,
-    Error, ResultSetMetadata, RowSetBuffer,
+    result_set_metadata::{utf16_display_sizes, utf8_display_sizes},
+    ColumnDescription, Error, ResultSetMetadata, RowSetBuffer,
 };
 
-use super::{Indicator, TextColumn};
+use super::{zeroed::Zeroable, Indicator, TextColumn};
 
 impl<C: ColumnBuffer> ColumnarBuffer<C> {
     /// Create a new instance from columns with unique indicies. Capacity of the buffer will be the
@@ -80,6 +82,34 @@ impl<C: ColumnBuffer> ColumnarBuffer<C> {
     pub fn column(&self, buffer_index: usize) -> C::View<'_> {
         self.columns[buffer_index].1.view(*self.num_rows)
     }
+
+    /// Iterates over the views of all columns in the buffer, each paired with its zero based
+    /// buffer index. Saves you from calling [`Self::column`] in a loop over [`Self::num_cols`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use odbc_api::buffers::{AnySlice, BufferDesc, ColumnarAnyBuffer};
+    ///
+    /// let buffer = ColumnarAnyBuffer::from_descs(
+    ///     10,
+    ///     [BufferDesc::I32 { nullable: false }, BufferDesc::Text { max_str_len: 5 }],
+    /// );
+    /// for (buffer_index, view) in buffer.views() {
+    ///     match view {
+    ///         AnySlice::I32(values) => println!("Column {buffer_index}: {values:?}"),
+    ///         AnySlice::Text(texts) => println!("Column {buffer_index}: {} texts", texts.len()),
+    ///         _ => println!("Column {buffer_index}: Other type"),
+    ///     }
+    /// }
+    /// ```
+    pub fn views(&self) -> impl ExactSizeIterator<Item = (usize, C::View<'_>)> + '_ {
+        let num_rows = *self.num_rows;
+        self.columns
+            .iter()
+            .enumerate()
+            .map(move |(buffer_index, (_col_index, column))| (buffer_index, column.view(num_rows)))
+    }
 }
 
 unsafe impl<C> RowSetBuffer for ColumnarBuffer<C>
@@ -100,7 +130,7 @@ where
 
     unsafe fn bind_colmuns_to_cursor(&mut self, mut cursor: StatementRef<'_>) -> Result<(), Error> {
         for (col_number, column) in &mut self.columns {
-            cursor.bind_col(*col_number, column).into_result(&cursor)?;
+            column.bind_to_col(*col_number, &mut cursor)?;
         }
         Ok(())
     }
@@ -118,6 +148,38 @@ where
                     })
             })
     }
+
+    fn find_all_truncations(&self) -> Vec<TruncationInfo> {
+        self.columns
+            .iter()
+            .enumerate()
+            .filter_map(|(buffer_index, (_col_index, col_buffer))| {
+                col_buffer
+                    .has_truncated_values(*self.num_rows)
+                    .map(|indicator| TruncationInfo {
+                        indicator: indicator.length(),
+                        buffer_index,
+                    })
+            })
+            .collect()
+    }
+
+    fn completes_row_sets(&self) -> bool {
+        self.columns
+            .iter()
+            .any(|(_col_number, column)| column.fetches_truncated_values())
+    }
+
+    unsafe fn complete_row_set(&mut self, mut cursor: StatementRef<'_>) -> Result<(), Error> {
+        for (col_number, column) in &mut self.columns {
+            let reallocated =
+                column.fetch_truncated_values(*col_number, *self.num_rows, cursor.as_stmt_ref())?;
+            if reallocated {
+                column.bind_to_col(*col_number, &mut cursor)?;
+            }
+        }
+        Ok(())
+    }
 }
 
 /// A columnar buffer intended to be bound with [crate::Cursor::bind_buffer] in order to obtain
@@ -259,6 +321,52 @@ pub unsafe trait ColumnBuffer: CDataMut {
     /// not being able to hold elements of that size. This method checks the indicator buffer
     /// element wise.
     fn has_truncated_values(&self, num_rows: usize) -> Option<Indicator>;
+
+    /// Called after a row set has been fetched into the first `num_rows` rows of the column. Column
+    /// buffers for long values use this to retrieve values truncated by the bulk fetch using
+    /// `SQLGetData`. The default implementation does nothing.
+    ///
+    /// # Return
+    ///
+    /// `true` if the buffer has been reallocated in the process, and must therefore be bound to
+    /// the cursor again.
+    ///
+    /// # Safety
+    ///
+    /// `cursor` must be positioned on the row set which has just been fetched into this column and
+    /// `column_number` must be the one based index of the result set column the buffer is bound to.
+    unsafe fn fetch_truncated_values(
+        &mut self,
+        _column_number: u16,
+        _num_rows: usize,
+        _cursor: StatementRef<'_>,
+    ) -> Result<bool, Error> {
+        Ok(false)
+    }
+
+    /// `true` if [`Self::fetch_truncated_values`] may call functions on the cursor. The default
+    /// implementation returns `false`.
+    fn fetches_truncated_values(&self) -> bool {
+        false
+    }
+
+    /// Binds the buffer to the column `column_number` of the result set. The default
+    /// implementation uses `SQLBindCol`. Buffers which need to adjust further fields of the
+    /// application row descriptor (e.g. precision and scale) can do so here.
+    ///
+    /// # Safety
+    ///
+    /// The buffer must outlive the binding, or be unbound before it is dropped or moved.
+    unsafe fn bind_to_col(
+        &mut self,
+        column_number: u16,
+        cursor: &mut StatementRef<'_>,
+    ) -> Result<(), Error>
+    where
+        Self: Sized,
+    {
+        cursor.bind_col(column_number, self).into_result(cursor)
+    }
 }
 
 unsafe impl<T> ColumnBuffer for WithDataType<T>
@@ -282,6 +390,28 @@ where
     fn has_truncated_values(&self, num_rows: usize) -> Option<Indicator> {
         self.value.has_truncated_values(num_rows)
     }
+
+    unsafe fn fetch_truncated_values(
+        &mut self,
+        column_number: u16,
+        num_rows: usize,
+        cursor: StatementRef<'_>,
+    ) -> Result<bool, Error> {
+        self.value
+            .fetch_truncated_values(column_number, num_rows, cursor)
+    }
+
+    fn fetches_truncated_values(&self) -> bool {
+        self.value.fetches_truncated_values()
+    }
+
+    unsafe fn bind_to_col(
+        &mut self,
+        column_number: u16,
+        cursor: &mut StatementRef<'_>,
+    ) -> Result<(), Error> {
+        self.value.bind_to_col(column_number, cursor)
+    }
 }
 
 unsafe impl<'a, T> BoundInputSlice<'a> for WithDataType<T>
@@ -402,38 +532,60 @@ impl TextRowSet {
         cursor: &mut impl ResultSetMetadata,
         max_str_limit: Option<usize>,
     ) -> Result<TextRowSet, Error> {
-        let buffers = utf8_display_sizes(cursor)?
-            .enumerate()
-            .map(|(buffer_index, reported_len)| {
-                let buffer_index = buffer_index as u16;
-                let col_index = buffer_index + 1;
-                let max_str_len = reported_len?;
-                let buffer = if let Some(upper_bound) = max_str_limit {
-                    let max_str_len = max_str_len
-                        .map(NonZeroUsize::get)
-                        .unwrap_or(upper_bound)
-                        .min(upper_bound);
-                    TextColumn::new(batch_size, max_str_len)
-                } else {
-                    let max_str_len = max_str_len.map(NonZeroUsize::get).ok_or(
-                        Error::TooLargeColumnBufferSize {
-                            buffer_index,
-                            num_elements: batch_size,
-                            element_size: usize::MAX,
-                        },
-                    )?;
-                    TextColumn::try_new(batch_size, max_str_len).map_err(|source| {
-                        Error::TooLargeColumnBufferSize {
-                            buffer_index,
-                            num_elements: source.num_elements,
-                            element_size: source.element_size,
-                        }
-                    })?
-                };
+        let buffers = text_columns(batch_size, utf8_display_sizes(cursor)?, max_str_limit)?;
+        Ok(TextRowSet {
+            row_capacity: batch_size,
+            num_rows: Box::new(0),
+            columns: buffers,
+        })
+    }
 
-                Ok::<_, Error>((col_index, buffer))
-            })
-            .collect::<Result<_, _>>()?;
+    /// Same as [`Self::for_cursor`], but derives the buffer sizes from `descriptions` instead of
+    /// querying the driver for each column. Useful for applications which already obtained the
+    /// descriptions of all columns, e.g. to export the schema, so they do not pay for the metadata
+    /// round trips twice. Element sizes are chosen using [`crate::DataType::utf8_len`]. For types
+    /// without a known maximum length (e.g. `LONGVARCHAR`), the element size is `max_str_limit`.
+    ///
+    /// `descriptions` must hold exactly one element for each column of the result set, ordered
+    /// like the columns. Otherwise [`Error::ColumnDescriptionCountMismatch`] is returned.
+    ///
+    /// ```
+    /// use odbc_api::{buffers::TextRowSet, ColumnDescription, Cursor, Error, ResultSetMetadata};
+    ///
+    /// fn bind_text(mut cursor: impl Cursor) -> Result<(), Error> {
+    ///     let num_cols = cursor.num_result_cols()? as u16;
+    ///     let mut descriptions = Vec::new();
+    ///     for col_index in 1..=num_cols {
+    ///         let mut desc = ColumnDescription::default();
+    ///         cursor.describe_col(col_index, &mut desc)?;
+    ///         descriptions.push(desc);
+    ///     }
+    ///     // ... export schema using descriptions ...
+    ///     let buffer = TextRowSet::for_cursor_with_desc(100, &mut cursor, &descriptions, Some(4096))?;
+    ///     let mut cursor = cursor.bind_buffer(buffer)?;
+    ///     while let Some(batch) = cursor.fetch()? {
+    ///         // ... process values in batch ...
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn for_cursor_with_desc(
+        batch_size: usize,
+        cursor: &mut impl ResultSetMetadata,
+        descriptions: &[ColumnDescription],
+        max_str_limit: Option<usize>,
+    ) -> Result<TextRowSet, Error> {
+        let num_columns: usize = cursor.num_result_cols()?.try_into().unwrap();
+        if num_columns != descriptions.len() {
+            return Err(Error::ColumnDescriptionCountMismatch {
+                num_columns,
+                num_descriptions: descriptions.len(),
+            });
+        }
+        let reported_lens = descriptions
+            .iter()
+            .map(|desc| Ok(desc.data_type.utf8_len()));
+        let buffers = text_columns(batch_size, reported_lens, max_str_limit)?;
         Ok(TextRowSet {
             row_capacity: batch_size,
             num_rows: Box::new(0),
@@ -441,6 +593,32 @@ impl TextRowSet {
         })
     }
 
+    /// Same as [`Self::for_cursor`], but also returns the names of the columns of the result set.
+    /// Useful e.g. for writing the header of a CSV file, before writing its rows.
+    ///
+    /// ```
+    /// use odbc_api::{buffers::TextRowSet, Cursor, Error};
+    ///
+    /// fn print_all_values(mut cursor: impl Cursor) -> Result<(), Error> {
+    ///     let (buffer, names) = TextRowSet::for_cursor_with_names(100, &mut cursor, Some(4096))?;
+    ///     println!("{}", names.join(","));
+    ///     let mut cursor = cursor.bind_buffer(buffer)?;
+    ///     while let Some(batch) = cursor.fetch()? {
+    ///         // ... print values in batch ...
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn for_cursor_with_names(
+        batch_size: usize,
+        cursor: &mut impl ResultSetMetadata,
+        max_str_limit: Option<usize>,
+    ) -> Result<(TextRowSet, Vec<String>), Error> {
+        let names = cursor.column_names()?.collect::<Result<Vec<_>, _>>()?;
+        let buffer = Self::for_cursor(batch_size, cursor, max_str_limit)?;
+        Ok((buffer, names))
+    }
+
     /// Creates a text buffer large enough to hold `batch_size` rows with one column for each item
     /// `max_str_lengths` of respective size.
     pub fn from_max_str_lens(
@@ -453,7 +631,7 @@ impl TextRowSet {
             .map(|(index, max_str_len)| {
                 Ok::<_, Error>((
                     (index + 1).try_into().unwrap(),
-                    TextColumn::try_new(row_capacity, max_str_len)
+                    TextColumn::try_new_zeroed(row_capacity, max_str_len)
                         .map_err(|source| source.add_context(index.try_into().unwrap()))?,
                 ))
             })
@@ -506,6 +684,165 @@ impl TextRowSet {
     pub fn max_len(&self, buf_index: usize) -> usize {
         self.columns[buf_index].1.max_len()
     }
+
+    /// Shrinks the maximum string length of each column to the longest value it currently holds.
+    /// See [`TextColumn::shrink_to_fit`].
+    pub fn shrink_to_fit(&mut self) {
+        for (_, column) in &mut self.columns {
+            column.shrink_to_fit(*self.num_rows);
+        }
+    }
+}
+
+/// A columnar buffer intended to be bound with [crate::Cursor::bind_buffer] in order to obtain
+/// results from a cursor. In contrast to [`TextRowSet`] values are bound as wide (utf-16) text.
+/// Use this on platforms there the narrow encoding of the driver manager is not utf-8 (e.g.
+/// Windows), or for schemas with many `NVARCHAR` columns, to avoid the driver converting the values
+/// into a narrow, possibly lossy, encoding.
+///
+/// ```
+/// use odbc_api::{buffers::WTextRowSet, Cursor, Error};
+///
+/// fn print_all_values(mut cursor: impl Cursor) -> Result<(), Error> {
+///     let buffer = WTextRowSet::for_cursor(100, &mut cursor, Some(4096))?;
+///     let mut row_set_cursor = cursor.bind_buffer(buffer)?;
+///     while let Some(batch) = row_set_cursor.fetch()? {
+///         for row_index in 0..batch.num_rows() {
+///             let row: Vec<String> = (0..batch.num_cols())
+///                 .map(|col_index| batch.at_as_string(col_index, row_index).unwrap_or_default())
+///                 .collect();
+///             println!("{}", row.join(","));
+///         }
+///     }
+///     Ok(())
+/// }
+/// ```
+pub type WTextRowSet = ColumnarBuffer<TextColumn<u16>>;
+
+impl WTextRowSet {
+    /// The resulting text buffer is not in any way tied to the cursor, other than that its buffer
+    /// sizes a tailor fitted to result set the cursor is iterating over.
+    ///
+    /// # Parameters
+    ///
+    /// * `batch_size`: The maximum number of rows the buffer is able to hold.
+    /// * `cursor`: Used to query the display size for each column of the row set. For character
+    ///   data the length in characters is multiplied by 2 in order to have enough space for
+    ///   characters represented by surrogate pairs.
+    /// * `max_str_limit`: Upper bound for the length of character data in `u16` code units. Any
+    ///   size reported by the driver is capped to this value. In case the upper bound can not
+    ///   inferred by the metadata reported by the driver the element size is set to this upper
+    ///   bound, too.
+    pub fn for_cursor(
+        batch_size: usize,
+        cursor: &mut impl ResultSetMetadata,
+        max_str_limit: Option<usize>,
+    ) -> Result<WTextRowSet, Error> {
+        let buffers = text_columns(batch_size, utf16_display_sizes(cursor)?, max_str_limit)?;
+        Ok(WTextRowSet {
+            row_capacity: batch_size,
+            num_rows: Box::new(0),
+            columns: buffers,
+        })
+    }
+
+    /// Creates a text buffer large enough to hold `batch_size` rows with one column for each item
+    /// `max_str_lengths` of respective size in `u16` code units.
+    pub fn from_max_str_lens(
+        row_capacity: usize,
+        max_str_lengths: impl IntoIterator<Item = usize>,
+    ) -> Result<Self, Error> {
+        let buffers = max_str_lengths
+            .into_iter()
+            .enumerate()
+            .map(|(index, max_str_len)| {
+                Ok::<_, Error>((
+                    (index + 1).try_into().unwrap(),
+                    TextColumn::try_new_zeroed(row_capacity, max_str_len)
+                        .map_err(|source| source.add_context(index.try_into().unwrap()))?,
+                ))
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(WTextRowSet {
+            row_capacity,
+            num_rows: Box::new(0),
+            columns: buffers,
+        })
+    }
+
+    /// Access the element at the specified position in the row set.
+    pub fn at(&self, buffer_index: usize, row_index: usize) -> Option<&[u16]> {
+        assert!(row_index < *self.num_rows);
+        self.columns[buffer_index].1.value_at(row_index)
+    }
+
+    /// Access the element at the specified position in the row set.
+    pub fn at_as_u16str(&self, col_index: usize, row_index: usize) -> Option<&U16Str> {
+        self.at(col_index, row_index).map(U16Str::from_slice)
+    }
+
+    /// Access the element at the specified position in the row set, decoded into an owned
+    /// `String`. Invalid utf-16 is replaced with the unicode replacement character.
+    pub fn at_as_string(&self, col_index: usize, row_index: usize) -> Option<String> {
+        self.at(col_index, row_index).map(String::from_utf16_lossy)
+    }
+
+    /// Indicator value at the specified position. Useful to detect truncation of data. See
+    /// [`TextRowSet::indicator_at`].
+    pub fn indicator_at(&self, buf_index: usize, row_index: usize) -> Indicator {
+        assert!(row_index < *self.num_rows);
+        self.columns[buf_index].1.indicator_at(row_index)
+    }
+
+    /// Maximum length in `u16` code units of elements in a column.
+    pub fn max_len(&self, buf_index: usize) -> usize {
+        self.columns[buf_index].1.max_len()
+    }
+}
+
+/// Allocates one text column for each reported maximum string length. Shared between
+/// [`TextRowSet::for_cursor`] and [`WTextRowSet::for_cursor`].
+fn text_columns<C>(
+    batch_size: usize,
+    reported_lens: impl Iterator<Item = Result<Option<NonZeroUsize>, Error>>,
+    max_str_limit: Option<usize>,
+) -> Result<Vec<(u16, TextColumn<C>)>, Error>
+where
+    C: Zeroable,
+{
+    reported_lens
+        .enumerate()
+        .map(|(buffer_index, reported_len)| {
+            let buffer_index = buffer_index as u16;
+            let col_index = buffer_index + 1;
+            let max_str_len = reported_len?;
+            let buffer = if let Some(upper_bound) = max_str_limit {
+                let max_str_len = max_str_len
+                    .map(NonZeroUsize::get)
+                    .unwrap_or(upper_bound)
+                    .min(upper_bound);
+                TextColumn::new_zeroed(batch_size, max_str_len)
+            } else {
+                let max_str_len =
+                    max_str_len
+                        .map(NonZeroUsize::get)
+                        .ok_or(Error::TooLargeColumnBufferSize {
+                            buffer_index,
+                            num_elements: batch_size,
+                            element_size: usize::MAX,
+                        })?;
+                TextColumn::try_new_zeroed(batch_size, max_str_len).map_err(|source| {
+                    Error::TooLargeColumnBufferSize {
+                        buffer_index,
+                        num_elements: source.num_elements,
+                        element_size: source.element_size,
+                    }
+                })?
+            };
+
+            Ok::<_, Error>((col_index, buffer))
+        })
+        .collect()
 }
 
 unsafe impl<T> ColumnBuffer for Vec<T>
@@ -533,6 +870,65 @@ where
     }
 }
 
+/// Binds memory owned by the caller, rather than by the buffer. This allows fetching into storage
+/// allocated elsewhere, e.g. a region of an arena or a memory mapped file. The borrow checker
+/// ensures the storage outlives the [`ColumnarBuffer`] and therefore the binding. Alignment is
+/// guaranteed by the slice type, so raw bytes need to be converted into a slice of `T` first, e.g.
+/// using [`slice::align_to_mut`].
+///
+/// Mutable slices bind columns of fixed size values, which are not nullable. Use
+/// [`crate::buffers::NullableSliceMut::new`] for nullable columns. Text and binary columns, as well
+/// as [`crate::buffers::ColumnarAnyBuffer`], always own their storage.
+///
+/// # Example
+///
+/// ```
+/// use odbc_api::{buffers::ColumnarBuffer, Cursor, Error};
+///
+/// /// Fetches the first two columns into `arena`, `batch_size` rows at a time.
+/// fn fetch_into_arena(
+///     cursor: impl Cursor,
+///     arena: &mut [i64],
+///     batch_size: usize,
+/// ) -> Result<(), Error> {
+///     let (first, rest) = arena.split_at_mut(batch_size);
+///     let second = &mut rest[..batch_size];
+///     let buffer = ColumnarBuffer::new(vec![(1, first), (2, second)]);
+///     let mut block_cursor = cursor.bind_buffer(buffer)?;
+///     while let Some(batch) = block_cursor.fetch()? {
+///         println!("{:?} {:?}", batch.column(0), batch.column(1));
+///     }
+///     Ok(())
+/// }
+/// ```
+unsafe impl<T> ColumnBuffer for &mut [T]
+where
+    T: Pod,
+{
+    type View<'a>
+        = &'a [T]
+    where
+        Self: 'a;
+
+    fn view(&self, valid_rows: usize) -> &[T] {
+        &self[..valid_rows]
+    }
+
+    fn fill_default(&mut self, from: usize, to: usize) {
+        for item in &mut self[from..to] {
+            *item = Default::default();
+        }
+    }
+
+    fn capacity(&self) -> usize {
+        self.len()
+    }
+
+    fn has_truncated_values(&self, _num_rows: usize) -> Option<Indicator> {
+        None
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -1,9 +1,16 @@
-use std::mem::size_of;
+use std::{
+    fmt::{self, Display, Formatter},
+    mem::size_of,
+};
 
-use odbc_sys::{Date, Time, Timestamp};
+use odbc_sys::{Date, Numeric, Time, Timestamp};
 
 use crate::{Bit, DataType};
 
+/// Maximum number of bytes bound per value for [`BufferDesc::LongText`] and
+/// [`BufferDesc::LongBinary`]. Longer values are retrieved using `SQLGetData` after the fetch.
+pub(crate) const LONG_DATA_CHUNK_SIZE: usize = 1024;
+
 /// Describes a column of a [`crate::buffers::ColumnarBuffer`].
 ///
 /// While related to to the [`crate::DataType`] of the column this is bound to, the Buffer type is
@@ -42,6 +49,42 @@ pub enum BufferDesc {
         /// implicitly allocated if required.
         max_str_len: usize,
     },
+    /// Text in the system encoding of arbitrary length, e.g. for `VARCHAR(max)` or `TEXT` columns,
+    /// whose values are not bounded by the schema. The buffer binds a moderately sized chunk for
+    /// each value. Values not fitting into it are retrieved in full using `SQLGetData` after each
+    /// fetch, growing the buffer as needed. This avoids truncation without guessing an upper bound
+    /// for the values, but it requires the driver to support `SQLGetData` for bound columns
+    /// (`SQL_GD_BOUND`) and, for fetch sizes larger than one, for block cursors (`SQL_GD_BLOCK`).
+    /// Otherwise fetching a value larger than the chunk fails with
+    /// [`crate::Error::GetDataExtensionsNotSupported`]. Since values are retrieved after the
+    /// fetch, buffers with long columns can not be bound to a [`crate::CursorPolling`].
+    ///
+    /// Values are accessed like values of a [`BufferDesc::Text`] buffer.
+    LongText,
+    /// Binary data of arbitrary length, e.g. for `VARBINARY(max)` columns. Behaves like
+    /// [`BufferDesc::LongText`], but values are accessed like values of a [`BufferDesc::Binary`]
+    /// buffer.
+    LongBinary,
+    /// Binds the column as `SQL_C_DEFAULT`, leaving the choice of the C type to the driver. The
+    /// driver picks the type based on the SQL type of the column, e.g. a 32 bit integer for an
+    /// `INTEGER` column. Values are accessed as raw bytes, like values of a [`BufferDesc::Binary`]
+    /// buffer, and it is up to the application to interpret them. Choose `length` using the octet
+    /// length reported for the column, see [`crate::ResultSetMetadata::col_octet_length`].
+    ///
+    /// The driver writes values of fixed size types (e.g. integers, floats, dates or timestamps)
+    /// regardless of `length`. Binding therefore fails with
+    /// [`crate::Error::DefaultCTypeBufferTooSmall`] if `length` is smaller than the default C type
+    /// of the column, or if the default C type of the column is not known (e.g. for driver
+    /// specific SQL types).
+    ///
+    /// This is mostly useful for passing values through to another system using the same type
+    /// mapping as the driver. Be aware that `SQL_C_DEFAULT` is not portable: the C type chosen
+    /// depends on the driver, and some drivers reject it altogether. Prefer binding an explicit
+    /// type if you know the type of the column.
+    Default {
+        /// Number of bytes per value.
+        length: usize,
+    },
     /// 64 bit floating point
     F64 {
         /// This indicates whether or not the buffer will be able to represent NULL values. This will
@@ -72,6 +115,22 @@ pub enum BufferDesc {
         /// cause an indicator buffer to be bound.
         nullable: bool,
     },
+    /// Describes a buffer holding [`crate::sys::Numeric`] values. Bound to a result set column,
+    /// precision and scale are taken from the metadata of the column, so `DECIMAL` and `NUMERIC`
+    /// columns can be fetched without loss of precision. Bound as a parameter, the buffer is
+    /// declared with `precision` and `scale`, and the driver interprets the `val` of each struct
+    /// using them. The `precision` and `scale` members of the individual structs are ignored in
+    /// this case.
+    Numeric {
+        /// Total number of significant digits. Only used if the buffer is bound as a parameter.
+        precision: usize,
+        /// Number of digits to the right of the decimal point. Only used if the buffer is bound as
+        /// a parameter.
+        scale: i16,
+        /// This indicates whether or not the buffer will be able to represent NULL values. This will
+        /// cause an indicator buffer to be bound.
+        nullable: bool,
+    },
     /// Signed 8 Bit integer
     I8 {
         /// This indicates whether or not the buffer will be able to represent NULL values. This will
@@ -152,18 +211,24 @@ impl BufferDesc {
     }
 
     /// Element size of buffer if bound as a columnar row. Can be used to estimate memory for
-    /// columnar bindings.
+    /// columnar bindings. For nullable buffers (see [`Self::is_nullable`]) this includes the size of
+    /// the indicator (an `isize`) stored alongside each value.
     pub fn bytes_per_row(&self) -> usize {
         let size_indicator = |nullable: bool| if nullable { size_of::<isize>() } else { 0 };
         match *self {
             BufferDesc::Binary { length } => length + size_indicator(true),
             BufferDesc::Text { max_str_len } => max_str_len + 1 + size_indicator(true),
             BufferDesc::WText { max_str_len } => (max_str_len + 1) * 2 + size_indicator(true),
+            // Estimate based on the chunk size. Larger values grow the buffer.
+            BufferDesc::LongText => LONG_DATA_CHUNK_SIZE + 1 + size_indicator(true),
+            BufferDesc::LongBinary => LONG_DATA_CHUNK_SIZE + size_indicator(true),
+            BufferDesc::Default { length } => length + size_indicator(true),
             BufferDesc::F64 { nullable } => size_of::<f64>() + size_indicator(nullable),
             BufferDesc::F32 { nullable } => size_of::<f32>() + size_indicator(nullable),
             BufferDesc::Date { nullable } => size_of::<Date>() + size_indicator(nullable),
             BufferDesc::Time { nullable } => size_of::<Time>() + size_indicator(nullable),
             BufferDesc::Timestamp { nullable } => size_of::<Timestamp>() + size_indicator(nullable),
+            BufferDesc::Numeric { nullable, .. } => size_of::<Numeric>() + size_indicator(nullable),
             BufferDesc::I8 { nullable } => size_of::<i8>() + size_indicator(nullable),
             BufferDesc::I16 { nullable } => size_of::<i16>() + size_indicator(nullable),
             BufferDesc::I32 { nullable } => size_of::<i32>() + size_indicator(nullable),
@@ -172,6 +237,125 @@ impl BufferDesc {
             BufferDesc::Bit { nullable } => size_of::<Bit>() + size_indicator(nullable),
         }
     }
+
+    /// `true` if the buffer described is able to represent `NULL` values. Variable sized buffers
+    /// (`Binary`, `Text` and `WText`) always bind an indicator and are therefore always nullable.
+    ///
+    /// ```
+    /// use odbc_api::buffers::BufferDesc;
+    ///
+    /// assert!(BufferDesc::I32 { nullable: true }.is_nullable());
+    /// assert!(!BufferDesc::I32 { nullable: false }.is_nullable());
+    /// assert!(BufferDesc::Text { max_str_len: 10 }.is_nullable());
+    /// ```
+    pub fn is_nullable(&self) -> bool {
+        match *self {
+            BufferDesc::Binary { .. }
+            | BufferDesc::Text { .. }
+            | BufferDesc::WText { .. }
+            | BufferDesc::LongText
+            | BufferDesc::LongBinary
+            | BufferDesc::Default { .. } => true,
+            BufferDesc::F64 { nullable }
+            | BufferDesc::F32 { nullable }
+            | BufferDesc::Date { nullable }
+            | BufferDesc::Time { nullable }
+            | BufferDesc::Timestamp { nullable }
+            | BufferDesc::Numeric { nullable, .. }
+            | BufferDesc::I8 { nullable }
+            | BufferDesc::I16 { nullable }
+            | BufferDesc::I32 { nullable }
+            | BufferDesc::I64 { nullable }
+            | BufferDesc::U8 { nullable }
+            | BufferDesc::Bit { nullable } => nullable,
+        }
+    }
+
+    /// Same buffer description, but with the `nullable` flag set to the specified value. Useful in
+    /// generic code which wants to change the nullability of a description without matching all of
+    /// its variants. Variable sized buffers (`Binary`, `Text` and `WText`) are always nullable and
+    /// returned unchanged.
+    ///
+    /// ```
+    /// use odbc_api::buffers::BufferDesc;
+    ///
+    /// let desc = BufferDesc::I64 { nullable: false };
+    /// assert_eq!(BufferDesc::I64 { nullable: true }, desc.with_nullable(true));
+    /// ```
+    pub fn with_nullable(self, nullable: bool) -> BufferDesc {
+        match self {
+            BufferDesc::Binary { .. }
+            | BufferDesc::Text { .. }
+            | BufferDesc::WText { .. }
+            | BufferDesc::LongText
+            | BufferDesc::LongBinary
+            | BufferDesc::Default { .. } => self,
+            BufferDesc::F64 { .. } => BufferDesc::F64 { nullable },
+            BufferDesc::F32 { .. } => BufferDesc::F32 { nullable },
+            BufferDesc::Date { .. } => BufferDesc::Date { nullable },
+            BufferDesc::Time { .. } => BufferDesc::Time { nullable },
+            BufferDesc::Timestamp { .. } => BufferDesc::Timestamp { nullable },
+            BufferDesc::Numeric {
+                precision, scale, ..
+            } => BufferDesc::Numeric {
+                precision,
+                scale,
+                nullable,
+            },
+            BufferDesc::I8 { .. } => BufferDesc::I8 { nullable },
+            BufferDesc::I16 { .. } => BufferDesc::I16 { nullable },
+            BufferDesc::I32 { .. } => BufferDesc::I32 { nullable },
+            BufferDesc::I64 { .. } => BufferDesc::I64 { nullable },
+            BufferDesc::U8 { .. } => BufferDesc::U8 { nullable },
+            BufferDesc::Bit { .. } => BufferDesc::Bit { nullable },
+        }
+    }
+}
+
+/// Human readable description of the buffer, e.g. for logging the buffers chosen for a result set.
+/// Fixed sized buffers binding an indicator are prefixed with `Nullable`.
+///
+/// ```
+/// use odbc_api::buffers::BufferDesc;
+///
+/// assert_eq!("F64", BufferDesc::F64 { nullable: false }.to_string());
+/// assert_eq!("Nullable I8", BufferDesc::I8 { nullable: true }.to_string());
+/// assert_eq!("Text(50)", BufferDesc::Text { max_str_len: 50 }.to_string());
+/// ```
+impl Display for BufferDesc {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let fixed_sized = |f: &mut Formatter<'_>, name: &str, nullable: bool| {
+            if nullable {
+                write!(f, "Nullable {name}")
+            } else {
+                write!(f, "{name}")
+            }
+        };
+        match *self {
+            BufferDesc::Binary { length } => write!(f, "Binary({length})"),
+            BufferDesc::Text { max_str_len } => write!(f, "Text({max_str_len})"),
+            BufferDesc::WText { max_str_len } => write!(f, "WText({max_str_len})"),
+            BufferDesc::LongText => write!(f, "LongText"),
+            BufferDesc::LongBinary => write!(f, "LongBinary"),
+            BufferDesc::Default { length } => write!(f, "Default({length})"),
+            BufferDesc::F64 { nullable } => fixed_sized(f, "F64", nullable),
+            BufferDesc::F32 { nullable } => fixed_sized(f, "F32", nullable),
+            BufferDesc::Date { nullable } => fixed_sized(f, "Date", nullable),
+            BufferDesc::Time { nullable } => fixed_sized(f, "Time", nullable),
+            BufferDesc::Timestamp { nullable } => fixed_sized(f, "Timestamp", nullable),
+            BufferDesc::Numeric {
+                precision,
+                scale,
+                nullable,
+            } => fixed_sized(f, &format!("Numeric({precision}, {scale})"), nullable),
+            BufferDesc::I8 { nullable } => fixed_sized(f, "I8", nullable),
+            BufferDesc::I16 { nullable } => fixed_sized(f, "I16", nullable),
+            BufferDesc::I32 { nullable } => fixed_sized(f, "I32", nullable),
+            BufferDesc::I64 { nullable } => fixed_sized(f, "I64", nullable),
+            BufferDesc::U8 { nullable } => fixed_sized(f, "U8", nullable),
+            BufferDesc::Bit { nullable } => fixed_sized(f, "Bit", nullable),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -207,4 +391,55 @@ mod tests {
         assert_eq!(8, BufferDesc::I64 { nullable: false }.bytes_per_row());
         assert_eq!(1, BufferDesc::U8 { nullable: false }.bytes_per_row());
     }
+
+    #[test]
+    fn bytes_per_row_includes_indicator_for_nullable_buffers() {
+        let non_nullable = BufferDesc::I32 { nullable: false }.bytes_per_row();
+        let nullable = BufferDesc::I32 { nullable: true }.bytes_per_row();
+
+        assert_eq!(size_of::<i32>(), non_nullable);
+        assert_eq!(size_of::<i32>() + size_of::<isize>(), nullable);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!("F32", BufferDesc::F32 { nullable: false }.to_string());
+        assert_eq!(
+            "Nullable F64",
+            BufferDesc::F64 { nullable: true }.to_string()
+        );
+        assert_eq!("I8", BufferDesc::I8 { nullable: false }.to_string());
+        assert_eq!("Nullable U8", BufferDesc::U8 { nullable: true }.to_string());
+        assert_eq!(
+            "Nullable Numeric(10, 2)",
+            BufferDesc::Numeric {
+                precision: 10,
+                scale: 2,
+                nullable: true
+            }
+            .to_string()
+        );
+        assert_eq!("Binary(16)", BufferDesc::Binary { length: 16 }.to_string());
+        assert_eq!("LongText", BufferDesc::LongText.to_string());
+    }
+
+    #[test]
+    fn toggle_nullability() {
+        let i32_desc = BufferDesc::I32 { nullable: false };
+        assert!(!i32_desc.is_nullable());
+        assert!(i32_desc.with_nullable(true).is_nullable());
+        assert_eq!(
+            BufferDesc::I32 { nullable: true },
+            i32_desc.with_nullable(true)
+        );
+        assert_eq!(i32_desc, i32_desc.with_nullable(true).with_nullable(false));
+
+        // Variable sized buffers always bind an indicator
+        let text = BufferDesc::Text { max_str_len: 10 };
+        assert!(text.is_nullable());
+        assert_eq!(text, text.with_nullable(false));
+        let binary = BufferDesc::Binary { length: 10 };
+        assert!(binary.is_nullable());
+        assert_eq!(binary, binary.with_nullable(false));
+    }
 }
@@ -1,4 +1,4 @@
-use odbc_sys::{Date, Time, Timestamp};
+use odbc_sys::{Date, Numeric, Time, Timestamp};
 
 use super::{AnySlice, AnySliceMut, BufferDesc, NullableSlice, NullableSliceMut};
 use crate::Bit;
@@ -63,9 +63,14 @@ pub trait Item: Sized + Copy {
 
 macro_rules! impl_item {
     ($t:ident, $plain:ident, $null:ident) => {
+        impl_item!($t, $plain, $null, |nullable| BufferDesc::$plain {
+            nullable
+        });
+    };
+    ($t:ident, $plain:ident, $null:ident, $buffer_desc:expr) => {
         impl Item for $t {
             fn buffer_desc(nullable: bool) -> BufferDesc {
-                BufferDesc::$plain { nullable }
+                ($buffer_desc)(nullable)
             }
 
             fn as_slice(variant: AnySlice<'_>) -> Option<&[Self]> {
@@ -112,3 +117,13 @@ impl_item!(Date, Date, NullableDate);
 impl_item!(Bit, Bit, NullableBit);
 impl_item!(Time, Time, NullableTime);
 impl_item!(Timestamp, Timestamp, NullableTimestamp);
+// Numeric buffers described by `Item` are declared with the largest precision supported by most
+// data sources and a scale of zero, if bound as a parameter. Use `BufferDesc::Numeric` directly to
+// insert values with fractional digits.
+impl_item!(Numeric, Numeric, NullableNumeric, |nullable| {
+    BufferDesc::Numeric {
+        precision: 38,
+        scale: 0,
+        nullable,
+    }
+});
@@ -1,4 +1,7 @@
-use std::{mem, ops::Deref};
+use std::{
+    mem,
+    ops::{Deref, DerefMut},
+};
 
 use crate::{
     buffers::Indicator,
@@ -12,7 +15,8 @@ use crate::{
 /// `String` can not be supported directly by [`FetchRow`]s for efficient bulk fetching, due to the
 /// fact it points to data on the heap.
 ///
-/// This trait is implement by tuples of [`FetchRowMember`]. In addition it can also be derived
+/// This trait is implement by tuples of [`FetchRowMember`] and for convenience by each
+/// [`FetchRowMember`] itself, representing a single column. In addition it can also be derived
 /// for structs there all members implement [`FetchRowMember`] using the `Fetch` derive macro if the
 /// optional derive feature is activated.
 ///
@@ -33,6 +37,54 @@ pub unsafe trait FetchRow: Copy {
 
     /// If it exists, this returns the "buffer index" of a member, which has been truncated.
     fn find_truncation(&self) -> Option<TruncationInfo>;
+
+    /// Appends the truncation info of every truncated member to `truncations`. The default
+    /// implementation only appends the first one found by [`Self::find_truncation`]. Tuples and
+    /// rows implemented using the `Fetch` derive macro report all truncated members.
+    fn find_all_truncations(&self, truncations: &mut Vec<TruncationInfo>) {
+        truncations.extend(self.find_truncation());
+    }
+}
+
+/// A group of consecutive columns within a [`FetchRow`]. The `Fetch` derive macro implements this
+/// alongside [`FetchRow`], so a derived struct can be embedded into another one, by annotating the
+/// field holding it with `#[odbc(flatten)]`. This allows for composing rows out of reusable column
+/// groups.
+///
+/// # Safety
+///
+/// Same as for [`FetchRow`]. In addition [`Self::NUM_COLUMNS`] must match the number of columns
+/// bound by [`Self::bind_columns_starting_at`].
+pub unsafe trait FetchRowGroup: Copy {
+    /// Number of result set columns bound to members of the group.
+    const NUM_COLUMNS: u16;
+
+    /// Binds the members of the group to consecutive columns of the result set, starting with
+    /// `first_column`.
+    ///
+    /// # Safety
+    ///
+    /// Caller must ensure self is alive and not moved in memory for the duration of the binding.
+    unsafe fn bind_columns_starting_at(
+        &mut self,
+        first_column: u16,
+        cursor: &mut StatementRef<'_>,
+    ) -> Result<(), Error>;
+
+    /// Like [`FetchRow::find_truncation`], with the buffer indices of the members starting at
+    /// `first_buffer_index`.
+    fn find_truncation_starting_at(&self, first_buffer_index: usize) -> Option<TruncationInfo>;
+
+    /// Like [`FetchRow::find_all_truncations`], with the buffer indices of the members starting at
+    /// `first_buffer_index`. The default implementation only appends the first truncation found
+    /// by [`Self::find_truncation_starting_at`].
+    fn find_all_truncations_starting_at(
+        &self,
+        first_buffer_index: usize,
+        truncations: &mut Vec<TruncationInfo>,
+    ) {
+        truncations.extend(self.find_truncation_starting_at(first_buffer_index));
+    }
 }
 
 /// A row wise buffer intended to be bound with [crate::Cursor::bind_buffer] in order to obtain
@@ -68,6 +120,8 @@ pub unsafe trait FetchRow: Copy {
 ///
 /// To fetch rows with this buffer type `R` must implement [`FetchRow`]. This is currently
 /// implemented for tuple types. Each element of these tuples must implement [`FetchRowMember`].
+/// Result sets with a single column can also be fetched into a bare [`FetchRowMember`], e.g.
+/// `RowVec<Nullable<i32>>` instead of `RowVec<(Nullable<i32>,)>`.
 ///
 /// Currently supported are: `f64`, `f32`, [`odbc_sys::Date`], [`odbc_sys::Timestamp`],
 /// [`odbc_sys::Time`], `i16`, `u36`, `i32`, `u32`, `i8`, `u8`, `Bit`, `i64`, `u64` and
@@ -114,6 +168,15 @@ impl<R> Deref for RowVec<R> {
     }
 }
 
+/// Allows modifying fetched rows, e.g. before writing them back using
+/// [`crate::UpdatableBlockCursor::update_row`]. Only the values can be changed, the rows stay
+/// where they are bound.
+impl<R> DerefMut for RowVec<R> {
+    fn deref_mut(&mut self) -> &mut [R] {
+        &mut self.rows[..*self.num_rows]
+    }
+}
+
 unsafe impl<R> RowSetBuffer for RowVec<R>
 where
     R: FetchRow,
@@ -144,10 +207,33 @@ where
             .take(*self.num_rows)
             .find_map(|row| row.find_truncation())
     }
+
+    fn find_all_truncations(&self) -> Vec<TruncationInfo> {
+        let mut truncations: Vec<TruncationInfo> = Vec::new();
+        let mut in_row = Vec::new();
+        for row in self.rows.iter().take(*self.num_rows) {
+            row.find_all_truncations(&mut in_row);
+            // Only report the first truncated value of each column.
+            for truncation in in_row.drain(..) {
+                if truncations
+                    .iter()
+                    .all(|known| known.buffer_index != truncation.buffer_index)
+                {
+                    truncations.push(truncation);
+                }
+            }
+        }
+        truncations.sort_by_key(|truncation| truncation.buffer_index);
+        truncations
+    }
 }
 
 /// Can be used as a member of a [`FetchRow`] and bound to a column during row wise fetching.
 ///
+/// Besides integers and arrays of characters, this includes floating point numbers (`f32`, `f64`)
+/// and booleans. Booleans are represented by [`crate::Bit`] rather than `bool`, since the driver
+/// is not guaranteed to write a valid `bool` into the buffer.
+///
 /// # Safety
 ///
 /// Must only be implemented for types completly representable by consequtive bytes. While members
@@ -206,6 +292,18 @@ macro_rules! impl_find_truncation {
     );
 }
 
+macro_rules! impl_find_all_truncations {
+    ($offset:expr, $truncations:ident,) => (
+        ()
+    );
+    ($offset:expr, $truncations:ident, $head:ident, $($tail:ident,)*) => (
+        {
+            $truncations.extend($head.find_truncation($offset));
+            impl_find_all_truncations!($offset+1, $truncations, $($tail,)*)
+        }
+    );
+}
+
 macro_rules! impl_fetch_row_for_tuple{
     ($($t:ident)*) => (
         #[allow(unused_mut)]
@@ -222,10 +320,29 @@ macro_rules! impl_fetch_row_for_tuple{
                 let ($(ref $t,)*) = self;
                 impl_find_truncation!(0, $($t,)*)
             }
+
+            fn find_all_truncations(&self, truncations: &mut Vec<TruncationInfo>) {
+                let ($(ref $t,)*) = self;
+                impl_find_all_truncations!(0, truncations, $($t,)*)
+            }
         }
     );
 }
 
+// Allows fetching single column result sets without wrapping the member into a one element tuple.
+unsafe impl<T> FetchRow for T
+where
+    T: FetchRowMember,
+{
+    unsafe fn bind_columns_to_cursor(&mut self, mut cursor: StatementRef<'_>) -> Result<(), Error> {
+        self.bind_to_col(1, &mut cursor)
+    }
+
+    fn find_truncation(&self) -> Option<TruncationInfo> {
+        FetchRowMember::find_truncation(self, 0)
+    }
+}
+
 impl_fetch_row_for_tuple! {}
 impl_fetch_row_for_tuple! { A }
 impl_fetch_row_for_tuple! { A B }
@@ -257,11 +374,40 @@ impl_fetch_row_for_tuple! { A B C D E F G H I J K L M N O P Q R S T U V W X Y Z}
 #[cfg(test)]
 mod tests {
 
+    use crate::{buffers::Indicator, parameter::VarCharArray, RowSetBuffer, TruncationInfo};
+
     use super::RowVec;
 
     #[derive(Default, Clone, Copy)]
     struct DummyRow;
 
+    #[test]
+    fn find_all_truncations_reports_every_truncated_member_of_a_row() {
+        let truncated =
+            |length| VarCharArray::<2>::from_buffer([b'a', 0], Indicator::Length(length));
+        let mut buffer = RowVec::<(i32, VarCharArray<2>, VarCharArray<2>)>::new(2);
+        buffer.rows[0] = (1, truncated(5), truncated(7));
+        buffer.rows[1] = (2, truncated(6), truncated(8));
+        *buffer.num_rows = 2;
+
+        let truncations = buffer.find_all_truncations();
+
+        // One truncation per column, reported for the first row with a truncated value.
+        assert_eq!(
+            vec![
+                TruncationInfo {
+                    indicator: Some(5),
+                    buffer_index: 1
+                },
+                TruncationInfo {
+                    indicator: Some(7),
+                    buffer_index: 2
+                }
+            ],
+            truncations
+        );
+    }
+
     #[test]
     #[should_panic]
     fn construction_should_panic_on_capacity_zero() {
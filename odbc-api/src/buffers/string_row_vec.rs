@@ -0,0 +1,180 @@
+use std::iter::repeat_n;
+
+use crate::{
+    buffers::{
+        any_buffer::{get_data_extensions_not_supported, position_for_get_data},
+        TextRowSet,
+    },
+    error::ExtendResult,
+    handles::{AsStatementRef, StatementRef},
+    CursorRow, Error, ResultSetMetadata, RowSetBuffer, TruncationInfo,
+};
+
+/// A row set buffer exposing the text representation of each value as an owned `String`, row by
+/// row. In contrast to a [`crate::buffers::RowVec`] of [`crate::parameter::VarCharArray`]s you do
+/// not need to choose a maximum string length up front, and no value is ever truncated.
+///
+/// All columns are bound as text, with each element capped at `max_str_len` bytes. Values which
+/// fit into the bound buffer are copied into their `String` directly after fetching the row set.
+/// Longer values are retrieved using `SQLGetData`, so the buffer works best if `max_str_len`
+/// covers the majority of values. Fetching the remainder requires the driver to support
+/// `SQL_GD_BOUND` and, for row sets larger than one row, `SQL_GD_BLOCK` (see
+/// `SQL_GETDATA_EXTENSIONS`). Otherwise fetching a row set with a truncated value fails with
+/// [`Error::GetDataExtensionsNotSupported`]. Since values are retrieved after the fetch, the buffer
+/// can not be bound to a [`crate::CursorPolling`].
+///
+/// # Example
+///
+/// ```
+/// use odbc_api::{buffers::StringRowVec, Cursor, Error};
+///
+/// fn print_all(mut cursor: impl Cursor) -> Result<(), Error> {
+///     let buffer = StringRowVec::for_cursor(100, &mut cursor, 64)?;
+///     let mut block_cursor = cursor.bind_buffer(buffer)?;
+///     while let Some(batch) = block_cursor.fetch()? {
+///         for row in batch.rows() {
+///             let cells: Vec<_> = row.iter().map(|cell| cell.as_deref()).collect();
+///             println!("{cells:?}");
+///         }
+///     }
+///     Ok(())
+/// }
+/// ```
+pub struct StringRowVec {
+    /// Bound to the cursor. Holds the values of the current row set, capped at the maximum string
+    /// length.
+    text: TextRowSet,
+    /// Complete values of all rows in the current row set, stored row major.
+    cells: Vec<Option<String>>,
+}
+
+impl StringRowVec {
+    /// Creates a buffer, which holds at most `capacity` rows and has one text column for each
+    /// column in the result set of `cursor`. Values up to `max_str_len` bytes are fetched in bulk.
+    pub fn for_cursor(
+        capacity: usize,
+        cursor: &mut impl ResultSetMetadata,
+        max_str_len: usize,
+    ) -> Result<Self, Error> {
+        let num_cols: usize = cursor.num_result_cols()?.try_into().unwrap();
+        let text = TextRowSet::from_max_str_lens(capacity, repeat_n(max_str_len, num_cols))?;
+        Ok(StringRowVec {
+            text,
+            cells: Vec::new(),
+        })
+    }
+
+    /// Number of valid rows in the buffer.
+    pub fn num_rows(&self) -> usize {
+        self.cells.len().checked_div(self.num_cols()).unwrap_or(0)
+    }
+
+    /// Number of columns in the buffer.
+    pub fn num_cols(&self) -> usize {
+        self.text.num_cols()
+    }
+
+    /// Values of the row at `row_index`. `None` represents `NULL`.
+    ///
+    /// Panics if `row_index` is not smaller than [`Self::num_rows`].
+    pub fn row(&self, row_index: usize) -> &[Option<String>] {
+        assert!(row_index < self.num_rows(), "Row index out of bounds.");
+        let num_cols = self.num_cols();
+        &self.cells[row_index * num_cols..(row_index + 1) * num_cols]
+    }
+
+    /// Iterates over the valid rows in the buffer.
+    pub fn rows(&self) -> impl Iterator<Item = &[Option<String>]> {
+        (0..self.num_rows()).map(|row_index| self.row(row_index))
+    }
+
+    /// Takes ownership of the values in the buffer, leaving it empty. Each inner `Vec` holds the
+    /// values of one row.
+    ///
+    /// A [`crate::BlockCursor`] only lends out its buffer, so use this with a
+    /// [`crate::ConcurrentBlockCursor`], which hands out each batch as an owned buffer.
+    ///
+    /// ```no_run
+    /// use odbc_api::{buffers::StringRowVec, ConcurrentBlockCursor, Cursor, Error};
+    ///
+    /// fn collect_all(
+    ///     mut cursor: impl Cursor + Send + 'static,
+    /// ) -> Result<Vec<Vec<Option<String>>>, Error> {
+    ///     let buffer = StringRowVec::for_cursor(100, &mut cursor, 64)?;
+    ///     let block_cursor = cursor.bind_buffer(buffer)?;
+    ///     let mut concurrent = ConcurrentBlockCursor::from_block_cursor(block_cursor);
+    ///     let mut rows = Vec::new();
+    ///     while let Some(mut batch) = concurrent.fetch()? {
+    ///         rows.extend(batch.take_rows());
+    ///         // Hand the buffer back, so it can be filled with the next batch
+    ///         concurrent.fill(batch);
+    ///     }
+    ///     Ok(rows)
+    /// }
+    /// ```
+    pub fn take_rows(&mut self) -> Vec<Vec<Option<String>>> {
+        let num_rows = self.num_rows();
+        let num_cols = self.num_cols();
+        let mut cells = std::mem::take(&mut self.cells).into_iter();
+        (0..num_rows)
+            .map(|_| cells.by_ref().take(num_cols).collect())
+            .collect()
+    }
+}
+
+unsafe impl RowSetBuffer for StringRowVec {
+    fn bind_type(&self) -> usize {
+        self.text.bind_type()
+    }
+
+    fn row_array_size(&self) -> usize {
+        self.text.row_array_size()
+    }
+
+    fn mut_num_fetch_rows(&mut self) -> &mut usize {
+        self.text.mut_num_fetch_rows()
+    }
+
+    unsafe fn bind_colmuns_to_cursor(&mut self, cursor: StatementRef<'_>) -> Result<(), Error> {
+        self.text.bind_colmuns_to_cursor(cursor)
+    }
+
+    /// Always `None`, since truncated values are completed using `SQLGetData`.
+    fn find_truncation(&self) -> Option<TruncationInfo> {
+        None
+    }
+
+    /// Always `true`, since truncated values are retrieved using `SQLGetData`.
+    fn completes_row_sets(&self) -> bool {
+        true
+    }
+
+    unsafe fn complete_row_set(&mut self, mut cursor: StatementRef<'_>) -> Result<(), Error> {
+        self.cells.clear();
+        for row_index in 0..self.text.num_rows() {
+            for buffer_index in 0..self.text.num_cols() {
+                let col_or_param_num = (buffer_index + 1) as u16;
+                let indicator = self.text.indicator_at(buffer_index, row_index);
+                let cell = if indicator.is_truncated(self.text.max_len(buffer_index)) {
+                    position_for_get_data(&mut cursor, row_index)?;
+                    let mut row = CursorRow::new(cursor.as_stmt_ref());
+                    let mut value = String::new();
+                    let is_not_null = row
+                        .append_text(col_or_param_num, &mut value)
+                        .provide_context_for_diagnostic(get_data_extensions_not_supported)?;
+                    is_not_null.then_some(value)
+                } else {
+                    self.text
+                        .at(buffer_index, row_index)
+                        .map(|bytes| {
+                            String::from_utf8(bytes.to_owned())
+                                .map_err(|_| Error::InvalidTextEncoding { col_or_param_num })
+                        })
+                        .transpose()?
+                };
+                self.cells.push(cell);
+            }
+        }
+        Ok(())
+    }
+}
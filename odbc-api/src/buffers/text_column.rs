@@ -1,11 +1,15 @@
 use crate::{
     columnar_bulk_inserter::BoundInputSlice,
+    decimal_text_to_i128,
     error::TooLargeBufferSize,
     handles::{CData, CDataMut, HasDataType, Statement, StatementRef},
     DataType, Error,
 };
 
-use super::{ColumnBuffer, Indicator};
+use super::{
+    zeroed::{try_zeroed_vec, zeroed_vec, Zeroable},
+    ColumnBuffer, Indicator,
+};
 
 use log::debug;
 use odbc_sys::{CDataType, NULL_DATA};
@@ -90,6 +94,47 @@ impl<C> TextColumn<C> {
         }
     }
 
+    /// Same as [`Self::try_new`], but requests the value buffer zeroed from the allocator, rather
+    /// than writing each element. Considerably faster for large buffers.
+    pub(crate) fn try_new_zeroed(
+        batch_size: usize,
+        max_str_len: usize,
+    ) -> Result<Self, TooLargeBufferSize>
+    where
+        C: Zeroable,
+    {
+        // Element size is +1 to account for terminating zero
+        let element_size = max_str_len + 1;
+        let too_large = || TooLargeBufferSize {
+            num_elements: batch_size,
+            // We want the element size in bytes
+            element_size: element_size * size_of::<C>(),
+        };
+        let len = element_size.checked_mul(batch_size).ok_or_else(too_large)?;
+        let values = try_zeroed_vec(len).ok_or_else(too_large)?;
+        Ok(TextColumn {
+            max_str_len,
+            values,
+            indicators: vec![0; batch_size],
+        })
+    }
+
+    /// Same as [`Self::new`], but requests the value buffer zeroed from the allocator, rather than
+    /// writing each element.
+    pub(crate) fn new_zeroed(batch_size: usize, max_str_len: usize) -> Self
+    where
+        C: Zeroable,
+    {
+        // Element size is +1 to account for terminating zero
+        let element_size = max_str_len + 1;
+        let len = element_size * batch_size;
+        TextColumn {
+            max_str_len,
+            values: zeroed_vec(len),
+            indicators: vec![NULL_DATA; batch_size],
+        }
+    }
+
     /// Bytes of string at the specified position. Includes interior nuls, but excludes the
     /// terminating nul.
     ///
@@ -208,6 +253,29 @@ impl<C> TextColumn<C> {
         self.max_str_len = new_max_str_len;
     }
 
+    /// Reallocates the buffer, so the maximum string length matches the longest value within the
+    /// first `num_rows` rows. Useful to release memory after fetching short values into a buffer
+    /// sized for the largest possible value, e.g. before keeping a batch around for a long time.
+    ///
+    /// Indicators are preserved. Values of truncated rows fill the old buffer completely, so they
+    /// prevent the buffer from shrinking. Rows after `num_rows` are not preserved.
+    ///
+    /// # Parameters
+    ///
+    /// * `num_rows`: Number of valid rows currently stored in this buffer.
+    pub fn shrink_to_fit(&mut self, num_rows: usize)
+    where
+        C: Default + Copy,
+    {
+        let new_max_str_len = (0..num_rows)
+            .filter_map(|row_index| self.content_length_at(row_index))
+            .max()
+            .unwrap_or(0);
+        if new_max_str_len < self.max_str_len {
+            self.resize_max_str(new_max_str_len, num_rows);
+        }
+    }
+
     /// Sets the value of the buffer at index at Null or the specified binary Text. This method will
     /// panic on out of bounds index, or if input holds a text which is larger than the maximum
     /// allowed element length. `input` must be specified without the terminating zero.
@@ -421,6 +489,24 @@ impl<'c, C> TextColumnView<'c, C> {
     }
 }
 
+impl<'c> TextColumnView<'c, u8> {
+    /// Applies [`crate::decimal_text_to_i128`] to each valid element of the column. `NULL` values
+    /// are mapped to `None`. Handy if decimals have been fetched as text, but are to be processed
+    /// as integers. The integers are the values of the decimals times 10 to the power of `scale`.
+    ///
+    /// ```
+    /// use odbc_api::buffers::TextColumnView;
+    ///
+    /// fn sum_of_cents(prices: TextColumnView<'_, u8>) -> i128 {
+    ///     prices.decimals_to_i128(2).flatten().sum()
+    /// }
+    /// ```
+    pub fn decimals_to_i128(&self, scale: usize) -> impl Iterator<Item = Option<i128>> + 'c {
+        self.iter()
+            .map(move |text| text.map(|text| decimal_text_to_i128(text, scale)))
+    }
+}
+
 unsafe impl<'a, C: 'static> BoundInputSlice<'a> for TextColumn<C> {
     type SliceMut = TextColumnSliceMut<'a, C>;
 
@@ -0,0 +1,83 @@
+//! Allocation of buffers whose memory is requested zeroed from the allocator.
+//!
+//! Filling a freshly reserved `Vec` with `resize` writes every single byte up front. For large
+//! column buffers (think `VARCHAR(MAX)` with a generous batch size) this is a noticeable cost,
+//! even though most of the memory will be overwritten by the driver anyway. Asking the allocator
+//! for zeroed memory instead allows it to hand out pages which are lazily zeroed by the operating
+//! system. In contrast to truly uninitialized memory this remains sound, since every element of
+//! the buffer is a valid value from the start.
+
+use std::alloc::{alloc_zeroed, handle_alloc_error, Layout};
+
+use odbc_sys::{Date, Numeric, Time, Timestamp};
+
+use crate::Bit;
+
+/// Types for which a value with all bytes set to zero is valid.
+///
+/// # Safety
+///
+/// Only implement this for types for which the all zero bit pattern is a valid instance.
+pub(crate) unsafe trait Zeroable: Copy {}
+
+unsafe impl Zeroable for u8 {}
+unsafe impl Zeroable for u16 {}
+unsafe impl Zeroable for Date {}
+unsafe impl Zeroable for Time {}
+unsafe impl Zeroable for Timestamp {}
+unsafe impl Zeroable for Numeric {}
+unsafe impl Zeroable for Bit {}
+
+/// Allocates a vector with `len` zeroed elements. `None` if the allocation fails, or the size of
+/// the buffer would overflow `isize::MAX`.
+pub(crate) fn try_zeroed_vec<T: Zeroable>(len: usize) -> Option<Vec<T>> {
+    let layout = Layout::array::<T>(len).ok()?;
+    if layout.size() == 0 {
+        return Some(Vec::new());
+    }
+    // Safety: Layout has a non zero size.
+    let ptr = unsafe { alloc_zeroed(layout) } as *mut T;
+    if ptr.is_null() {
+        return None;
+    }
+    // Safety: `ptr` has been allocated with the global allocator using the layout of an array of
+    // `len` elements of `T`. All elements are initialized, since `T` is valid if zeroed.
+    Some(unsafe { Vec::from_raw_parts(ptr, len, len) })
+}
+
+/// Allocates a vector with `len` zeroed elements. Panics or aborts if the memory can not be
+/// allocated, just like `vec![T::default(); len]` would.
+pub(crate) fn zeroed_vec<T: Zeroable>(len: usize) -> Vec<T> {
+    try_zeroed_vec(len).unwrap_or_else(|| match Layout::array::<T>(len) {
+        Ok(layout) => handle_alloc_error(layout),
+        Err(_) => panic!("Capacity overflow allocating buffer with {len} elements."),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use odbc_sys::Timestamp;
+
+    use super::{try_zeroed_vec, zeroed_vec};
+
+    #[test]
+    fn zeroed_vec_contains_only_zeroes() {
+        let values: Vec<u16> = zeroed_vec(10_000);
+        assert_eq!(10_000, values.len());
+        assert!(values.iter().all(|&c| c == 0));
+
+        let timestamps: Vec<Timestamp> = zeroed_vec(3);
+        assert_eq!(vec![Timestamp::default(); 3], timestamps);
+    }
+
+    #[test]
+    fn zeroed_vec_of_length_zero() {
+        let values: Vec<u8> = zeroed_vec(0);
+        assert!(values.is_empty());
+    }
+
+    #[test]
+    fn zeroed_vec_too_large() {
+        assert!(try_zeroed_vec::<u16>(usize::MAX).is_none());
+    }
+}
@@ -0,0 +1,463 @@
+//! Conversions between the date and time types of [`chrono`](::chrono) and their ODBC
+//! counterparts in [`crate::sys`]. Requires the `chrono` feature.
+//!
+//! The memory layout of `NaiveDate`, `NaiveTime` and `NaiveDateTime` does not match the structs
+//! ODBC expects, so bind [`ChronoDate`], [`ChronoTime`] or [`ChronoTimestamp`] instead. These wrap
+//! the structs from [`crate::sys`] and convert from and to their `chrono` counterparts. They can be
+//! used as input or output parameters and be fetched.
+//!
+//! `chrono` supports years, which ODBC can not represent. Therefore `NaiveDate` and
+//! `NaiveDateTime` are converted with [`TryFrom`], which fails with [`Error::YearOutOfRange`] for
+//! these. `NaiveTime` also implements [`IntoParameter`], since its conversion can not fail.
+//!
+//! ```
+//! use odbc_api::{chrono::ChronoTimestamp, Connection, Error};
+//! use chrono::NaiveDateTime;
+//!
+//! fn insert_event(conn: &Connection<'_>, happened: NaiveDateTime) -> Result<(), Error> {
+//!     let happened = ChronoTimestamp::try_from(happened)?;
+//!     conn.execute("INSERT INTO Events (happened) VALUES (?)", &happened)?;
+//!     Ok(())
+//! }
+//! ```
+//!
+//! ```
+//! use odbc_api::{buffers::RowVec, chrono::ChronoTimestamp, Connection, Cursor, Error};
+//! use chrono::NaiveDateTime;
+//!
+//! fn events(conn: &Connection<'_>) -> Result<Vec<Option<NaiveDateTime>>, Error> {
+//!     let cursor = conn
+//!         .execute("SELECT happened FROM Events", ())?
+//!         .expect("SELECT must yield a result set");
+//!     let mut block_cursor = cursor.bind_buffer(RowVec::<(ChronoTimestamp,)>::new(100))?;
+//!     let mut events = Vec::new();
+//!     while let Some(batch) = block_cursor.fetch()? {
+//!         events.extend(batch.iter().map(|(happened,)| happened.to_naive()));
+//!     }
+//!     Ok(events)
+//! }
+//! ```
+
+use ::chrono::{Datelike, NaiveDate, NaiveDateTime, NaiveTime, Timelike};
+use odbc_sys::{CDataType, Date, Time, Timestamp};
+use std::{
+    ffi::c_void,
+    ptr::{null, null_mut},
+};
+
+use crate::{
+    buffers::{
+        cast_slice, cast_slice_mut, AnySlice, AnySliceMut, BufferDesc, FetchRowMember, Indicator,
+        Item, NullableSlice, NullableSliceMut,
+    },
+    fixed_sized::{impl_input_fixed_sized, impl_pod, Pod},
+    handles::{CData, CDataMut, HasDataType},
+    parameter::{CElement, OutputParameter},
+    DataType, Error, IntoParameter, Nullable,
+};
+
+/// Number of fractional digits of timestamps bound as parameters. `7` is the maximum precision
+/// supported by Microsoft SQL Server, whose driver rejects parameters declaring a higher one.
+const TIMESTAMP_PRECISION: i16 = 7;
+
+/// Converts a [`NaiveDate`] into an ODBC date struct. `None` if the year does not fit into a 16 Bit
+/// signed integer.
+pub fn to_date(date: NaiveDate) -> Option<Date> {
+    Some(Date {
+        year: date.year().try_into().ok()?,
+        month: date.month() as u16,
+        day: date.day() as u16,
+    })
+}
+
+/// Converts a [`NaiveTime`] into an ODBC time struct. The ODBC time struct does not hold fractional
+/// seconds, so these are truncated.
+pub fn to_time(time: NaiveTime) -> Time {
+    Time {
+        hour: time.hour() as u16,
+        minute: time.minute() as u16,
+        second: time.second() as u16,
+    }
+}
+
+/// Converts a [`NaiveDateTime`] into an ODBC timestamp struct. Nanoseconds map one to one onto the
+/// `fraction` field, which is specified in billionths of a second. A leap second is represented as
+/// the last nanosecond of the preceding second. `None` if the year does not fit into a 16 Bit
+/// signed integer.
+///
+/// ```
+/// use odbc_api::{chrono::to_timestamp, sys::Timestamp};
+/// use chrono::NaiveDate;
+///
+/// let date_time = NaiveDate::from_ymd_opt(2025, 1, 31)
+///     .unwrap()
+///     .and_hms_nano_opt(13, 45, 8, 123_456_789)
+///     .unwrap();
+/// assert_eq!(
+///     Some(Timestamp {
+///         year: 2025,
+///         month: 1,
+///         day: 31,
+///         hour: 13,
+///         minute: 45,
+///         second: 8,
+///         fraction: 123_456_789,
+///     }),
+///     to_timestamp(date_time)
+/// );
+/// ```
+pub fn to_timestamp(date_time: NaiveDateTime) -> Option<Timestamp> {
+    Some(Timestamp {
+        year: date_time.year().try_into().ok()?,
+        month: date_time.month() as u16,
+        day: date_time.day() as u16,
+        hour: date_time.hour() as u16,
+        minute: date_time.minute() as u16,
+        second: date_time.second() as u16,
+        fraction: date_time.nanosecond().min(999_999_999),
+    })
+}
+
+/// Converts an ODBC date struct into a [`NaiveDate`]. `None` if the struct does not describe a
+/// valid date.
+pub fn from_date(date: &Date) -> Option<NaiveDate> {
+    NaiveDate::from_ymd_opt(date.year.into(), date.month.into(), date.day.into())
+}
+
+/// Converts an ODBC time struct into a [`NaiveTime`]. `None` if the struct does not describe a
+/// valid time.
+pub fn from_time(time: &Time) -> Option<NaiveTime> {
+    NaiveTime::from_hms_opt(time.hour.into(), time.minute.into(), time.second.into())
+}
+
+/// Converts an ODBC timestamp struct into a [`NaiveDateTime`]. `None` if the struct does not
+/// describe a valid point in time.
+pub fn from_timestamp(timestamp: &Timestamp) -> Option<NaiveDateTime> {
+    let date = NaiveDate::from_ymd_opt(
+        timestamp.year.into(),
+        timestamp.month.into(),
+        timestamp.day.into(),
+    )?;
+    date.and_hms_nano_opt(
+        timestamp.hour.into(),
+        timestamp.minute.into(),
+        timestamp.second.into(),
+        timestamp.fraction,
+    )
+}
+
+/// Wraps an ODBC [`Date`] and converts it from and to a [`NaiveDate`]. In contrast to `NaiveDate`,
+/// it can be fetched, e.g. as part of a [`crate::buffers::RowVec`] or via
+/// [`crate::CursorRow::get_data`], be bound as an input or output parameter and be extracted from
+/// columnar buffers using [`Item`]. Bound as `DATE`.
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ChronoDate(pub Date);
+
+impl ChronoDate {
+    /// `None` if the year does not fit into a 16 Bit signed integer.
+    pub fn from_naive(date: NaiveDate) -> Option<Self> {
+        to_date(date).map(ChronoDate)
+    }
+
+    /// `None` if the wrapped struct does not describe a valid date.
+    pub fn to_naive(&self) -> Option<NaiveDate> {
+        from_date(&self.0)
+    }
+}
+
+impl TryFrom<NaiveDate> for ChronoDate {
+    type Error = Error;
+
+    /// Fails with [`Error::YearOutOfRange`] if the year does not fit into a 16 Bit signed integer.
+    fn try_from(date: NaiveDate) -> Result<Self, Error> {
+        Self::from_naive(date).ok_or(Error::YearOutOfRange { year: date.year() })
+    }
+}
+
+/// Wraps an ODBC [`Time`] and converts it from and to a [`NaiveTime`]. In contrast to `NaiveTime`,
+/// it can be fetched, e.g. as part of a [`crate::buffers::RowVec`] or via
+/// [`crate::CursorRow::get_data`], be bound as an input or output parameter and be extracted from
+/// columnar buffers using [`Item`]. Bound as `TIME`. The ODBC time struct does not hold fractional
+/// seconds, so these are truncated.
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ChronoTime(pub Time);
+
+impl ChronoTime {
+    /// Truncates fractional seconds. See [`to_time`].
+    pub fn from_naive(time: NaiveTime) -> Self {
+        ChronoTime(to_time(time))
+    }
+
+    /// `None` if the wrapped struct does not describe a valid time.
+    pub fn to_naive(&self) -> Option<NaiveTime> {
+        from_time(&self.0)
+    }
+}
+
+impl From<NaiveTime> for ChronoTime {
+    fn from(time: NaiveTime) -> Self {
+        Self::from_naive(time)
+    }
+}
+
+/// Wraps an ODBC [`Timestamp`] and converts it from and to a [`NaiveDateTime`]. In contrast to
+/// `NaiveDateTime`, it can be fetched, e.g. as part of a [`crate::buffers::RowVec`] or via
+/// [`crate::CursorRow::get_data`], be bound as an output parameter and be extracted from columnar
+/// buffers using [`Item`].
+///
+/// Bound as `TIMESTAMP` with a precision of seven fractional digits, i.e. 100 nanoseconds. This is
+/// the maximum precision supported by Microsoft SQL Server, whose driver rejects parameters
+/// declaring a higher one. Conversions from a `NaiveDateTime` therefore truncate the fraction to
+/// this precision. Use [`to_timestamp`] in order to keep all nine digits.
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ChronoTimestamp(pub Timestamp);
+
+impl ChronoTimestamp {
+    /// `None` if the year does not fit into a 16 Bit signed integer. The fraction is truncated to
+    /// 100 nanoseconds.
+    pub fn from_naive(date_time: NaiveDateTime) -> Option<Self> {
+        let mut timestamp = to_timestamp(date_time)?;
+        let resolution = 10u32.pow(9 - TIMESTAMP_PRECISION as u32);
+        timestamp.fraction -= timestamp.fraction % resolution;
+        Some(ChronoTimestamp(timestamp))
+    }
+
+    /// `None` if the wrapped struct does not describe a valid point in time.
+    pub fn to_naive(&self) -> Option<NaiveDateTime> {
+        from_timestamp(&self.0)
+    }
+}
+
+impl TryFrom<NaiveDateTime> for ChronoTimestamp {
+    type Error = Error;
+
+    /// Fails with [`Error::YearOutOfRange`] if the year does not fit into a 16 Bit signed integer.
+    fn try_from(date_time: NaiveDateTime) -> Result<Self, Error> {
+        Self::from_naive(date_time).ok_or(Error::YearOutOfRange {
+            year: date_time.year(),
+        })
+    }
+}
+
+impl_pod!(ChronoDate, CDataType::TypeDate);
+impl_pod!(ChronoTime, CDataType::TypeTime);
+impl_pod!(ChronoTimestamp, CDataType::TypeTimestamp);
+impl_input_fixed_sized!(ChronoDate, DataType::Date);
+impl_input_fixed_sized!(ChronoTime, DataType::Time { precision: 0 });
+impl_input_fixed_sized!(
+    ChronoTimestamp,
+    DataType::Timestamp {
+        precision: TIMESTAMP_PRECISION
+    }
+);
+
+macro_rules! impl_item_for_wrapper {
+    ($t:ident, $inner:ident) => {
+        // Safety: `$t` is a transparent wrapper around `$inner`, so the slices share their layout.
+        impl Item for $t {
+            fn buffer_desc(nullable: bool) -> BufferDesc {
+                $inner::buffer_desc(nullable)
+            }
+
+            fn as_slice(variant: AnySlice<'_>) -> Option<&[Self]> {
+                $inner::as_slice(variant).map(|values| unsafe { cast_slice(values) })
+            }
+
+            fn as_nullable_slice(variant: AnySlice<'_>) -> Option<NullableSlice<'_, Self>> {
+                $inner::as_nullable_slice(variant).map(|values| unsafe { values.cast() })
+            }
+
+            fn as_slice_mut(variant: AnySliceMut<'_>) -> Option<&'_ mut [Self]> {
+                $inner::as_slice_mut(variant).map(|values| unsafe { cast_slice_mut(values) })
+            }
+
+            fn as_nullable_slice_mut(
+                variant: AnySliceMut<'_>,
+            ) -> Option<NullableSliceMut<'_, Self>> {
+                $inner::as_nullable_slice_mut(variant).map(|values| unsafe { values.cast() })
+            }
+        }
+    };
+}
+
+impl_item_for_wrapper!(ChronoDate, Date);
+impl_item_for_wrapper!(ChronoTime, Time);
+impl_item_for_wrapper!(ChronoTimestamp, Timestamp);
+
+/// Bound as `TIME`. Fractional seconds are truncated.
+impl IntoParameter for NaiveTime {
+    type Parameter = ChronoTime;
+
+    fn into_parameter(self) -> Self::Parameter {
+        ChronoTime::from_naive(self)
+    }
+}
+
+impl IntoParameter for Option<NaiveTime> {
+    type Parameter = Nullable<ChronoTime>;
+
+    fn into_parameter(self) -> Self::Parameter {
+        match self {
+            Some(time) => Nullable::new(ChronoTime::from_naive(time)),
+            None => Nullable::null(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ::chrono::NaiveDate;
+    use odbc_sys::{Date, Time, Timestamp};
+
+    use crate::{
+        buffers::{AnyBuffer, AnySlice, AnySliceMut, ColumnBuffer, Item},
+        handles::HasDataType,
+        DataType, Error,
+    };
+
+    use super::{
+        from_date, from_time, from_timestamp, to_date, to_time, to_timestamp, ChronoDate,
+        ChronoTime, ChronoTimestamp,
+    };
+
+    #[test]
+    fn round_trip_timestamp() {
+        let date_time = NaiveDate::from_ymd_opt(1999, 12, 31)
+            .unwrap()
+            .and_hms_nano_opt(23, 59, 59, 999_999_999)
+            .unwrap();
+
+        let timestamp = to_timestamp(date_time).unwrap();
+
+        assert_eq!(999_999_999, timestamp.fraction);
+        assert_eq!(Some(date_time), from_timestamp(&timestamp));
+    }
+
+    #[test]
+    fn leap_second_is_clamped_to_last_nanosecond() {
+        let date_time = NaiveDate::from_ymd_opt(2016, 12, 31)
+            .unwrap()
+            .and_hms_nano_opt(23, 59, 59, 1_500_000_000)
+            .unwrap();
+
+        let timestamp = to_timestamp(date_time).unwrap();
+
+        assert_eq!(59, timestamp.second);
+        assert_eq!(999_999_999, timestamp.fraction);
+    }
+
+    #[test]
+    fn timestamp_parameter_truncates_to_100_nanoseconds() {
+        let date_time = NaiveDate::from_ymd_opt(2025, 1, 31)
+            .unwrap()
+            .and_hms_nano_opt(13, 45, 8, 123_456_789)
+            .unwrap();
+
+        let parameter = ChronoTimestamp::try_from(date_time).unwrap();
+
+        assert_eq!(123_456_700, parameter.0.fraction);
+        assert_eq!(DataType::Timestamp { precision: 7 }, parameter.data_type());
+    }
+
+    #[test]
+    fn year_outside_of_16_bit_range() {
+        let date = NaiveDate::from_ymd_opt(40_000, 2, 29).unwrap();
+        let date_time = date.and_hms_opt(12, 0, 0).unwrap();
+
+        assert_eq!(None, to_date(date));
+        assert_eq!(None, to_timestamp(date_time));
+        assert!(matches!(
+            ChronoDate::try_from(date),
+            Err(Error::YearOutOfRange { year: 40_000 })
+        ));
+        assert!(matches!(
+            ChronoTimestamp::try_from(date_time),
+            Err(Error::YearOutOfRange { year: 40_000 })
+        ));
+    }
+
+    #[test]
+    fn wrappers_are_extracted_from_any_slices() {
+        let date = NaiveDate::from_ymd_opt(2025, 1, 31).unwrap();
+        let date_time = date.and_hms_opt(13, 45, 8).unwrap();
+        let time = date_time.time();
+        let mut dates = [Date::default()];
+        let mut times = [Time::default()];
+        let mut timestamps = AnyBuffer::from_desc(2, ChronoTimestamp::buffer_desc(true));
+
+        AnySliceMut::Date(&mut dates)
+            .as_slice::<ChronoDate>()
+            .unwrap()[0] = ChronoDate::from_naive(date).unwrap();
+        AnySliceMut::Time(&mut times)
+            .as_slice::<ChronoTime>()
+            .unwrap()[0] = ChronoTime::from_naive(time);
+        let AnyBuffer::NullableTimestamp(column) = &mut timestamps else {
+            panic!("Nullable timestamps must be buffered as such")
+        };
+        let mut writer = AnySliceMut::NullableTimestamp(column.writer_n(2))
+            .as_nullable_slice::<ChronoTimestamp>()
+            .unwrap();
+        writer.set_cell(0, ChronoTimestamp::from_naive(date_time));
+        writer.set_cell(1, None);
+
+        let dates = AnySlice::Date(&dates).as_slice::<ChronoDate>().unwrap();
+        let times = AnySlice::Time(&times).as_slice::<ChronoTime>().unwrap();
+        let timestamps: Vec<_> = timestamps
+            .view(2)
+            .as_nullable_slice::<ChronoTimestamp>()
+            .unwrap()
+            .map(|timestamp| timestamp.and_then(ChronoTimestamp::to_naive))
+            .collect();
+        assert_eq!(Some(date), dates[0].to_naive());
+        assert_eq!(Some(time), times[0].to_naive());
+        assert_eq!(vec![Some(date_time), None], timestamps);
+    }
+
+    #[test]
+    fn time_drops_fraction() {
+        let time = NaiveDate::from_ymd_opt(2025, 1, 31)
+            .unwrap()
+            .and_hms_milli_opt(1, 2, 3, 400)
+            .unwrap()
+            .time();
+
+        assert_eq!(
+            Time {
+                hour: 1,
+                minute: 2,
+                second: 3
+            },
+            to_time(time)
+        );
+    }
+
+    #[test]
+    fn invalid_structs_are_none() {
+        let date = Date {
+            year: 2025,
+            month: 2,
+            day: 30,
+        };
+        let time = Time {
+            hour: 24,
+            minute: 0,
+            second: 0,
+        };
+        let timestamp = Timestamp {
+            year: 2025,
+            month: 1,
+            day: 1,
+            hour: 0,
+            minute: 0,
+            second: 0,
+            fraction: 1_000_000_000,
+        };
+
+        assert_eq!(None, from_date(&date));
+        assert_eq!(None, from_time(&time));
+        assert_eq!(None, from_timestamp(&timestamp));
+    }
+}
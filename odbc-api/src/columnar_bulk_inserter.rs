@@ -1,7 +1,10 @@
+use odbc_sys::{CDataType, Pointer};
+
 use crate::{
     buffers::{ColumnBuffer, TextColumn},
-    execute::execute,
-    handles::{AsStatementRef, HasDataType, Statement, StatementRef},
+    connection::TransactionTracker,
+    execute::{execute, row_count},
+    handles::{AsStatementRef, CData, Descriptor, HasDataType, Statement, StatementRef},
     CursorImpl, Error,
 };
 
@@ -22,6 +25,9 @@ pub struct ColumnarBulkInserter<S, C> {
     capacity: usize,
     /// We maintain the invariant that none of these buffers is truncated.
     parameters: Vec<C>,
+    /// Marked each time the statement is executed, since this may start a transaction on the
+    /// connection.
+    transaction: TransactionTracker,
 }
 
 impl<S, C> ColumnarBulkInserter<S, C>
@@ -45,10 +51,7 @@ where
         let mut parameter_number = 1;
         // Bind buffers to statement.
         for column in &parameters {
-            if let Err(error) = stmt
-                .bind_input_parameter(parameter_number, column)
-                .into_result(&stmt)
-            {
+            if let Err(error) = bind_input_column(&mut stmt, parameter_number, column) {
                 // This early return using `?` is risky. We actually did bind some parameters
                 // already. We cannot guarantee that the bound pointers stay valid in case of an
                 // error since `Self` is never constructed. We would away with this, if we took
@@ -70,9 +73,16 @@ where
             parameter_set_size: 0,
             capacity,
             parameters,
+            transaction: TransactionTracker::default(),
         })
     }
 
+    /// Shares the transaction state with the connection the statement has been prepared on.
+    pub(crate) fn with_transaction(mut self, transaction: TransactionTracker) -> Self {
+        self.transaction = transaction;
+        self
+    }
+
     /// Execute the prepared statement, with the parameters bound
     pub fn execute(&mut self) -> Result<Option<CursorImpl<StatementRef<'_>>>, Error> {
         let mut stmt = self.statement.as_stmt_ref();
@@ -85,6 +95,7 @@ where
                 // We reset the parameter set size, in order to adequatly handle batches of
                 // different size then inserting into the database.
                 stmt.set_paramset_size(self.parameter_set_size);
+                self.transaction.mark();
                 execute(stmt, None)
             }
         }
@@ -190,10 +201,152 @@ where
         }
     }
 
+    /// Like [`Self::column_mut`], but returns [`Error::BufferIndexOutOfRange`] instead of
+    /// panicking, if `buffer_index` does not refer to a bound parameter buffer. Useful in generic
+    /// code where the index is computed at runtime.
+    pub fn try_column_mut<'a>(&'a mut self, buffer_index: usize) -> Result<C::SliceMut, Error>
+    where
+        C: BoundInputSlice<'a>,
+    {
+        let num_buffers = self.parameters.len();
+        if buffer_index >= num_buffers {
+            return Err(Error::BufferIndexOutOfRange {
+                buffer_index,
+                num_buffers,
+            });
+        }
+        Ok(self.column_mut(buffer_index))
+    }
+
     /// Maximum number of rows the buffer can hold at once.
     pub fn capacity(&self) -> usize {
         self.capacity
     }
+
+    /// Appends each of the `rows` to the buffer, using [`InsertRow::write_row`] to fill in the
+    /// values. Whenever the buffer is full, its contents are sent to the database using
+    /// [`Self::execute`] and the buffer is cleared. Rows remaining in the buffer after the last
+    /// element of the iterator are sent, too, so the buffer is empty once this method returns.
+    ///
+    /// Returns the total number of rows affected by all executions, or `None` if the driver could
+    /// not report the number of affected rows for any of them.
+    ///
+    /// ```no_run
+    /// use odbc_api::{
+    ///     buffers::{AnyBuffer, BufferDesc},
+    ///     handles::AsStatementRef,
+    ///     ColumnarBulkInserter, Connection, Error, InsertRow,
+    /// };
+    ///
+    /// struct Birthday<'a> {
+    ///     name: &'a str,
+    ///     year: i16,
+    /// }
+    ///
+    /// impl InsertRow<AnyBuffer> for Birthday<'_> {
+    ///     fn write_row<S: AsStatementRef>(
+    ///         self,
+    ///         inserter: &mut ColumnarBulkInserter<S, AnyBuffer>,
+    ///         row_index: usize,
+    ///     ) -> Result<(), Error> {
+    ///         let mut names = inserter.column_mut(0).as_text_view().unwrap();
+    ///         names.ensure_max_element_length(self.name.len(), row_index)?;
+    ///         names.set_cell(row_index, Some(self.name.as_bytes()));
+    ///         inserter.column_mut(1).as_slice::<i16>().unwrap()[row_index] = self.year;
+    ///         Ok(())
+    ///     }
+    /// }
+    ///
+    /// fn insert_birthdays(conn: &Connection<'_>, birthdays: Vec<Birthday>) -> Result<(), Error> {
+    ///     let prepared = conn.prepare("INSERT INTO Birthdays (name, year) VALUES (?, ?)")?;
+    ///     let descs = [
+    ///         BufferDesc::Text { max_str_len: 50 },
+    ///         BufferDesc::I16 { nullable: false },
+    ///     ];
+    ///     let mut inserter = prepared.into_column_inserter(1000, descs)?;
+    ///     inserter.extend_rows(birthdays)?;
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// Should writing or sending any row fail, [`Error::ExtendRowsFailed`] is returned. It tells how
+    /// many rows have already been sent to the data source. The row which failed to be written is
+    /// not part of the buffer, yet rows written before it and not sent yet remain in the buffer.
+    ///
+    /// Panics if the capacity of the buffer is zero.
+    pub fn extend_rows<R>(
+        &mut self,
+        rows: impl IntoIterator<Item = R>,
+    ) -> Result<Option<usize>, Error>
+    where
+        R: InsertRow<C>,
+    {
+        assert!(
+            self.capacity > 0,
+            "Rows can not be appended to a buffer with a capacity of zero."
+        );
+        let mut rows_flushed = 0;
+        self.extend_rows_impl(rows, &mut rows_flushed)
+            .map_err(|source| Error::ExtendRowsFailed {
+                rows_flushed,
+                source: Box::new(source),
+            })
+    }
+
+    fn extend_rows_impl<R>(
+        &mut self,
+        rows: impl IntoIterator<Item = R>,
+        rows_flushed: &mut usize,
+    ) -> Result<Option<usize>, Error>
+    where
+        R: InsertRow<C>,
+    {
+        let mut affected_rows = Some(0);
+        for row in rows {
+            if self.parameter_set_size == self.capacity {
+                affected_rows = Self::add_row_count(affected_rows, self.flush(rows_flushed)?);
+            }
+            let row_index = self.parameter_set_size;
+            self.set_num_rows(row_index + 1);
+            if let Err(error) = row.write_row(self, row_index) {
+                // Do not send the partially written row.
+                self.set_num_rows(row_index);
+                return Err(error);
+            }
+        }
+        if self.parameter_set_size != 0 {
+            affected_rows = Self::add_row_count(affected_rows, self.flush(rows_flushed)?);
+        }
+        Ok(affected_rows)
+    }
+
+    /// Executes the statement with the rows currently in the buffer and clears it afterwards.
+    /// Returns the number of affected rows, if reported by the driver. `rows_flushed` is
+    /// incremented by the number of rows sent.
+    fn flush(&mut self, rows_flushed: &mut usize) -> Result<Option<usize>, Error> {
+        let num_rows = self.parameter_set_size;
+        self.execute()?;
+        *rows_flushed += num_rows;
+        self.clear();
+        row_count(self.statement.as_stmt_ref())
+    }
+
+    fn add_row_count(total: Option<usize>, batch: Option<usize>) -> Option<usize> {
+        Some(total? + batch?)
+    }
+}
+
+/// A row which can be written into the buffers of a [`ColumnarBulkInserter`]. Implement this for
+/// your own row types, in order to insert them using [`ColumnarBulkInserter::extend_rows`].
+pub trait InsertRow<C> {
+    /// Writes the values of this row into the parameter buffers of `inserter` at `row_index`. The
+    /// number of rows of `inserter` already includes the row, so its buffers can be accessed using
+    /// [`ColumnarBulkInserter::column_mut`].
+    fn write_row<S: AsStatementRef>(
+        self,
+        inserter: &mut ColumnarBulkInserter<S, C>,
+        row_index: usize,
+    ) -> Result<(), Error>;
 }
 
 /// You can obtain a mutable slice of a column buffer which allows you to change its contents.
@@ -266,3 +419,84 @@ impl<S> ColumnarBulkInserter<S, TextColumn<u8>> {
         Ok(())
     }
 }
+
+/// Binds `column` as an input parameter. For numeric structs precision and scale are also set in
+/// the application parameter descriptor. Otherwise drivers interpret the values using a scale of
+/// zero, i.e. all fractional digits would be lost.
+///
+/// # Safety
+///
+/// `column` must stay valid as long as it is bound to the statement.
+unsafe fn bind_input_column(
+    stmt: &mut StatementRef<'_>,
+    parameter_number: u16,
+    column: &(impl CData + HasDataType),
+) -> Result<(), Error> {
+    stmt.bind_input_parameter(parameter_number, column)
+        .into_result(stmt)?;
+    if column.cdata_type() != CDataType::Numeric {
+        return Ok(());
+    }
+    let data_type = column.data_type();
+    let rec_number = parameter_number as i16;
+    // Only keep the raw handle from the result, so the error can borrow the statement to fetch
+    // diagnostics.
+    let apd = stmt
+        .application_parameter_descriptor()
+        .map(|apd| apd.as_sys())
+        .into_result(stmt)?;
+    let mut apd = Descriptor::new(apd);
+    apd.set_type(rec_number, CDataType::Numeric)
+        .into_result(&apd)?;
+    let precision = data_type.column_size().map_or(0, |size| size.get());
+    apd.set_precision(rec_number, precision as i16)
+        .into_result(&apd)?;
+    apd.set_scale(rec_number, data_type.decimal_digits())
+        .into_result(&apd)?;
+    // Setting any other field of the descriptor record unbinds the data pointer, so it is set last.
+    apd.set_data_ptr(rec_number, column.value_ptr() as Pointer)
+        .into_result(&apd)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        buffers::AnyBuffer,
+        connection::TransactionTracker,
+        handles::{AsStatementRef, StatementRef},
+        Error,
+    };
+
+    use super::ColumnarBulkInserter;
+
+    /// Stand in for a statement, for tests which must not reach the driver.
+    struct UnreachableStatement;
+
+    impl AsStatementRef for UnreachableStatement {
+        fn as_stmt_ref(&mut self) -> StatementRef<'_> {
+            unreachable!("Statement must not be accessed")
+        }
+    }
+
+    #[test]
+    fn try_column_mut_with_out_of_range_index() {
+        let mut inserter = ColumnarBulkInserter {
+            statement: UnreachableStatement,
+            parameter_set_size: 0,
+            capacity: 10,
+            parameters: vec![AnyBuffer::I32(vec![0; 10]), AnyBuffer::I32(vec![0; 10])],
+            transaction: TransactionTracker::default(),
+        };
+
+        let result = inserter.try_column_mut(2);
+
+        assert!(matches!(
+            result,
+            Err(Error::BufferIndexOutOfRange {
+                buffer_index: 2,
+                num_buffers: 2
+            })
+        ));
+    }
+}
@@ -1,23 +1,38 @@
 use crate::{
     buffers::BufferDesc,
+    error::ExtendResult,
     execute::{
         execute_columns, execute_foreign_keys, execute_tables, execute_with_parameters,
-        execute_with_parameters_polling,
+        execute_with_parameters_polling, map_timeout_expired, timeout_in_sec,
     },
-    handles::{self, slice_to_utf8, SqlText, State, Statement, StatementImpl},
+    handles::{self, slice_to_utf8, HasDataType, SqlText, State, Statement, StatementImpl},
+    instrumentation::{instrumented, ExecEvent, ExecPhase, Instrumentation},
+    parameter::InputParameter,
+    parameter_log::LoggedParameters,
+    query::{count_placeholders, next_placeholder},
     statement_connection::StatementConnection,
-    CursorImpl, CursorPolling, Error, ParameterCollectionRef, Preallocated, Prepared, Sleep,
+    ColumnDescription, Cursor, CursorImpl, CursorPolling, DataType, Error, Nullability, Nullable,
+    ParameterCollectionRef, Preallocated, Prepared, Sleep, TempTable,
 };
 use log::error;
-use odbc_sys::HDbc;
+use odbc_sys::{HDbc, SqlDataType, StatementAttribute};
 use std::{
     borrow::Cow,
     fmt::{self, Debug, Display},
     mem::ManuallyDrop,
+    ops::RangeInclusive,
     str,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
     thread::panicking,
+    time::Duration,
 };
 
+/// Maximum length in bytes of the SQL text attached to [`Error::ExecutionFailed`].
+const MAX_SQL_LEN_IN_ERROR: usize = 1000;
+
 impl Drop for Connection<'_> {
     fn drop(&mut self) {
         match self.connection.disconnect().into_result(&self.connection) {
@@ -68,17 +83,33 @@ impl Drop for Connection<'_> {
 /// look at [`crate::Environment::set_connection_pooling`].
 pub struct Connection<'c> {
     connection: handles::Connection<'c>,
+    /// Callback invoked with the duration of each statement prepared or executed directly over
+    /// this connection. `None` by default, in which case nothing is measured.
+    instrumentation: Option<Instrumentation>,
+    /// Marked if a statement has been executed on this connection since the last commit or
+    /// rollback. In manual-commit mode any statement may start a transaction, even a `SELECT`.
+    transaction: TransactionTracker,
+    /// If `true` the values of parameters passed to [`Self::execute`] are logged. `false` by
+    /// default.
+    parameter_logging: bool,
 }
 
 impl<'c> Connection<'c> {
     pub(crate) fn new(connection: handles::Connection<'c>) -> Self {
-        Self { connection }
+        Self {
+            connection,
+            instrumentation: None,
+            transaction: TransactionTracker::default(),
+            parameter_logging: false,
+        }
     }
 
     /// Transfers ownership of the handle to this open connection to the raw ODBC pointer.
     pub fn into_sys(self) -> HDbc {
         // We do not want to run the drop handler, but transfer ownership instead.
-        ManuallyDrop::new(self).connection.as_sys()
+        let mut this = ManuallyDrop::new(self);
+        this.instrumentation = None;
+        this.connection.as_sys()
     }
 
     /// Transfer ownership of this open connection to a wrapper around the raw ODBC pointer. The
@@ -89,7 +120,83 @@ impl<'c> Connection<'c> {
     /// but, in case it is not, this may help you to break out of the type structure which might be
     /// to rigid for you, while simultaneously abondoning its safeguards.
     pub fn into_handle(self) -> handles::Connection<'c> {
-        unsafe { handles::Connection::new(ManuallyDrop::new(self).connection.as_sys()) }
+        unsafe { handles::Connection::new(self.into_sys()) }
+    }
+
+    /// Registers a callback, which is invoked with the wall clock time spent each time a statement
+    /// is prepared ([`Self::prepare`], [`Self::into_prepared`]) or executed ([`Self::execute`] and
+    /// the methods build upon it, or [`Prepared::execute`]) over this connection. The callback is
+    /// also invoked for each row or row set fetched from the cursors these executions return.
+    /// Statements and cursors keep reporting to the callback, which has been registered at the time
+    /// they have been created. Replaces any previously registered callback. The callback is invoked
+    /// also if the operation fails.
+    ///
+    /// Unless a callback is registered, no time is measured at all.
+    ///
+    /// ```no_run
+    /// use odbc_api::{Connection, ExecPhase};
+    ///
+    /// fn log_slow_statements(conn: &mut Connection<'_>) {
+    ///     conn.set_instrumentation(|event| {
+    ///         if event.phase == ExecPhase::Execute && event.duration.as_secs() > 10 {
+    ///             eprintln!("Slow statement: {}", event.sql.unwrap_or_default());
+    ///         }
+    ///     });
+    /// }
+    /// ```
+    pub fn set_instrumentation(&mut self, callback: impl Fn(&ExecEvent) + Send + Sync + 'static) {
+        self.instrumentation = Some(Arc::new(callback));
+    }
+
+    /// Removes the callback registered with [`Self::set_instrumentation`].
+    pub fn clear_instrumentation(&mut self) {
+        self.instrumentation = None;
+    }
+
+    /// Emits the value of each parameter passed to [`Self::execute`] (and the methods build upon
+    /// it) as a `trace` record using the `log` crate. Intended for debugging parameterized queries.
+    /// Values of parameters wrapped in [`crate::Sensitive`] are replaced by `<redacted>`. Values
+    /// which can not be formatted are represented by their C data type.
+    ///
+    /// Parameter values may contain personal data, which is why nothing is logged unless this
+    /// method is called. Mind that all non-sensitive values end up wherever your logger sends
+    /// them.
+    ///
+    /// ```no_run
+    /// use odbc_api::{Connection, Error, IntoParameter, Sensitive};
+    ///
+    /// fn login(conn: &mut Connection<'_>, user: &str, password: &str) -> Result<(), Error> {
+    ///     // Log the user name, but not the password
+    ///     conn.enable_parameter_logging();
+    ///     let password = password.into_parameter();
+    ///     let params = (&user.into_parameter(), Sensitive(&password));
+    ///     conn.execute("CALL Login(?, ?)", params)?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn enable_parameter_logging(&mut self) {
+        self.parameter_logging = true;
+    }
+
+    /// Stops logging parameter values enabled with [`Self::enable_parameter_logging`].
+    pub fn disable_parameter_logging(&mut self) {
+        self.parameter_logging = false;
+    }
+
+    /// Invokes `operation` and reports the time it took to the instrumentation callback, if any.
+    fn instrumented<T>(&self, phase: ExecPhase, sql: &str, operation: impl FnOnce() -> T) -> T {
+        instrumented(self.instrumentation.as_ref(), phase, Some(sql), operation)
+    }
+
+    /// Like [`Self::instrumented`] for the execution of `sql`. The returned cursor reports its
+    /// fetches to the instrumentation callback, too.
+    fn instrumented_execute<'s>(
+        &self,
+        sql: &str,
+        operation: impl FnOnce() -> Result<Option<CursorImpl<StatementImpl<'s>>>, Error>,
+    ) -> Result<Option<CursorImpl<StatementImpl<'s>>>, Error> {
+        let maybe_cursor = self.instrumented(ExecPhase::Execute, sql, operation)?;
+        Ok(maybe_cursor.map(|cursor| cursor.with_instrumentation(self.instrumentation.clone())))
     }
 
     /// Executes an SQL statement. This is the fastest way to submit an SQL statement for one-time
@@ -98,6 +205,7 @@ impl<'c> Connection<'c> {
     /// # Parameters
     ///
     /// * `query`: The text representation of the SQL statement. E.g. "SELECT * FROM my_table;".
+    ///   Both `&str` and `String` are accepted, so the result of `format!` can be passed directly.
     /// * `params`: `?` may be used as a placeholder in the statement text. You can use `()` to
     ///   represent no parameters. See the [`crate::parameter`] module level documentation for more
     ///   information on how to pass parameters.
@@ -108,6 +216,12 @@ impl<'c> Connection<'c> {
     /// e.g. the query came back empty). Note that an empty query may also create a cursor with zero
     /// rows.
     ///
+    /// The cursor only borrows the connection immutably, so several cursors of the same connection
+    /// may be alive at once. Whether the driver allows for fetching them interleaved is another
+    /// matter. Microsoft SQL Server e.g. requires Multiple Active Result Sets to be enabled in the
+    /// connection string (`MARS_Connection=yes;`), otherwise executing the second statement fails
+    /// with "Connection is busy with results for another command".
+    ///
     /// # Example
     ///
     /// ```no_run
@@ -126,12 +240,202 @@ impl<'c> Connection<'c> {
     /// ```
     pub fn execute(
         &self,
-        query: &str,
+        query: impl AsRef<str>,
         params: impl ParameterCollectionRef,
     ) -> Result<Option<CursorImpl<StatementImpl<'_>>>, Error> {
-        let query = SqlText::new(query);
+        let sql = query.as_ref();
+        let query = SqlText::new(sql);
         let lazy_statement = move || self.allocate_statement();
-        execute_with_parameters(lazy_statement, Some(&query), params)
+        self.instrumented_execute(sql, || {
+            if self.parameter_logging {
+                let params = LoggedParameters::new(params);
+                execute_with_parameters(lazy_statement, Some(&query), params)
+            } else {
+                execute_with_parameters(lazy_statement, Some(&query), params)
+            }
+        })
+    }
+
+    /// Like [`Self::execute`], but applies `options` to the statement before executing it. Use
+    /// this to e.g. pick the cursor type best suited for a large scan.
+    ///
+    /// ```no_run
+    /// use odbc_api::{Connection, CursorType, Error, StatementOptions};
+    ///
+    /// fn scan(conn: &Connection<'_>) -> Result<(), Error> {
+    ///     let options = StatementOptions {
+    ///         cursor_type: Some(CursorType::ForwardOnly),
+    ///         ..StatementOptions::default()
+    ///     };
+    ///     if let Some(_cursor) = conn.execute_with_options("SELECT * FROM Movies", (), options)? {
+    ///         // Fetch rows
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn execute_with_options(
+        &self,
+        query: &str,
+        params: impl ParameterCollectionRef,
+        options: StatementOptions,
+    ) -> Result<Option<CursorImpl<StatementImpl<'_>>>, Error> {
+        let sql = query;
+        let query = SqlText::new(sql);
+        let lazy_statement = move || {
+            let mut stmt = self.allocate_statement()?;
+            options.apply(&mut stmt)?;
+            Ok(stmt)
+        };
+        self.instrumented_execute(sql, || {
+            execute_with_parameters(lazy_statement, Some(&query), params)
+        })
+    }
+
+    /// Like [`Self::execute`], but the data source gives up executing the statement once `timeout`
+    /// has expired. In that case [`Error::QueryTimeout`] is returned. ODBC specifies timeouts in
+    /// whole seconds, so `timeout` is rounded up. A zero duration means no timeout. Drivers may
+    /// not support timeouts, or only honor them for some statements.
+    ///
+    /// This corresponds to the `SQL_ATTR_QUERY_TIMEOUT` statement attribute in the ODBC
+    /// specification.
+    ///
+    /// ```no_run
+    /// use odbc_api::{Connection, Error};
+    /// use std::time::Duration;
+    ///
+    /// fn report(conn: &Connection<'_>) -> Result<(), Error> {
+    ///     let query = "SELECT * FROM Sales";
+    ///     match conn.execute_with_timeout(query, (), Duration::from_secs(30)) {
+    ///         Ok(_cursor) => { /* Fetch rows */ }
+    ///         Err(Error::QueryTimeout(_)) => println!("Report took too long. Try again later."),
+    ///         Err(other) => return Err(other),
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn execute_with_timeout(
+        &self,
+        query: &str,
+        params: impl ParameterCollectionRef,
+        timeout: Duration,
+    ) -> Result<Option<CursorImpl<StatementImpl<'_>>>, Error> {
+        let sql = query;
+        let query = SqlText::new(sql);
+        // The statement is allocated for this execution only, so there is no need to reset the
+        // timeout afterwards.
+        let lazy_statement = move || {
+            let mut stmt = self.allocate_statement()?;
+            stmt.set_query_timeout_sec(timeout_in_sec(timeout))
+                .into_result(&stmt)?;
+            Ok(stmt)
+        };
+        self.instrumented_execute(sql, || {
+            execute_with_parameters(lazy_statement, Some(&query), params)
+                .provide_context_for_diagnostic(map_timeout_expired)
+        })
+    }
+
+    /// Like [`Self::execute`], but validates the number of parameters against the number of
+    /// placeholders (`?`) in `query`, before sending anything to the data source. Question marks
+    /// within quoted string literals, quoted identifiers and comments are not counted as
+    /// placeholders. If the number of parameters can not be known upfront, as is the case e.g. for
+    /// sparse parameters, the validation is skipped.
+    ///
+    /// ```no_run
+    /// use odbc_api::{Connection, Error};
+    ///
+    /// fn insert(conn: &Connection<'_>) -> Result<(), Error> {
+    ///     // One parameter is missing
+    ///     let result = conn.execute_checked("INSERT INTO T (a, b) VALUES (?, ?)", &42);
+    ///     assert!(matches!(
+    ///         result,
+    ///         Err(Error::ParameterCountMismatch { expected: 2, provided: 1 })
+    ///     ));
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn execute_checked(
+        &self,
+        query: &str,
+        params: impl ParameterCollectionRef,
+    ) -> Result<Option<CursorImpl<StatementImpl<'_>>>, Error> {
+        if let Some(provided) = params.num_parameters() {
+            let expected = count_placeholders(query);
+            if expected != provided {
+                return Err(Error::ParameterCountMismatch { expected, provided });
+            }
+        }
+        self.execute(query, params)
+    }
+
+    /// Like [`Self::execute`], but should the execution fail, the error is wrapped in an
+    /// [`Error::ExecutionFailed`] carrying the text of `query`. Helpful to tell which statement
+    /// failed, in applications executing many of them. Queries longer than 1000 bytes are
+    /// truncated in the error. Parameter values are not included, to avoid leaking data into logs.
+    ///
+    /// ```no_run
+    /// use odbc_api::{Connection, Error};
+    ///
+    /// fn drop_table(conn: &Connection<'_>) {
+    ///     if let Err(error) = conn.execute_with_sql_context("DROP TABLE Movies", ()) {
+    ///         // Prints e.g. "Failed to execute 'DROP TABLE Movies': ..."
+    ///         eprintln!("{error}");
+    ///     }
+    /// }
+    /// ```
+    pub fn execute_with_sql_context(
+        &self,
+        query: &str,
+        params: impl ParameterCollectionRef,
+    ) -> Result<Option<CursorImpl<StatementImpl<'_>>>, Error> {
+        self.execute(query, params)
+            .map_err(|source| Error::ExecutionFailed {
+                sql: truncate_sql(query).into_owned(),
+                source: Box::new(source),
+            })
+    }
+
+    /// Like [`Self::execute`], but lets Microsoft SQL Server cache and reuse the execution plan of
+    /// `query`, without the need to keep a [`Prepared`] statement around. On Microsoft SQL Server
+    /// the query is wrapped in a call to `sp_executesql`. The placeholders (`?`) are declared as
+    /// typed parameters, with their types derived from the [`DataType`] of `params`. Text and
+    /// binary parameters of variable length are declared with a length of `MAX`, so values of
+    /// different lengths share the same plan. Other data sources execute `query` directly.
+    ///
+    /// The number of placeholders in `query` must match the number of `params`. Otherwise
+    /// [`Error::ParameterCountMismatch`] is returned. Question marks in literals, quoted
+    /// identifiers and comments are not counted, see [`Self::execute_checked`].
+    ///
+    /// Plan reuse is a performance property, the results are identical to those of
+    /// [`Self::execute`].
+    ///
+    /// ```no_run
+    /// use odbc_api::{Connection, Error, IntoParameter, parameter::InputParameter};
+    ///
+    /// fn find_user(conn: &Connection<'_>, name: &str) -> Result<(), Error> {
+    ///     let params: [Box<dyn InputParameter>; 1] = [Box::new(name.to_owned().into_parameter())];
+    ///     conn.execute_reusing_plan("SELECT id FROM Users WHERE name = ?", &params)?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn execute_reusing_plan(
+        &self,
+        query: &str,
+        params: &[Box<dyn InputParameter>],
+    ) -> Result<Option<CursorImpl<StatementImpl<'_>>>, Error> {
+        let expected = count_placeholders(query);
+        if expected != params.len() {
+            return Err(Error::ParameterCountMismatch {
+                expected,
+                provided: params.len(),
+            });
+        }
+        if self.dbms()? == Dbms::MsSql {
+            let query = sp_executesql(query, params.iter().map(|param| param.data_type()));
+            self.execute(&query, params)
+        } else {
+            self.execute(query, params)
+        }
     }
 
     /// Asynchronous sibling of [`Self::execute`]. Uses polling mode to be asynchronous. `sleep`
@@ -165,11 +469,11 @@ impl<'c> Connection<'c> {
     /// just be aware that may block until they are finished.
     pub async fn execute_polling(
         &self,
-        query: &str,
+        query: impl AsRef<str>,
         params: impl ParameterCollectionRef,
         sleep: impl Sleep,
     ) -> Result<Option<CursorPolling<StatementImpl<'_>>>, Error> {
-        let query = SqlText::new(query);
+        let query = SqlText::new(query.as_ref());
         let lazy_statement = move || {
             let mut stmt = self.allocate_statement()?;
             stmt.set_async_enable(true).into_result(&stmt)?;
@@ -206,7 +510,7 @@ impl<'c> Connection<'c> {
     /// ```
     pub fn into_cursor(
         self,
-        query: &str,
+        query: impl AsRef<str>,
         params: impl ParameterCollectionRef,
     ) -> Result<Option<CursorImpl<StatementConnection<'c>>>, ConnectionAndError<'c>> {
         // With the current Rust version the borrow checker needs some convincing, so that it allows
@@ -225,18 +529,120 @@ impl<'c> Connection<'c> {
                 connection: self,
             });
         }
-        let cursor = cursor.unwrap();
+        let instrumentation = self.instrumentation.clone();
         // The rust compiler needs some help here. It assumes otherwise that the lifetime of the
-        // resulting cursor would depend on the lifetime of `params`.
-        let mut cursor = ManuallyDrop::new(cursor);
-        let handle = cursor.as_sys();
+        // resulting cursor would depend on the lifetime of `params`. Giving up ownership of the raw
+        // handle neither closes the cursor nor frees the statement.
+        let handle = cursor.unwrap().into_stmt().into_sys();
         // Safe: `handle` is a valid statement, and we are giving up ownership of `self`.
         let statement = unsafe { StatementConnection::new(handle, self) };
         // Safe: `statement is in the cursor state`.
-        let cursor = unsafe { CursorImpl::new(statement) };
+        let cursor = unsafe { CursorImpl::new(statement) }.with_instrumentation(instrumentation);
         Ok(Some(cursor))
     }
 
+    /// Inserts a single row into `table` and returns a cursor over the values generated by the
+    /// database for the row, e.g. an identity or auto increment id. Since there is no standard way
+    /// to do this, the syntax used depends on the name of the database management system:
+    ///
+    /// * Microsoft SQL Server: `INSERT INTO ... OUTPUT INSERTED.<col> ... VALUES (...)`
+    /// * SQLite: `INSERT` followed by `SELECT last_insert_rowid()`
+    /// * MariaDB / MySQL: `INSERT` followed by `SELECT LAST_INSERT_ID()`
+    /// * Any other: `INSERT INTO ... VALUES (...) RETURNING <col>, ...` (e.g. PostgreSQL)
+    ///
+    /// SQLite and MariaDB can only report the last generated auto increment value. For these
+    /// `returning_columns` must be empty, and the cursor has exactly one column holding the id.
+    /// Otherwise [`Error::ReturningColumnsNotSupported`] is returned. All other databases require at
+    /// least one returning column, or [`Error::ReturningColumnsRequired`] is returned.
+    ///
+    /// `table` and all column names are quoted as identifiers, see [`Self::quote_identifier`]. A
+    /// `table` qualified with a schema, like `my_schema.my_table`, is split at each `.` and each part
+    /// is quoted separately. Table names containing a `.` themselves are therefore not supported.
+    ///
+    /// # Parameters
+    ///
+    /// * `table`: Name of the table to insert into.
+    /// * `columns`: Names of the columns `params` are inserted into.
+    /// * `params`: One parameter for each column in `columns`.
+    /// * `returning_columns`: Names of the columns with generated values to return. Must be empty
+    ///   for SQLite and MariaDB and must not be empty for any other database.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use odbc_api::{Connection, Cursor, Error, IntoParameter, Nullable};
+    ///
+    /// fn insert_person(conn: &Connection<'_>, name: &str) -> Result<Option<i64>, Error> {
+    ///     let mut cursor = conn.insert_returning(
+    ///         "Persons",
+    ///         &["name"],
+    ///         &name.into_parameter(),
+    ///         &["id"],
+    ///     )?;
+    ///     let mut id = Nullable::<i64>::null();
+    ///     if let Some(mut row) = cursor.next_row()? {
+    ///         row.get_data(1, &mut id)?;
+    ///     }
+    ///     Ok(id.into_opt())
+    /// }
+    /// ```
+    pub fn insert_returning(
+        &self,
+        table: &str,
+        columns: &[&str],
+        params: impl ParameterCollectionRef,
+        returning_columns: &[&str],
+    ) -> Result<CursorImpl<StatementImpl<'_>>, Error> {
+        let quote = self.identifier_quote_char()?;
+        let quote_list = |identifiers: &[&str], prefix: &str| {
+            identifiers
+                .iter()
+                .map(|identifier| format!("{prefix}{}", quote_with(identifier, &quote)))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        let table = quote_qualified_with(table, &quote);
+        let column_list = quote_list(columns, "");
+        let placeholders = vec!["?"; columns.len()].join(", ");
+        let dbms = self.dbms()?;
+        let last_generated_id = match dbms {
+            Dbms::Sqlite | Dbms::MySqlOrMaria if !returning_columns.is_empty() => {
+                return Err(Error::ReturningColumnsNotSupported { dbms });
+            }
+            Dbms::Sqlite => "SELECT last_insert_rowid()",
+            Dbms::MySqlOrMaria => "SELECT LAST_INSERT_ID()",
+            // An empty `OUTPUT` or `RETURNING` clause would be a syntax error
+            _ if returning_columns.is_empty() => {
+                return Err(Error::ReturningColumnsRequired { dbms });
+            }
+            Dbms::MsSql => {
+                let output = quote_list(returning_columns, "INSERTED.");
+                let query = format!(
+                    "INSERT INTO {table} ({column_list}) OUTPUT {output} VALUES ({placeholders})"
+                );
+                return self
+                    .execute(&query, params)?
+                    .ok_or(Error::ExpectedResultSet);
+            }
+            _ => {
+                let returning = quote_list(returning_columns, "");
+                let query = format!(
+                    "INSERT INTO {table} ({column_list}) VALUES ({placeholders}) \
+                    RETURNING {returning}"
+                );
+                return self
+                    .execute(&query, params)?
+                    .ok_or(Error::ExpectedResultSet);
+            }
+        };
+        // Database can not return generated values as part of the insert statement. Query the last
+        // generated id in a second statement on the same connection.
+        let insert = format!("INSERT INTO {table} ({column_list}) VALUES ({placeholders})");
+        self.execute(&insert, params)?;
+        self.execute(last_generated_id, ())?
+            .ok_or(Error::ExpectedResultSet)
+    }
+
     /// Prepares an SQL statement. This is recommended for repeated execution of similar queries.
     ///
     /// Should your use case require you to execute the same query several times with different
@@ -273,11 +679,38 @@ impl<'c> Connection<'c> {
     /// * `query`: The text representation of the SQL statement. E.g. "SELECT * FROM my_table;". `?`
     ///   may be used as a placeholder in the statement text, to be replaced with parameters during
     ///   execution.
-    pub fn prepare(&self, query: &str) -> Result<Prepared<StatementImpl<'_>>, Error> {
-        let query = SqlText::new(query);
+    pub fn prepare(&self, query: impl AsRef<str>) -> Result<Prepared<StatementImpl<'_>>, Error> {
+        let sql = query.as_ref();
+        let query = SqlText::new(sql);
         let mut stmt = self.allocate_statement()?;
-        stmt.prepare(&query).into_result(&stmt)?;
-        Ok(Prepared::new(stmt))
+        self.instrumented(ExecPhase::Prepare, sql, || {
+            stmt.prepare(&query).into_result(&stmt)
+        })?;
+        Ok(Prepared::new(stmt)
+            .with_instrumentation(self.instrumentation.clone(), sql)
+            .with_transaction(self.transaction.clone()))
+    }
+
+    /// Describes the columns of the result set `query` would produce, without executing it. The
+    /// statement is only prepared, and discarded after its columns have been described, so no rows
+    /// are transferred. Empty if the statement would not produce a result set.
+    ///
+    /// Note that some drivers execute the statement during preparation in order to learn about the
+    /// schema of the result set.
+    ///
+    /// ```
+    /// use odbc_api::{Connection, Error};
+    ///
+    /// fn print_schema(conn: &Connection<'_>) -> Result<(), Error> {
+    ///     for column in conn.describe_query("SELECT title, year FROM Movies")? {
+    ///         println!("{}: {:?}", column.name_to_string().unwrap(), column.data_type);
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn describe_query(&self, query: &str) -> Result<Vec<ColumnDescription>, Error> {
+        let mut prepared = self.prepare(query)?;
+        Ok(prepared.cached_columns()?.to_vec())
     }
 
     /// Prepares an SQL statement which takes ownership of the connection. The advantage over
@@ -323,13 +756,23 @@ impl<'c> Connection<'c> {
     ///     prepared.into_column_inserter(capacity, buffers)
     /// }
     /// ```
-    pub fn into_prepared(self, query: &str) -> Result<Prepared<StatementConnection<'c>>, Error> {
-        let query = SqlText::new(query);
+    pub fn into_prepared(
+        self,
+        query: impl AsRef<str>,
+    ) -> Result<Prepared<StatementConnection<'c>>, Error> {
+        let sql = query.as_ref();
+        let query = SqlText::new(sql);
         let mut stmt = self.allocate_statement()?;
-        stmt.prepare(&query).into_result(&stmt)?;
+        self.instrumented(ExecPhase::Prepare, sql, || {
+            stmt.prepare(&query).into_result(&stmt)
+        })?;
+        let instrumentation = self.instrumentation.clone();
+        let transaction = self.transaction.clone();
         // Safe: `handle` is a valid statement, and we are giving up ownership of `self`.
         let stmt = unsafe { StatementConnection::new(stmt.into_sys(), self) };
-        Ok(Prepared::new(stmt))
+        Ok(Prepared::new(stmt)
+            .with_instrumentation(instrumentation, sql)
+            .with_transaction(transaction))
     }
 
     /// Allocates an SQL statement handle. This is recommended if you want to sequentially execute
@@ -363,8 +806,13 @@ impl<'c> Connection<'c> {
     /// }
     /// ```
     pub fn preallocate(&self) -> Result<Preallocated<'_>, Error> {
-        let stmt = self.allocate_statement()?;
-        unsafe { Ok(Preallocated::new(stmt)) }
+        // Allocating the statement does not start a transaction, executing it does. So we do not
+        // use `allocate_statement`, but let the preallocated statement mark the transaction.
+        let stmt = self
+            .connection
+            .allocate_statement()
+            .into_result(&self.connection)?;
+        unsafe { Ok(Preallocated::new(stmt).with_transaction(self.transaction.clone())) }
     }
 
     /// Specify the transaction mode. By default, ODBC transactions are in auto-commit mode.
@@ -380,17 +828,156 @@ impl<'c> Connection<'c> {
     pub fn set_autocommit(&self, enabled: bool) -> Result<(), Error> {
         self.connection
             .set_autocommit(enabled)
+            .into_result(&self.connection)?;
+        // Either any open transaction has just been committed, or we have been in auto-commit mode
+        // until now, in which case there is no open transaction either.
+        self.transaction.reset();
+        Ok(())
+    }
+
+    /// `true` if the connection is in auto-commit mode, `false` if it is in manual-commit mode.
+    /// See [`Self::set_autocommit`].
+    pub fn is_autocommit(&self) -> Result<bool, Error> {
+        self.connection
+            .is_autocommit()
             .into_result(&self.connection)
     }
 
     /// To commit a transaction in manual-commit mode.
     pub fn commit(&self) -> Result<(), Error> {
-        self.connection.commit().into_result(&self.connection)
+        self.connection.commit().into_result(&self.connection)?;
+        self.transaction.reset();
+        Ok(())
     }
 
     /// To rollback a transaction in manual-commit mode.
     pub fn rollback(&self) -> Result<(), Error> {
-        self.connection.rollback().into_result(&self.connection)
+        self.connection.rollback().into_result(&self.connection)?;
+        self.transaction.reset();
+        Ok(())
+    }
+
+    /// `true` if the connection is in manual-commit mode and a transaction may have been started
+    /// since the last commit or rollback. ODBC offers no way to ask the driver about an open
+    /// transaction, so this is tracked by the connection: Executing or preparing any statement on
+    /// it (including `SELECT`s and catalog functions) is considered to start a transaction. This
+    /// includes executing a [`Prepared`] or [`Preallocated`] statement (or the bulk inserters
+    /// created from them) allocated on this connection, even if it has been allocated before the
+    /// last commit. Statements constructed from raw handles using unsafe
+    /// constructors like [`Preallocated::new`] are not tracked.
+    ///
+    /// Always `false` in auto-commit mode.
+    pub fn in_transaction(&self) -> Result<bool, Error> {
+        Ok(self.transaction.is_marked() && !self.is_autocommit()?)
+    }
+
+    /// Commits, if [`Self::in_transaction`] is `true`. Helps not to forget the commit in
+    /// manual-commit mode, after queries, which do not change anything, but still open a
+    /// transaction. Returns `true` if a commit has been issued.
+    ///
+    /// ```no_run
+    /// use odbc_api::{Connection, Error};
+    ///
+    /// fn count_birthdays(conn: &Connection<'_>) -> Result<(), Error> {
+    ///     conn.set_autocommit(false)?;
+    ///     conn.execute("SELECT COUNT(*) FROM Birthdays", ())?;
+    ///     // The `SELECT` started a transaction, which must be closed before disconnecting.
+    ///     conn.commit_if_needed()?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn commit_if_needed(&self) -> Result<bool, Error> {
+        if !self.in_transaction()? {
+            return Ok(false);
+        }
+        self.commit()?;
+        Ok(true)
+    }
+
+    /// Inserts rows into `table` using `INSERT` statements with multiple rows in their `VALUES`
+    /// clause. Each statement contains at most `max_parameters` parameter markers. Data sources
+    /// limit the number of parameter markers in a single statement, e.g. Microsoft SQL Server to
+    /// 2100. Rows exceeding that limit are inserted by additional statements.
+    ///
+    /// All statements are executed within the same transaction. In auto-commit mode the connection
+    /// switches to manual-commit mode for the duration of the call. The transaction is committed
+    /// if all statements succeed and rolled back otherwise. In manual-commit mode the statements
+    /// are added to the current transaction, which is left open for the application.
+    ///
+    /// `table` and all column names are quoted as identifiers, like in [`Self::insert_returning`].
+    ///
+    /// # Parameters
+    ///
+    /// * `table`: Name of the table to insert into. May be qualified with a schema.
+    /// * `columns`: Names of the columns to insert into.
+    /// * `values`: Values of all rows, one row after another. Each row holds one value for each
+    ///   column.
+    /// * `max_parameters`: Maximum number of parameter markers in a single statement.
+    ///
+    /// ```no_run
+    /// use odbc_api::{Connection, Error, IntoParameter, parameter::InputParameter};
+    ///
+    /// fn insert_birthdays(conn: &Connection<'_>, birthdays: &[(&str, i16)]) -> Result<(), Error> {
+    ///     let values: Vec<Box<dyn InputParameter>> = birthdays
+    ///         .iter()
+    ///         .flat_map(|&(name, year)| -> [Box<dyn InputParameter>; 2] {
+    ///             [Box::new(name.to_owned().into_parameter()), Box::new(year)]
+    ///         })
+    ///         .collect();
+    ///     conn.insert_in_chunks("Birthdays", &["name", "year"], &values, 2100)
+    /// }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If `max_parameters` is smaller than the number of columns, or the number of `values` is
+    /// not a multiple of the number of columns.
+    pub fn insert_in_chunks(
+        &self,
+        table: &str,
+        columns: &[&str],
+        values: &[impl InputParameter],
+        max_parameters: usize,
+    ) -> Result<(), Error> {
+        let num_cols = columns.len();
+        assert!(
+            num_cols != 0 && max_parameters >= num_cols,
+            "max_parameters must allow for at least one row of parameters."
+        );
+        assert!(
+            values.len().is_multiple_of(num_cols),
+            "Number of values must be a multiple of the number of columns."
+        );
+        let rows_per_statement = max_parameters / num_cols;
+        let quote = self.identifier_quote_char()?;
+        let table = quote_qualified_with(table, &quote);
+        let column_list = columns
+            .iter()
+            .map(|column| quote_with(column, &quote))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let row_markers = format!("({})", vec!["?"; num_cols].join(", "));
+        let insert_all = || {
+            for chunk in values.chunks(rows_per_statement * num_cols) {
+                let rows = vec![row_markers.as_str(); chunk.len() / num_cols].join(", ");
+                let sql = format!("INSERT INTO {table} ({column_list}) VALUES {rows}");
+                self.execute(&sql, chunk)?;
+            }
+            Ok(())
+        };
+
+        if !self.is_autocommit()? {
+            return insert_all();
+        }
+        self.set_autocommit(false)?;
+        let result = insert_all();
+        let end_transaction = if result.is_ok() {
+            self.commit()
+        } else {
+            self.rollback()
+        };
+        let restore_autocommit = self.set_autocommit(true);
+        result.and(end_transaction).and(restore_autocommit)
     }
 
     /// Indicates the state of the connection. If `true` the connection has been lost. If `false`,
@@ -404,6 +991,76 @@ impl<'c> Connection<'c> {
         self.connection.packet_size().into_result(&self.connection)
     }
 
+    /// Sets an integer valued connection attribute via `SQLSetConnectAttr`. This is an escape hatch
+    /// for attributes this crate does not offer a dedicated method for. `attribute` is the raw
+    /// attribute identifier, so driver specific attributes, which are not part of
+    /// [`crate::sys::ConnectionAttribute`], can be set, too.
+    ///
+    /// ```no_run
+    /// use odbc_api::{Connection, Error};
+    ///
+    /// /// `SQL_COPT_SS_TXN_ISOLATION` of the Microsoft SQL Server driver.
+    /// const SQL_COPT_SS_TXN_ISOLATION: i32 = 1227;
+    /// /// `SQL_TXN_SS_SNAPSHOT`
+    /// const SNAPSHOT: u32 = 32;
+    ///
+    /// fn use_snapshot_isolation(conn: &Connection<'_>) -> Result<(), Error> {
+    ///     // Safety: The isolation level is an integer attribute
+    ///     unsafe { conn.set_connect_attr_u32(SQL_COPT_SS_TXN_ISOLATION, SNAPSHOT) }
+    /// }
+    /// ```
+    ///
+    /// # Safety
+    ///
+    /// `attribute` must identify an attribute, whose value is an integer. Attributes, which are
+    /// pointers, would cause the driver to dereference `value`.
+    pub unsafe fn set_connect_attr_u32(&self, attribute: i32, value: u32) -> Result<(), Error> {
+        self.connection
+            .set_attribute_u32(attribute, value)
+            .into_result(&self.connection)
+    }
+
+    /// Sets a string valued connection attribute via `SQLSetConnectAttr`. See
+    /// [`Self::set_connect_attr_u32`].
+    ///
+    /// # Safety
+    ///
+    /// `attribute` must identify an attribute, whose value is a character string.
+    pub unsafe fn set_connect_attr_str(&self, attribute: i32, value: &str) -> Result<(), Error> {
+        let value = SqlText::new(value);
+        self.connection
+            .set_attribute_text(attribute, &value)
+            .into_result(&self.connection)
+    }
+
+    /// Reads an integer valued connection attribute via `SQLGetConnectAttr`. See
+    /// [`Self::set_connect_attr_u32`].
+    ///
+    /// # Safety
+    ///
+    /// `attribute` must identify an attribute, whose value is a 32 bit integer. Otherwise the
+    /// driver may write beyond the value.
+    pub unsafe fn connect_attr_u32(&self, attribute: i32) -> Result<u32, Error> {
+        self.connection
+            .attribute_u32(attribute)
+            .into_result(&self.connection)
+    }
+
+    /// Reads a string valued connection attribute via `SQLGetConnectAttr`. See
+    /// [`Self::set_connect_attr_u32`].
+    ///
+    /// # Safety
+    ///
+    /// `attribute` must identify an attribute, whose value is a character string.
+    pub unsafe fn connect_attr_str(&self, attribute: i32) -> Result<String, Error> {
+        let mut buf = Vec::new();
+        self.connection
+            .fetch_attribute_text(attribute, &mut buf)
+            .into_result(&self.connection)?;
+        let value = slice_to_utf8(&buf).unwrap();
+        Ok(value)
+    }
+
     /// Get the name of the database management system used by the connection.
     pub fn database_management_system_name(&self) -> Result<String, Error> {
         let mut buf = Vec::new();
@@ -414,6 +1071,123 @@ impl<'c> Connection<'c> {
         Ok(name)
     }
 
+    /// Flavor of the database management system used by the connection. Derived from
+    /// [`Self::database_management_system_name`]. Useful for code which needs to branch on vendor
+    /// specific behavior.
+    ///
+    /// ```no_run
+    /// use odbc_api::{Connection, Dbms, Error};
+    ///
+    /// fn top_one(conn: &Connection<'_>, table: &str) -> Result<String, Error> {
+    ///     let query = match conn.dbms()? {
+    ///         Dbms::MsSql => format!("SELECT TOP 1 * FROM {table}"),
+    ///         _ => format!("SELECT * FROM {table} LIMIT 1"),
+    ///     };
+    ///     Ok(query)
+    /// }
+    /// ```
+    pub fn dbms(&self) -> Result<Dbms, Error> {
+        let name = self.database_management_system_name()?;
+        Ok(Dbms::from_name(name))
+    }
+
+    /// Quotes `identifier` (e.g. a table or column name) using the quote character reported by the
+    /// driver (`SQL_IDENTIFIER_QUOTE_CHAR`). Quote characters within `identifier` are escaped by
+    /// doubling them. If the data source does not support quoted identifiers, `identifier` is
+    /// returned unchanged.
+    ///
+    /// ```no_run
+    /// use odbc_api::{Connection, Error};
+    ///
+    /// fn count_rows(conn: &Connection<'_>, table: &str) -> Result<(), Error> {
+    ///     let query = format!("SELECT COUNT(*) FROM {}", conn.quote_identifier(table)?);
+    ///     conn.execute(&query, ())?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn quote_identifier(&self, identifier: &str) -> Result<String, Error> {
+        let quote = self.identifier_quote_char()?;
+        Ok(quote_with(identifier, &quote))
+    }
+
+    /// `SQL_IDENTIFIER_QUOTE_CHAR`. A blank if quoted identifiers are not supported.
+    fn identifier_quote_char(&self) -> Result<String, Error> {
+        let mut buf = Vec::new();
+        self.connection
+            .fetch_identifier_quote_char(&mut buf)
+            .into_result(&self.connection)?;
+        Ok(slice_to_utf8(&buf).unwrap())
+    }
+
+    /// Escapes `literal`, so it can be passed as a search pattern to catalog functions like
+    /// [`Self::tables`] or [`Self::columns`], without any of its characters being interpreted as
+    /// wildcards.
+    ///
+    /// In search pattern arguments `%` matches any sequence of characters and `_` matches any
+    /// single character. A table named `my_table` would therefore also match `myXtable`. This
+    /// method prefixes `%`, `_` and the escape character itself with the escape character reported
+    /// by the driver (`SQL_SEARCH_PATTERN_ESCAPE`). If the driver does not support an escape
+    /// character, `literal` is returned unchanged.
+    ///
+    /// ```no_run
+    /// use odbc_api::{Connection, Cursor, Error};
+    ///
+    /// fn table_exists(conn: &Connection<'_>, table: &str) -> Result<bool, Error> {
+    ///     let pattern = conn.escape_search_pattern(table)?;
+    ///     let mut cursor = conn.tables("", "", &pattern, "")?;
+    ///     Ok(cursor.next_row()?.is_some())
+    /// }
+    /// ```
+    pub fn escape_search_pattern(&self, literal: &str) -> Result<String, Error> {
+        let mut buf = Vec::new();
+        self.connection
+            .fetch_search_pattern_escape(&mut buf)
+            .into_result(&self.connection)?;
+        let escape = slice_to_utf8(&buf).unwrap();
+        Ok(escape_search_pattern_with(literal, &escape))
+    }
+
+    /// Creates a temporary table named `name` with the given `columns`. Each column is described by
+    /// its name and its SQL type, e.g. `("id", "INTEGER")`. Table and column names are quoted using
+    /// [`Self::quote_identifier`]. The table is dropped (`DROP TABLE IF EXISTS`) once the returned
+    /// guard goes out of scope.
+    ///
+    /// Microsoft SQL Server does not know `CREATE TEMPORARY TABLE`. Instead its temporary tables are
+    /// identified by a leading `#`, which is prepended to `name` for this database. Use
+    /// [`TempTable::name`] to refer to the table in queries.
+    ///
+    /// ```no_run
+    /// use odbc_api::{Connection, Error};
+    ///
+    /// fn stage(conn: &Connection<'_>) -> Result<(), Error> {
+    ///     let staging = conn.create_temp_table("staging", &[("id", "INTEGER")])?;
+    ///     conn.execute(&format!("INSERT INTO {} VALUES (42)", staging.name()), ())?;
+    ///     // ... use staging table ...
+    ///     Ok(())
+    ///     // Table is dropped here
+    /// }
+    /// ```
+    pub fn create_temp_table(
+        &self,
+        name: &str,
+        columns: &[(&str, &str)],
+    ) -> Result<TempTable<'_>, Error> {
+        let (create, name) = if self.dbms()? == Dbms::MsSql {
+            ("CREATE TABLE", self.quote_identifier(&format!("#{name}"))?)
+        } else {
+            ("CREATE TEMPORARY TABLE", self.quote_identifier(name)?)
+        };
+        let columns = columns
+            .iter()
+            .map(|(column, data_type)| {
+                Ok(format!("{} {data_type}", self.quote_identifier(column)?))
+            })
+            .collect::<Result<Vec<_>, Error>>()?
+            .join(", ");
+        self.execute(format!("{create} {name} ({columns})"), ())?;
+        Ok(TempTable::new(self, name))
+    }
+
     /// Maximum length of catalog names.
     pub fn max_catalog_name_len(&self) -> Result<u16, Error> {
         self.connection
@@ -452,6 +1226,29 @@ impl<'c> Connection<'c> {
         Ok(name)
     }
 
+    /// The SQL text the driver would send to the data source for `sql`. ODBC escape sequences like
+    /// `{ts '2021-03-20 15:24:12'}` or `{call my_procedure(?)}` are replaced with their
+    /// counterparts in the SQL dialect of the data source. The statement is not executed. Useful to
+    /// debug how a driver rewrites statements.
+    ///
+    /// ```no_run
+    /// use odbc_api::{Connection, Error};
+    ///
+    /// fn print_translation(conn: &Connection<'_>) -> Result<(), Error> {
+    ///     let native = conn.native_sql("SELECT {fn UCASE(name)} FROM Products")?;
+    ///     println!("{native}");
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn native_sql(&self, sql: &str) -> Result<String, Error> {
+        let mut buf = Vec::new();
+        self.connection
+            .native_sql(&SqlText::new(sql), &mut buf)
+            .into_result(&self.connection)?;
+        let native = slice_to_utf8(&buf).expect("Native SQL text must be correctly encoded");
+        Ok(native)
+    }
+
     /// A cursor describing columns of all tables matching the patterns. Patterns support as
     /// placeholder `%` for multiple characters or `_` for a single character. Use `\` to escape.The
     /// returned cursor has the columns:
@@ -477,6 +1274,76 @@ impl<'c> Connection<'c> {
         )
     }
 
+    /// Describes the columns of the tables matching the patterns, whose `ORDINAL_POSITION` lies
+    /// within `ordinal_positions`. Useful for very wide tables, if only some of the columns are of
+    /// interest. The descriptions are returned together with their ordinal position, in the order
+    /// reported by [`Self::columns`]. Since `SQLColumns` can not filter by position, the metadata
+    /// of all columns is still transmitted, but only the requested ones are decoded.
+    ///
+    /// ```no_run
+    /// use odbc_api::{Connection, Error};
+    ///
+    /// fn print_second_to_fifth_column(conn: &Connection<'_>) -> Result<(), Error> {
+    ///     for (ordinal, column) in conn.columns_range("", "", "WideTable", 2..=5)? {
+    ///         println!("{ordinal}: {}", column.name_to_string().unwrap());
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn columns_range(
+        &self,
+        catalog_name: &str,
+        schema_name: &str,
+        table_name: &str,
+        ordinal_positions: RangeInclusive<u16>,
+    ) -> Result<Vec<(u16, ColumnDescription)>, Error> {
+        // One based indices of the columns in the result set of `SQLColumns`
+        const COLUMN_NAME: u16 = 4;
+        const DATA_TYPE: u16 = 5;
+        const COLUMN_SIZE: u16 = 7;
+        const DECIMAL_DIGITS: u16 = 9;
+        const NULLABLE: u16 = 11;
+        const ORDINAL_POSITION: u16 = 17;
+
+        let mut cursor = self.columns(catalog_name, schema_name, table_name, "%")?;
+        let mut columns = Vec::new();
+        let mut name = String::new();
+        while let Some(mut row) = cursor.next_row()? {
+            // Some drivers require `get_data` to be called in increasing column order, so we can
+            // not look at the ordinal position first.
+            name.clear();
+            row.append_text(COLUMN_NAME, &mut name)?;
+            let mut data_type = 0i16;
+            row.get_data(DATA_TYPE, &mut data_type)?;
+            let mut column_size = Nullable::<i32>::null();
+            row.get_data(COLUMN_SIZE, &mut column_size)?;
+            let mut decimal_digits = Nullable::<i16>::null();
+            row.get_data(DECIMAL_DIGITS, &mut decimal_digits)?;
+            let mut nullable = 0i16;
+            row.get_data(NULLABLE, &mut nullable)?;
+            let mut ordinal_position = 0i32;
+            row.get_data(ORDINAL_POSITION, &mut ordinal_position)?;
+
+            let Ok(ordinal_position) = u16::try_from(ordinal_position) else {
+                continue;
+            };
+            if !ordinal_positions.contains(&ordinal_position) {
+                continue;
+            }
+            let data_type = DataType::new(
+                SqlDataType(data_type),
+                column_size.into_opt().unwrap_or(0).try_into().unwrap_or(0),
+                decimal_digits.into_opt().unwrap_or(0),
+            );
+            let nullability = Nullability::new(odbc_sys::Nullability(nullable));
+            columns.push((
+                ordinal_position,
+                ColumnDescription::new(&name, data_type, nullability),
+            ));
+        }
+        Ok(columns)
+    }
+
     /// List tables, schemas, views and catalogs of a datasource.
     ///
     /// # Parameters
@@ -670,10 +1537,37 @@ impl<'c> Connection<'c> {
         ])
     }
 
+    /// Allocates a statement, which is executed or prepared right away. Either may start a
+    /// transaction, so it is marked.
     fn allocate_statement(&self) -> Result<StatementImpl<'_>, Error> {
-        self.connection
+        let statement = self
+            .connection
             .allocate_statement()
-            .into_result(&self.connection)
+            .into_result(&self.connection)?;
+        self.transaction.mark();
+        Ok(statement)
+    }
+}
+
+/// Tracks whether a statement has been executed over a connection since the last commit or
+/// rollback. Shared between the connection and the prepared and preallocated statements allocated
+/// on it, since executing these may start a transaction, too. Statements constructed from raw
+/// handles use a default instance, which is not shared with any connection.
+#[derive(Clone, Default)]
+pub(crate) struct TransactionTracker(Arc<AtomicBool>);
+
+impl TransactionTracker {
+    /// Records that a transaction may have been started.
+    pub(crate) fn mark(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    fn reset(&self) {
+        self.0.store(false, Ordering::Relaxed);
+    }
+
+    fn is_marked(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
     }
 }
 
@@ -720,6 +1614,120 @@ impl ConnectionOptions {
     }
 }
 
+/// Options applied to a statement before executing a query. See
+/// [`Connection::execute_with_options`].
+#[derive(Debug, Default, Clone, Copy)]
+pub struct StatementOptions {
+    /// Type of the cursor used to iterate the result set. `None` keeps the default of the driver,
+    /// which usually is [`CursorType::ForwardOnly`]. If a cursor type is specified, but no
+    /// [`Self::concurrency`], the concurrency of the cursor is set to read-only.
+    ///
+    /// This corresponds to the `SQL_ATTR_CURSOR_TYPE` attribute in the ODBC specification.
+    pub cursor_type: Option<CursorType>,
+    /// Concurrency control of the cursor. Choose anything other than [`Concurrency::ReadOnly`] in
+    /// order to modify rows in place using [`crate::BlockCursor::into_updatable`]. Drivers may
+    /// only support updatable cursors for some cursor types, so you usually want to specify
+    /// [`Self::cursor_type`], too.
+    ///
+    /// This corresponds to the `SQL_ATTR_CONCURRENCY` attribute in the ODBC specification.
+    pub concurrency: Option<Concurrency>,
+}
+
+impl StatementOptions {
+    /// Set the attributes corresponding to the statement options on an allocated statement
+    /// handle. Usually you would rather pass the options to
+    /// [`Connection::execute_with_options`] than calling this method yourself.
+    pub fn apply(&self, stmt: &mut impl Statement) -> Result<(), Error> {
+        // The cursor type must be set first, since changing it may reset the concurrency.
+        if let Some(cursor_type) = self.cursor_type {
+            // Safety: The attribute is an integer and no buffers are bound yet.
+            unsafe {
+                stmt.set_attribute_u32(StatementAttribute::CursorType, cursor_type as u32)
+                    .into_result(stmt)?;
+            }
+        }
+        let concurrency = self
+            .concurrency
+            .or(self.cursor_type.map(|_| Concurrency::ReadOnly));
+        if let Some(concurrency) = concurrency {
+            // Safety: The attribute is an integer and no buffers are bound yet.
+            unsafe {
+                stmt.set_attribute_u32(StatementAttribute::Concurrency, concurrency as u32)
+                    .into_result(stmt)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Cursor types, which determine whether and how a cursor can scroll through a result set and
+/// whether it reflects changes made to the data after the query has been executed.
+///
+/// See: <https://learn.microsoft.com/sql/odbc/reference/develop-app/cursor-types>
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CursorType {
+    /// Only moves forward through the result set. Usually the default and the cheapest option.
+    ForwardOnly = 0,
+    /// Detects changes to and deletions of the rows in the result set, but not insertions.
+    KeysetDriven = 1,
+    /// Detects all changes to the underlying data, but is usually the most expensive option.
+    Dynamic = 2,
+    /// Works on a snapshot of the result set and is scrollable.
+    Static = 3,
+}
+
+/// Concurrency control of a cursor. Determines whether rows of the result set can be updated or
+/// deleted through the cursor and how conflicts with other transactions are detected.
+///
+/// See: <https://learn.microsoft.com/sql/odbc/reference/develop-app/concurrency-types>
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Concurrency {
+    /// The cursor can not be used to modify rows. The default.
+    ReadOnly = 1,
+    /// Rows are locked, so they can be updated without risk of conflicts.
+    Lock = 2,
+    /// Optimistic concurrency, detecting conflicts by comparing row versions.
+    RowVersion = 3,
+    /// Optimistic concurrency, detecting conflicts by comparing values.
+    Values = 4,
+}
+
+/// Flavor of a database management system, as reported by [`Connection::dbms`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Dbms {
+    /// Microsoft SQL Server
+    MsSql,
+    /// MySQL or MariaDB. Both share the same dialect and are therefore not distinguished.
+    MySqlOrMaria,
+    /// SQLite
+    Sqlite,
+    /// PostgreSQL
+    PostgreSql,
+    /// Any other database management system. Holds the name reported by the driver.
+    Other(String),
+}
+
+impl Dbms {
+    /// Maps the name of a database management system, as returned by
+    /// [`Connection::database_management_system_name`], to its flavor.
+    ///
+    /// ```
+    /// use odbc_api::Dbms;
+    ///
+    /// assert_eq!(Dbms::MsSql, Dbms::from_name("Microsoft SQL Server".to_owned()));
+    /// assert_eq!(Dbms::Other("DB2".to_owned()), Dbms::from_name("DB2".to_owned()));
+    /// ```
+    pub fn from_name(name: String) -> Self {
+        match name.as_str() {
+            "Microsoft SQL Server" => Dbms::MsSql,
+            "MariaDB" | "MySQL" => Dbms::MySqlOrMaria,
+            "SQLite" => Dbms::Sqlite,
+            "PostgreSQL" => Dbms::PostgreSql,
+            _ => Dbms::Other(name),
+        }
+    }
+}
+
 /// You can use this method to escape a password so it is suitable to be appended to an ODBC
 /// connection string as the value for the `PWD` attribute. This method is only of interest for
 /// application in need to create their own connection strings.
@@ -776,6 +1784,132 @@ pub fn escape_attribute_value(unescaped: &str) -> Cow<'_, str> {
     }
 }
 
+/// Shortens `query` to at most [`MAX_SQL_LEN_IN_ERROR`] bytes, so huge statements do not bloat error
+/// messages. Truncated queries end with `...`.
+fn truncate_sql(query: &str) -> Cow<'_, str> {
+    if query.len() <= MAX_SQL_LEN_IN_ERROR {
+        return Cow::Borrowed(query);
+    }
+    let mut end = MAX_SQL_LEN_IN_ERROR;
+    while !query.is_char_boundary(end) {
+        end -= 1;
+    }
+    Cow::Owned(format!("{}...", &query[..end]))
+}
+
+/// Surrounds `identifier` with `quote` and escapes any occurrences of `quote` within it. A blank
+/// `quote` indicates that the data source does not support quoted identifiers.
+fn quote_with(identifier: &str, quote: &str) -> String {
+    if quote.trim().is_empty() {
+        identifier.to_owned()
+    } else {
+        let escaped = identifier.replace(quote, &quote.repeat(2));
+        format!("{quote}{escaped}{quote}")
+    }
+}
+
+/// Like [`quote_with`], but quotes each `.` separated part of a qualified name, like
+/// `schema.table`, separately.
+fn quote_qualified_with(name: &str, quote: &str) -> String {
+    name.split('.')
+        .map(|part| quote_with(part, quote))
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+/// Wraps `query` into a call to `sp_executesql`. Each placeholder, as found by
+/// [`count_placeholders`], is replaced by a named parameter, which is declared with the
+/// corresponding type. The parameter values themselves are passed as placeholders to
+/// `sp_executesql`.
+fn sp_executesql(query: &str, data_types: impl Iterator<Item = DataType>) -> String {
+    // `query` is passed as a literal, so every quote needs escaping, including those in comments.
+    let escape = |text: &str| text.replace('\'', "''");
+    let mut statement = String::with_capacity(query.len());
+    let mut num_params = 0;
+    let mut start = 0;
+    while let Some(position) = next_placeholder(query, start) {
+        num_params += 1;
+        statement.push_str(&escape(&query[start..position]));
+        statement.push_str(&format!("@P{num_params}"));
+        start = position + 1;
+    }
+    statement.push_str(&escape(&query[start..]));
+    let declarations = data_types
+        .enumerate()
+        .map(|(index, data_type)| format!("@P{} {}", index + 1, mssql_type_name(data_type)))
+        .collect::<Vec<_>>()
+        .join(", ");
+    if num_params == 0 {
+        format!("EXEC sp_executesql N'{statement}'")
+    } else {
+        let placeholders = vec!["?"; num_params].join(", ");
+        format!("EXEC sp_executesql N'{statement}', N'{declarations}', {placeholders}")
+    }
+}
+
+/// Name of the Transact-SQL type used to declare a parameter of type `data_type`. Variable length
+/// types are always declared as `MAX`. Their length is usually derived from the parameter value,
+/// and a different declaration for each length would defeat plan reuse.
+fn mssql_type_name(data_type: DataType) -> String {
+    // Longest lengths which can be declared without resorting to `MAX`.
+    const MAX_CHAR_LEN: usize = 8000;
+    const MAX_WCHAR_LEN: usize = 4000;
+    match data_type {
+        DataType::Char { length } => match length {
+            Some(length) if length.get() <= MAX_CHAR_LEN => format!("CHAR({length})"),
+            _ => "VARCHAR(MAX)".to_owned(),
+        },
+        DataType::WChar { length } => match length {
+            Some(length) if length.get() <= MAX_WCHAR_LEN => format!("NCHAR({length})"),
+            _ => "NVARCHAR(MAX)".to_owned(),
+        },
+        DataType::Varchar { .. } | DataType::LongVarchar { .. } => "VARCHAR(MAX)".to_owned(),
+        DataType::WVarchar { .. } => "NVARCHAR(MAX)".to_owned(),
+        DataType::Binary { length } => match length {
+            Some(length) if length.get() <= MAX_CHAR_LEN => format!("BINARY({length})"),
+            _ => "VARBINARY(MAX)".to_owned(),
+        },
+        DataType::Varbinary { .. } | DataType::LongVarbinary { .. } => "VARBINARY(MAX)".to_owned(),
+        DataType::Numeric { precision, scale } => format!("NUMERIC({precision}, {scale})"),
+        DataType::Decimal { precision, scale } => format!("DECIMAL({precision}, {scale})"),
+        DataType::Integer => "INT".to_owned(),
+        DataType::SmallInt => "SMALLINT".to_owned(),
+        DataType::TinyInt => "TINYINT".to_owned(),
+        DataType::BigInt => "BIGINT".to_owned(),
+        DataType::Bit => "BIT".to_owned(),
+        DataType::Float { precision } => format!("FLOAT({precision})"),
+        DataType::Real => "REAL".to_owned(),
+        DataType::Double => "FLOAT(53)".to_owned(),
+        DataType::Date => "DATE".to_owned(),
+        DataType::Time { precision } => format!("TIME({precision})"),
+        DataType::Timestamp { precision } => format!("DATETIME2({precision})"),
+        DataType::Unknown | DataType::Other { .. } => "SQL_VARIANT".to_owned(),
+    }
+}
+
+/// Prefixes `%`, `_` and `escape` itself within `literal` with `escape`. An empty `escape` leaves
+/// `literal` unchanged.
+fn escape_search_pattern_with(literal: &str, escape: &str) -> String {
+    if escape.is_empty() {
+        return literal.to_owned();
+    }
+    let mut escaped = String::with_capacity(literal.len());
+    let mut rest = literal;
+    while let Some(c) = rest.chars().next() {
+        let len = if rest.starts_with(escape) {
+            escape.len()
+        } else {
+            c.len_utf8()
+        };
+        if rest.starts_with(escape) || matches!(c, '%' | '_') {
+            escaped.push_str(escape);
+        }
+        escaped.push_str(&rest[..len]);
+        rest = &rest[len..];
+    }
+    escaped
+}
+
 /// An error type wrapping an [`Error`] and a [`Connection`]. It is used by
 /// [`Connection::into_cursor`], so that in case of failure the user can reuse the connection to try
 /// again. [`Connection::into_cursor`] could achieve the same by returning a tuple in case of an
@@ -804,3 +1938,132 @@ impl std::error::Error for ConnectionAndError<'_> {
         self.error.source()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+
+    use crate::DataType;
+
+    use super::{
+        escape_search_pattern_with, mssql_type_name, quote_qualified_with, quote_with,
+        sp_executesql, truncate_sql, MAX_SQL_LEN_IN_ERROR,
+    };
+
+    #[test]
+    fn short_sql_is_not_truncated() {
+        assert_eq!("SELECT 42", truncate_sql("SELECT 42"));
+    }
+
+    #[test]
+    fn truncate_long_sql_at_char_boundary() {
+        // Two byte characters after an odd length prefix, ensure the limit falls within a character
+        let query = format!("-- {}", "ä".repeat(MAX_SQL_LEN_IN_ERROR));
+
+        let truncated = truncate_sql(&query);
+
+        assert_eq!(MAX_SQL_LEN_IN_ERROR - 1 + 3, truncated.len());
+        assert!(truncated.starts_with("-- ää"));
+        assert!(truncated.ends_with("ä..."));
+    }
+
+    #[test]
+    fn quote_identifier_escapes_quote_char() {
+        assert_eq!("\"my table\"", quote_with("my table", "\""));
+        assert_eq!("`a``b`", quote_with("a`b", "`"));
+    }
+
+    #[test]
+    fn quote_each_part_of_qualified_name() {
+        assert_eq!(
+            "\"dbo\".\"my table\"",
+            quote_qualified_with("dbo.my table", "\"")
+        );
+        assert_eq!("dbo.t", quote_qualified_with("dbo.t", " "));
+    }
+
+    #[test]
+    fn identifier_unchanged_if_quoting_is_not_supported() {
+        assert_eq!("my_table", quote_with("my_table", " "));
+    }
+
+    #[test]
+    fn wrap_query_in_sp_executesql() {
+        let query = "SELECT a FROM T WHERE b = ? AND c = '?' AND d = ? -- Isn't it?";
+        let data_types = [
+            DataType::Integer,
+            DataType::Varchar {
+                length: NonZeroUsize::new(10),
+            },
+        ];
+
+        let actual = sp_executesql(query, data_types.into_iter());
+
+        assert_eq!(
+            "EXEC sp_executesql N'SELECT a FROM T WHERE b = @P1 AND c = ''?'' AND d = @P2 \
+            -- Isn''t it?', N'@P1 INT, @P2 VARCHAR(MAX)', ?, ?",
+            actual
+        );
+    }
+
+    #[test]
+    fn wrap_query_without_parameters_in_sp_executesql() {
+        let actual = sp_executesql("SELECT 42", std::iter::empty());
+
+        assert_eq!("EXEC sp_executesql N'SELECT 42'", actual);
+    }
+
+    #[test]
+    fn mssql_type_names_fall_back_to_max() {
+        assert_eq!(
+            "NVARCHAR(MAX)",
+            mssql_type_name(DataType::WVarchar {
+                length: NonZeroUsize::new(5)
+            })
+        );
+        assert_eq!(
+            "CHAR(5)",
+            mssql_type_name(DataType::Char {
+                length: NonZeroUsize::new(5)
+            })
+        );
+        assert_eq!(
+            "VARBINARY(MAX)",
+            mssql_type_name(DataType::Varbinary { length: None })
+        );
+        assert_eq!(
+            "DATETIME2(7)",
+            mssql_type_name(DataType::Timestamp { precision: 7 })
+        );
+    }
+
+    #[test]
+    fn escape_search_pattern_without_wildcards_is_unchanged() {
+        assert_eq!("MyTable", escape_search_pattern_with("MyTable", "\\"));
+        assert_eq!("", escape_search_pattern_with("", "\\"));
+    }
+
+    #[test]
+    fn escape_search_pattern_escapes_wildcards() {
+        assert_eq!(r"my\_table", escape_search_pattern_with("my_table", "\\"));
+        assert_eq!(r"\%\%", escape_search_pattern_with("%%", "\\"));
+    }
+
+    #[test]
+    fn escape_search_pattern_escapes_the_escape_character() {
+        assert_eq!(r"a\\b\_c", escape_search_pattern_with(r"a\b_c", "\\"));
+    }
+
+    #[test]
+    fn escape_search_pattern_uses_driver_specific_escape() {
+        assert_eq!(
+            "my!_t\\able!!",
+            escape_search_pattern_with("my_t\\able!", "!")
+        );
+    }
+
+    #[test]
+    fn escape_search_pattern_without_escape_character_is_unchanged() {
+        assert_eq!("my_table", escape_search_pattern_with("my_table", ""));
+    }
+}
@@ -0,0 +1,273 @@
+use std::borrow::Cow;
+
+use crate::{escape_attribute_value, Error};
+
+/// Builds a connection string from individual attributes. Values are escaped using
+/// [`escape_attribute_value`], so they can not inject additional attributes into the connection
+/// string, even if they contain separators like `;`. Values starting with `{`, or with leading or
+/// trailing whitespace are enclosed in curly braces, too, so they are passed to the driver
+/// verbatim. Keys can not be escaped, so keys containing `=`, `;` or curly braces are rejected by
+/// [`Self::build`]. Pass the builder to
+/// [`crate::Environment::connect_with_builder`] to connect.
+///
+/// ```
+/// use odbc_api::ConnectionStringBuilder;
+///
+/// let connection_string = ConnectionStringBuilder::new()
+///     .driver("ODBC Driver 18 for SQL Server")
+///     .server("localhost")
+///     .attribute("UID", "SA")
+///     .attribute("PWD", "abc;123")
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(
+///     "Driver={ODBC Driver 18 for SQL Server};Server=localhost;UID=SA;PWD={abc;123};",
+///     connection_string
+/// );
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ConnectionStringBuilder {
+    /// Key value pairs in the order they have been added. Values are unescaped.
+    attributes: Vec<(String, String)>,
+}
+
+impl ConnectionStringBuilder {
+    /// A builder without any attributes.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Name of the ODBC driver, e.g. `ODBC Driver 18 for SQL Server`. Sets the `Driver` attribute.
+    /// Driver names are always enclosed in curly braces, as is customary.
+    pub fn driver(self, name: &str) -> Self {
+        self.attribute("Driver", name)
+    }
+
+    /// Sets the `Server` attribute.
+    pub fn server(self, server: &str) -> Self {
+        self.attribute("Server", server)
+    }
+
+    /// Sets the `Database` attribute.
+    pub fn database(self, database: &str) -> Self {
+        self.attribute("Database", database)
+    }
+
+    /// Adds an arbitrary attribute. `value` is passed unescaped. Setting an attribute a second
+    /// time replaces its value. Keys are compared case insensitive.
+    pub fn attribute(mut self, key: &str, value: &str) -> Self {
+        if let Some((_, existing)) = self
+            .attributes
+            .iter_mut()
+            .find(|(existing_key, _)| existing_key.eq_ignore_ascii_case(key))
+        {
+            *existing = value.to_owned();
+        } else {
+            self.attributes.push((key.to_owned(), value.to_owned()));
+        }
+        self
+    }
+
+    /// Unescaped value of the attribute with the name `key`. Keys are compared case insensitive.
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(existing_key, _)| existing_key.eq_ignore_ascii_case(key))
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Parses a connection string into its attributes. Values enclosed in curly braces are
+    /// unescaped, so [`Self::get`] returns the value as it is intended to be understood by the
+    /// driver.
+    ///
+    /// ```
+    /// use odbc_api::ConnectionStringBuilder;
+    ///
+    /// let parsed = ConnectionStringBuilder::parse("DSN=MyDb;PWD={a}}b;c};").unwrap();
+    /// assert_eq!(Some("MyDb"), parsed.get("dsn"));
+    /// assert_eq!(Some("a}b;c"), parsed.get("PWD"));
+    /// ```
+    pub fn parse(connection_string: &str) -> Result<Self, Error> {
+        let mut builder = Self::new();
+        let mut position = 0;
+        while position < connection_string.len() {
+            let rest = &connection_string[position..];
+            // Skip empty attributes, e.g. due to a trailing separator
+            if let Some(stripped) = rest.trim_start().strip_prefix(';') {
+                position = connection_string.len() - stripped.len();
+                continue;
+            }
+            if rest.trim().is_empty() {
+                break;
+            }
+            let (key, value_and_rest) = rest
+                .split_once('=')
+                .filter(|(key, _)| is_valid_key(key))
+                .ok_or(Error::InvalidConnectionString { position })?;
+            let value_position = connection_string.len() - value_and_rest.len();
+            let (value, consumed) =
+                parse_value(value_and_rest).ok_or(Error::InvalidConnectionString {
+                    position: value_position,
+                })?;
+            builder = builder.attribute(key.trim(), &value);
+            position = value_position + consumed;
+        }
+        Ok(builder)
+    }
+
+    /// The connection string. Attributes are separated by `;`.
+    ///
+    /// Fails with [`Error::InvalidConnectionStringKey`] if a key contains `=`, `;`, `{` or `}`.
+    /// These can not be escaped and would change the meaning of the connection string.
+    pub fn build(&self) -> Result<String, Error> {
+        let mut connection_string = String::new();
+        for (key, value) in &self.attributes {
+            if !is_valid_key(key) {
+                return Err(Error::InvalidConnectionStringKey { key: key.clone() });
+            }
+            connection_string.push_str(key);
+            connection_string.push('=');
+            if key.eq_ignore_ascii_case("driver") {
+                connection_string.push('{');
+                connection_string.push_str(&value.replace('}', "}}"));
+                connection_string.push('}');
+            } else {
+                connection_string.push_str(&escape_value(value));
+            }
+            connection_string.push(';');
+        }
+        Ok(connection_string)
+    }
+}
+
+/// Keys can not be escaped. A key containing `=` or `;` would be split into separate tokens, curly
+/// braces are not allowed in keys by the connection string grammar.
+fn is_valid_key(key: &str) -> bool {
+    !key.contains(['=', ';', '{', '}'])
+}
+
+/// Like [`escape_attribute_value`], but also encloses values in curly braces, which would otherwise
+/// be altered by the driver. Unbraced values starting with `{` are mistaken for braced ones and
+/// whitespace around unbraced values is not significant.
+fn escape_value(value: &str) -> Cow<'_, str> {
+    if value.trim_start().starts_with('{') || value.trim() != value {
+        Cow::Owned(format!("{{{}}}", value.replace('}', "}}")))
+    } else {
+        escape_attribute_value(value)
+    }
+}
+
+/// Parses a single attribute value at the beginning of `input`. Returns the unescaped value and
+/// the number of bytes consumed, including the terminating `;`. `None` if a value enclosed in
+/// curly braces is not terminated, or followed by anything but a separator.
+fn parse_value(input: &str) -> Option<(String, usize)> {
+    let Some(braced) = input.trim_start().strip_prefix('{') else {
+        let end = input.find(';').unwrap_or(input.len());
+        // Also consume the separator, unless we reached the end of the connection string.
+        let consumed = (end + 1).min(input.len());
+        return Some((input[..end].trim().to_owned(), consumed));
+    };
+    let mut value = String::new();
+    let mut chars = braced.char_indices().peekable();
+    while let Some((index, c)) = chars.next() {
+        if c != '}' {
+            value.push(c);
+        } else if chars.next_if(|&(_, next)| next == '}').is_some() {
+            value.push('}');
+        } else {
+            // Closing brace. Only whitespace may separate it from the next attribute.
+            let after = braced[index + 1..].trim_start();
+            let consumed = match after.strip_prefix(';') {
+                Some(rest) => input.len() - rest.len(),
+                None if after.is_empty() => input.len(),
+                None => return None,
+            };
+            return Some((value, consumed));
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Error;
+
+    use super::ConnectionStringBuilder;
+
+    #[test]
+    fn braced_password_round_trips_through_parse() {
+        let password = "p;a}ss{=word";
+        let connection_string = ConnectionStringBuilder::new()
+            .driver("PostgreSQL UNICODE")
+            .server("localhost")
+            .database("test")
+            .attribute("UID", "postgres")
+            .attribute("PWD", password)
+            .build()
+            .unwrap();
+
+        let parsed = ConnectionStringBuilder::parse(&connection_string).unwrap();
+
+        assert_eq!(
+            "Driver={PostgreSQL UNICODE};Server=localhost;Database=test;UID=postgres;\
+            PWD={p;a}}ss{=word};",
+            connection_string
+        );
+        assert_eq!(Some(password), parsed.get("PWD"));
+        assert_eq!(Some("PostgreSQL UNICODE"), parsed.get("driver"));
+        assert_eq!(Some("test"), parsed.get("Database"));
+    }
+
+    #[test]
+    fn parse_without_trailing_separator() {
+        let parsed = ConnectionStringBuilder::parse("DSN = MyDb ; UID=SA").unwrap();
+
+        assert_eq!(Some("MyDb"), parsed.get("DSN"));
+        assert_eq!(Some("SA"), parsed.get("UID"));
+    }
+
+    #[test]
+    fn unterminated_brace_is_an_error() {
+        assert!(ConnectionStringBuilder::parse("PWD={abc;").is_err());
+    }
+
+    #[test]
+    fn missing_equal_sign_is_an_error() {
+        assert!(ConnectionStringBuilder::parse("DSN=MyDb;garbage;").is_err());
+    }
+
+    #[test]
+    fn values_starting_with_brace_round_trip_through_parse() {
+        let connection_string = ConnectionStringBuilder::new()
+            .attribute("PWD", "{abc}")
+            .attribute("UID", " padded ")
+            .build()
+            .unwrap();
+
+        let parsed = ConnectionStringBuilder::parse(&connection_string).unwrap();
+
+        assert_eq!("PWD={{abc}}};UID={ padded };", connection_string);
+        assert_eq!(Some("{abc}"), parsed.get("PWD"));
+        assert_eq!(Some(" padded "), parsed.get("UID"));
+    }
+
+    #[test]
+    fn keys_which_can_not_be_escaped_are_rejected() {
+        for key in ["A=B", "A;B", "{A}"] {
+            let result = ConnectionStringBuilder::new()
+                .attribute(key, "value")
+                .build();
+
+            assert!(matches!(
+                result,
+                Err(Error::InvalidConnectionStringKey { key: rejected }) if rejected == key
+            ));
+        }
+    }
+
+    #[test]
+    fn braces_in_key_are_an_error() {
+        assert!(ConnectionStringBuilder::parse("{DSN}=MyDb;").is_err());
+    }
+}
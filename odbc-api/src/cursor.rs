@@ -1,24 +1,35 @@
 mod block_cursor;
 mod concurrent_block_cursor;
+#[cfg(feature = "serde")]
+mod row_deserializer;
+mod updatable_block_cursor;
 
-use odbc_sys::HStmt;
+use odbc_sys::{FetchOrientation, HStmt};
 
 use crate::{
-    buffers::Indicator,
+    buffers::{FetchRow, Indicator, RowVec},
     error::ExtendResult,
     handles::{AsStatementRef, CDataMut, SqlResult, State, Statement, StatementRef},
-    parameter::{Binary, CElement, Text, VarCell, VarKind, WideText},
+    instrumentation::{instrumented, ExecPhase, Instrumentation},
+    parameter::{Binary, CElement, CElementMut, Text, VarCell, VarKind, WideText},
     sleep::{wait_for, Sleep},
     Error, ResultSetMetadata,
 };
 
 use std::{
-    mem::{size_of, MaybeUninit},
+    mem::{self, size_of, MaybeUninit},
     ptr,
     thread::panicking,
 };
 
-pub use self::{block_cursor::BlockCursor, concurrent_block_cursor::ConcurrentBlockCursor};
+pub use self::{
+    block_cursor::{BlockCursor, NextResultSetError},
+    concurrent_block_cursor::ConcurrentBlockCursor,
+    updatable_block_cursor::UpdatableBlockCursor,
+};
+
+#[cfg(feature = "serde")]
+pub use self::row_deserializer::RowDeserializer;
 
 /// Cursors are used to process and iterate the result sets returned by executing queries.
 ///
@@ -77,12 +88,154 @@ pub trait Cursor: ResultSetMetadata {
         Ok(ret)
     }
 
+    /// Positions the cursor before the first row of the result set, so the next call to
+    /// [`Self::next_row`] yields the first row again. This allows iterating over a result set
+    /// twice, without executing the query again.
+    ///
+    /// Only scrollable cursors support this, so the statement must have been executed with a
+    /// [`crate::CursorType`] other than [`crate::CursorType::ForwardOnly`] (e.g.
+    /// [`crate::CursorType::Static`]). Forward only cursors, which are the default, return an
+    /// error. In that case you need to execute the query again.
+    ///
+    /// ```no_run
+    /// use odbc_api::{Connection, Cursor, CursorType, Error, StatementOptions};
+    ///
+    /// fn print_twice(conn: &Connection<'_>) -> Result<(), Error> {
+    ///     let options = StatementOptions {
+    ///         cursor_type: Some(CursorType::Static),
+    ///         ..StatementOptions::default()
+    ///     };
+    ///     let mut cursor = conn
+    ///         .execute_with_options("SELECT name FROM Birthdays", (), options)?
+    ///         .expect("SELECT yields a cursor");
+    ///     for _ in 0..2 {
+    ///         let mut name = Vec::new();
+    ///         while let Some(mut row) = cursor.next_row()? {
+    ///             row.get_text(1, &mut name)?;
+    ///             println!("{}", String::from_utf8_lossy(&name));
+    ///         }
+    ///         cursor.restart()?;
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    fn restart(&mut self) -> Result<(), Error> {
+        let mut stmt = self.as_stmt_ref();
+        // Fetching the absolute position `0` does not fetch any row, but positions the cursor in
+        // front of the first one.
+        unsafe { stmt.fetch_scroll(FetchOrientation::Absolute, 0) }.into_result_bool(&stmt)?;
+        Ok(())
+    }
+
+    /// Fetches the one row a query is expected to produce into `R`. Returns `None` if the result
+    /// set is empty. If the result set holds more than one row [`Error::MoreThanOneRow`] is
+    /// returned. This is the row wise counterpart of fetching a single scalar value.
+    ///
+    /// Internally a [`crate::buffers::RowVec`] with a capacity of one row is bound to the cursor.
+    /// Variable sized values which do not fit into their member of `R` are truncated.
+    ///
+    /// ```
+    /// use odbc_api::{Connection, Cursor, Error, parameter::VarCharArray};
+    ///
+    /// fn name_of_user(conn: &Connection<'_>, id: i32) -> Result<Option<String>, Error> {
+    ///     let cursor = conn
+    ///         .execute("SELECT id, name FROM Users WHERE id = ?", &id)?
+    ///         .expect("SELECT yields a cursor");
+    ///     let row = cursor.fetch_one::<(i32, VarCharArray<50>)>()?;
+    ///     Ok(row.and_then(|(_id, name)| name.as_str().unwrap().map(str::to_owned)))
+    /// }
+    /// ```
+    fn fetch_one<R>(self) -> Result<Option<R>, Error>
+    where
+        Self: Sized,
+        R: FetchRow + Default,
+    {
+        let mut block_cursor = self.bind_buffer(RowVec::<R>::new(1))?;
+        let Some(batch) = block_cursor.fetch()? else {
+            return Ok(None);
+        };
+        let row = batch[0];
+        if block_cursor.fetch()?.is_some() {
+            return Err(Error::MoreThanOneRow);
+        }
+        Ok(Some(row))
+    }
+
+    /// Fetches the next row of the result set into `R`. Returns `None` once all rows have been
+    /// consumed. In contrast to [`Self::fetch_one`] the cursor is only borrowed, so this can be
+    /// called repeatedly and the cursor can still be moved on to the next result set using
+    /// [`Self::more_results`] afterwards.
+    ///
+    /// Internally a [`crate::buffers::RowVec`] with a capacity of one row is bound to the cursor
+    /// for the duration of the call and unbound again before it returns. Variable sized values
+    /// which do not fit into their member of `R` are truncated. To fetch many rows, binding a
+    /// larger buffer using [`Self::bind_buffer`] is more efficient, since it requires fewer round
+    /// trips to the data source.
+    ///
+    /// ```
+    /// use odbc_api::{Connection, Cursor, Error, parameter::VarCharArray};
+    ///
+    /// fn print_users(conn: &Connection<'_>) -> Result<(), Error> {
+    ///     let mut cursor = conn
+    ///         .execute("SELECT id, name FROM Users", ())?
+    ///         .expect("SELECT yields a cursor");
+    ///     while let Some((id, name)) = cursor.next_row_as::<(i32, VarCharArray<50>)>()? {
+    ///         println!("{id}: {}", name.as_str().unwrap().unwrap_or("NULL"));
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    fn next_row_as<R>(&mut self) -> Result<Option<R>, Error>
+    where
+        R: FetchRow + Default,
+    {
+        let mut buffer = RowVec::<R>::new(1);
+        let mut stmt = self.as_stmt_ref();
+        // Safety: `buffer` is unbound from the statement again, before it is dropped at the end of
+        // this function. If unbinding fails, it is never dropped.
+        let has_row = unsafe {
+            bind_row_set_buffer_to_statement(stmt.as_stmt_ref(), &mut buffer).and_then(|()| {
+                let result = stmt.fetch();
+                error_handling_for_fetch(result, stmt.as_stmt_ref(), &mut buffer, false)
+            })
+        };
+        // Unbind even if binding or fetching failed, yet report the original error first.
+        if let Err(error) = unbind_buffer_from_cursor(&mut stmt) {
+            // The statement may still hold pointers into the buffer. Leak it, so a subsequent
+            // fetch can not write into freed memory.
+            mem::forget(buffer);
+            return Err(has_row.err().unwrap_or(error));
+        }
+        Ok(has_row?.then(|| buffer[0]))
+    }
+
     /// Binds this cursor to a buffer holding a row set.
     fn bind_buffer<B>(self, row_set_buffer: B) -> Result<BlockCursor<Self, B>, Error>
     where
         Self: Sized,
         B: RowSetBuffer;
 
+    /// Binds this cursor to a buffer holding a row set, like [`Self::bind_buffer`] does. Yet
+    /// instead of fetching as many rows as the buffer can hold, each call to
+    /// [`BlockCursor::fetch`] only fetches up to `fetch_rows` rows. This allows to allocate a
+    /// buffer with a large capacity once, but decide independently how many rows to fetch per
+    /// round trip. The fetch size can be changed later using [`BlockCursor::set_fetch_size`].
+    ///
+    /// Panics if `fetch_rows` is `0` or larger than the capacity of `row_set_buffer`.
+    fn bind_buffer_with_fetch_size<B>(
+        self,
+        row_set_buffer: B,
+        fetch_rows: usize,
+    ) -> Result<BlockCursor<Self, B>, Error>
+    where
+        Self: Sized,
+        B: RowSetBuffer,
+    {
+        let mut block_cursor = self.bind_buffer(row_set_buffer)?;
+        block_cursor.set_fetch_size(fetch_rows)?;
+        Ok(block_cursor)
+    }
+
     /// For some datasources it is possible to create more than one result set at once via a call to
     /// execute. E.g. by calling a stored procedure or executing multiple SQL statements at once.
     /// This method consumes the current cursor and creates a new one representing the next result
@@ -101,7 +254,7 @@ impl<'s> CursorRow<'s> {
     /// # Safety
     ///
     /// `statement` must be in a cursor state.
-    unsafe fn new(statement: StatementRef<'s>) -> Self {
+    pub(crate) unsafe fn new(statement: StatementRef<'s>) -> Self {
         CursorRow { statement }
     }
 }
@@ -128,6 +281,40 @@ impl CursorRow<'_> {
             })
     }
 
+    /// Fetches every column of the current row into the corresponding target, by calling
+    /// [`Self::get_data`] for each of them. `targets[0]` receives column `1`, `targets[1]` column
+    /// `2` and so on. Saves you from writing a `get_data` call for each column of wide rows.
+    ///
+    /// Some drivers require `get_data` to be called in increasing column order and do not allow
+    /// fetching a column twice. By mapping targets to columns by position, this method always
+    /// fetches in increasing order, starting with the first column. If there are fewer targets
+    /// than columns, the remaining columns are not fetched.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use odbc_api::{Cursor, Error, parameter::{CElementMut, VarCharArray}};
+    ///
+    /// fn print_id_and_name(mut cursor: impl Cursor) -> Result<(), Error> {
+    ///     let mut id = 0i32;
+    ///     let mut name = VarCharArray::<64>::NULL;
+    ///     while let Some(mut row) = cursor.next_row()? {
+    ///         row.get_all(&mut [&mut id as &mut dyn CElementMut, &mut name])?;
+    ///         println!("{id}: {:?}", name.as_str());
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn get_all(&mut self, targets: &mut [&mut dyn CElementMut]) -> Result<(), Error> {
+        for (index, target) in targets.iter_mut().enumerate() {
+            let col_or_param_num = (index + 1)
+                .try_into()
+                .expect("Number of targets must not exceed the maximum number of columns.");
+            self.get_data(col_or_param_num, target)?;
+        }
+        Ok(())
+    }
+
     /// Retrieves arbitrary large character data from the row and stores it in the buffer. Column
     /// index starts at `1`. The used encoding is accordig to the ODBC standard determined by your
     /// system local. Ultimatly the choice is up to the implementation of your ODBC driver, which
@@ -189,12 +376,158 @@ impl CursorRow<'_> {
         self.get_variadic::<Binary>(col_or_param_num, buf)
     }
 
+    /// Retrieves arbitrary large character data from the row and appends it to `text`. Column
+    /// index starts at `1`. Contrary to [`Self::get_text`] the text is validated (or transcoded
+    /// from UTF-16 if the `wide` feature is active) and written directly into `text`, so a single
+    /// `String` can be reused across many rows.
+    ///
+    /// ```
+    /// use odbc_api::{Cursor, Error};
+    ///
+    /// fn concat_first_column(cursor: &mut impl Cursor) -> Result<String, Error> {
+    ///     let mut text = String::new();
+    ///     while let Some(mut row) = cursor.next_row()? {
+    ///         row.append_text(1, &mut text)?;
+    ///     }
+    ///     Ok(text)
+    /// }
+    /// ```
+    ///
+    /// # Return
+    ///
+    /// `true` indicates that the value has not been `NULL` and the value has been appended to
+    /// `text`. `false` indicates that the value is `NULL`. `text` is unchanged in that case. Should
+    /// the fetched text not be valid UTF-8 (or UTF-16) [`Error::InvalidTextEncoding`] is returned
+    /// and `text` is left unchanged, too.
+    pub fn append_text(&mut self, col_or_param_num: u16, text: &mut String) -> Result<bool, Error> {
+        #[cfg(not(any(feature = "wide", all(not(feature = "narrow"), target_os = "windows"))))]
+        {
+            let mut bytes = std::mem::take(text).into_bytes();
+            let old_len = bytes.len();
+            let result = self.append_variadic::<Text>(col_or_param_num, &mut bytes);
+            // Only the appended part needs validation. Roll back to the original content on error,
+            // so a failed fetch leaves `text` as it has been.
+            let is_valid = std::str::from_utf8(&bytes[old_len..]).is_ok();
+            if result.is_err() || !is_valid {
+                bytes.truncate(old_len);
+            }
+            // Safety: `bytes[..old_len]` originates from a `String` and the rest has just been
+            // validated, or truncated.
+            *text = unsafe { String::from_utf8_unchecked(bytes) };
+            let is_not_null = result?;
+            if !is_valid {
+                return Err(Error::InvalidTextEncoding { col_or_param_num });
+            }
+            Ok(is_not_null)
+        }
+        #[cfg(any(feature = "wide", all(not(feature = "narrow"), target_os = "windows")))]
+        {
+            let mut utf16 = Vec::new();
+            if !self.get_wide_text(col_or_param_num, &mut utf16)? {
+                return Ok(false);
+            }
+            let utf8 = String::from_utf16(&utf16)
+                .map_err(|_| Error::InvalidTextEncoding { col_or_param_num })?;
+            text.push_str(&utf8);
+            Ok(true)
+        }
+    }
+
+    /// Retrieves arbitrary large character data from the row and decodes it using `encoding`,
+    /// rather than assuming UTF-8. Use this for legacy data sources which return e.g. Latin-1
+    /// encoded text then fetching narrow characters. Column index starts at `1`. `text` is cleared
+    /// before the value is written into it.
+    ///
+    /// # Return
+    ///
+    /// `true` indicates that the value has not been `NULL` and has been decoded into `text`.
+    /// `false` indicates that the value is `NULL`, `text` is empty in that case. Should the fetched
+    /// bytes not be valid in `encoding`, [`Error::UndecodableText`] is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use odbc_api::{encoding_rs::WINDOWS_1252, CursorRow, Error};
+    ///
+    /// fn get_latin_1_text(row: &mut CursorRow<'_>) -> Result<Option<String>, Error> {
+    ///     let mut text = String::new();
+    ///     let is_not_null = row.get_text_with_encoding(1, WINDOWS_1252, &mut text)?;
+    ///     Ok(is_not_null.then_some(text))
+    /// }
+    /// ```
+    #[cfg(feature = "encoding_rs")]
+    pub fn get_text_with_encoding(
+        &mut self,
+        col_or_param_num: u16,
+        encoding: &'static encoding_rs::Encoding,
+        text: &mut String,
+    ) -> Result<bool, Error> {
+        text.clear();
+        let mut bytes = Vec::new();
+        if !self.get_text(col_or_param_num, &mut bytes)? {
+            return Ok(false);
+        }
+        let (decoded, had_errors) = encoding.decode_without_bom_handling(&bytes);
+        if had_errors {
+            return Err(Error::UndecodableText {
+                col_or_param_num,
+                encoding: encoding.name(),
+            });
+        }
+        text.push_str(&decoded);
+        Ok(true)
+    }
+
+    /// Deserializes the row into `T` using `serde`. Columns are assigned to the fields of `T` by
+    /// position, `NULL` values are mapped to `None`. See [`crate::RowDeserializer`] for how values
+    /// are converted. Saves writing a call to [`Self::get_data`] or [`Self::get_text`] for each
+    /// column.
+    ///
+    /// ```
+    /// use odbc_api::{Cursor, Error};
+    /// use serde::Deserialize;
+    ///
+    /// #[derive(Deserialize)]
+    /// struct Product {
+    ///     id: i32,
+    ///     name: Option<String>,
+    ///     price: f64,
+    /// }
+    ///
+    /// fn fetch_products(mut cursor: impl Cursor) -> Result<Vec<Product>, Error> {
+    ///     let mut products = Vec::new();
+    ///     while let Some(mut row) = cursor.next_row()? {
+    ///         products.push(row.deserialize()?);
+    ///     }
+    ///     Ok(products)
+    /// }
+    /// ```
+    #[cfg(feature = "serde")]
+    pub fn deserialize<T>(&mut self) -> Result<T, Error>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        T::deserialize(RowDeserializer::new(self))
+    }
+
     fn get_variadic<K: VarKind>(
         &mut self,
         col_or_param_num: u16,
         buf: &mut Vec<K::Element>,
     ) -> Result<bool, Error> {
-        if buf.capacity() == 0 {
+        buf.clear();
+        self.append_variadic::<K>(col_or_param_num, buf)
+    }
+
+    /// Fetches the value into `buf`, starting after its current end. `buf` is truncated to its
+    /// original length, if the value is `NULL`.
+    fn append_variadic<K: VarKind>(
+        &mut self,
+        col_or_param_num: u16,
+        buf: &mut Vec<K::Element>,
+    ) -> Result<bool, Error> {
+        let offset = buf.len();
+        if buf.capacity() == offset {
             // User did just provide an empty buffer. So it is fair to assume not much domain
             // knowledge has been used to decide its size. We just default to 256 to increase the
             // chance that we get it done with one alloctaion. The buffer size being 0 we need at
@@ -216,7 +549,7 @@ impl CursorRow<'_> {
         // to contain the **next** part of the data, whereas buf contains the entire accumulated
         // value so far.
         let mut target =
-            VarCell::<&mut [K::Element], K>::from_buffer(buf.as_mut_slice(), Indicator::NoTotal);
+            VarCell::<&mut [K::Element], K>::from_buffer(&mut buf[offset..], Indicator::NoTotal);
         self.get_data(col_or_param_num, &mut target)?;
         while !target.is_complete() {
             // Amount of payload bytes (excluding terminating zeros) fetched with the last call to
@@ -279,7 +612,7 @@ impl CursorRow<'_> {
             Ok(true)
         } else {
             // value is NULL
-            buf.clear();
+            buf.truncate(offset);
             Ok(false)
         }
     }
@@ -291,6 +624,8 @@ impl CursorRow<'_> {
 pub struct CursorImpl<Stmt: AsStatementRef> {
     /// A statement handle in cursor mode.
     statement: Stmt,
+    /// Callback registered with the connection, which is informed about the time spent fetching.
+    instrumentation: Option<Instrumentation>,
 }
 
 impl<S> Drop for CursorImpl<S>
@@ -324,6 +659,23 @@ impl<S> Cursor for CursorImpl<S>
 where
     S: AsStatementRef,
 {
+    fn next_row(&mut self) -> Result<Option<CursorRow<'_>>, Error> {
+        let mut stmt = self.statement.as_stmt_ref();
+        let row_available = instrumented(
+            self.instrumentation.as_ref(),
+            ExecPhase::Fetch,
+            None,
+            || unsafe { stmt.fetch() },
+        )
+        .into_result_bool(&stmt)?;
+        let ret = if row_available {
+            Some(unsafe { CursorRow::new(self.statement.as_stmt_ref()) })
+        } else {
+            None
+        };
+        Ok(ret)
+    }
+
     fn bind_buffer<B>(mut self, mut row_set_buffer: B) -> Result<BlockCursor<Self, B>, Error>
     where
         B: RowSetBuffer,
@@ -332,7 +684,8 @@ where
         unsafe {
             bind_row_set_buffer_to_statement(stmt, &mut row_set_buffer)?;
         }
-        Ok(BlockCursor::new(row_set_buffer, self))
+        let instrumentation = self.instrumentation.clone();
+        Ok(BlockCursor::new(row_set_buffer, self, instrumentation))
     }
 
     fn more_results(self) -> Result<Option<Self>, Error>
@@ -340,12 +693,15 @@ where
         Self: Sized,
     {
         // Consume self without calling drop to avoid calling close_cursor.
-        let mut statement = self.into_stmt();
+        let (mut statement, instrumentation) = self.into_parts();
         let mut stmt = statement.as_stmt_ref();
 
         let has_another_result = unsafe { stmt.more_results() }.into_result_bool(&stmt)?;
         let next = if has_another_result {
-            Some(CursorImpl { statement })
+            Some(CursorImpl {
+                statement,
+                instrumentation,
+            })
         } else {
             None
         };
@@ -368,21 +724,66 @@ where
     ///
     /// `statement` must be in Cursor state, for the invariants of this type to hold.
     pub unsafe fn new(statement: S) -> Self {
-        Self { statement }
+        Self {
+            statement,
+            instrumentation: None,
+        }
+    }
+
+    /// Reports the time spent fetching rows from this cursor to `instrumentation`.
+    pub(crate) fn with_instrumentation(mut self, instrumentation: Option<Instrumentation>) -> Self {
+        self.instrumentation = instrumentation;
+        self
     }
 
     /// Deconstructs the `CursorImpl` without calling drop. This is a way to get to the underlying
-    /// statement, while preventing a call to close cursor.
+    /// statement, while preventing a call to close cursor. The statement is still in cursor state,
+    /// so it can be used with the raw ODBC API (e.g. via [`crate::handles::Statement`]) and then be
+    /// turned into a safe cursor again using [`Self::new`]. If you do not intend to do so, you are
+    /// responsible for closing the cursor yourself.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use odbc_api::{Connection, Cursor, CursorImpl, Error, handles::Statement};
+    ///
+    /// fn fetch_first_row_manually(conn: &Connection<'_>) -> Result<(), Error> {
+    ///     let cursor = conn.execute("SELECT a FROM T", ())?.expect("SELECT yields a cursor");
+    ///     let mut statement = cursor.into_stmt();
+    ///     // Escape hatch: Use the raw statement handle to fetch a row
+    ///     unsafe { statement.fetch() };
+    ///     // Safety: The statement is still in cursor state.
+    ///     let mut cursor = unsafe { CursorImpl::new(statement) };
+    ///     // Continue with the safe API, starting with the second row
+    ///     while let Some(_row) = cursor.next_row()? {}
+    ///     Ok(())
+    /// }
+    /// ```
     pub fn into_stmt(self) -> S {
-        // We want to move `statement` out of self, which would make self partially uninitialized.
+        let (statement, _instrumentation) = self.into_parts();
+        statement
+    }
+
+    /// Deconstructs the `CursorImpl` without calling drop, like [`Self::into_stmt`], but also hands
+    /// out the instrumentation callback.
+    fn into_parts(self) -> (S, Option<Instrumentation>) {
+        // We want to move the fields out of self, which would make self partially uninitialized.
         let dont_drop_me = MaybeUninit::new(self);
         let self_ptr = dont_drop_me.as_ptr();
 
-        // Safety: We know `dont_drop_me` is valid at this point so reading the ptr is okay
-        unsafe { ptr::read(&(*self_ptr).statement) }
+        // Safety: We know `dont_drop_me` is valid at this point so reading the ptr is okay. Each
+        // field is read exactly once, and `dont_drop_me` is never dropped.
+        unsafe {
+            (
+                ptr::read(&(*self_ptr).statement),
+                ptr::read(&(*self_ptr).instrumentation),
+            )
+        }
     }
 
-    pub(crate) fn as_sys(&mut self) -> HStmt {
+    /// Raw ODBC statement handle of the cursor. Allows calling ODBC functions not (yet) wrapped by
+    /// this crate, without giving up ownership of the cursor.
+    pub fn as_sys(&mut self) -> HStmt {
         self.as_stmt_ref().as_sys()
     }
 }
@@ -422,6 +823,32 @@ pub unsafe trait RowSetBuffer {
 
     /// Find an indicator larger than the maximum element size of the buffer.
     fn find_truncation(&self) -> Option<TruncationInfo>;
+
+    /// Like [`Self::find_truncation`], but reports every column holding a truncated value, rather
+    /// than stopping at the first one. At most one [`TruncationInfo`] is reported per column. The
+    /// default implementation only reports the first truncation found.
+    fn find_all_truncations(&self) -> Vec<TruncationInfo> {
+        self.find_truncation().into_iter().collect()
+    }
+
+    /// Called after each row set has been fetched into the buffer. Allows buffers to complete
+    /// values which did not fit into the bound memory, e.g. by retrieving them using `SQLGetData`.
+    /// Buffers may rebind their columns to `cursor` in the process. The default implementation
+    /// does nothing.
+    ///
+    /// # Safety
+    ///
+    /// `cursor` must be positioned on the row set which has just been fetched into this buffer.
+    unsafe fn complete_row_set(&mut self, _cursor: StatementRef<'_>) -> Result<(), Error> {
+        Ok(())
+    }
+
+    /// `true` if [`Self::complete_row_set`] may call further functions on the statement, e.g.
+    /// `SQLGetData`. Such buffers can not be bound to a [`CursorPolling`], since these calls would
+    /// not be polled. The default implementation returns `false`.
+    fn completes_row_sets(&self) -> bool {
+        false
+    }
 }
 
 /// Returned by [`RowSetBuffer::find_truncation`]. Contains information about the truncation found.
@@ -453,6 +880,18 @@ unsafe impl<T: RowSetBuffer> RowSetBuffer for &mut T {
     fn find_truncation(&self) -> Option<TruncationInfo> {
         (**self).find_truncation()
     }
+
+    fn find_all_truncations(&self) -> Vec<TruncationInfo> {
+        (**self).find_all_truncations()
+    }
+
+    unsafe fn complete_row_set(&mut self, cursor: StatementRef<'_>) -> Result<(), Error> {
+        (*self).complete_row_set(cursor)
+    }
+
+    fn completes_row_sets(&self) -> bool {
+        (**self).completes_row_sets()
+    }
 }
 
 /// The asynchronous sibiling of [`CursorImpl`]. Use this to fetch results in asynchronous code.
@@ -483,6 +922,10 @@ where
     }
 
     /// Binds this cursor to a buffer holding a row set.
+    ///
+    /// Buffers retrieving values after the fetch using `SQLGetData`, like columns described by
+    /// [`crate::buffers::BufferDesc::LongText`] or a [`crate::buffers::StringRowVec`], are rejected
+    /// with [`Error::CompletingRowSetWhilePolling`].
     pub fn bind_buffer<B>(
         mut self,
         mut row_set_buffer: B,
@@ -490,6 +933,9 @@ where
     where
         B: RowSetBuffer,
     {
+        if row_set_buffer.completes_row_sets() {
+            return Err(Error::CompletingRowSetWhilePolling);
+        }
         let stmt = self.statement.as_stmt_ref();
         unsafe {
             bind_row_set_buffer_to_statement(stmt, &mut row_set_buffer)?;
@@ -575,11 +1021,57 @@ where
     {
         let mut stmt = self.cursor.as_stmt_ref();
         let result = unsafe { wait_for(|| stmt.fetch(), &mut sleep).await };
-        let has_row = error_handling_for_fetch(result, stmt, &self.buffer, error_for_truncation)?;
+        let has_row =
+            error_handling_for_fetch(result, stmt, &mut self.buffer, error_for_truncation)?;
         Ok(has_row.then_some(&self.buffer))
     }
 }
 
+/// Converts the result of executing a statement into a `Result`, depending on whether or not the
+/// application expects the statement to produce a result set. This makes a mismatch between the
+/// statement executed and the intention of the application obvious, rather than silently ignoring
+/// an unexpected cursor (or the absence of an expected one).
+///
+/// # Example
+///
+/// ```no_run
+/// use odbc_api::{Connection, Error, ExpectCursor};
+///
+/// fn delete_all(conn: &Connection<'_>) -> Result<(), Error> {
+///     conn.execute("DELETE FROM Birthdays", ())?.expect_no_cursor()
+/// }
+/// ```
+pub trait ExpectCursor {
+    /// The cursor type in case a result set has been produced.
+    type Cursor;
+
+    /// Returns the cursor, or [`Error::ExpectedResultSet`] if the statement did not produce a
+    /// result set.
+    fn expect_cursor(self) -> Result<Self::Cursor, Error>;
+
+    /// Returns `Ok(())` if the statement did not produce a result set. Otherwise the cursor is
+    /// closed and [`Error::UnexpectedResultSet`] is returned.
+    fn expect_no_cursor(self) -> Result<(), Error>;
+}
+
+impl<C> ExpectCursor for Option<C>
+where
+    C: Cursor,
+{
+    type Cursor = C;
+
+    fn expect_cursor(self) -> Result<C, Error> {
+        self.ok_or(Error::ExpectedResultSet)
+    }
+
+    fn expect_no_cursor(self) -> Result<(), Error> {
+        match self {
+            None => Ok(()),
+            Some(_cursor) => Err(Error::UnexpectedResultSet),
+        }
+    }
+}
+
 /// Binds a row set buffer to a statment. Implementation is shared between synchronous and
 /// asynchronous cursors.
 unsafe fn bind_row_set_buffer_to_statement(
@@ -589,8 +1081,17 @@ unsafe fn bind_row_set_buffer_to_statement(
     stmt.set_row_bind_type(row_set_buffer.bind_type())
         .into_result(&stmt)?;
     let size = row_set_buffer.row_array_size();
+    set_row_array_size(&mut stmt, size)?;
+    stmt.set_num_rows_fetched(row_set_buffer.mut_num_fetch_rows())
+        .into_result(&stmt)?;
+    row_set_buffer.bind_colmuns_to_cursor(stmt)?;
+    Ok(())
+}
+
+/// Sets the number of rows fetched with each call to `SQLFetch`.
+unsafe fn set_row_array_size(stmt: &mut StatementRef<'_>, size: usize) -> Result<(), Error> {
     stmt.set_row_array_size(size)
-        .into_result(&stmt)
+        .into_result(stmt)
         // SAP anywhere has been seen to return with an "invalid attribute" error instead of
         // a success with "option value changed" info. Let us map invalid attributes during
         // setting row set array size to something more precise.
@@ -600,27 +1101,44 @@ unsafe fn bind_row_set_buffer_to_statement(
             } else {
                 Error::Diagnostics { record, function }
             }
-        })?;
-    stmt.set_num_rows_fetched(row_set_buffer.mut_num_fetch_rows())
-        .into_result(&stmt)?;
-    row_set_buffer.bind_colmuns_to_cursor(stmt)?;
-    Ok(())
+        })
 }
 
 /// Error handling for bulk fetching is shared between synchronous and asynchronous usecase.
 fn error_handling_for_fetch(
     result: SqlResult<()>,
     mut stmt: StatementRef,
-    buffer: &impl RowSetBuffer,
+    buffer: &mut impl RowSetBuffer,
     error_for_truncation: bool,
 ) -> Result<bool, Error> {
+    let with_info = result == SqlResult::SuccessWithInfo(());
+    let has_row = result
+        .on_success(|| true)
+        .into_result_with(&stmt.as_stmt_ref(), Some(false), None)
+        // Oracle's ODBC driver does not support 64Bit integers. Furthermore, it does not
+        // tell it to the user when binding parameters, but rather now then we fetch
+        // results. The error code returned is `HY004` rather than `HY003` which should
+        // be used to indicate invalid buffer types.
+        .provide_context_for_diagnostic(|record, function| {
+            if record.state == State::INVALID_SQL_DATA_TYPE {
+                Error::OracleOdbcDriverDoesNotSupport64Bit(record)
+            } else {
+                Error::Diagnostics { record, function }
+            }
+        })?;
+
+    if has_row {
+        // Safety: `stmt` has just been used to fetch the row set into `buffer`.
+        unsafe { buffer.complete_row_set(stmt.as_stmt_ref())? };
+    }
+
     // Only check for truncation if a) the user indicated that he wants to error instead of just
     // ignoring it and if there is at least one diagnostic record. ODBC standard requires a
     // diagnostic record to be there in case of truncation. Sadly we can not rely on this particular
     // record to be there, as the driver could generate a large amount of diagnostic records,
     // while we are limited in the amount we can check. The second check serves as an optimization
     // for the happy path.
-    if error_for_truncation && result == SqlResult::SuccessWithInfo(()) {
+    if error_for_truncation && with_info {
         if let Some(TruncationInfo {
             indicator,
             buffer_index,
@@ -633,20 +1151,6 @@ fn error_handling_for_fetch(
         }
     }
 
-    let has_row = result
-        .on_success(|| true)
-        .into_result_with(&stmt.as_stmt_ref(), Some(false), None)
-        // Oracle's ODBC driver does not support 64Bit integers. Furthermore, it does not
-        // tell it to the user when binding parameters, but rather now then we fetch
-        // results. The error code returned is `HY004` rather than `HY003` which should
-        // be used to indicate invalid buffer types.
-        .provide_context_for_diagnostic(|record, function| {
-            if record.state == State::INVALID_SQL_DATA_TYPE {
-                Error::OracleOdbcDriverDoesNotSupport64Bit(record)
-            } else {
-                Error::Diagnostics { record, function }
-            }
-        })?;
     Ok(has_row)
 }
 
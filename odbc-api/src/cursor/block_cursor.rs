@@ -1,11 +1,24 @@
-use std::{mem::MaybeUninit, ptr, thread::panicking};
+use std::{
+    fmt::{self, Debug, Display},
+    mem::{self, MaybeUninit},
+    ptr,
+    thread::panicking,
+};
+
+use odbc_sys::StatementAttribute;
 
 use crate::{
-    handles::{AsStatementRef, Statement as _},
-    Error,
+    buffers::BufferDesc,
+    handles::{AsStatementRef, Diagnostics, Record as DiagnosticRecord, SqlResult, Statement as _},
+    instrumentation::{instrumented, ExecPhase, Instrumentation},
+    Concurrency, DataType, Error,
 };
 
-use super::{error_handling_for_fetch, unbind_buffer_from_cursor, Cursor, RowSetBuffer};
+use super::{
+    bind_row_set_buffer_to_statement, error_handling_for_fetch, set_row_array_size,
+    unbind_buffer_from_cursor, Cursor, CursorRow, RowSetBuffer, TruncationInfo,
+    UpdatableBlockCursor,
+};
 
 /// In order to save on network overhead, it is recommended to use block cursors instead of fetching
 /// values individually. This can greatly reduce the time applications need to fetch data. You can
@@ -27,16 +40,32 @@ use super::{error_handling_for_fetch, unbind_buffer_from_cursor, Cursor, RowSetB
 ///
 /// See: <https://learn.microsoft.com/en-us/sql/odbc/reference/develop-app/block-cursors>
 pub struct BlockCursor<C: AsStatementRef, B> {
-    buffer: B,
-    cursor: C,
+    pub(super) buffer: B,
+    pub(super) cursor: C,
+    /// Number of rows fetched with each call to fetch. Must not exceed the capacity of `buffer`.
+    fetch_size: usize,
+    /// Diagnostic records reported alongside the last row set, see [`Self::warnings`].
+    warnings: Vec<DiagnosticRecord>,
+    /// Callback registered with the connection, which is informed about the time spent fetching.
+    instrumentation: Option<Instrumentation>,
 }
 
 impl<C, B> BlockCursor<C, B>
 where
     C: Cursor,
 {
-    pub(crate) fn new(buffer: B, cursor: C) -> Self {
-        Self { buffer, cursor }
+    pub(crate) fn new(buffer: B, cursor: C, instrumentation: Option<Instrumentation>) -> Self
+    where
+        B: RowSetBuffer,
+    {
+        let fetch_size = buffer.row_array_size();
+        Self {
+            buffer,
+            cursor,
+            fetch_size,
+            warnings: Vec::new(),
+            instrumentation,
+        }
     }
 
     /// Fills the bound buffer with the next row set.
@@ -99,12 +128,163 @@ where
     where
         B: RowSetBuffer,
     {
+        let has_row = self.fetch_into_buffer(error_for_truncation)?;
+        Ok(has_row.then_some(&self.buffer))
+    }
+
+    /// Fills the bound buffer with the next row set. In contrast to
+    /// [`Self::fetch_with_truncation_check`] truncated values are not reported as an error, but
+    /// alongside the batch. This leaves it up to the caller to decide whether truncation is fatal,
+    /// while retaining access to the (truncated) values of the batch. E.g. an application could
+    /// log the truncated values, or use the indicator in [`TruncationInfo`] to allocate a larger
+    /// buffer and fetch the row set again, using a scrollable cursor.
+    ///
+    /// # Return
+    ///
+    /// `None` if the result set is empty and all row sets have been extracted. `Some` with a
+    /// reference to the internal buffer otherwise. The second element of the tuple is `Some` if a
+    /// truncated value has been found in the batch.
+    ///
+    /// ```
+    /// use odbc_api::{buffers::TextRowSet, Cursor, Error};
+    ///
+    /// fn print_all_values(mut cursor: impl Cursor) -> Result<(), Error> {
+    ///     let buffer = TextRowSet::for_cursor(100, &mut cursor, Some(4000))?;
+    ///     let mut cursor = cursor.bind_buffer(buffer)?;
+    ///     while let Some((batch, truncation)) = cursor.fetch_reporting_truncation()? {
+    ///         if let Some(info) = truncation {
+    ///             eprintln!("Truncated value in column {}", info.buffer_index + 1);
+    ///         }
+    ///         // ... print values in batch ...
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn fetch_reporting_truncation(
+        &mut self,
+    ) -> Result<Option<(&B, Option<TruncationInfo>)>, Error>
+    where
+        B: RowSetBuffer,
+    {
+        let has_row = self.fetch_into_buffer(false)?;
+        if !has_row {
+            return Ok(None);
+        }
+        // Unlike `error_handling_for_fetch` we do not rely on a diagnostic record to be present, as
+        // scanning the indicators is cheap compared to the round trip of fetching the batch.
+        let truncation = self.buffer.find_truncation();
+        Ok(Some((&self.buffer, truncation)))
+    }
+
+    /// Like [`Self::fetch_reporting_truncation`], but reports every column holding a truncated value
+    /// in the batch, rather than only the first one. Useful e.g. for export tools, which want to
+    /// surface all columns with insufficient buffer sizes in a single pass. See
+    /// [`RowSetBuffer::find_all_truncations`].
+    ///
+    /// # Return
+    ///
+    /// `None` if the result set is empty and all row sets have been extracted. `Some` with a
+    /// reference to the internal buffer otherwise, alongside one [`TruncationInfo`] for each column
+    /// with a truncated value. The list is empty if nothing has been truncated.
+    ///
+    /// ```
+    /// use odbc_api::{buffers::TextRowSet, Cursor, Error};
+    ///
+    /// fn report_truncated_columns(mut cursor: impl Cursor) -> Result<(), Error> {
+    ///     let buffer = TextRowSet::for_cursor(100, &mut cursor, Some(4000))?;
+    ///     let mut cursor = cursor.bind_buffer(buffer)?;
+    ///     while let Some((_batch, truncations)) = cursor.fetch_collect_truncations()? {
+    ///         for info in truncations {
+    ///             eprintln!("Truncated value in column {}", info.buffer_index + 1);
+    ///         }
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn fetch_collect_truncations(&mut self) -> Result<Option<(&B, Vec<TruncationInfo>)>, Error>
+    where
+        B: RowSetBuffer,
+    {
+        let has_row = self.fetch_into_buffer(false)?;
+        if !has_row {
+            return Ok(None);
+        }
+        let truncations = self.buffer.find_all_truncations();
+        Ok(Some((&self.buffer, truncations)))
+    }
+
+    /// Fetches the next row into the bound buffer and additionally grants access to the current
+    /// row, so columns which are not bound can be retrieved using [`CursorRow::get_data`]. This
+    /// allows to bind columns of fixed size, while streaming large values, e.g. using
+    /// [`CursorRow::get_text`].
+    ///
+    /// ODBC places some restrictions on mixing bound columns with `SQLGetData`:
+    ///
+    /// * Only one row may be fetched at a time. This method panics, unless the fetch size is `1`.
+    ///   See [`Self::set_fetch_size`].
+    /// * Only columns after the last bound column can be retrieved with `get_data` and only in
+    ///   increasing order. Some drivers lift this restriction, but do not rely on it. To be on the
+    ///   safe side, bind leading columns and fetch trailing ones using `get_data`.
+    ///
+    /// Alternatively [`Self::unbind`] the buffer, before accessing rows using
+    /// [`Cursor::next_row`].
+    ///
+    /// ```
+    /// use odbc_api::{buffers::{BufferDesc, ColumnarAnyBuffer}, Cursor, Error};
+    ///
+    /// /// Cursor has an integer id as first and a large text as second column.
+    /// fn print_ids_and_texts(cursor: impl Cursor) -> Result<(), Error> {
+    ///     // Only bind the first column
+    ///     let buffer = ColumnarAnyBuffer::from_descs_and_indices(
+    ///         1,
+    ///         [(1, BufferDesc::I32 { nullable: false })].into_iter(),
+    ///     );
+    ///     let mut block_cursor = cursor.bind_buffer(buffer)?;
+    ///     let mut text = Vec::new();
+    ///     while let Some((batch, mut row)) = block_cursor.fetch_row()? {
+    ///         let id = batch.column(0).as_slice::<i32>().unwrap()[0];
+    ///         row.get_text(2, &mut text)?;
+    ///         println!("{id}: {}", String::from_utf8_lossy(&text));
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn fetch_row(&mut self) -> Result<Option<(&B, CursorRow<'_>)>, Error>
+    where
+        B: RowSetBuffer,
+    {
+        assert_eq!(
+            1, self.fetch_size,
+            "Fetch size must be 1 in order to access the current row using get_data."
+        );
+        let has_row = self.fetch_into_buffer(false)?;
+        if !has_row {
+            return Ok(None);
+        }
+        // Safety: We just fetched a row, so the statement is in cursor state.
+        let row = unsafe { CursorRow::new(self.cursor.as_stmt_ref()) };
+        Ok(Some((&self.buffer, row)))
+    }
+
+    /// Fetches the next row set into the bound buffer. Should the driver return
+    /// `SQL_SUCCESS_WITH_INFO`, the diagnostic records are kept in `warnings`.
+    fn fetch_into_buffer(&mut self, error_for_truncation: bool) -> Result<bool, Error>
+    where
+        B: RowSetBuffer,
+    {
+        self.warnings.clear();
         let mut stmt = self.cursor.as_stmt_ref();
         unsafe {
-            let result = stmt.fetch();
-            let has_row =
-                error_handling_for_fetch(result, stmt, &self.buffer, error_for_truncation)?;
-            Ok(has_row.then_some(&self.buffer))
+            let result = instrumented(
+                self.instrumentation.as_ref(),
+                ExecPhase::Fetch,
+                None,
+                || stmt.fetch(),
+            );
+            if result == SqlResult::SuccessWithInfo(()) {
+                collect_diagnostics(&stmt, &mut self.warnings);
+            }
+            error_handling_for_fetch(result, stmt, &mut self.buffer, error_for_truncation)
         }
     }
 
@@ -115,6 +295,16 @@ where
     ///    set obtained with [Cursor::more_results`].
     /// 2. Reusing the same buffer with a different statement.
     pub fn unbind(self) -> Result<(C, B), Error> {
+        let (mut cursor, buffer) = self.into_parts();
+
+        // Now that we have cursor out of block cursor, we need to unbind the buffer.
+        unbind_buffer_from_cursor(&mut cursor)?;
+
+        Ok((cursor, buffer))
+    }
+
+    /// Moves cursor and buffer out of self, without unbinding the buffer.
+    fn into_parts(self) -> (C, B) {
         // In this method we want to deconstruct self and move cursor out of it. We need to
         // negotiate with the compiler a little bit though, since BlockCursor does implement `Drop`.
 
@@ -123,13 +313,77 @@ where
         let self_ptr = dont_drop_me.as_ptr();
 
         // Safety: We know `dont_drop_me` is valid at this point so reading the ptr is okay
-        let mut cursor = unsafe { ptr::read(&(*self_ptr).cursor) };
+        let cursor = unsafe { ptr::read(&(*self_ptr).cursor) };
         let buffer = unsafe { ptr::read(&(*self_ptr).buffer) };
+        // Move out the warnings and the instrumentation too, so they are freed.
+        let _warnings = unsafe { ptr::read(&(*self_ptr).warnings) };
+        let _instrumentation = unsafe { ptr::read(&(*self_ptr).instrumentation) };
 
-        // Now that we have cursor out of block cursor, we need to unbind the buffer.
-        unbind_buffer_from_cursor(&mut cursor)?;
+        (cursor, buffer)
+    }
 
-        Ok((cursor, buffer))
+    /// Advances to the next result set and fetches it into the same buffer. Useful if a statement
+    /// emits several result sets with the same schema, e.g. a batch of identical queries. Saves
+    /// the application from unbinding, calling [`Cursor::more_results`] and binding the buffer
+    /// again. The fetch size is preserved.
+    ///
+    /// The buffer has been allocated for the columns of the current result set, so the columns of
+    /// the next result set must be compatible with it. Columns are compatible if they would be
+    /// bound to the same kind of buffer, e.g. text columns of different lengths are compatible, yet
+    /// an integer and a text column are not. Otherwise [`Error::ResultSetSchemaMismatch`] is
+    /// returned. In case of an error the buffer is handed back to the caller as part of
+    /// [`NextResultSetError`], alongside the cursor, if it already points to the next result set.
+    ///
+    /// # Return
+    ///
+    /// `None` if there is no further result set. The buffer is dropped in that case. Use
+    /// [`Self::unbind`] and [`Cursor::more_results`] to keep it.
+    pub fn next_result_set(self) -> Result<Option<Self>, NextResultSetError<C, B>>
+    where
+        B: RowSetBuffer,
+    {
+        let fetch_size = self.fetch_size;
+        let instrumentation = self.instrumentation.clone();
+        let (mut cursor, mut buffer) = self.into_parts();
+        if let Err(e) = unbind_buffer_from_cursor(&mut cursor) {
+            // Same as `Drop` would. We must not hand out a buffer, which might still be bound.
+            panic!("Unexpected error unbinding columns: {e:?}")
+        }
+        let expected = match column_data_types(&mut cursor) {
+            Ok(expected) => expected,
+            Err(error) => return Err(NextResultSetError::new(error, buffer, None)),
+        };
+        let mut cursor = match cursor.more_results() {
+            Ok(Some(cursor)) => cursor,
+            Ok(None) => return Ok(None),
+            Err(error) => return Err(NextResultSetError::new(error, buffer, None)),
+        };
+        let actual = match column_data_types(&mut cursor) {
+            Ok(actual) => actual,
+            Err(error) => return Err(NextResultSetError::new(error, buffer, Some(cursor))),
+        };
+        let compatible = expected.len() == actual.len()
+            && expected
+                .iter()
+                .zip(&actual)
+                .all(|(previous, next)| is_compatible(previous, next));
+        if !compatible {
+            let error = Error::ResultSetSchemaMismatch { expected, actual };
+            return Err(NextResultSetError::new(error, buffer, Some(cursor)));
+        }
+        let bound = unsafe {
+            bind_row_set_buffer_to_statement(cursor.as_stmt_ref(), &mut buffer)
+                .and_then(|()| set_row_array_size(&mut cursor.as_stmt_ref(), fetch_size))
+        };
+        if let Err(error) = bound {
+            if let Err(e) = unbind_buffer_from_cursor(&mut cursor) {
+                panic!("Unexpected error unbinding columns: {e:?}")
+            }
+            return Err(NextResultSetError::new(error, buffer, Some(cursor)));
+        }
+        let mut block_cursor = BlockCursor::new(buffer, cursor, instrumentation);
+        block_cursor.fetch_size = fetch_size;
+        Ok(Some(block_cursor))
     }
 }
 
@@ -138,10 +392,207 @@ where
     B: RowSetBuffer,
     C: AsStatementRef,
 {
+    /// Diagnostic records the driver reported alongside the row set of the most recent call to
+    /// [`Self::fetch`] or any of its siblings, i.e. if fetching returned `SQL_SUCCESS_WITH_INFO`.
+    /// Empty if the last fetch succeeded without further information. E.g. a record with
+    /// [`State::STRING_DATA_RIGHT_TRUNCATION`] (`01004`) indicates that at least one value in the
+    /// batch did not fit into the bound buffer. This is a cheap signal for truncation, since it
+    /// does not require scanning the indicators, yet it depends on the driver to report it.
+    ///
+    /// [`State::STRING_DATA_RIGHT_TRUNCATION`]: crate::handles::State::STRING_DATA_RIGHT_TRUNCATION
+    pub fn warnings(&self) -> &[DiagnosticRecord] {
+        &self.warnings
+    }
+
     /// Maximum amount of rows fetched from the database in the next call to fetch.
     pub fn row_array_size(&self) -> usize {
-        self.buffer.row_array_size()
+        self.fetch_size
+    }
+
+    /// Changes the maximum number of rows fetched with each call to [`Self::fetch`]. The capacity
+    /// of the bound buffer is not affected by this. Allows e.g. to start with fetching small row
+    /// sets and increasing their size later, without rebinding the buffer.
+    ///
+    /// Panics if `fetch_size` is `0` or larger than the capacity of the bound buffer.
+    pub fn set_fetch_size(&mut self, fetch_size: usize) -> Result<(), Error> {
+        let capacity = self.buffer.row_array_size();
+        if fetch_size == 0 || fetch_size > capacity {
+            panic!(
+                "Fetch size must be at least 1 and must not exceed the buffer capacity of \
+                {capacity}. Fetch size: {fetch_size}"
+            )
+        }
+        let mut stmt = self.cursor.as_stmt_ref();
+        unsafe { set_row_array_size(&mut stmt, fetch_size)? };
+        self.fetch_size = fetch_size;
+        Ok(())
     }
+
+    /// Binds `buffer` to the cursor in place of the current one and returns the previously bound
+    /// buffer. The next call to [`Self::fetch`] fills `buffer`. This enables manual double
+    /// buffering, e.g. processing the returned buffer on another thread while fetching into the
+    /// new one. The fetch size is reset to the capacity of `buffer`.
+    ///
+    /// Should binding `buffer` fail, the error is returned and the previous buffer is bound again,
+    /// if possible. `buffer` is never left bound to the statement. Should even unbinding it fail,
+    /// it is leaked rather than dropped.
+    ///
+    /// ```
+    /// use odbc_api::{buffers::TextRowSet, Cursor, Error};
+    ///
+    /// fn alternate_buffers(cursor: impl Cursor) -> Result<(), Error> {
+    ///     let first = TextRowSet::from_max_str_lens(100, [50usize])?;
+    ///     let mut spare = TextRowSet::from_max_str_lens(100, [50usize])?;
+    ///     let mut block_cursor = cursor.bind_buffer(first)?;
+    ///     while block_cursor.fetch()?.is_some() {
+    ///         spare = block_cursor.rebind(spare)?;
+    ///         // Process `spare`, while the next batch is fetched into the other buffer.
+    ///         println!("Fetched {} rows", spare.num_rows());
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn rebind(&mut self, mut buffer: B) -> Result<B, Error> {
+        unbind_buffer_from_cursor(&mut self.cursor)?;
+        let stmt = self.cursor.as_stmt_ref();
+        // Safety: `buffer` is moved into `self` directly afterwards. Only the heap allocations of
+        // the buffer are bound, so they stay valid, as long as `self` is alive.
+        if let Err(error) = unsafe { bind_row_set_buffer_to_statement(stmt, &mut buffer) } {
+            // Do not leave the statement bound to `buffer`, which is going to be dropped. If it can
+            // not be unbound, leak it, so a subsequent fetch can not write into freed memory.
+            if unbind_buffer_from_cursor(&mut self.cursor).is_err() {
+                mem::forget(buffer);
+                return Err(error);
+            }
+            // Restoring the previous binding is best effort. The caller is interested in why
+            // binding `buffer` failed, not in any follow up error.
+            let mut stmt = self.cursor.as_stmt_ref();
+            let _ = unsafe {
+                bind_row_set_buffer_to_statement(stmt.as_stmt_ref(), &mut self.buffer)
+                    .and_then(|()| set_row_array_size(&mut stmt, self.fetch_size))
+            };
+            return Err(error);
+        }
+        self.fetch_size = buffer.row_array_size();
+        Ok(std::mem::replace(&mut self.buffer, buffer))
+    }
+
+    /// Allows writing modifications of fetched rows back to the data source, or deleting them.
+    /// The statement must have been executed with a concurrency other than
+    /// [`Concurrency::ReadOnly`], see [`crate::StatementOptions::concurrency`]. Otherwise
+    /// [`Error::ReadOnlyCursor`] is returned.
+    pub fn into_updatable(mut self) -> Result<UpdatableBlockCursor<C, B>, Error> {
+        let stmt = self.cursor.as_stmt_ref();
+        // Safety: Concurrency is an integer attribute.
+        let concurrency =
+            unsafe { stmt.attribute_u32(StatementAttribute::Concurrency) }.into_result(&stmt)?;
+        if concurrency == Concurrency::ReadOnly as u32 {
+            return Err(Error::ReadOnlyCursor);
+        }
+        Ok(UpdatableBlockCursor::new(self))
+    }
+}
+
+/// Appends all diagnostic records currently associated with `handle` to `records`.
+fn collect_diagnostics(handle: &impl Diagnostics, records: &mut Vec<DiagnosticRecord>) {
+    let mut record = DiagnosticRecord::default();
+    let mut rec_number = 1;
+    while record.fill_from(handle, rec_number) {
+        records.push(mem::take(&mut record));
+        // Drivers may emit a record for each row, do not overflow the record number.
+        if rec_number == i16::MAX {
+            break;
+        }
+        rec_number += 1;
+    }
+}
+
+/// An error type wrapping an [`Error`] and the buffer which had been bound to a [`BlockCursor`]. It
+/// is used by [`BlockCursor::next_result_set`], so that in case of failure the user can reuse the
+/// buffer. [`Error`] implements [`From`] [`NextResultSetError`], so it works with the question mark
+/// operator (`?`).
+pub struct NextResultSetError<C, B> {
+    pub error: Error,
+    /// The buffer, which is no longer bound to any cursor.
+    pub buffer: B,
+    /// Cursor pointing to the next result set, if the error occurred after advancing to it. E.g.
+    /// in case of [`Error::ResultSetSchemaMismatch`] it can be used to bind a different buffer.
+    pub cursor: Option<C>,
+}
+
+impl<C, B> NextResultSetError<C, B> {
+    fn new(error: Error, buffer: B, cursor: Option<C>) -> Self {
+        Self {
+            error,
+            buffer,
+            cursor,
+        }
+    }
+}
+
+impl<C, B> From<NextResultSetError<C, B>> for Error {
+    fn from(value: NextResultSetError<C, B>) -> Self {
+        value.error
+    }
+}
+
+// Cursors and buffers usually do not implement `Debug`, so only the error is printed. This allows
+// to `unwrap` the result of `next_result_set`.
+impl<C, B> Debug for NextResultSetError<C, B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NextResultSetError")
+            .field("error", &self.error)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<C, B> Display for NextResultSetError<C, B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.error)
+    }
+}
+
+impl<C, B> std::error::Error for NextResultSetError<C, B> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.error.source()
+    }
+}
+
+/// `true` if a buffer bound to a column of type `previous` can also be bound to a column of type
+/// `next`. Text and binary columns are compatible regardless of their length, since values too
+/// large for the buffer are reported as truncated. Other types are compatible if they are mapped to
+/// the same buffer.
+fn is_compatible(previous: &DataType, next: &DataType) -> bool {
+    let is_text = |data_type: &DataType| {
+        matches!(
+            data_type,
+            DataType::Char { .. }
+                | DataType::WChar { .. }
+                | DataType::Varchar { .. }
+                | DataType::WVarchar { .. }
+                | DataType::LongVarchar { .. }
+        )
+    };
+    let is_binary = |data_type: &DataType| {
+        matches!(
+            data_type,
+            DataType::Binary { .. } | DataType::Varbinary { .. } | DataType::LongVarbinary { .. }
+        )
+    };
+    if is_text(previous) || is_binary(previous) {
+        return (is_text(previous) && is_text(next)) || (is_binary(previous) && is_binary(next));
+    }
+    previous == next
+        || BufferDesc::from_data_type(*previous, true)
+            .is_some_and(|desc| Some(desc) == BufferDesc::from_data_type(*next, true))
+}
+
+/// Data types of all the columns in the current result set of `cursor`.
+fn column_data_types(cursor: &mut impl Cursor) -> Result<Vec<DataType>, Error> {
+    let num_cols: u16 = cursor.num_result_cols()?.try_into().unwrap();
+    (1..=num_cols)
+        .map(|column_number| cursor.col_data_type(column_number))
+        .collect()
 }
 
 impl<C, B> Drop for BlockCursor<C, B>
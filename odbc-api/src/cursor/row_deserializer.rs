@@ -0,0 +1,443 @@
+use std::fmt::Display;
+
+use odbc_sys::SqlDataType;
+use serde::{
+    de::{self, value::SeqDeserializer, DeserializeSeed, SeqAccess, Visitor},
+    forward_to_deserialize_any,
+};
+
+use crate::{handles::Statement as _, Bit, Error, Nullable};
+
+use super::CursorRow;
+
+/// Deserializes the current row of a result set into any type implementing
+/// [`serde::Deserialize`]. Columns are mapped to the fields of a struct, or the elements of a
+/// tuple, by position. The first column is assigned to the first field, the second column to the
+/// second field and so on. Names are not taken into account. If the number of fields does not
+/// match the number of columns in the result set, [`Error::FieldCountMismatch`] is returned.
+///
+/// Each value is fetched using `SQLGetData`, with a C type chosen according to the SQL type of the
+/// column. Integer, floating point, bit and binary columns are fetched as such, all other columns
+/// are fetched as text. The value is then converted into the type of the field, e.g. a `DECIMAL`
+/// column can be deserialized into an `f64` and an `INTEGER` column into a `String`. `NULL` is
+/// mapped to `None`, so fields of nullable columns must be `Option`s.
+///
+/// Usually you want to call [`CursorRow::deserialize`] rather than constructing this directly.
+///
+/// Requires the `serde` feature.
+pub struct RowDeserializer<'r, 's> {
+    row: &'r mut CursorRow<'s>,
+}
+
+impl<'r, 's> RowDeserializer<'r, 's> {
+    /// Deserializer for the row the cursor is currently positioned at.
+    pub fn new(row: &'r mut CursorRow<'s>) -> Self {
+        Self { row }
+    }
+
+    fn num_columns(&mut self) -> Result<u16, Error> {
+        let stmt = &self.row.statement;
+        let num_columns = stmt.num_result_cols().into_result(stmt)?;
+        Ok(num_columns.try_into().unwrap_or(0))
+    }
+
+    /// Visits all columns of the row, after checking that their number matches `num_fields`.
+    fn deserialize_fields<'de, V>(
+        mut self,
+        num_fields: usize,
+        visitor: V,
+    ) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        let num_columns = self.num_columns()?;
+        if num_fields != num_columns as usize {
+            return Err(Error::FieldCountMismatch {
+                num_columns: num_columns as usize,
+                num_fields,
+            });
+        }
+        visitor.visit_seq(Columns {
+            row: self.row,
+            next_column: 1,
+            num_columns,
+        })
+    }
+}
+
+impl<'de> de::Deserializer<'de> for RowDeserializer<'_, '_> {
+    type Error = Error;
+
+    fn deserialize_any<V>(mut self, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        let num_columns = self.num_columns()?;
+        self.deserialize_fields(num_columns as usize, visitor)
+    }
+
+    fn deserialize_tuple<V>(self, len: usize, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_fields(len, visitor)
+    }
+
+    fn deserialize_tuple_struct<V>(
+        self,
+        _name: &'static str,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_fields(len, visitor)
+    }
+
+    fn deserialize_struct<V>(
+        self,
+        _name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_fields(fields.len(), visitor)
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string bytes byte_buf option
+        unit unit_struct newtype_struct seq map enum identifier ignored_any
+    }
+}
+
+/// Hands out the columns of a row one by one, to be deserialized into the fields of a struct.
+struct Columns<'r, 's> {
+    row: &'r mut CursorRow<'s>,
+    /// One based index of the column to fetch next.
+    next_column: u16,
+    num_columns: u16,
+}
+
+impl<'de> SeqAccess<'de> for Columns<'_, '_> {
+    type Error = Error;
+
+    fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>, Error>
+    where
+        T: DeserializeSeed<'de>,
+    {
+        if self.next_column > self.num_columns {
+            return Ok(None);
+        }
+        let col = self.next_column;
+        self.next_column += 1;
+        let value = fetch_value(self.row, col)?;
+        seed.deserialize(ColumnDeserializer { value })
+            .map(Some)
+            .map_err(|error| match error {
+                Error::Deserialization(message) => {
+                    Error::Deserialization(format!("Column {col}: {message}"))
+                }
+                other => other,
+            })
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some((self.num_columns + 1 - self.next_column) as usize)
+    }
+}
+
+/// A single field of a row, fetched into the representation closest to its SQL type.
+#[derive(Debug, Clone, PartialEq)]
+enum Value {
+    Null,
+    Bool(bool),
+    Integer(i64),
+    Float(f64),
+    Text(String),
+    Bytes(Vec<u8>),
+}
+
+fn fetch_value(row: &mut CursorRow<'_>, col: u16) -> Result<Value, Error> {
+    let stmt = &row.statement;
+    let sql_type = stmt.col_concise_type(col).into_result(stmt)?;
+    let value = match sql_type {
+        SqlDataType::EXT_BIT => {
+            let mut bit = Nullable::<Bit>::null();
+            row.get_data(col, &mut bit)?;
+            bit.into_opt().map(|Bit(bit)| Value::Bool(bit != 0))
+        }
+        SqlDataType::EXT_TINY_INT
+        | SqlDataType::SMALLINT
+        | SqlDataType::INTEGER
+        | SqlDataType::EXT_BIG_INT => {
+            let mut integer = Nullable::<i64>::null();
+            row.get_data(col, &mut integer)?;
+            integer.into_opt().map(Value::Integer)
+        }
+        SqlDataType::REAL | SqlDataType::FLOAT | SqlDataType::DOUBLE => {
+            let mut float = Nullable::<f64>::null();
+            row.get_data(col, &mut float)?;
+            float.into_opt().map(Value::Float)
+        }
+        SqlDataType::EXT_BINARY
+        | SqlDataType::EXT_VAR_BINARY
+        | SqlDataType::EXT_LONG_VAR_BINARY => {
+            let mut bytes = Vec::new();
+            row.get_binary(col, &mut bytes)?
+                .then_some(Value::Bytes(bytes))
+        }
+        _ => {
+            let mut text = String::new();
+            row.append_text(col, &mut text)?
+                .then_some(Value::Text(text))
+        }
+    };
+    Ok(value.unwrap_or(Value::Null))
+}
+
+/// Deserializes the value of a single column into a field.
+struct ColumnDeserializer {
+    value: Value,
+}
+
+impl ColumnDeserializer {
+    /// Error returned if a `NULL` is encountered for a field which is not an `Option`.
+    fn unexpected_null(&self) -> Error {
+        Error::Deserialization(
+            "Value is NULL, but the field it is deserialized into is not an Option.".to_owned(),
+        )
+    }
+}
+
+impl<'de> de::Deserializer<'de> for ColumnDeserializer {
+    type Error = Error;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self.value {
+            Value::Null => visitor.visit_none(),
+            Value::Bool(boolean) => visitor.visit_bool(boolean),
+            Value::Integer(integer) => visitor.visit_i64(integer),
+            Value::Float(float) => visitor.visit_f64(float),
+            Value::Text(text) => visitor.visit_string(text),
+            Value::Bytes(bytes) => visitor.visit_byte_buf(bytes),
+        }
+    }
+
+    fn deserialize_option<V>(self, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self.value {
+            Value::Null => visitor.visit_none(),
+            _ => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_bool<V>(self, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        match &self.value {
+            Value::Null => Err(self.unexpected_null()),
+            Value::Integer(integer) => visitor.visit_bool(*integer != 0),
+            Value::Text(text) => match text.trim() {
+                "1" | "true" | "TRUE" => visitor.visit_bool(true),
+                "0" | "false" | "FALSE" => visitor.visit_bool(false),
+                _ => self.deserialize_any(visitor),
+            },
+            _ => self.deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_i64<V>(self, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        match &self.value {
+            Value::Null => Err(self.unexpected_null()),
+            Value::Text(text) => {
+                let text = text.trim();
+                if let Ok(integer) = text.parse::<i64>() {
+                    visitor.visit_i64(integer)
+                } else if let Ok(integer) = text.parse::<u64>() {
+                    visitor.visit_u64(integer)
+                } else {
+                    self.deserialize_any(visitor)
+                }
+            }
+            _ => self.deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_f64<V>(self, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        match &self.value {
+            Value::Null => Err(self.unexpected_null()),
+            Value::Text(text) => match text.trim().parse::<f64>() {
+                Ok(float) => visitor.visit_f64(float),
+                Err(_) => self.deserialize_any(visitor),
+            },
+            _ => self.deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_string<V>(self, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self.value {
+            Value::Null => Err(self.unexpected_null()),
+            Value::Bool(boolean) => visitor.visit_string(boolean.to_string()),
+            Value::Integer(integer) => visitor.visit_string(integer.to_string()),
+            Value::Float(float) => visitor.visit_string(float.to_string()),
+            _ => self.deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_byte_buf<V>(self, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self.value {
+            Value::Null => Err(self.unexpected_null()),
+            Value::Text(text) => visitor.visit_byte_buf(text.into_bytes()),
+            _ => self.deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_seq<V>(self, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        // `Vec<u8>` is deserialized as a sequence, rather than as bytes.
+        match self.value {
+            Value::Bytes(bytes) => visitor.visit_seq(SeqDeserializer::new(bytes.into_iter())),
+            Value::Text(text) => {
+                visitor.visit_seq(SeqDeserializer::new(text.into_bytes().into_iter()))
+            }
+            Value::Null => Err(self.unexpected_null()),
+            _ => self.deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_i8<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        self.deserialize_i64(visitor)
+    }
+
+    fn deserialize_i16<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        self.deserialize_i64(visitor)
+    }
+
+    fn deserialize_i32<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        self.deserialize_i64(visitor)
+    }
+
+    fn deserialize_u8<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        self.deserialize_i64(visitor)
+    }
+
+    fn deserialize_u16<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        self.deserialize_i64(visitor)
+    }
+
+    fn deserialize_u32<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        self.deserialize_i64(visitor)
+    }
+
+    fn deserialize_u64<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        self.deserialize_i64(visitor)
+    }
+
+    fn deserialize_f32<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        self.deserialize_f64(visitor)
+    }
+
+    fn deserialize_str<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        self.deserialize_string(visitor)
+    }
+
+    fn deserialize_bytes<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        self.deserialize_byte_buf(visitor)
+    }
+
+    forward_to_deserialize_any! {
+        i128 u128 char unit unit_struct newtype_struct tuple tuple_struct map struct enum identifier
+        ignored_any
+    }
+}
+
+impl de::Error for Error {
+    fn custom<T>(msg: T) -> Self
+    where
+        T: Display,
+    {
+        Error::Deserialization(msg.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::Deserialize;
+
+    use crate::Error;
+
+    use super::{ColumnDeserializer, Value};
+
+    fn deserialize<'de, T: Deserialize<'de>>(value: Value) -> Result<T, Error> {
+        T::deserialize(ColumnDeserializer { value })
+    }
+
+    #[test]
+    fn null_is_none() {
+        assert_eq!(None, deserialize::<Option<String>>(Value::Null).unwrap());
+        assert_eq!(None, deserialize::<Option<i32>>(Value::Null).unwrap());
+    }
+
+    #[test]
+    fn null_into_non_optional_field_is_an_error() {
+        let result = deserialize::<i32>(Value::Null);
+
+        assert!(matches!(result, Err(Error::Deserialization(_))));
+    }
+
+    #[test]
+    fn text_is_parsed_into_numbers() {
+        assert_eq!(42i32, deserialize(Value::Text("42".to_owned())).unwrap());
+        assert_eq!(
+            Some(12.5f64),
+            deserialize(Value::Text("12.50".to_owned())).unwrap()
+        );
+        assert!(deserialize::<bool>(Value::Text("1".to_owned())).unwrap());
+    }
+
+    #[test]
+    fn numbers_are_formatted_into_text() {
+        assert_eq!(
+            Some("42".to_owned()),
+            deserialize(Value::Integer(42)).unwrap()
+        );
+    }
+
+    #[test]
+    fn integer_out_of_range_is_an_error() {
+        let result = deserialize::<i32>(Value::Integer(i64::MAX));
+
+        assert!(matches!(result, Err(Error::Deserialization(_))));
+    }
+
+    #[test]
+    fn bytes_into_vec() {
+        assert_eq!(
+            Some(vec![1u8, 2, 3]),
+            deserialize(Value::Bytes(vec![1, 2, 3])).unwrap()
+        );
+    }
+}
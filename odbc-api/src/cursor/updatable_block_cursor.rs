@@ -0,0 +1,115 @@
+use odbc_sys::Operation;
+
+use crate::{
+    handles::{AsStatementRef, Statement as _},
+    Error,
+};
+
+use super::{BlockCursor, Cursor, RowSetBuffer};
+
+/// A block cursor, which is able to write modifications of the fetched rows back to the data
+/// source. The bound buffer serves both as the target of the fetch and as the source of updates.
+/// Created using [`BlockCursor::into_updatable`].
+///
+/// Updates and deletes are performed using `SQLSetPos` and always refer to a row of the row set
+/// fetched last. This allows e.g. ETL tools to correct rows in place, without the need to issue
+/// separate `UPDATE` statements keyed on a primary key.
+///
+/// # Example
+///
+/// ```no_run
+/// use odbc_api::{
+///     buffers::RowVec, Concurrency, Connection, Cursor, CursorType, Error, StatementOptions,
+/// };
+///
+/// fn double_all_prices(conn: &Connection<'_>) -> Result<(), Error> {
+///     let options = StatementOptions {
+///         cursor_type: Some(CursorType::KeysetDriven),
+///         concurrency: Some(Concurrency::Lock),
+///     };
+///     let cursor = conn
+///         .execute_with_options("SELECT price FROM Products", (), options)?
+///         .expect("SELECT statement must produce a cursor");
+///     let buffer = RowVec::<(i32,)>::new(100);
+///     let mut cursor = cursor.bind_buffer(buffer)?.into_updatable()?;
+///     while cursor.fetch()?.is_some() {
+///         // Safety: Values of the rows are only modified, the buffer is not replaced.
+///         let rows = unsafe { cursor.buffer_mut() };
+///         let num_rows = rows.len();
+///         for row in rows.iter_mut() {
+///             row.0 *= 2;
+///         }
+///         for row_index in 0..num_rows {
+///             cursor.update_row(row_index)?;
+///         }
+///     }
+///     Ok(())
+/// }
+/// ```
+pub struct UpdatableBlockCursor<C: AsStatementRef, B> {
+    block_cursor: BlockCursor<C, B>,
+}
+
+impl<C, B> UpdatableBlockCursor<C, B>
+where
+    C: AsStatementRef,
+    B: RowSetBuffer,
+{
+    pub(super) fn new(block_cursor: BlockCursor<C, B>) -> Self {
+        Self { block_cursor }
+    }
+
+    /// Fills the bound buffer with the next row set. See [`BlockCursor::fetch`].
+    pub fn fetch(&mut self) -> Result<Option<&B>, Error>
+    where
+        C: Cursor,
+    {
+        self.block_cursor.fetch()
+    }
+
+    /// Mutable access to the bound buffer, so the values of the fetched rows can be modified before
+    /// writing them back to the data source using [`Self::update_row`].
+    ///
+    /// # Safety
+    ///
+    /// The memory of the buffer is bound to the statement. The buffer must neither be replaced, nor
+    /// may any of its columns be reallocated.
+    pub unsafe fn buffer_mut(&mut self) -> &mut B {
+        &mut self.block_cursor.buffer
+    }
+
+    /// Writes the values of the row with the zero based `row_index` in the bound buffer back to the
+    /// data source. Calls `SQLSetPos` with `SQL_UPDATE`.
+    ///
+    /// Panics if `row_index` is not smaller than the number of rows fetched last.
+    pub fn update_row(&mut self, row_index: usize) -> Result<(), Error> {
+        self.set_pos(row_index, Operation::UPDATE)
+    }
+
+    /// Deletes the row with the zero based `row_index` within the row set fetched last from the
+    /// data source. Calls `SQLSetPos` with `SQL_DELETE`.
+    ///
+    /// Panics if `row_index` is not smaller than the number of rows fetched last.
+    pub fn delete_row(&mut self, row_index: usize) -> Result<(), Error> {
+        self.set_pos(row_index, Operation::DELETE)
+    }
+
+    /// Turns this back into a plain block cursor, keeping the buffer bound.
+    pub fn into_block_cursor(self) -> BlockCursor<C, B> {
+        self.block_cursor
+    }
+
+    fn set_pos(&mut self, row_index: usize, operation: Operation) -> Result<(), Error> {
+        let num_rows = *self.block_cursor.buffer.mut_num_fetch_rows();
+        if row_index >= num_rows {
+            panic!(
+                "Row index {row_index} is out of bounds. The last row set fetched contains \
+                {num_rows} rows."
+            )
+        }
+        let mut stmt = self.block_cursor.cursor.as_stmt_ref();
+        // Safety: A row set has been fetched, so the statement is in cursor state and `row_index`
+        // has been checked against its size. The buffer stays bound for the lifetime of `self`.
+        unsafe { stmt.set_pos(row_index + 1, operation) }.into_result(&stmt)
+    }
+}
@@ -4,19 +4,20 @@
 pub enum DriverCompleteOption {
     /// Do not show a prompt to the user. This implies that the connection string, must already
     /// provide all information needed to Connect to the data source, otherwise the operation fails.
-    /// This is the only supported variant on non windows platforms
+    /// This is the only supported variant on non windows platforms. `SQL_DRIVER_NOPROMPT`.
     NoPrompt,
-    /// Always show a prompt to the user.
+    /// Always show a prompt to the user. `SQL_DRIVER_PROMPT`.
     Prompt,
     /// Only show a prompt to the user if the information in the connection string is not sufficient
-    /// to connect to the data source.
+    /// to connect to the data source. `SQL_DRIVER_COMPLETE`.
     Complete,
     /// Like complete, but the user may not change any information already provided within the
-    /// connection string.
+    /// connection string. Only required attributes are prompted for. `SQL_DRIVER_COMPLETE_REQUIRED`.
     CompleteRequired,
 }
 
 impl DriverCompleteOption {
+    /// The completion mode passed to `SQLDriverConnect`.
     pub fn as_sys(&self) -> odbc_sys::DriverConnectOption {
         match self {
             DriverCompleteOption::NoPrompt => odbc_sys::DriverConnectOption::NoPrompt,
@@ -28,3 +29,30 @@ impl DriverCompleteOption {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use odbc_sys::DriverConnectOption;
+
+    use super::DriverCompleteOption;
+
+    #[test]
+    fn map_each_completion_mode_to_sys_constant() {
+        assert_eq!(
+            DriverConnectOption::NoPrompt,
+            DriverCompleteOption::NoPrompt.as_sys()
+        );
+        assert_eq!(
+            DriverConnectOption::Prompt,
+            DriverCompleteOption::Prompt.as_sys()
+        );
+        assert_eq!(
+            DriverConnectOption::Complete,
+            DriverCompleteOption::Complete.as_sys()
+        );
+        assert_eq!(
+            DriverConnectOption::CompleteRequired,
+            DriverCompleteOption::CompleteRequired.as_sys()
+        );
+    }
+}
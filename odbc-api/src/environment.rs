@@ -2,19 +2,22 @@ use std::{
     cmp::max,
     collections::HashMap,
     ptr::null_mut,
-    sync::{Mutex, OnceLock},
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        Mutex, OnceLock,
+    },
 };
 
 use crate::{
     connection::ConnectionOptions,
     error::ExtendResult,
     handles::{
-        self, log_diagnostics, slice_to_utf8, OutputStringBuffer, SqlChar, SqlResult, SqlText,
-        State, SzBuffer,
+        self, log_diagnostics, slice_to_cow_utf8, slice_to_utf8, OutputStringBuffer, SqlChar,
+        SqlResult, SqlText, State, SzBuffer,
     },
-    Connection, DriverCompleteOption, Error,
+    Connection, ConnectionStringBuilder, DriverCompleteOption, EnvironmentSetupError, Error,
 };
-use log::debug;
+use log::{debug, warn};
 use odbc_sys::{AttrCpMatch, AttrOdbcVersion, FetchOrientation, HWnd};
 
 #[cfg(target_os = "windows")]
@@ -27,6 +30,11 @@ use winit::{
     window::{Window, WindowId},
 };
 
+/// Number of [`Environment`] instances currently alive in this process. Used to detect accidental
+/// creation of more than one environment, and to reject setting the connection pooling scheme,
+/// which is only applied to environments allocated afterwards.
+static NUM_ENVIRONMENTS: AtomicUsize = AtomicUsize::new(0);
+
 #[cfg(not(feature = "odbc_version_3_5"))]
 const ODBC_API_VERSION: AttrOdbcVersion = AttrOdbcVersion::Odbc3_80;
 
@@ -56,6 +64,10 @@ pub struct Environment {
     /// If multiple fallible operations are executed in parallel, we need the mutex to ensure the
     /// errors are fetched by the correct thread.
     internal_state: Mutex<()>,
+    /// `true` once the first connection handle has been allocated from this environment. The
+    /// pooling match criteria are not applied to connections which already exist, so changing them
+    /// afterwards is reported as an error.
+    any_connection_allocated: AtomicBool,
 }
 
 unsafe impl Sync for Environment {}
@@ -104,10 +116,18 @@ impl Environment {
     ///
     /// Also note that this is changes global mutable state for the entire process. As such it is
     /// vulnerable to race conditions if called from more than one place in your application. It is
-    /// recommened to call this in the beginning, before creating any connection.
+    /// recommened to call this in the beginning, before creating the environment.
+    ///
+    /// # Errors
+    ///
+    /// [`Error::ConnectionPoolingAfterEnvironment`] if an environment is alive within this process.
+    /// The driver manager would silently ignore the scheme for it.
     pub unsafe fn set_connection_pooling(
         scheme: odbc_sys::AttrConnectionPooling,
     ) -> Result<(), Error> {
+        if NUM_ENVIRONMENTS.load(Ordering::SeqCst) != 0 {
+            return Err(Error::ConnectionPoolingAfterEnvironment);
+        }
         match handles::Environment::set_connection_pooling(scheme) {
             SqlResult::Error { .. } => Err(Error::FailedSettingConnectionPooling),
             SqlResult::Success(()) | SqlResult::SuccessWithInfo(()) => Ok(()),
@@ -131,7 +151,15 @@ impl Environment {
     ///   is the default.
     /// * [`crate::sys::AttrCpMatch::Relaxed`] = Connections with matching connection string \
     ///   keywords can be used. Keywords must match, but not all connection attributes must match.
+    ///
+    /// # Errors
+    ///
+    /// [`Error::ConnectionPoolingMatchingAfterConnect`] if a connection has already been allocated
+    /// from this environment.
     pub fn set_connection_pooling_matching(&mut self, matching: AttrCpMatch) -> Result<(), Error> {
+        if self.any_connection_allocated.load(Ordering::SeqCst) {
+            return Err(Error::ConnectionPoolingMatchingAfterConnect);
+        }
         self.environment
             .set_connection_pooling_matching(matching)
             .into_result(&self.environment)
@@ -140,58 +168,58 @@ impl Environment {
     /// Entry point into this API. Allocates a new ODBC Environment and declares to the driver
     /// manager that the Application wants to use ODBC version 3.8.
     ///
-    /// # Safety
+    /// # Only one environment per process
     ///
-    /// There may only be one ODBC environment in any process at any time. Take care using this
-    /// function in unit tests, as these run in parallel by default in Rust. Also no library should
-    /// probably wrap the creation of an odbc environment into a safe function call. This is because
-    /// using two of these "safe" libraries at the same time in different parts of your program may
-    /// lead to race condition thus violating Rust's safety guarantees.
+    /// There should only be one ODBC environment in any process at any time. Take care using this
+    /// function in unit tests, as these run in parallel by default in Rust. Also libraries should
+    /// not create their own environment, but rather borrow one from the application, since using
+    /// two of these libraries at the same time in different parts of your program would create two
+    /// environments.
     ///
-    /// Creating one environment in your binary is safe however.
+    /// Creating a second environment, while the first one is still alive, is detected and logged
+    /// as a warning. Consider using [`environment`], which always returns the same instance, if
+    /// you want to share one environment across your application.
     pub fn new() -> Result<Self, Error> {
-        let result = handles::Environment::new();
+        Self::try_new().map_err(Error::from)
+    }
 
-        let environment = match result {
-            SqlResult::Success(env) => env,
-            SqlResult::SuccessWithInfo(env) => {
-                log_diagnostics(&env);
-                env
-            }
-            SqlResult::Error { .. } => return Err(Error::FailedAllocatingEnvironment),
-            other => panic!("Unexpected return value '{other:?}'"),
-        };
+    /// Like [`Self::new`], but reports failures with an [`EnvironmentSetupError`], which tells
+    /// whether allocating the environment handle (`SQLAllocHandle`) or declaring the ODBC version
+    /// (`SQLSetEnvAttr`) failed, together with the diagnostic record of the driver manager, if
+    /// available. Unexpected return values of the driver manager are reported as errors, too,
+    /// rather than causing a panic.
+    ///
+    /// ```no_run
+    /// use odbc_api::Environment;
+    ///
+    /// match Environment::try_new() {
+    ///     Ok(_env) => { /* Connect to data sources */ }
+    ///     Err(error) => eprintln!("Check your driver manager installation: {error}"),
+    /// }
+    /// ```
+    pub fn try_new() -> Result<Self, EnvironmentSetupError> {
+        let environment = allocated_environment(handles::Environment::new())?;
 
         debug!("ODBC Environment created.");
 
         debug!("Setting ODBC API version to {ODBC_API_VERSION:?}");
-        let result = environment
+        environment
             .declare_version(ODBC_API_VERSION)
-            .into_result(&environment);
+            .into_result(&environment)
+            .map_err(version_declaration_error)?;
 
-        // Status code S1009 has been seen with unixODBC 2.3.1. S1009 meant (among other things)
-        // invalid attribute. If we see this then we try to declare the ODBC version it is of course
-        // likely that the driver manager only knows ODBC 2.x.
-        // See: <https://learn.microsoft.com/sql/odbc/reference/develop-app/sqlstate-mappings>
-        const ODBC_2_INVALID_ATTRIBUTE: State = State(*b"S1009");
-
-        // Translate invalid attribute into a more meaningful error, provided the additional
-        // context that we know we tried to set version number.
-        result.provide_context_for_diagnostic(|record, function| match record.state {
-            // INVALID_STATE_TRANSACTION has been seen with some really old version of unixODBC on
-            // a CentOS used to build manylinux wheels, with the preinstalled ODBC version.
-            // INVALID_ATTRIBUTE_VALUE is the correct status code to emit for a driver manager if it
-            // does not know the version and has been seen with an unknown version of unixODBC on an
-            // Oracle Linux.
-            ODBC_2_INVALID_ATTRIBUTE
-            | State::INVALID_STATE_TRANSACTION
-            | State::INVALID_ATTRIBUTE_VALUE => Error::UnsupportedOdbcApiVersion(record),
-            _ => Error::Diagnostics { record, function },
-        })?;
+        let num_alive = NUM_ENVIRONMENTS.fetch_add(1, Ordering::SeqCst);
+        if num_alive != 0 {
+            warn!(
+                "Created an ODBC environment while {num_alive} other environment(s) are still \
+                alive. There should only be one environment per process."
+            );
+        }
 
         Ok(Self {
             environment,
             internal_state: Mutex::new(()),
+            any_connection_allocated: AtomicBool::new(false),
         })
     }
 
@@ -277,17 +305,80 @@ impl Environment {
         connection_string: &str,
         options: ConnectionOptions,
     ) -> Result<Connection<'_>, Error> {
-        let connection_string = SqlText::new(connection_string);
+        let sql_connection_string = SqlText::new(connection_string);
         let mut connection = self.allocate_connection()?;
 
         options.apply(&connection)?;
 
         connection
-            .connect_with_connection_string(&connection_string)
-            .into_result(&connection)?;
+            .connect_with_connection_string(&sql_connection_string)
+            .into_result(&connection)
+            .map_err(|error| self.context_for_missing_driver(connection_string, error))?;
         Ok(Connection::new(connection))
     }
 
+    /// Connects using a connection string assembled from individual attributes. See
+    /// [`ConnectionStringBuilder`].
+    ///
+    /// ```no_run
+    /// use odbc_api::{ConnectionOptions, ConnectionStringBuilder, Environment};
+    ///
+    /// let env = Environment::new()?;
+    ///
+    /// let builder = ConnectionStringBuilder::new()
+    ///     .driver("ODBC Driver 18 for SQL Server")
+    ///     .server("localhost")
+    ///     .attribute("UID", "SA")
+    ///     .attribute("PWD", "My@Test@Password1");
+    ///
+    /// let mut conn = env.connect_with_builder(&builder, ConnectionOptions::default())?;
+    /// # Ok::<(), odbc_api::Error>(())
+    /// ```
+    pub fn connect_with_builder(
+        &self,
+        builder: &ConnectionStringBuilder,
+        options: ConnectionOptions,
+    ) -> Result<Connection<'_>, Error> {
+        self.connect_with_connection_string(&builder.build()?, options)
+    }
+
+    /// The diagnostic emitted for a connection string naming a driver which is not installed does
+    /// not tell the user which drivers would be available. Map it to
+    /// [`Error::DriverNotInstalled`] in that case.
+    fn context_for_missing_driver(&self, connection_string: &str, error: Error) -> Error {
+        let Error::Diagnostics { record, function } = error else {
+            return error;
+        };
+        let installed_drivers = match (
+            driver_from_connection_string(connection_string),
+            self.drivers(),
+        ) {
+            (Some(driver), Ok(drivers)) => {
+                let installed_drivers: Vec<_> =
+                    drivers.into_iter().map(|info| info.description).collect();
+                if installed_drivers
+                    .iter()
+                    .any(|installed| installed.eq_ignore_ascii_case(driver))
+                {
+                    None
+                } else {
+                    Some((driver, installed_drivers))
+                }
+            }
+            _ => None,
+        };
+        let Some((driver, installed_drivers)) = installed_drivers else {
+            return Error::Diagnostics { record, function };
+        };
+        let closest_match = closest_match(driver, &installed_drivers).map(str::to_owned);
+        Error::DriverNotInstalled {
+            driver: driver.to_owned(),
+            installed_drivers,
+            closest_match,
+            record: Box::new(record),
+        }
+    }
+
     /// Allocates a connection handle and establishes connections to a driver and a data source.
     ///
     /// An alternative to `connect` and `connect_with_connection_string`. This method can be
@@ -310,8 +401,9 @@ impl Environment {
     /// * `driver_completion`: Specifies how and if the driver manager uses a prompt to complete
     ///   the provided connection string. For arguments other than
     ///   [`crate::DriverCompleteOption::NoPrompt`] this method is going to create a message only
-    ///   parent window for you on windows. On other platform this method is going to panic. In case
-    ///   you want to provide your own parent window please use [`Self::driver_connect_with_hwnd`].
+    ///   parent window for you on windows. On other platforms this method returns
+    ///   [`Error::PromptNotSupported`]. In case you want to provide your own parent window please
+    ///   use [`Self::driver_connect_with_hwnd`].
     ///
     /// # Examples
     ///
@@ -415,7 +507,7 @@ impl Environment {
                 return window_app.result.unwrap();
             }
             #[cfg(not(target_os = "windows"))]
-            _ => panic!("Prompt is not supported for non-windows systems."),
+            _ => return Err(Error::PromptNotSupported),
         };
         let hwnd = null_mut();
         driver_connect(hwnd)
@@ -527,6 +619,46 @@ impl Environment {
         Ok(driver_info)
     }
 
+    /// Connection string keywords the driver asks for in order to connect, as reported by
+    /// `SQLBrowseConnect`. Only the first level of keywords is reported, i.e. the ones the driver
+    /// requests then given nothing but its name. Answering these (e.g. with a server name) might
+    /// reveal further keywords, like a database on that server.
+    ///
+    /// Not every driver supports browsing for connection attributes. In that case an error is
+    /// returned.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use odbc_api::Environment;
+    ///
+    /// let env = Environment::new()?;
+    /// let capabilities = env.driver_capabilities("ODBC Driver 18 for SQL Server")?;
+    /// println!("Required: {:?}", capabilities.required_keywords);
+    /// println!("Optional: {:?}", capabilities.optional_keywords);
+    ///
+    /// # Ok::<_, odbc_api::Error>(())
+    /// ```
+    pub fn driver_capabilities(&self, driver: &str) -> Result<DriverCapabilities, Error> {
+        let mut connection = self.allocate_connection()?;
+        let connection_string = format!("DRIVER={{{driver}}};");
+        let connection_string = SqlText::new(&connection_string);
+        let mut browse_result = vec![0; 4096];
+        let need_data = connection
+            .browse_connect(&connection_string, &mut browse_result)
+            .on_success(|| false)
+            .into_result_with(&connection, None, Some(true))?;
+        let capabilities = if need_data {
+            DriverCapabilities::from_browse_result(&slice_to_cow_utf8(&browse_result))
+        } else {
+            // The driver did not need anything besides its name to connect.
+            DriverCapabilities::default()
+        };
+        // Either terminates the browsing, or closes the connection just established.
+        connection.disconnect().into_result(&connection)?;
+        Ok(capabilities)
+    }
+
     /// User and system data sources
     ///
     /// # Example
@@ -581,6 +713,129 @@ impl Environment {
         self.data_sources_impl(FetchOrientation::FirstUser)
     }
 
+    /// Creates, modifies or removes a data source (DSN), using the installer API of the driver
+    /// manager (`SQLConfigDataSource`). Useful for installers, which need to set up a data source
+    /// for an application. Requires the `odbcinst` feature.
+    ///
+    /// # Parameters
+    ///
+    /// * `action`: Whether to add, modify or remove a user or system data source.
+    /// * `driver`: Name of the driver, as listed by [`Self::drivers`].
+    /// * `attributes`: Keyword value pairs describing the data source. Must at least contain
+    ///   `DSN`, the name of the data source. Other keywords are driver specific.
+    ///
+    /// Neither `driver` nor any of the `attributes` may contain a zero byte, otherwise
+    /// [`Error::InteriorNul`] is returned.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use odbc_api::{DataSourceAction, Environment};
+    ///
+    /// let env = Environment::new()?;
+    /// env.configure_data_source(
+    ///     DataSourceAction::Add,
+    ///     "SQLite3",
+    ///     &[("DSN", "Inventory"), ("Database", "inventory.db")],
+    /// )?;
+    ///
+    /// # Ok::<_, odbc_api::Error>(())
+    /// ```
+    #[cfg(feature = "odbcinst")]
+    pub fn configure_data_source(
+        &self,
+        action: DataSourceAction,
+        driver: &str,
+        attributes: &[(&str, &str)],
+    ) -> Result<(), Error> {
+        if let Some(argument) = find_interior_nul(driver, attributes) {
+            return Err(Error::InteriorNul {
+                argument: argument.to_owned(),
+            });
+        }
+        handles::config_data_source(action as u16, driver, attributes).map_err(|error| {
+            Error::Installer {
+                function: "SQLConfigDataSource",
+                code: error.code,
+                message: slice_to_utf8(&error.message).unwrap_or_default(),
+            }
+        })
+    }
+
+    /// Name and version of the ODBC driver manager this application is linked against. Useful to
+    /// include in bug reports, since the behavior of drivers may differ between driver managers.
+    ///
+    /// The version is obtained via `SQLGetInfo` on a scratch connection handle, which is allocated
+    /// for this purpose, but never connected to any data source. The name is not reported by the
+    /// driver manager, but determined by the platform and the features this crate has been
+    /// compiled with.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use odbc_api::Environment;
+    ///
+    /// let env = Environment::new()?;
+    /// let info = env.driver_manager_info()?;
+    /// println!("{} {}", info.name, info.version);
+    ///
+    /// # Ok::<_, odbc_api::Error>(())
+    /// ```
+    pub fn driver_manager_info(&self) -> Result<DriverManagerInfo, Error> {
+        let connection = self.allocate_connection()?;
+        let mut buf = Vec::new();
+        connection
+            .fetch_driver_manager_version(&mut buf)
+            .into_result(&connection)?;
+        let version = slice_to_utf8(&buf).unwrap();
+        Ok(DriverManagerInfo {
+            name: DRIVER_MANAGER_NAME,
+            version,
+        })
+    }
+
+    /// Commits the open transactions of all connections allocated on this environment, which are
+    /// in manual-commit mode. Many drivers do not support ending transactions on environment
+    /// level. In that case [`Error::UnsupportedEnvironmentTransaction`] is returned.
+    ///
+    /// Note that this is not a distributed transaction. The driver manager calls `SQLEndTran` on
+    /// each connection in turn, so a failure may leave some connections committed and others not.
+    pub fn commit_all(&self) -> Result<(), Error> {
+        let _lock = self.internal_state.lock().unwrap();
+        self.environment
+            .commit()
+            .into_result(&self.environment)
+            .provide_context_for_diagnostic(map_unsupported_environment_transaction)
+    }
+
+    /// Rolls back the open transactions of all connections allocated on this environment, which
+    /// are in manual-commit mode. Useful to reset state, e.g. during cleanup in tests. Many drivers
+    /// do not support ending transactions on environment level. In that case
+    /// [`Error::UnsupportedEnvironmentTransaction`] is returned.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use odbc_api::{Environment, Error};
+    ///
+    /// let env = Environment::new()?;
+    /// // ... open connections in manual-commit mode and do some work ...
+    /// match env.rollback_all() {
+    ///     Err(Error::UnsupportedEnvironmentTransaction(_)) => {
+    ///         // Roll back each connection individually instead.
+    ///     }
+    ///     other => other?,
+    /// }
+    /// # Ok::<_, odbc_api::Error>(())
+    /// ```
+    pub fn rollback_all(&self) -> Result<(), Error> {
+        let _lock = self.internal_state.lock().unwrap();
+        self.environment
+            .rollback()
+            .into_result(&self.environment)
+            .provide_context_for_diagnostic(map_unsupported_environment_transaction)
+    }
+
     fn data_sources_impl(&self, direction: FetchOrientation) -> Result<Vec<DataSourceInfo>, Error> {
         let mut data_source_info = Vec::new();
 
@@ -597,14 +852,14 @@ impl Environment {
             {
                 res
             } else {
-                // No drivers present
+                // No data sources present
                 return Ok(Vec::new());
             };
 
             // If there are let's loop over the rest
             while let Some((candidate_name_len, candidate_decs_len)) = self
                 .environment
-                .drivers_buffer_len(FetchOrientation::Next)
+                .data_source_buffer_len(FetchOrientation::Next)
                 .into_result_option(&self.environment)?
             {
                 server_name_len = max(candidate_name_len, server_name_len);
@@ -644,15 +899,76 @@ impl Environment {
     fn allocate_connection(&self) -> Result<handles::Connection, Error> {
         // Hold lock diagnostics errors are consumed in this thread.
         let _lock = self.internal_state.lock().unwrap();
-        self.environment
+        let connection = self
+            .environment
             .allocate_connection()
-            .into_result(&self.environment)
+            .into_result(&self.environment)?;
+        self.any_connection_allocated.store(true, Ordering::SeqCst);
+        Ok(connection)
+    }
+}
+
+/// Driver managers and drivers which do not support `SQLEndTran` on environment level report
+/// either "optional feature not implemented" or "driver does not support this function".
+fn map_unsupported_environment_transaction(
+    record: handles::Record,
+    function: &'static str,
+) -> Error {
+    const DRIVER_DOES_NOT_SUPPORT_FUNCTION: State = State(*b"IM001");
+    match record.state {
+        State::OPTIONAL_FEATURE_NOT_IMPLEMENTED | DRIVER_DOES_NOT_SUPPORT_FUNCTION => {
+            Error::UnsupportedEnvironmentTransaction(record)
+        }
+        _ => Error::Diagnostics { record, function },
+    }
+}
+
+/// Maps setup errors to the variants [`Environment::new`] has always been returning.
+impl From<EnvironmentSetupError> for Error {
+    fn from(error: EnvironmentSetupError) -> Self {
+        // Status code S1009 has been seen with unixODBC 2.3.1. S1009 meant (among other things)
+        // invalid attribute. If we see this then we try to declare the ODBC version it is of course
+        // likely that the driver manager only knows ODBC 2.x.
+        // See: <https://learn.microsoft.com/sql/odbc/reference/develop-app/sqlstate-mappings>
+        const ODBC_2_INVALID_ATTRIBUTE: State = State(*b"S1009");
+
+        let EnvironmentSetupError { function, record } = error;
+        let Some(record) = record else {
+            // Without a handle there are no diagnostics. A failure declaring the version on the
+            // other hand should have produced some.
+            return if function == "SQLAllocHandle" {
+                Error::FailedAllocatingEnvironment
+            } else {
+                Error::NoDiagnostics { function }
+            };
+        };
+        // Translate invalid attribute into a more meaningful error, provided the additional
+        // context that we know we tried to set version number.
+        match record.state {
+            // INVALID_STATE_TRANSACTION has been seen with some really old version of unixODBC on
+            // a CentOS used to build manylinux wheels, with the preinstalled ODBC version.
+            // INVALID_ATTRIBUTE_VALUE is the correct status code to emit for a driver manager if it
+            // does not know the version and has been seen with an unknown version of unixODBC on an
+            // Oracle Linux.
+            ODBC_2_INVALID_ATTRIBUTE
+            | State::INVALID_STATE_TRANSACTION
+            | State::INVALID_ATTRIBUTE_VALUE => Error::UnsupportedOdbcApiVersion(record),
+            _ => Error::Diagnostics { record, function },
+        }
+    }
+}
+
+impl Drop for Environment {
+    fn drop(&mut self) {
+        NUM_ENVIRONMENTS.fetch_sub(1, Ordering::SeqCst);
     }
 }
 
 /// An ODBC [`Environment`] with static lifetime. This function always returns a reference to the
 /// same instance. The environment is constructed then the function is called for the first time.
-/// Every time after the initial construction this function must succeed.
+/// Every time after the initial construction this function must succeed. Concurrent calls from
+/// several threads during the initial construction are safe and result in only one environment
+/// being created.
 ///
 /// Useful if your application uses ODBC for the entirety of its lifetime, since using a static
 /// lifetime means there is one less lifetime you and the borrow checker need to worry about. If
@@ -661,15 +977,22 @@ impl Environment {
 /// the application, it is recommended to only have one [`Environment`] per process.
 pub fn environment() -> Result<&'static Environment, Error> {
     static ENV: OnceLock<Environment> = OnceLock::new();
+    // Serializes the initialization, so two threads racing to initialize the environment do not
+    // end up creating two environments.
+    static INIT: Mutex<()> = Mutex::new(());
     if let Some(env) = ENV.get() {
         // Environment already initialized, nothing to do, but to return it.
-        Ok(env)
-    } else {
-        // ODBC Environment not initialized yet. Let's do so and return it.
-        let env = Environment::new()?;
-        let env = ENV.get_or_init(|| env);
-        Ok(env)
+        return Ok(env);
     }
+    let _lock = INIT.lock().unwrap();
+    if let Some(env) = ENV.get() {
+        // Another thread initialized the environment, while we waited for the lock.
+        return Ok(env);
+    }
+    // ODBC Environment not initialized yet. Let's do so and return it.
+    let env = Environment::new()?;
+    let env = ENV.get_or_init(|| env);
+    Ok(env)
 }
 
 /// Struct holding information available on a driver. Can be obtained via [`Environment::drivers`].
@@ -681,6 +1004,81 @@ pub struct DriverInfo {
     pub attributes: HashMap<String, String>,
 }
 
+/// Connection string keywords of a driver. Can be obtained via
+/// [`Environment::driver_capabilities`].
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct DriverCapabilities {
+    /// Keywords which must be specified in the connection string, e.g. `SERVER`.
+    pub required_keywords: Vec<String>,
+    /// Keywords which may be specified in the connection string, e.g. `DATABASE`.
+    pub optional_keywords: Vec<String>,
+}
+
+impl DriverCapabilities {
+    /// Parses the connection string returned by `SQLBrowseConnect`. It consists of attributes like
+    /// `KEYWORD:Prompt=?;`, or `KEYWORD:Prompt={choice1,choice2};` if the driver knows the valid
+    /// values. Following the ODBC convention keywords prefixed with `*` are optional, all others
+    /// are required.
+    pub fn from_browse_result(browse_result: &str) -> Self {
+        let mut capabilities = DriverCapabilities::default();
+        for attribute in split_browse_result(browse_result) {
+            let (optional, attribute) = match attribute.strip_prefix('*') {
+                Some(attribute) => (true, attribute),
+                None => (false, attribute),
+            };
+            let keyword = attribute
+                .split([':', '='])
+                .next()
+                .unwrap_or_default()
+                .trim();
+            if keyword.is_empty() {
+                continue;
+            }
+            if optional {
+                capabilities.optional_keywords.push(keyword.to_owned());
+            } else {
+                capabilities.required_keywords.push(keyword.to_owned());
+            }
+        }
+        capabilities
+    }
+}
+
+/// Splits a browse result connection string into its attributes. Semicolons enclosed in curly
+/// braces, like they may appear in a list of choices, are not treated as separators.
+fn split_browse_result(browse_result: &str) -> impl Iterator<Item = &str> {
+    let mut depth = 0;
+    browse_result
+        .split(move |c| {
+            match c {
+                '{' => depth += 1,
+                '}' => depth -= 1,
+                _ => (),
+            }
+            c == ';' && depth == 0
+        })
+        .map(str::trim)
+        .filter(|attribute| !attribute.is_empty())
+}
+
+/// Name of the driver manager this crate links against.
+#[cfg(target_os = "windows")]
+const DRIVER_MANAGER_NAME: &str = "Windows ODBC Driver Manager";
+#[cfg(all(not(target_os = "windows"), feature = "iodbc"))]
+const DRIVER_MANAGER_NAME: &str = "iODBC";
+#[cfg(all(not(target_os = "windows"), not(feature = "iodbc")))]
+const DRIVER_MANAGER_NAME: &str = "unixODBC";
+
+/// Name and version of the ODBC driver manager. Can be obtained via
+/// [`Environment::driver_manager_info`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DriverManagerInfo {
+    /// Name of the driver manager, e.g. `unixODBC`.
+    pub name: &'static str,
+    /// Version as reported by the driver manager, e.g. `03.52.0016.0000`.
+    pub version: String,
+}
+
 /// Holds name and description of a datasource
 ///
 /// Can be obtained via [`Environment::data_sources`]
@@ -692,6 +1090,24 @@ pub struct DataSourceInfo {
     pub driver: String,
 }
 
+/// Request passed to [`Environment::configure_data_source`]. Each variant corresponds to one of the
+/// `fRequest` values of `SQLConfigDataSource`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DataSourceAction {
+    /// Adds a new user data source (`ODBC_ADD_DSN`).
+    Add = 1,
+    /// Modifies an existing user data source (`ODBC_CONFIG_DSN`).
+    Configure = 2,
+    /// Removes an existing user data source (`ODBC_REMOVE_DSN`).
+    Remove = 3,
+    /// Adds a new system data source (`ODBC_ADD_SYS_DSN`).
+    AddSystem = 4,
+    /// Modifies an existing system data source (`ODBC_CONFIG_SYS_DSN`).
+    ConfigureSystem = 5,
+    /// Removes an existing system data source (`ODBC_REMOVE_SYS_DSN`).
+    RemoveSystem = 6,
+}
+
 /// Message loop for prompt dialog. Used by [`Environment::driver_connect`].
 #[cfg(target_os = "windows")]
 struct MessageOnlyWindowEventHandler<'a, F> {
@@ -740,11 +1156,275 @@ fn attributes_iter(attributes: &str) -> impl Iterator<Item = (String, String)> +
         })
 }
 
+/// Name of the driver specified in a connection string, without curly braces. `None` if no driver
+/// is specified, or if the driver is specified as a path to a library, rather than by its name.
+fn driver_from_connection_string(connection_string: &str) -> Option<&str> {
+    let driver = connection_string.split(';').find_map(|attribute| {
+        let (key, value) = attribute.split_once('=')?;
+        key.trim()
+            .eq_ignore_ascii_case("driver")
+            .then_some(value.trim())
+    })?;
+    let driver = driver
+        .strip_prefix('{')
+        .and_then(|driver| driver.strip_suffix('}'))
+        .unwrap_or(driver);
+    if driver.is_empty() || driver.contains(['/', '\\']) {
+        None
+    } else {
+        Some(driver)
+    }
+}
+
+/// The first of the arguments to `SQLConfigDataSource` which contains a zero byte, if any.
+#[cfg(feature = "odbcinst")]
+fn find_interior_nul<'a>(driver: &'a str, attributes: &[(&'a str, &'a str)]) -> Option<&'a str> {
+    attributes
+        .iter()
+        .flat_map(|&(keyword, value)| [keyword, value])
+        .chain(std::iter::once(driver))
+        .find(|argument| argument.contains('\0'))
+}
+
+/// Unwraps the environment handle allocated by [`Environment::try_new`]. Any result other than
+/// success is reported as a failure of `SQLAllocHandle`. Diagnostics can not be fetched in that
+/// case, since there is no handle to fetch them from.
+fn allocated_environment(
+    result: SqlResult<handles::Environment>,
+) -> Result<handles::Environment, EnvironmentSetupError> {
+    match result {
+        SqlResult::Success(env) => Ok(env),
+        SqlResult::SuccessWithInfo(env) => {
+            log_diagnostics(&env);
+            Ok(env)
+        }
+        other => {
+            debug!("Allocating ODBC environment returned '{other:?}'");
+            Err(EnvironmentSetupError {
+                function: "SQLAllocHandle",
+                record: None,
+            })
+        }
+    }
+}
+
+/// Maps a failure to declare the ODBC version in [`Environment::try_new`].
+fn version_declaration_error(error: Error) -> EnvironmentSetupError {
+    match error {
+        Error::Diagnostics { record, function } => EnvironmentSetupError {
+            function,
+            record: Some(record),
+        },
+        _ => EnvironmentSetupError {
+            function: "SQLSetEnvAttr",
+            record: None,
+        },
+    }
+}
+
+/// The candidate most similar to `name`, as measured by the edit distance. `None` if no candidate
+/// is similar enough to be a plausible misspelling.
+fn closest_match<'a>(name: &str, candidates: &'a [String]) -> Option<&'a str> {
+    let name = name.to_lowercase();
+    candidates
+        .iter()
+        .map(|candidate| (edit_distance(&name, &candidate.to_lowercase()), candidate))
+        .filter(|(distance, _)| *distance <= name.chars().count() / 2)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate.as_str())
+}
+
+/// Levenshtein distance between two strings, counted in characters.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1; b.len() + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != *cb);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
 #[cfg(test)]
 mod tests {
 
     use super::*;
 
+    #[test]
+    fn setup_error_reports_failed_function_and_diagnostics() {
+        let record = handles::Record {
+            state: State(*b"HY024"),
+            native_error: 0,
+            message: handles::to_vec_sql_char("Invalid attribute value"),
+        };
+        let error = EnvironmentSetupError {
+            function: "SQLSetEnvAttr",
+            record: Some(record),
+        };
+
+        let message = error.to_string();
+
+        assert!(message.contains("'SQLSetEnvAttr'"));
+        assert!(message.contains("HY024"));
+        assert!(message.contains("Invalid attribute value"));
+        assert!(matches!(
+            Error::from(error),
+            Error::UnsupportedOdbcApiVersion(record) if record.state == State(*b"HY024")
+        ));
+    }
+
+    #[test]
+    fn failed_allocation_maps_to_failed_allocating_environment() {
+        let error = EnvironmentSetupError {
+            function: "SQLAllocHandle",
+            record: None,
+        };
+
+        assert_eq!(
+            "Failed to set up the ODBC environment calling 'SQLAllocHandle'.",
+            error.to_string()
+        );
+        assert!(matches!(
+            Error::from(error),
+            Error::FailedAllocatingEnvironment
+        ));
+    }
+
+    #[test]
+    fn failed_version_declaration_without_diagnostics_names_set_env_attr() {
+        let error = EnvironmentSetupError {
+            function: "SQLSetEnvAttr",
+            record: None,
+        };
+
+        assert!(matches!(
+            Error::from(error),
+            Error::NoDiagnostics {
+                function: "SQLSetEnvAttr"
+            }
+        ));
+    }
+
+    #[cfg(feature = "odbcinst")]
+    #[test]
+    fn find_zero_bytes_in_data_source_configuration() {
+        assert_eq!(None, find_interior_nul("SQLite3", &[("DSN", "Inventory")]));
+        assert_eq!(
+            Some("SQLite3\0"),
+            find_interior_nul("SQLite3\0", &[("DSN", "Inventory")])
+        );
+        assert_eq!(
+            Some("Inventory\0Database=other.db"),
+            find_interior_nul("SQLite3", &[("DSN", "Inventory\0Database=other.db")])
+        );
+    }
+
+    #[test]
+    fn failing_to_allocate_environment_is_a_setup_error() {
+        for result in [
+            SqlResult::Error {
+                function: "SQLAllocHandle",
+            },
+            SqlResult::NoData,
+        ] {
+            let error = allocated_environment(result).unwrap_err();
+
+            assert_eq!("SQLAllocHandle", error.function);
+            assert!(error.record.is_none());
+        }
+    }
+
+    #[test]
+    fn failing_to_declare_version_is_a_setup_error() {
+        let record = handles::Record {
+            state: State(*b"HY092"),
+            native_error: 0,
+            message: handles::to_vec_sql_char("Invalid attribute identifier"),
+        };
+        let error = Error::Diagnostics {
+            record,
+            function: "SQLSetEnvAttr",
+        };
+
+        let error = version_declaration_error(error);
+
+        assert_eq!("SQLSetEnvAttr", error.function);
+        assert_eq!(State(*b"HY092"), error.record.unwrap().state);
+        let error = version_declaration_error(Error::NoDiagnostics {
+            function: "SQLSetEnvAttr",
+        });
+        assert_eq!("SQLSetEnvAttr", error.function);
+        assert!(error.record.is_none());
+    }
+
+    #[test]
+    fn try_new_sets_up_environment() {
+        let env = Environment::try_new().unwrap();
+
+        // The ODBC version has been declared, so the environment is ready to allocate connections.
+        env.allocate_connection().unwrap();
+    }
+
+    #[test]
+    fn parse_driver_from_connection_string() {
+        assert_eq!(
+            Some("ODBC Driver 18 for SQL Server"),
+            driver_from_connection_string(
+                "Driver={ODBC Driver 18 for SQL Server};Server=localhost;"
+            )
+        );
+        assert_eq!(
+            Some("SQLite3"),
+            driver_from_connection_string("Database=test.db; DRIVER = SQLite3")
+        );
+        assert_eq!(None, driver_from_connection_string("DSN=MyDataSource;"));
+        assert_eq!(
+            None,
+            driver_from_connection_string("Driver=/usr/lib/libsqlite3odbc.so;")
+        );
+    }
+
+    #[test]
+    fn suggest_closest_driver() {
+        let installed = [
+            "ODBC Driver 18 for SQL Server".to_owned(),
+            "SQLite3".to_owned(),
+        ];
+        assert_eq!(
+            Some("ODBC Driver 18 for SQL Server"),
+            closest_match("ODBC Driver 18 for SQL Servr", &installed)
+        );
+        assert_eq!(Some("SQLite3"), closest_match("sqlite", &installed));
+        assert_eq!(None, closest_match("PostgreSQL UNICODE", &installed));
+    }
+
+    #[test]
+    fn parse_browse_result_of_sql_server_driver() {
+        let browse_result = "SERVER:Server={(local),MYSERVER};UID:Login ID=?;PWD:Password=?;\
+                             *APP:AppName=?;*WSID:WorkStation ID=?;*DATABASE:Database=?";
+        let capabilities = DriverCapabilities::from_browse_result(browse_result);
+        assert_eq!(
+            vec!["SERVER".to_owned(), "UID".to_owned(), "PWD".to_owned()],
+            capabilities.required_keywords
+        );
+        assert_eq!(
+            vec!["APP".to_owned(), "WSID".to_owned(), "DATABASE".to_owned()],
+            capabilities.optional_keywords
+        );
+    }
+
+    #[test]
+    fn separators_within_choices_do_not_split_browse_result() {
+        let capabilities =
+            DriverCapabilities::from_browse_result("*Encrypt:Encrypt={yes;no};SERVER:Server=?;");
+        assert_eq!(vec!["SERVER".to_owned()], capabilities.required_keywords);
+        assert_eq!(vec!["Encrypt".to_owned()], capabilities.optional_keywords);
+    }
+
     #[test]
     fn parse_attributes() {
         let buffer = "APILevel=2\0ConnectFunctions=YYY\0CPTimeout=60\0DriverODBCVer=03.\
@@ -761,11 +1441,42 @@ mod tests {
 
     #[cfg(not(target_os = "windows"))]
     #[test]
-    #[should_panic(expected = "Prompt is not supported for non-windows systems.")]
-    fn driver_connect_with_prompt_panics_under_linux() {
+    fn driver_connect_with_prompt_errors_under_linux() {
         let env = Environment::new().unwrap();
-        let mut out = OutputStringBuffer::empty();
-        env.driver_connect("", &mut out, DriverCompleteOption::Prompt)
-            .unwrap();
+        for option in [
+            DriverCompleteOption::Prompt,
+            DriverCompleteOption::Complete,
+            DriverCompleteOption::CompleteRequired,
+        ] {
+            let mut out = OutputStringBuffer::empty();
+            let result = env.driver_connect("", &mut out, option);
+            assert!(matches!(result, Err(Error::PromptNotSupported)));
+        }
+    }
+
+    #[test]
+    fn setting_connection_pooling_after_environment_allocation_fails() {
+        let _env = Environment::new().unwrap();
+
+        let result =
+            unsafe { Environment::set_connection_pooling(odbc_sys::AttrConnectionPooling::Off) };
+
+        assert!(matches!(
+            result,
+            Err(Error::ConnectionPoolingAfterEnvironment)
+        ));
+    }
+
+    #[test]
+    fn setting_connection_pooling_matching_after_connection_allocation_fails() {
+        let mut env = Environment::new().unwrap();
+        env.allocate_connection().unwrap();
+
+        let result = env.set_connection_pooling_matching(AttrCpMatch::Relaxed);
+
+        assert!(matches!(
+            result,
+            Err(Error::ConnectionPoolingMatchingAfterConnect)
+        ));
     }
 }
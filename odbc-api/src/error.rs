@@ -2,7 +2,10 @@ use std::io;
 
 use thiserror::Error as ThisError;
 
-use crate::handles::{log_diagnostics, Diagnostics, Record as DiagnosticRecord, SqlResult};
+use crate::{
+    handles::{log_diagnostics, Diagnostics, Record as DiagnosticRecord, SqlResult},
+    DataType, Dbms,
+};
 
 /// Error indicating a failed allocation for a column buffer
 #[derive(Debug)]
@@ -25,6 +28,23 @@ impl TooLargeBufferSize {
     }
 }
 
+/// Error indicating the ODBC environment could not be set up. Returned by
+/// [`crate::Environment::try_new`]. Tells apart the individual steps of the setup, so the cause is
+/// easier to diagnose.
+#[derive(Debug, ThisError)]
+#[error(
+    "Failed to set up the ODBC environment calling '{function}'.{}",
+    .record.as_ref().map(|record| format!(" Diagnostic record:\n{record}")).unwrap_or_default()
+)]
+pub struct EnvironmentSetupError {
+    /// The ODBC function which failed. Either `SQLAllocHandle`, if the environment handle could not
+    /// be allocated, or `SQLSetEnvAttr`, if the driver manager rejected the declared ODBC version.
+    pub function: &'static str,
+    /// Diagnostic record emitted by the driver manager. Always `None` if allocating the
+    /// environment handle failed, since diagnostics would be retrieved using that very handle.
+    pub record: Option<DiagnosticRecord>,
+}
+
 #[cfg(feature = "odbc_version_3_5")]
 const ODBC_VERSION_STRING: &str = "3.5";
 #[cfg(not(feature = "odbc_version_3_5"))]
@@ -37,6 +57,20 @@ pub enum Error {
     /// [`crate::Environment::set_connection_pooling`].
     #[error("Failed to set connection pooling.")]
     FailedSettingConnectionPooling,
+    /// The connection pooling scheme must be set before the environment is allocated. Emitted by
+    /// [`crate::Environment::set_connection_pooling`].
+    #[error(
+        "Connection pooling must be configured before the environment is allocated. Call \
+        `Environment::set_connection_pooling` before creating the environment."
+    )]
+    ConnectionPoolingAfterEnvironment,
+    /// The pooling match criteria must be set before the first connection is allocated from the
+    /// environment. Emitted by [`crate::Environment::set_connection_pooling_matching`].
+    #[error(
+        "Connection pooling match criteria must be configured before the first connection is \
+        allocated from the environment."
+    )]
+    ConnectionPoolingMatchingAfterConnect,
     /// Allocating the environment itself fails. Further diagnostics are not available, as they
     /// would be retrieved using the envirorment handle. Exclusively emitted by
     /// [`crate::Environment::new`].
@@ -63,9 +97,25 @@ pub enum Error {
         /// ODBC API call which produced the diagnostic record
         function: &'static str,
     },
+    /// Returned by [`crate::ConnectionStringBuilder::parse`] if the connection string is malformed,
+    /// e.g. because an attribute lacks a `=`, or a value enclosed in curly braces is not
+    /// terminated.
+    #[error("Malformed connection string. Parsing failed at byte position {position}.")]
+    InvalidConnectionString {
+        /// Zero based byte position in the connection string at which parsing failed.
+        position: usize,
+    },
     /// A user dialog to complete the connection string has been aborted.
     #[error("The dialog shown to provide or complete the connection string has been aborted.")]
     AbortedConnectionStringCompletion,
+    /// Emitted by [`crate::Environment::driver_connect`] on platforms other than windows, if any
+    /// [`crate::DriverCompleteOption`] other than `NoPrompt` is requested. Showing a dialog to
+    /// complete the connection string is only supported by the windows driver manager.
+    #[error(
+        "Prompting the user to complete the connection string is only supported on windows. Use \
+        `DriverCompleteOption::NoPrompt` and provide a complete connection string instead."
+    )]
+    PromptNotSupported,
     /// An error returned if we fail to set the ODBC version
     #[error(
         "The ODBC diver manager installed in your system does not seem to support ODBC API version \
@@ -74,9 +124,10 @@ pub enum Error {
         Linux. Diagnostic record returned by SQLSetEnvAttr:\n{0}"
     )]
     UnsupportedOdbcApiVersion(DiagnosticRecord),
-    /// An error emitted by an `std::io::ReadBuf` implementation used as an input argument.
+    /// An error emitted by an `std::io::ReadBuf` implementation used as an input argument. The IO
+    /// error is also available as [`std::error::Error::source`].
     #[error("Sending data to the database at statement execution time failed. IO error:\n{0}")]
-    FailedReadingInput(io::Error),
+    FailedReadingInput(#[source] io::Error),
     /// Driver returned "invalid attribute" then setting the row array size. Most likely the array
     /// size is too large. Instead of returing "option value changed (SQLSTATE 01S02)" as suggested
     /// in <https://docs.microsoft.com/en-us/sql/odbc/reference/syntax/sqlsetstmtattr-function> the
@@ -134,6 +185,305 @@ pub enum Error {
         /// Index of the buffer in which the truncation occurred.
         buffer_index: usize,
     },
+    /// Text fetched from the data source could not be decoded. Emitted by
+    /// [`crate::CursorRow::append_text`].
+    #[error(
+        "Text fetched from column {col_or_param_num} is not valid UTF-8 (or UTF-16 if wide \
+        character encoding is used)."
+    )]
+    InvalidTextEncoding {
+        /// One based index of the column the text has been fetched from.
+        col_or_param_num: u16,
+    },
+    /// The application expected the statement to not produce a result set, yet it did. Emitted by
+    /// [`crate::ExpectCursor::expect_no_cursor`].
+    #[error(
+        "The executed statement produced a result set, yet the application expected it not to. \
+        Maybe a query has been passed, where a statement like INSERT, UPDATE or DELETE has been \
+        expected."
+    )]
+    UnexpectedResultSet,
+    /// The application expected the statement to produce a result set, yet it did not. Emitted by
+    /// [`crate::ExpectCursor::expect_cursor`].
+    #[error(
+        "The executed statement did not produce a result set, yet the application expected one. \
+        Maybe a statement like INSERT, UPDATE or DELETE has been passed, where a query has been \
+        expected."
+    )]
+    ExpectedResultSet,
+    /// Ending transactions for all connections of an environment at once is not supported by at
+    /// least one of the drivers involved. Emitted by [`crate::Environment::commit_all`] and
+    /// [`crate::Environment::rollback_all`].
+    #[error(
+        "Ending transactions on environment level is not supported. Commit or roll back the \
+        transactions of each connection individually instead. Diagnostic record returned by \
+        SQLEndTran:\n{0}"
+    )]
+    UnsupportedEnvironmentTransaction(DiagnosticRecord),
+    /// A buffer index has been used to access a column of a buffer, which does not have that many
+    /// columns. Emitted by [`crate::ColumnarBulkInserter::try_column_mut`].
+    #[error(
+        "Buffer index {buffer_index} is out of range. There are only {num_buffers} buffers bound. \
+        Note that buffer indices are zero based."
+    )]
+    BufferIndexOutOfRange {
+        /// Zero based index which has been requested.
+        buffer_index: usize,
+        /// Number of buffers. Valid indices are smaller than this.
+        num_buffers: usize,
+    },
+    /// Connecting failed and the driver named in the connection string is not among the drivers
+    /// installed on the system. Emitted by [`crate::Environment::connect_with_connection_string`].
+    #[error(
+        "Failed to connect. The ODBC driver '{driver}' specified in the connection string is not \
+        installed. {}Installed drivers: {}.\nDiagnostic record returned by SQLDriverConnect:\n\
+        {record}",
+        did_you_mean(.closest_match),
+        .installed_drivers.join(", ")
+    )]
+    DriverNotInstalled {
+        /// Name of the driver as specified in the connection string.
+        driver: String,
+        /// Names of all the drivers installed on the system.
+        installed_drivers: Vec<String>,
+        /// Installed driver with the name most similar to `driver`, if any is similar enough.
+        closest_match: Option<String>,
+        /// Diagnostic record returned by the failed connection attempt.
+        record: Box<DiagnosticRecord>,
+    },
+    /// The number of placeholders (`?`) in a query does not match the number of parameters
+    /// passed alongside it. Emitted by [`crate::Connection::execute_checked`] and
+    /// [`crate::Connection::execute_reusing_plan`].
+    #[error(
+        "The query contains {expected} placeholders, but {provided} parameters have been provided."
+    )]
+    ParameterCountMismatch {
+        /// Number of placeholders found in the query text.
+        expected: usize,
+        /// Number of parameters passed alongside the query.
+        provided: usize,
+    },
+    /// The next result set has columns which require other buffers than the ones of the previous
+    /// result set, so the buffer bound to the previous one could not be reused. Emitted by
+    /// [`crate::BlockCursor::next_result_set`].
+    #[error(
+        "The column types of the next result set are not compatible with the ones of the previous \
+        result set. Previous: {expected:?}, Next: {actual:?}"
+    )]
+    ResultSetSchemaMismatch {
+        /// Column types of the previous result set.
+        expected: Vec<DataType>,
+        /// Column types of the next result set.
+        actual: Vec<DataType>,
+    },
+    /// Executing a statement failed. In addition to the underlying error, this carries the SQL
+    /// text of the statement, to tell which of many statements failed. Emitted by
+    /// [`crate::Connection::execute_with_sql_context`].
+    #[error("Failed to execute '{sql}':\n{source}")]
+    ExecutionFailed {
+        /// SQL text of the failed statement. Long statements are truncated. Parameter values are
+        /// never included.
+        sql: String,
+        /// The error which caused the execution to fail.
+        source: Box<Error>,
+    },
+    /// The result set contains more than one row, yet the application expected at most one.
+    /// Emitted by [`crate::Cursor::fetch_one`].
+    #[error(
+        "The result set contains more than one row, yet the application expected at most one."
+    )]
+    MoreThanOneRow,
+    /// The installer API of the driver manager reported an error. Emitted by
+    /// [`crate::Environment::configure_data_source`].
+    #[error(
+        "The ODBC installer API emitted an error calling '{function}' (code {code}): {message}"
+    )]
+    Installer {
+        /// Installer API call which failed.
+        function: &'static str,
+        /// Installer error code, e.g. `ODBC_ERROR_INVALID_KEYWORD_VALUE` (`8`).
+        code: u32,
+        /// Error message as reported by the installer.
+        message: String,
+    },
+    /// The number of column descriptions passed by the application does not match the number of
+    /// columns in the result set. Emitted by [`crate::buffers::TextRowSet::for_cursor_with_desc`].
+    #[error(
+        "The result set has {num_columns} columns, but {num_descriptions} column descriptions \
+        have been provided."
+    )]
+    ColumnDescriptionCountMismatch {
+        /// Number of columns in the result set.
+        num_columns: usize,
+        /// Number of column descriptions passed by the application.
+        num_descriptions: usize,
+    },
+    /// The query timeout period expired before the data source completed the execution of the
+    /// statement. Emitted by e.g. [`crate::Connection::execute_with_timeout`].
+    #[error(
+        "The query timeout expired before the data source finished executing the statement. \
+        Diagnostic record returned:\n{0}"
+    )]
+    QueryTimeout(DiagnosticRecord),
+    /// Rows can only be modified through a cursor, whose concurrency allows it. Emitted by
+    /// [`crate::BlockCursor::into_updatable`].
+    #[error(
+        "The cursor is read-only. Execute the statement with a concurrency other than read-only \
+        (see `StatementOptions::concurrency`) in order to update or delete rows through it."
+    )]
+    ReadOnlyCursor,
+    /// A row could not be deserialized into the type requested by the application, e.g. because a
+    /// column is `NULL` but the corresponding field is not an `Option`, or a value is out of range
+    /// for the type of its field. Emitted by `CursorRow::deserialize`.
+    #[error("Failed to deserialize row. {0}")]
+    Deserialization(String),
+    /// The type a row is deserialized into has a different number of fields than the result set
+    /// has columns. Emitted by `CursorRow::deserialize`.
+    #[error(
+        "The result set has {num_columns} columns, but the type rows are deserialized into has \
+        {num_fields} fields."
+    )]
+    FieldCountMismatch {
+        /// Number of columns in the result set.
+        num_columns: usize,
+        /// Number of fields of the type rows are deserialized into.
+        num_fields: usize,
+    },
+    /// The database management system can only report the last generated id of an inserted row,
+    /// but not the values of arbitrary columns. Emitted by [`crate::Connection::insert_returning`].
+    #[error(
+        "{dbms:?} can not return the values of specific columns of an inserted row. Pass an empty \
+        list of returning columns in order to retrieve the last generated id instead."
+    )]
+    ReturningColumnsNotSupported {
+        /// Database management system the connection is to.
+        dbms: Dbms,
+    },
+    /// Values of [`crate::buffers::BufferDesc::LongText`] and
+    /// [`crate::buffers::BufferDesc::LongBinary`] columns which do not fit into the bound chunk are
+    /// retrieved with `SQLGetData` after the fetch. This requires the driver to support `SQLGetData`
+    /// for bound columns and block cursors (`SQL_GD_BOUND` and `SQL_GD_BLOCK` in
+    /// `SQL_GETDATA_EXTENSIONS`). Emitted by [`crate::BlockCursor::fetch`].
+    #[error(
+        "The driver does not support retrieving values of bound columns within a block cursor \
+        using `SQLGetData` (SQL_GD_BOUND and SQL_GD_BLOCK). Long text or binary columns require \
+        this in order to fetch values larger than the bound chunk. Bind the column with a buffer \
+        large enough for all values instead, or fetch one row at a time. Diagnostic record \
+        returned by {function}:\n{record}"
+    )]
+    GetDataExtensionsNotSupported {
+        /// Diagnostic record returned by the driver.
+        record: DiagnosticRecord,
+        /// ODBC API call which produced the diagnostic record.
+        function: &'static str,
+    },
+    /// Keys of connection string attributes can not be escaped, so they must not contain `=`, `;`,
+    /// `{` or `}`. Emitted by [`crate::ConnectionStringBuilder::build`].
+    #[error(
+        "The connection string attribute key '{key}' contains a character which is not allowed in \
+        keys ('=', ';', '{{' or '}}')."
+    )]
+    InvalidConnectionStringKey {
+        /// The rejected key.
+        key: String,
+    },
+    /// Drivers write values of fixed size C types into buffers bound as `SQL_C_DEFAULT`,
+    /// regardless of the buffer length. So a [`crate::buffers::BufferDesc::Default`] buffer must
+    /// be large enough for the default C type of its column, and the default C type must be known.
+    /// Emitted by [`crate::Cursor::bind_buffer`].
+    #[error(
+        "Column {column_number} can not be bound as SQL_C_DEFAULT to a buffer with {length} bytes \
+        per value. {}",
+        default_c_type_requirement(.required)
+    )]
+    DefaultCTypeBufferTooSmall {
+        /// One based index of the column.
+        column_number: u16,
+        /// Bytes per value of the buffer.
+        length: usize,
+        /// Bytes per value required by the default C type of the column. `None` if the default C
+        /// type is not known, e.g. because the column has a driver specific SQL type.
+        required: Option<usize>,
+    },
+    /// Buffers which retrieve values using `SQLGetData` after each fetch can not be bound to a
+    /// cursor in asynchronous polling mode, since these calls would not be polled. Emitted by
+    /// [`crate::CursorPolling::bind_buffer`].
+    #[error(
+        "The buffer retrieves values after each fetch using `SQLGetData`, e.g. because it has \
+        long text or binary columns. It can not be bound to a cursor in asynchronous polling mode. \
+        Bind columns with a fixed maximum length instead."
+    )]
+    CompletingRowSetWhilePolling,
+    /// The database management system returns values of an inserted row only for the columns
+    /// explicitly listed. Emitted by [`crate::Connection::insert_returning`].
+    #[error(
+        "{dbms:?} requires at least one returning column in order to return values of an \
+        inserted row."
+    )]
+    ReturningColumnsRequired {
+        /// Database management system the connection is to.
+        dbms: Dbms,
+    },
+    /// ODBC represents years as 16 Bit signed integers, so dates before the year `-32768` or after
+    /// `32767` can not be converted. Emitted by the conversions from `chrono` types, e.g. into
+    /// `odbc_api::chrono::ChronoDate`.
+    #[error("The year {year} can not be represented by ODBC date or timestamp structs.")]
+    YearOutOfRange {
+        /// Year of the rejected date.
+        year: i32,
+    },
+    /// Parameter positions are one based and must fit into a 16 Bit unsigned integer. Emitted by
+    /// binding a `HashMap<usize, _>` of parameters with a key outside of this range.
+    #[error(
+        "Invalid parameter position {position}. Positions are one based and must not exceed \
+        {max}.",
+        max = u16::MAX
+    )]
+    InvalidParameterPosition {
+        /// Key of the parameter map which is not a valid position.
+        position: usize,
+    },
+    /// Text fetched from the data source is not valid in the encoding specified by the
+    /// application. Emitted by `CursorRow::get_text_with_encoding`.
+    #[error("Text fetched from column {col_or_param_num} is not valid {encoding}.")]
+    UndecodableText {
+        /// One based index of the column the text has been fetched from.
+        col_or_param_num: u16,
+        /// Name of the encoding used to decode the text, e.g. `windows-1252`.
+        encoding: &'static str,
+    },
+    /// An argument passed to the installer API contains a zero byte. The installer API reads its
+    /// arguments up to the first zero, so the argument would be silently truncated. Emitted by
+    /// [`crate::Environment::configure_data_source`].
+    #[error("Argument passed to the ODBC installer API contains a zero byte: {argument:?}")]
+    InteriorNul {
+        /// The rejected driver name, keyword or value.
+        argument: String,
+    },
+    /// Appending rows to a bulk inserter failed. In addition to the underlying error, this carries
+    /// the number of rows which had already been sent to the data source. Emitted by
+    /// [`crate::ColumnarBulkInserter::extend_rows`].
+    #[error("Failed to insert rows after {rows_flushed} rows have been sent:\n{source}")]
+    ExtendRowsFailed {
+        /// Number of rows which have been sent to the data source before the error occurred.
+        rows_flushed: usize,
+        /// The error which caused appending rows to fail.
+        source: Box<Error>,
+    },
+}
+
+fn did_you_mean(closest_match: &Option<String>) -> String {
+    closest_match
+        .as_ref()
+        .map(|name| format!("Did you mean '{name}'? "))
+        .unwrap_or_default()
+}
+
+fn default_c_type_requirement(required: &Option<usize>) -> String {
+    match required {
+        Some(required) => format!("Its default C type requires {required} bytes."),
+        None => "The size of its default C type is not known.".to_owned(),
+    }
 }
 
 impl Error {
@@ -1,7 +1,13 @@
-use std::intrinsics::transmute;
+use std::{intrinsics::transmute, time::Duration};
+
+use log::warn;
 
 use crate::{
-    handles::{AsStatementRef, SqlText, Statement},
+    error::ExtendResult,
+    handles::{
+        AsStatementRef, Record as DiagnosticRecord, SqlResult, SqlText, State, Statement,
+        StatementRef,
+    },
     parameter::Blob,
     sleep::wait_for,
     CursorImpl, CursorPolling, Error, ParameterCollectionRef, Sleep,
@@ -35,6 +41,67 @@ where
     }
 }
 
+/// Like [`execute_with_parameters`], but sets the query timeout of the statement for the duration
+/// of the execution. Afterwards the previous timeout is restored, so later executions using the
+/// same handle are not affected. Restoring is best effort: a failure is logged, but the result of
+/// the execution is returned either way. Shared between [`crate::Preallocated`] and
+/// [`crate::Prepared`].
+pub fn execute_with_timeout<'s>(
+    mut statement: StatementRef<'s>,
+    query: Option<&SqlText<'_>>,
+    params: impl ParameterCollectionRef,
+    timeout: Duration,
+) -> Result<Option<CursorImpl<StatementRef<'s>>>, Error> {
+    let previous = statement.query_timeout_sec().into_result(&statement)?;
+    statement
+        .set_query_timeout_sec(timeout_in_sec(timeout))
+        .into_result(&statement)?;
+    // Execute using a reborrow, so we can still restore the timeout afterwards. `into_stmt` keeps
+    // the cursor open, it is handed out again below.
+    let has_cursor = execute_with_parameters(|| Ok(statement.as_stmt_ref()), query, params)
+        .provide_context_for_diagnostic(map_timeout_expired)
+        .map(|cursor| cursor.map(CursorImpl::into_stmt).is_some());
+    if let Err(e) = statement
+        .set_query_timeout_sec(previous)
+        .into_result(&statement)
+    {
+        warn!("Failed to restore the query timeout of a statement: {e}");
+    }
+    // Safety: `statement` is in cursor state, if the execution created a cursor.
+    has_cursor.map(|has_cursor| has_cursor.then(|| unsafe { CursorImpl::new(statement) }))
+}
+
+/// ODBC specifies query timeouts in whole seconds. Rounds up, so a timeout never expires earlier
+/// than requested. A zero duration results in `0`, i.e. no timeout.
+pub fn timeout_in_sec(timeout: Duration) -> u32 {
+    let seconds = timeout
+        .as_secs()
+        .saturating_add(u64::from(timeout.subsec_nanos() != 0));
+    seconds.try_into().unwrap_or(u32::MAX)
+}
+
+/// Maps the diagnostic emitted for an expired query timeout to [`Error::QueryTimeout`].
+pub fn map_timeout_expired(record: DiagnosticRecord, function: &'static str) -> Error {
+    if record.state == State::TIMEOUT_EXPIRED {
+        Error::QueryTimeout(record)
+    } else {
+        Error::Diagnostics { record, function }
+    }
+}
+
+/// Shared implementation for the number of rows affected by the last executed statement between
+/// [`crate::Preallocated`], [`crate::Prepared`] and [`crate::ColumnarBulkInserter`].
+pub fn row_count(stmt: StatementRef<'_>) -> Result<Option<usize>, Error> {
+    stmt.row_count().into_result(&stmt).map(|count| {
+        // ODBC returns -1 in case a row count is not available
+        if count == -1 {
+            None
+        } else {
+            Some(count.try_into().unwrap())
+        }
+    })
+}
+
 /// Asynchronous sibiling of [`execute_with_parameters`]
 pub async fn execute_with_parameters_polling<S>(
     lazy_statement: impl FnOnce() -> Result<S, Error>,
@@ -107,17 +174,36 @@ where
         .into_result_with(&stmt, Some(false), Some(true))?;
 
     if need_data {
-        // Check if any delayed parameters have been bound which stream data to the database at
-        // statement execution time. Loops over each bound stream.
-        while let Some(blob_ptr) = stmt.param_data().into_result(&stmt)? {
+        // Delayed parameters have been bound which stream data to the database at statement
+        // execution time. Send each stream requested by the driver.
+        let mut read_error = None;
+        let result = stmt.put_data_at_exec(|blob_ptr, stmt| {
             // The safe interfaces currently exclusively bind pointers to `Blob` trait objects
             let blob_ptr: *mut &mut dyn Blob = transmute(blob_ptr);
             let blob_ref = &mut *blob_ptr;
             // Loop over all batches within each blob
-            while let Some(batch) = blob_ref.next_batch().map_err(Error::FailedReadingInput)? {
-                stmt.put_binary_batch(batch).into_result(&stmt)?;
+            loop {
+                match blob_ref.next_batch() {
+                    Ok(Some(batch)) => match stmt.put_binary_batch(batch) {
+                        SqlResult::Success(()) | SqlResult::SuccessWithInfo(()) => (),
+                        other => return other,
+                    },
+                    Ok(None) => return SqlResult::Success(()),
+                    Err(error) => {
+                        // Abort the loop. The error is reported below, before `result` is looked
+                        // at.
+                        read_error = Some(error);
+                        return SqlResult::Error {
+                            function: "SQLPutData",
+                        };
+                    }
+                }
             }
+        });
+        if let Some(error) = read_error {
+            return Err(Error::FailedReadingInput(error));
         }
+        result.into_result_with(&stmt, Some(()), None)?;
     }
 
     // Check if a result set has been created.
@@ -160,7 +246,8 @@ where
 
     if need_data {
         // Check if any delayed parameters have been bound which stream data to the database at
-        // statement execution time. Loops over each bound stream.
+        // statement execution time. Loops over each bound stream. Unlike `execute` this can not
+        // use `Statement::put_data_at_exec`, since each call to `SQLPutData` is awaited.
         while let Some(blob_ptr) = stmt.param_data().into_result(&stmt)? {
             // The safe interfaces currently exclusively bind pointers to `Blob` trait objects
             let blob_ptr: *mut &mut dyn Blob = transmute(blob_ptr);
@@ -271,3 +358,19 @@ where
 
     Ok(cursor)
 }
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::timeout_in_sec;
+
+    #[test]
+    fn timeout_is_rounded_up_to_whole_seconds() {
+        assert_eq!(0, timeout_in_sec(Duration::ZERO));
+        assert_eq!(1, timeout_in_sec(Duration::from_millis(1)));
+        assert_eq!(2, timeout_in_sec(Duration::from_millis(1001)));
+        assert_eq!(3, timeout_in_sec(Duration::from_secs(3)));
+        assert_eq!(u32::MAX, timeout_in_sec(Duration::MAX));
+    }
+}
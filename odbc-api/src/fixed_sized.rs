@@ -106,6 +106,9 @@ macro_rules! impl_pod {
     };
 }
 
+#[cfg(feature = "chrono")]
+pub(crate) use impl_pod;
+
 impl_pod!(f64, CDataType::Double);
 impl_pod!(f32, CDataType::Float);
 impl_pod!(Date, CDataType::TypeDate);
@@ -138,6 +141,9 @@ macro_rules! impl_input_fixed_sized {
     };
 }
 
+#[cfg(feature = "chrono")]
+pub(crate) use impl_input_fixed_sized;
+
 impl_input_fixed_sized!(f64, DataType::Double);
 impl_input_fixed_sized!(f32, DataType::Real);
 impl_input_fixed_sized!(Date, DataType::Date);
@@ -14,6 +14,8 @@ mod data_type;
 mod descriptor;
 mod diagnostics;
 mod environment;
+#[cfg(feature = "odbcinst")]
+mod installer;
 mod logging;
 mod sql_char;
 mod sql_result;
@@ -34,6 +36,12 @@ pub use {
     statement::{AsStatementRef, ParameterDescription, Statement, StatementImpl, StatementRef},
 };
 
+#[cfg(feature = "odbcinst")]
+pub use installer::{config_data_source, InstallerError};
+
+#[cfg(test)]
+pub(crate) use sql_char::to_vec_sql_char;
+
 use log::debug;
 use odbc_sys::{Handle, HandleType, SQLFreeHandle, SqlReturn};
 use std::thread::panicking;
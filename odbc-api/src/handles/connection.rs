@@ -13,24 +13,134 @@ use super::{
 use log::debug;
 use odbc_sys::{
     CompletionType, ConnectionAttribute, DriverConnectOption, HDbc, HEnv, HStmt, HWnd, Handle,
-    HandleType, InfoType, Pointer, SQLAllocHandle, SQLDisconnect, SQLEndTran, IS_UINTEGER,
+    HandleType, InfoType, Pointer, SQLAllocHandle, SQLDisconnect, SQLEndTran, SqlReturn,
+    IS_UINTEGER,
 };
 use std::{ffi::c_void, marker::PhantomData, mem::size_of, ptr::null_mut};
 
 #[cfg(not(any(feature = "wide", all(not(feature = "narrow"), target_os = "windows"))))]
 use odbc_sys::{
-    SQLConnect as sql_connect, SQLDriverConnect as sql_driver_connect,
-    SQLGetConnectAttr as sql_get_connect_attr, SQLGetInfo as sql_get_info,
+    SQLConnect as sql_connect, SQLDriverConnect as sql_driver_connect, SQLGetInfo as sql_get_info,
     SQLSetConnectAttr as sql_set_connect_attr,
 };
 
 #[cfg(any(feature = "wide", all(not(feature = "narrow"), target_os = "windows")))]
 use odbc_sys::{
     SQLConnectW as sql_connect, SQLDriverConnectW as sql_driver_connect,
-    SQLGetConnectAttrW as sql_get_connect_attr, SQLGetInfoW as sql_get_info,
-    SQLSetConnectAttrW as sql_set_connect_attr,
+    SQLGetInfoW as sql_get_info, SQLSetConnectAttrW as sql_set_connect_attr,
 };
 
+// `odbc-sys` does not bind `SQLNativeSql` and only binds the wide variant of `SQLBrowseConnect`,
+// so we declare them here. They are exported by the same driver manager library `odbc-sys` links
+// against. `SQLGetInfo` is declared once more, accepting info types which are not part of
+// `InfoType`, like `SQL_DM_VER`. Likewise `SQLSetConnectAttr` and `SQLGetConnectAttr` accept
+// driver specific attributes, which are not part of `ConnectionAttribute`. There is no `#[link]` attribute
+// on purpose: the symbols are resolved by the library `odbc-sys` links, so whatever it chooses
+// (e.g. static linking or iODBC) applies to these declarations, too.
+extern "system" {
+    #[cfg(not(any(feature = "wide", all(not(feature = "narrow"), target_os = "windows"))))]
+    #[link_name = "SQLBrowseConnect"]
+    fn sql_browse_connect(
+        connection_handle: HDbc,
+        in_connection_string: *const SqlChar,
+        string_length: i16,
+        out_connection_string: *mut SqlChar,
+        buffer_length: i16,
+        out_buffer_length: *mut i16,
+    ) -> SqlReturn;
+
+    #[cfg(any(feature = "wide", all(not(feature = "narrow"), target_os = "windows")))]
+    #[link_name = "SQLBrowseConnectW"]
+    fn sql_browse_connect(
+        connection_handle: HDbc,
+        in_connection_string: *const SqlChar,
+        string_length: i16,
+        out_connection_string: *mut SqlChar,
+        buffer_length: i16,
+        out_buffer_length: *mut i16,
+    ) -> SqlReturn;
+
+    #[cfg(not(any(feature = "wide", all(not(feature = "narrow"), target_os = "windows"))))]
+    #[link_name = "SQLNativeSql"]
+    fn sql_native_sql(
+        connection_handle: HDbc,
+        in_statement_text: *const SqlChar,
+        text_length_1: i32,
+        out_statement_text: *mut SqlChar,
+        buffer_length: i32,
+        text_length_2: *mut i32,
+    ) -> SqlReturn;
+
+    #[cfg(any(feature = "wide", all(not(feature = "narrow"), target_os = "windows")))]
+    #[link_name = "SQLNativeSqlW"]
+    fn sql_native_sql(
+        connection_handle: HDbc,
+        in_statement_text: *const SqlChar,
+        text_length_1: i32,
+        out_statement_text: *mut SqlChar,
+        buffer_length: i32,
+        text_length_2: *mut i32,
+    ) -> SqlReturn;
+
+    #[cfg(not(any(feature = "wide", all(not(feature = "narrow"), target_os = "windows"))))]
+    #[link_name = "SQLGetInfo"]
+    fn sql_get_info_u16(
+        connection_handle: HDbc,
+        info_type: u16,
+        info_value_ptr: Pointer,
+        buffer_length: i16,
+        string_length_ptr: *mut i16,
+    ) -> SqlReturn;
+
+    #[cfg(any(feature = "wide", all(not(feature = "narrow"), target_os = "windows")))]
+    #[link_name = "SQLGetInfoW"]
+    fn sql_get_info_u16(
+        connection_handle: HDbc,
+        info_type: u16,
+        info_value_ptr: Pointer,
+        buffer_length: i16,
+        string_length_ptr: *mut i16,
+    ) -> SqlReturn;
+
+    #[cfg(not(any(feature = "wide", all(not(feature = "narrow"), target_os = "windows"))))]
+    #[link_name = "SQLSetConnectAttr"]
+    fn sql_set_connect_attr_i32(
+        connection_handle: HDbc,
+        attribute: i32,
+        value: Pointer,
+        str_length: i32,
+    ) -> SqlReturn;
+
+    #[cfg(any(feature = "wide", all(not(feature = "narrow"), target_os = "windows")))]
+    #[link_name = "SQLSetConnectAttrW"]
+    fn sql_set_connect_attr_i32(
+        connection_handle: HDbc,
+        attribute: i32,
+        value: Pointer,
+        str_length: i32,
+    ) -> SqlReturn;
+
+    #[cfg(not(any(feature = "wide", all(not(feature = "narrow"), target_os = "windows"))))]
+    #[link_name = "SQLGetConnectAttr"]
+    fn sql_get_connect_attr_i32(
+        connection_handle: HDbc,
+        attribute: i32,
+        value_ptr: Pointer,
+        buffer_length: i32,
+        string_length_ptr: *mut i32,
+    ) -> SqlReturn;
+
+    #[cfg(any(feature = "wide", all(not(feature = "narrow"), target_os = "windows")))]
+    #[link_name = "SQLGetConnectAttrW"]
+    fn sql_get_connect_attr_i32(
+        connection_handle: HDbc,
+        attribute: i32,
+        value_ptr: Pointer,
+        buffer_length: i32,
+        string_length_ptr: *mut i32,
+    ) -> SqlReturn;
+}
+
 /// The connection handle references storage of all information about the connection to the data
 /// source, including status, transaction state, and error information.
 ///
@@ -170,6 +280,37 @@ impl Connection<'_> {
         .into_sql_result("SQLDriverConnect")
     }
 
+    /// Discovers the attributes required to connect to a data source, one level at a time. The
+    /// attributes still missing are written to `out` using the browse result connection string
+    /// syntax. `out` is truncated to the length of the returned string.
+    ///
+    /// # Return
+    ///
+    /// [`SqlResult::NeedData`] if further attributes are required to connect. A success indicates
+    /// that the connection has been established.
+    pub fn browse_connect(
+        &mut self,
+        connection_string: &SqlText,
+        out: &mut Vec<SqlChar>,
+    ) -> SqlResult<()> {
+        let mut actual_len = 0;
+        let buffer_len = out.len().try_into().unwrap_or(i16::MAX);
+        let result = unsafe {
+            sql_browse_connect(
+                self.handle,
+                connection_string.ptr(),
+                connection_string.len_char().try_into().unwrap(),
+                mut_buf_ptr(out),
+                buffer_len,
+                &mut actual_len,
+            )
+            .into_sql_result("SQLBrowseConnect")
+        };
+        // Do not include the terminating zero. Truncate in case the result did not fit.
+        out.truncate((actual_len.max(0) as usize).min(out.len().saturating_sub(1)));
+        result
+    }
+
     /// Disconnect from an ODBC data source.
     pub fn disconnect(&mut self) -> SqlResult<()> {
         unsafe { SQLDisconnect(self.handle).into_sql_result("SQLDisconnect") }
@@ -202,6 +343,11 @@ impl Connection<'_> {
         }
     }
 
+    /// `true` if the connection is in auto-commit mode, `false` if it is in manual-commit mode.
+    pub fn is_autocommit(&self) -> SqlResult<bool> {
+        unsafe { self.attribute_u32(ConnectionAttribute::AutoCommit as i32) }.map(|v| v != 0)
+    }
+
     /// Number of seconds to wait for a login request to complete before returning to the
     /// application. The default is driver-dependent. If `0` the timeout is dasabled and a
     /// connection attempt will wait indefinitely.
@@ -246,6 +392,36 @@ impl Connection<'_> {
         }
     }
 
+    /// Sets an integer valued connection attribute. `attribute` is the raw attribute identifier, so
+    /// driver specific attributes can be set, too.
+    ///
+    /// # Safety
+    ///
+    /// `attribute` must identify an attribute, whose value is an integer, rather than a pointer.
+    pub unsafe fn set_attribute_u32(&self, attribute: i32, value: u32) -> SqlResult<()> {
+        // The value is passed directly instead of a pointer to it. The length is ignored for
+        // integer attributes.
+        sql_set_connect_attr_i32(self.handle, attribute, value as usize as Pointer, 0)
+            .into_sql_result("SQLSetConnectAttr")
+    }
+
+    /// Sets a string valued connection attribute. `attribute` is the raw attribute identifier.
+    ///
+    /// # Safety
+    ///
+    /// `attribute` must identify an attribute, whose value is a character string.
+    pub unsafe fn set_attribute_text(&self, attribute: i32, value: &SqlText) -> SqlResult<()> {
+        // String length of the attribute value in bytes.
+        let length = value.len_char() * size_of::<SqlChar>();
+        sql_set_connect_attr_i32(
+            self.handle,
+            attribute,
+            value.ptr() as Pointer,
+            length.try_into().unwrap(),
+        )
+        .into_sql_result("SQLSetConnectAttr")
+    }
+
     /// To commit a transaction in manual-commit mode.
     pub fn commit(&self) -> SqlResult<()> {
         unsafe {
@@ -265,47 +441,37 @@ impl Connection<'_> {
     /// Fetch the name of the database management system used by the connection and store it into
     /// the provided `buf`.
     pub fn fetch_database_management_system_name(&self, buf: &mut Vec<SqlChar>) -> SqlResult<()> {
-        // String length in bytes, not characters. Terminating zero is excluded.
-        let mut string_length_in_bytes: i16 = 0;
-        // Let's utilize all of `buf`s capacity.
-        buf.resize(buf.capacity(), 0);
+        self.info_string(InfoType::DbmsName, buf)
+    }
 
-        unsafe {
-            let mut res = sql_get_info(
-                self.handle,
-                InfoType::DbmsName,
-                mut_buf_ptr(buf) as Pointer,
-                binary_length(buf).try_into().unwrap(),
-                &mut string_length_in_bytes as *mut i16,
-            )
-            .into_sql_result("SQLGetInfo");
+    /// Fetch the character used to quote identifiers and store it into the provided `buf`. A space
+    /// is reported, if the data source does not support quoted identifiers.
+    pub fn fetch_identifier_quote_char(&self, buf: &mut Vec<SqlChar>) -> SqlResult<()> {
+        self.info_string(InfoType::IdentifierQuoteChar, buf)
+    }
 
-            if res.is_err() {
-                return res;
-            }
+    /// Fetch the character used to escape wildcards in search pattern arguments of catalog
+    /// functions and store it into the provided `buf`. Empty, if the driver does not support an
+    /// escape character.
+    pub fn fetch_search_pattern_escape(&self, buf: &mut Vec<SqlChar>) -> SqlResult<()> {
+        self.info_string(InfoType::SearchPatternEscape, buf)
+    }
 
-            // Call has been a success but let's check if the buffer had been large enough.
-            if is_truncated_bin(buf, string_length_in_bytes.try_into().unwrap()) {
-                // It seems we must try again with a large enough buffer.
-                resize_to_fit_with_tz(buf, string_length_in_bytes.try_into().unwrap());
-                res = sql_get_info(
-                    self.handle,
-                    InfoType::DbmsName,
-                    mut_buf_ptr(buf) as Pointer,
-                    binary_length(buf).try_into().unwrap(),
-                    &mut string_length_in_bytes as *mut i16,
-                )
-                .into_sql_result("SQLGetInfo");
-
-                if res.is_err() {
-                    return res;
-                }
-            }
+    /// Fetch the version of the driver manager and store it into the provided `buf`. The version is
+    /// reported by the driver manager itself, so the connection does not need to be connected.
+    pub fn fetch_driver_manager_version(&self, buf: &mut Vec<SqlChar>) -> SqlResult<()> {
+        // `SQL_DM_VER` is not part of `InfoType`, so `sql_get_info` can not be used to request it.
+        const SQL_DM_VER: u16 = 171;
+        fetch_info_string(buf, |value, buffer_length, string_length| unsafe {
+            sql_get_info_u16(self.handle, SQL_DM_VER, value, buffer_length, string_length)
+        })
+    }
 
-            // Resize buffer to exact string length without terminal zero
-            resize_to_fit_without_tz(buf, string_length_in_bytes.try_into().unwrap());
-            res
-        }
+    /// Fetches a string valued info type and stores it into `buf`.
+    fn info_string(&self, info_type: InfoType, buf: &mut Vec<SqlChar>) -> SqlResult<()> {
+        fetch_info_string(buf, |value, buffer_length, string_length| unsafe {
+            sql_get_info(self.handle, info_type, value, buffer_length, string_length)
+        })
     }
 
     fn info_u16(&self, info_type: InfoType) -> SqlResult<u16> {
@@ -347,55 +513,109 @@ impl Connection<'_> {
         self.info_u16(InfoType::MaxColumnNameLen)
     }
 
+    /// Translates `statement_text` into the native SQL grammar of the data source, without executing
+    /// it, and stores the result into `buffer`. `buffer` is grown, should it be too small to hold
+    /// the entire translation. See [SQLNativeSql][1].
+    ///
+    /// [1]: https://learn.microsoft.com/sql/odbc/reference/syntax/sqlnativesql-function
+    pub fn native_sql(&self, statement_text: &SqlText, buffer: &mut Vec<SqlChar>) -> SqlResult<()> {
+        let native_sql = |buffer: &mut Vec<SqlChar>, text_length: &mut i32| unsafe {
+            // Contrary to most other functions, lengths are specified in characters, not bytes.
+            sql_native_sql(
+                self.handle,
+                statement_text.ptr(),
+                statement_text.len_char().try_into().unwrap(),
+                mut_buf_ptr(buffer),
+                buffer.len().try_into().unwrap(),
+                text_length,
+            )
+            .into_sql_result("SQLNativeSql")
+        };
+
+        // Length of the translated text in characters. Terminating zero is excluded.
+        let mut text_length: i32 = 0;
+        // Let's utilize all of `buffer`s capacity.
+        buffer.resize(buffer.capacity(), 0);
+
+        let mut res = native_sql(buffer, &mut text_length);
+        if res.is_err() {
+            return res;
+        }
+
+        let required_len: usize = text_length.try_into().unwrap();
+        if required_len >= buffer.len() {
+            // Buffer has been too small. Try again with enough room for the terminating zero.
+            buffer.resize(required_len + 1, 0);
+            res = native_sql(buffer, &mut text_length);
+            if res.is_err() {
+                return res;
+            }
+        }
+
+        buffer.truncate(text_length.try_into().unwrap());
+        res
+    }
+
     /// Fetch the name of the current catalog being used by the connection and store it into the
     /// provided `buf`.
     pub fn fetch_current_catalog(&self, buffer: &mut Vec<SqlChar>) -> SqlResult<()> {
+        unsafe { self.fetch_attribute_text(ConnectionAttribute::CurrentCatalog as i32, buffer) }
+    }
+
+    /// Fetches a string valued connection attribute and stores it into `buffer`.
+    ///
+    /// # Safety
+    ///
+    /// `attribute` must identify an attribute, whose value is a character string.
+    pub unsafe fn fetch_attribute_text(
+        &self,
+        attribute: i32,
+        buffer: &mut Vec<SqlChar>,
+    ) -> SqlResult<()> {
         // String length in bytes, not characters. Terminating zero is excluded.
         let mut string_length_in_bytes: i32 = 0;
         // Let's utilize all of `buf`s capacity.
         buffer.resize(buffer.capacity(), 0);
 
-        unsafe {
-            let mut res = sql_get_connect_attr(
+        let mut res = sql_get_connect_attr_i32(
+            self.handle,
+            attribute,
+            mut_buf_ptr(buffer) as Pointer,
+            binary_length(buffer).try_into().unwrap(),
+            &mut string_length_in_bytes as *mut i32,
+        )
+        .into_sql_result("SQLGetConnectAttr");
+
+        if res.is_err() {
+            return res;
+        }
+
+        if is_truncated_bin(buffer, string_length_in_bytes.try_into().unwrap()) {
+            resize_to_fit_with_tz(buffer, string_length_in_bytes.try_into().unwrap());
+            res = sql_get_connect_attr_i32(
                 self.handle,
-                ConnectionAttribute::CurrentCatalog,
+                attribute,
                 mut_buf_ptr(buffer) as Pointer,
                 binary_length(buffer).try_into().unwrap(),
                 &mut string_length_in_bytes as *mut i32,
             )
             .into_sql_result("SQLGetConnectAttr");
+        }
 
-            if res.is_err() {
-                return res;
-            }
-
-            if is_truncated_bin(buffer, string_length_in_bytes.try_into().unwrap()) {
-                resize_to_fit_with_tz(buffer, string_length_in_bytes.try_into().unwrap());
-                res = sql_get_connect_attr(
-                    self.handle,
-                    ConnectionAttribute::CurrentCatalog,
-                    mut_buf_ptr(buffer) as Pointer,
-                    binary_length(buffer).try_into().unwrap(),
-                    &mut string_length_in_bytes as *mut i32,
-                )
-                .into_sql_result("SQLGetConnectAttr");
-            }
-
-            if res.is_err() {
-                return res;
-            }
-
-            // Resize buffer to exact string length without terminal zero
-            resize_to_fit_without_tz(buffer, string_length_in_bytes.try_into().unwrap());
-            res
+        if res.is_err() {
+            return res;
         }
+
+        // Resize buffer to exact string length without terminal zero
+        resize_to_fit_without_tz(buffer, string_length_in_bytes.try_into().unwrap());
+        res
     }
 
     /// Indicates the state of the connection. If `true` the connection has been lost. If `false`,
     /// the connection is still active.
     pub fn is_dead(&self) -> SqlResult<bool> {
         unsafe {
-            self.attribute_u32(ConnectionAttribute::ConnectionDead)
+            self.attribute_u32(ConnectionAttribute::ConnectionDead as i32)
                 .map(|v| match v {
                     0 => false,
                     1 => true,
@@ -406,15 +626,17 @@ impl Connection<'_> {
 
     /// Networ packet size in bytes.
     pub fn packet_size(&self) -> SqlResult<u32> {
-        unsafe { self.attribute_u32(ConnectionAttribute::PacketSize) }
+        unsafe { self.attribute_u32(ConnectionAttribute::PacketSize as i32) }
     }
 
+    /// Fetches an integer valued connection attribute.
+    ///
     /// # Safety
     ///
     /// Caller must ensure connection attribute is numeric.
-    unsafe fn attribute_u32(&self, attribute: ConnectionAttribute) -> SqlResult<u32> {
+    pub unsafe fn attribute_u32(&self, attribute: i32) -> SqlResult<u32> {
         let mut out: u32 = 0;
-        sql_get_connect_attr(
+        sql_get_connect_attr_i32(
             self.handle,
             attribute,
             &mut out as *mut u32 as *mut c_void,
@@ -432,3 +654,46 @@ impl Connection<'_> {
         })
     }
 }
+
+/// Calls `get_info` (a wrapper around `SQLGetInfo`) to fetch a string valued info type and stores
+/// it into `buf`. `buf` is grown, should it be too small to hold the entire string.
+fn fetch_info_string(
+    buf: &mut Vec<SqlChar>,
+    get_info: impl Fn(Pointer, i16, *mut i16) -> SqlReturn,
+) -> SqlResult<()> {
+    // String length in bytes, not characters. Terminating zero is excluded.
+    let mut string_length_in_bytes: i16 = 0;
+    // Let's utilize all of `buf`s capacity.
+    buf.resize(buf.capacity(), 0);
+
+    let mut res = get_info(
+        mut_buf_ptr(buf) as Pointer,
+        binary_length(buf).try_into().unwrap(),
+        &mut string_length_in_bytes as *mut i16,
+    )
+    .into_sql_result("SQLGetInfo");
+
+    if res.is_err() {
+        return res;
+    }
+
+    // Call has been a success but let's check if the buffer had been large enough.
+    if is_truncated_bin(buf, string_length_in_bytes.try_into().unwrap()) {
+        // It seems we must try again with a large enough buffer.
+        resize_to_fit_with_tz(buf, string_length_in_bytes.try_into().unwrap());
+        res = get_info(
+            mut_buf_ptr(buf) as Pointer,
+            binary_length(buf).try_into().unwrap(),
+            &mut string_length_in_bytes as *mut i16,
+        )
+        .into_sql_result("SQLGetInfo");
+
+        if res.is_err() {
+            return res;
+        }
+    }
+
+    // Resize buffer to exact string length without terminal zero
+    resize_to_fit_without_tz(buf, string_length_in_bytes.try_into().unwrap());
+    res
+}
@@ -198,6 +198,27 @@ impl DataType {
         }
     }
 
+    /// The inverse of [`Self::new`]. Returns the `data_type`, `column_size` and `decimal_digits`
+    /// this variant corresponds to, as they would be reported by `SQLDescribeCol`. Handy to assert
+    /// that a data type survives the round trip through the ODBC API.
+    ///
+    /// ```
+    /// use odbc_api::{DataType, sys::SqlDataType};
+    /// use std::num::NonZeroUsize;
+    ///
+    /// let data_type = DataType::Decimal { precision: 10, scale: 2 };
+    /// let (sql_type, column_size, decimal_digits) = data_type.as_sys();
+    /// assert_eq!(SqlDataType::DECIMAL, sql_type);
+    /// assert_eq!(NonZeroUsize::new(10), column_size);
+    /// assert_eq!(2, decimal_digits);
+    ///
+    /// let column_size = column_size.map(NonZeroUsize::get).unwrap_or(0);
+    /// assert_eq!(data_type, DataType::new(sql_type, column_size, decimal_digits));
+    /// ```
+    pub fn as_sys(&self) -> (SqlDataType, Option<NonZeroUsize>, i16) {
+        (self.data_type(), self.column_size(), self.decimal_digits())
+    }
+
     /// The associated `data_type` discriminator for this variant.
     pub fn data_type(&self) -> SqlDataType {
         match self {
@@ -356,7 +377,11 @@ impl DataType {
         }
     }
 
-    /// The maximum length of the UTF-8 representation in bytes.
+    /// The maximum length of the UTF-8 representation in bytes. Use this to choose the element size
+    /// of a text buffer, which must be able to hold any value of the column without truncation.
+    /// `None` if no upper bound is known. This is also the case for `LONGVARCHAR` and
+    /// `LONGVARBINARY`, since the lengths drivers report for them are usually too large to be
+    /// allocated for each element, so the application has to choose an upper bound itself.
     ///
     /// ```
     /// use odbc_api::DataType;
@@ -371,6 +396,8 @@ impl DataType {
     /// // For other types return value is identical to display size as they are assumed to be
     /// // entirely representable with ASCII characters.
     /// assert_eq!(DataType::Numeric { precision: 10, scale: 3}.utf8_len(), nz(10 + 2));
+    /// // No sensible upper bound for long types.
+    /// assert_eq!(DataType::LongVarchar { length: nz(1 << 30) }.utf8_len(), None);
     /// ```
     pub fn utf8_len(&self) -> Option<NonZeroUsize> {
         match self {
@@ -379,11 +406,14 @@ impl DataType {
             | DataType::WVarchar { length }
             | DataType::WChar { length }
             | DataType::Char { length } => length.map(|l| l.get() * 4).and_then(NonZeroUsize::new),
+            DataType::LongVarchar { .. } | DataType::LongVarbinary { .. } => None,
             other => other.display_size(),
         }
     }
 
-    /// The maximum length of the UTF-16 representation in 2-Byte characters.
+    /// The maximum length of the UTF-16 representation in 2-Byte characters. Like
+    /// [`Self::utf8_len`], `None` if no upper bound is known, including `LONGVARCHAR` and
+    /// `LONGVARBINARY`.
     ///
     /// ```
     /// use odbc_api::DataType;
@@ -399,6 +429,8 @@ impl DataType {
     /// // For other types return value is identical to display size as they are assumed to be
     /// // entirely representable with ASCII characters.
     /// assert_eq!(DataType::Numeric { precision: 10, scale: 3}.utf16_len(), nz(10 + 2));
+    /// // No sensible upper bound for long types.
+    /// assert_eq!(DataType::LongVarbinary { length: nz(1 << 30) }.utf16_len(), None);
     /// ```
     pub fn utf16_len(&self) -> Option<NonZeroUsize> {
         match self {
@@ -407,7 +439,143 @@ impl DataType {
             | DataType::WVarchar { length }
             | DataType::WChar { length }
             | DataType::Char { length } => length.map(|l| l.get() * 2).and_then(NonZeroUsize::new),
+            DataType::LongVarchar { .. } | DataType::LongVarbinary { .. } => None,
             other => other.display_size(),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+
+    use odbc_sys::SqlDataType;
+
+    use super::DataType;
+
+    fn nz(n: usize) -> Option<NonZeroUsize> {
+        NonZeroUsize::new(n)
+    }
+
+    /// Assert the sys triple and that it maps back to the same data type.
+    fn assert_as_sys(data_type: DataType, expected: (SqlDataType, Option<NonZeroUsize>, i16)) {
+        let actual = data_type.as_sys();
+        assert_eq!(expected, actual);
+        let (sql_type, column_size, decimal_digits) = actual;
+        let column_size = column_size.map(NonZeroUsize::get).unwrap_or(0);
+        assert_eq!(
+            data_type,
+            DataType::new(sql_type, column_size, decimal_digits)
+        );
+    }
+
+    #[test]
+    fn as_sys_for_character_types() {
+        let len = nz(10);
+        assert_as_sys(DataType::Char { length: len }, (SqlDataType::CHAR, len, 0));
+        assert_as_sys(
+            DataType::WChar { length: len },
+            (SqlDataType::EXT_W_CHAR, len, 0),
+        );
+        assert_as_sys(
+            DataType::Varchar { length: len },
+            (SqlDataType::VARCHAR, len, 0),
+        );
+        assert_as_sys(
+            DataType::WVarchar { length: len },
+            (SqlDataType::EXT_W_VARCHAR, len, 0),
+        );
+        assert_as_sys(
+            DataType::LongVarchar { length: None },
+            (SqlDataType::EXT_LONG_VARCHAR, None, 0),
+        );
+    }
+
+    #[test]
+    fn as_sys_for_binary_types() {
+        let len = nz(16);
+        assert_as_sys(
+            DataType::Binary { length: len },
+            (SqlDataType::EXT_BINARY, len, 0),
+        );
+        assert_as_sys(
+            DataType::Varbinary { length: len },
+            (SqlDataType::EXT_VAR_BINARY, len, 0),
+        );
+        assert_as_sys(
+            DataType::LongVarbinary { length: None },
+            (SqlDataType::EXT_LONG_VAR_BINARY, None, 0),
+        );
+    }
+
+    #[test]
+    fn as_sys_for_numeric_types() {
+        assert_as_sys(
+            DataType::Numeric {
+                precision: 5,
+                scale: 2,
+            },
+            (SqlDataType::NUMERIC, nz(5), 2),
+        );
+        assert_as_sys(
+            DataType::Decimal {
+                precision: 10,
+                scale: 3,
+            },
+            (SqlDataType::DECIMAL, nz(10), 3),
+        );
+        assert_as_sys(
+            DataType::Float { precision: 53 },
+            (SqlDataType::FLOAT, nz(53), 0),
+        );
+        assert_as_sys(DataType::Real, (SqlDataType::REAL, None, 0));
+        assert_as_sys(DataType::Double, (SqlDataType::DOUBLE, None, 0));
+        assert_as_sys(DataType::TinyInt, (SqlDataType::EXT_TINY_INT, None, 0));
+        assert_as_sys(DataType::SmallInt, (SqlDataType::SMALLINT, None, 0));
+        assert_as_sys(DataType::Integer, (SqlDataType::INTEGER, None, 0));
+        assert_as_sys(DataType::BigInt, (SqlDataType::EXT_BIG_INT, None, 0));
+        assert_as_sys(DataType::Bit, (SqlDataType::EXT_BIT, None, 0));
+    }
+
+    #[test]
+    fn as_sys_for_temporal_types() {
+        assert_as_sys(DataType::Date, (SqlDataType::DATE, None, 0));
+        assert_as_sys(
+            DataType::Time { precision: 3 },
+            (SqlDataType::TIME, None, 3),
+        );
+        assert_as_sys(
+            DataType::Timestamp { precision: 7 },
+            (SqlDataType::TIMESTAMP, None, 7),
+        );
+    }
+
+    #[test]
+    fn as_sys_for_unknown_and_other() {
+        assert_as_sys(DataType::Unknown, (SqlDataType::UNKNOWN_TYPE, None, 0));
+        // Custom type, e.g. `DATETIMEOFFSET` of Microsoft SQL Server, is passed through unchanged
+        let custom = SqlDataType(-155);
+        assert_as_sys(
+            DataType::Other {
+                data_type: custom,
+                column_size: nz(34),
+                decimal_digits: 7,
+            },
+            (custom, nz(34), 7),
+        );
+    }
+
+    #[test]
+    fn encoded_len_of_long_types_is_unknown() {
+        let length = nz(1 << 30);
+        for data_type in [
+            DataType::LongVarchar { length },
+            DataType::LongVarbinary { length },
+        ] {
+            assert_eq!(None, data_type.utf8_len());
+            assert_eq!(None, data_type.utf16_len());
+        }
+        // Binary data is represented in hexadecimal, two characters per byte.
+        assert_eq!(nz(20), DataType::Varbinary { length: nz(10) }.utf8_len());
+    }
+}
@@ -33,6 +33,20 @@ impl State {
     pub const STRING_DATA_RIGHT_TRUNCATION: State = State(*b"01004");
     /// StrLen_or_IndPtr was a null pointer and NULL data was retrieved.
     pub const INDICATOR_VARIABLE_REQUIRED_BUT_NOT_SUPPLIED: State = State(*b"22002");
+    /// The driver or data source does not support the requested optional feature. E.g. ending
+    /// transactions on environment level.
+    pub const OPTIONAL_FEATURE_NOT_IMPLEMENTED: State = State(*b"HYC00");
+    /// The function has been canceled using `SQLCancel` while it has been executing.
+    pub const OPERATION_CANCELED: State = State(*b"HY008");
+    /// The query timeout period expired before the data source returned the result set.
+    pub const TIMEOUT_EXPIRED: State = State(*b"HYT00");
+    /// The column number is not valid for the requested operation. E.g. returned by `SQLGetData`
+    /// for a bound column, if the driver does not support `SQLGetData` for bound columns.
+    pub const INVALID_DESCRIPTOR_INDEX: State = State(*b"07009");
+    /// The cursor is not positioned on a row the requested operation could be performed on. E.g.
+    /// returned by `SQLGetData` after positioning a block cursor, if the driver does not support
+    /// `SQLGetData` for block cursors.
+    pub const INVALID_CURSOR_POSITION: State = State(*b"HY109");
 
     /// Drops terminating zero and changes char type, if required
     pub fn from_chars_with_nul(code: &[SqlChar; SQLSTATE_SIZE + 1]) -> Self {
@@ -137,7 +151,7 @@ pub trait Diagnostics {
 
         self.diagnostic_record(rec_number, message_text)
             .map(|mut result| {
-                let mut text_length = result.text_length.try_into().unwrap();
+                let text_length: usize = result.text_length.try_into().unwrap();
 
                 // Check if the buffer has been large enough to hold the message.
                 if text_length > message_text.len() {
@@ -155,19 +169,22 @@ pub trait Diagnostics {
                 }
                 // Now `message_text` has been large enough to hold the entire message.
 
-                // Some drivers pad the message with null-chars (which is still a valid C string,
-                // but not a valid Rust string).
-                while text_length > 0 && message_text[text_length - 1] == 0 {
-                    text_length -= 1;
-                }
                 // Resize Vec to hold exactly the message.
-                message_text.resize(text_length, 0);
+                message_text.truncate(text_length);
+                remove_nul_chars(message_text);
 
                 result
             })
     }
 }
 
+/// Some drivers pad the message with null-chars (which is still a valid C string, but not a valid
+/// Rust string), others even emit them in the middle of a message. Since they break downstream
+/// formatting, they are removed, wherever they appear.
+fn remove_nul_chars(message: &mut Vec<SqlChar>) {
+    message.retain(|&c| c != 0);
+}
+
 impl<T: AsHandle + ?Sized> Diagnostics for T {
     fn diagnostic_record(
         &self,
@@ -255,7 +272,11 @@ impl Record {
 
 impl fmt::Display for Record {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let message = slice_to_cow_utf8(&self.message);
+        let mut message = slice_to_cow_utf8(&self.message);
+        // Records may also be constructed directly, rather than being filled by the driver.
+        if message.contains('\0') {
+            message = message.replace('\0', "").into();
+        }
 
         write!(
             f,
@@ -276,19 +297,12 @@ impl fmt::Debug for Record {
 #[cfg(test)]
 mod tests {
 
-    use crate::handles::diagnostics::State;
-
-    use super::Record;
+    use crate::{
+        handles::{diagnostics::State, to_vec_sql_char},
+        Error,
+    };
 
-    #[cfg(any(feature = "wide", all(not(feature = "narrow"), target_os = "windows")))]
-    fn to_vec_sql_char(text: &str) -> Vec<u16> {
-        text.encode_utf16().collect()
-    }
-
-    #[cfg(not(any(feature = "wide", all(not(feature = "narrow"), target_os = "windows"))))]
-    fn to_vec_sql_char(text: &str) -> Vec<u8> {
-        text.bytes().collect()
-    }
+    use super::{remove_nul_chars, Record};
 
     #[test]
     fn formatting() {
@@ -307,4 +321,31 @@ mod tests {
              Function sequence error"
         );
     }
+
+    #[test]
+    fn remove_interior_and_trailing_nul_chars_from_message() {
+        let mut message = to_vec_sql_char("Invalid\0 object\0\0 name\0\0\0");
+
+        remove_nul_chars(&mut message);
+
+        assert_eq!(to_vec_sql_char("Invalid object name"), message);
+    }
+
+    #[test]
+    fn error_message_does_not_contain_nul_chars() {
+        let record = Record {
+            state: State(*b"42S02"),
+            message: to_vec_sql_char("Invalid object\0 name\0\0"),
+            ..Record::default()
+        };
+        let error = Error::Diagnostics {
+            record,
+            function: "SQLExecDirect",
+        };
+
+        let message = error.to_string();
+
+        assert!(!message.contains('\0'));
+        assert!(message.contains("Invalid object name"));
+    }
 }
@@ -7,8 +7,8 @@ use super::{
 };
 use log::debug;
 use odbc_sys::{
-    AttrCpMatch, AttrOdbcVersion, EnvironmentAttribute, FetchOrientation, HDbc, HEnv, Handle,
-    HandleType, SQLAllocHandle, SQLSetEnvAttr,
+    AttrCpMatch, AttrOdbcVersion, CompletionType, EnvironmentAttribute, FetchOrientation, HDbc,
+    HEnv, Handle, HandleType, SQLAllocHandle, SQLEndTran, SQLSetEnvAttr,
 };
 use std::ptr::null_mut;
 
@@ -138,6 +138,24 @@ impl Environment {
         }
     }
 
+    /// Commit the transactions of all connections allocated on this environment, which are in
+    /// manual-commit mode.
+    pub fn commit(&self) -> SqlResult<()> {
+        unsafe {
+            SQLEndTran(HandleType::Env, self.as_handle(), CompletionType::Commit)
+                .into_sql_result("SQLEndTran")
+        }
+    }
+
+    /// Roll back the transactions of all connections allocated on this environment, which are in
+    /// manual-commit mode.
+    pub fn rollback(&self) -> SqlResult<()> {
+        unsafe {
+            SQLEndTran(HandleType::Env, self.as_handle(), CompletionType::Rollback)
+                .into_sql_result("SQLEndTran")
+        }
+    }
+
     /// Provides access to the raw ODBC environment handle.
     pub fn as_raw(&self) -> HEnv {
         self.handle
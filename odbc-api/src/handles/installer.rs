@@ -0,0 +1,155 @@
+//! Bindings to the installer API of the driver manager. It is shipped as a separate library
+//! (`odbccp32` on windows, `odbcinst` for unixODBC and `iodbcinst` for iODBC), which is why it is
+//! only linked if the `odbcinst` feature is active.
+
+use std::{ffi::c_void, ptr::null_mut};
+
+use super::{
+    buffer::mut_buf_ptr,
+    sql_char::{SqlChar, SqlText},
+};
+
+/// Return value of `SQLInstallerError` indicating that there are no more errors to report.
+const SQL_NO_DATA: i16 = 100;
+
+#[cfg_attr(target_os = "windows", link(name = "odbccp32"))]
+#[cfg_attr(
+    all(not(target_os = "windows"), not(feature = "iodbc")),
+    link(name = "odbcinst")
+)]
+#[cfg_attr(
+    all(not(target_os = "windows"), feature = "iodbc"),
+    link(name = "iodbcinst")
+)]
+extern "system" {
+    #[cfg(not(any(feature = "wide", all(not(feature = "narrow"), target_os = "windows"))))]
+    #[link_name = "SQLConfigDataSource"]
+    fn sql_config_data_source(
+        hwnd_parent: *mut c_void,
+        request: u16,
+        driver: *const SqlChar,
+        attributes: *const SqlChar,
+    ) -> i32;
+
+    #[cfg(any(feature = "wide", all(not(feature = "narrow"), target_os = "windows")))]
+    #[link_name = "SQLConfigDataSourceW"]
+    fn sql_config_data_source(
+        hwnd_parent: *mut c_void,
+        request: u16,
+        driver: *const SqlChar,
+        attributes: *const SqlChar,
+    ) -> i32;
+
+    #[cfg(not(any(feature = "wide", all(not(feature = "narrow"), target_os = "windows"))))]
+    #[link_name = "SQLInstallerError"]
+    fn sql_installer_error(
+        error: u16,
+        error_code: *mut u32,
+        error_message: *mut SqlChar,
+        error_message_max: u16,
+        error_message_len: *mut u16,
+    ) -> i16;
+
+    #[cfg(any(feature = "wide", all(not(feature = "narrow"), target_os = "windows")))]
+    #[link_name = "SQLInstallerErrorW"]
+    fn sql_installer_error(
+        error: u16,
+        error_code: *mut u32,
+        error_message: *mut SqlChar,
+        error_message_max: u16,
+        error_message_len: *mut u16,
+    ) -> i16;
+}
+
+/// Error reported by the installer API. See [SQLInstallerError][1].
+///
+/// [1]: https://learn.microsoft.com/sql/odbc/reference/syntax/sqlinstallererror-function
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InstallerError {
+    /// Installer error code, e.g. `ODBC_ERROR_INVALID_KEYWORD_VALUE` (`8`).
+    pub code: u32,
+    /// Error message, as reported by the installer.
+    pub message: Vec<SqlChar>,
+}
+
+/// Adds, modifies or removes a data source. See [SQLConfigDataSource][1].
+///
+/// # Parameters
+///
+/// * `request`: Raw value of the request, e.g. `ODBC_ADD_DSN` (`1`).
+/// * `driver`: Name of the driver.
+/// * `attributes`: Keyword value pairs describing the data source.
+///
+/// `SQLConfigDataSource` reads its arguments up to the terminating zero, so both are copied into
+/// zero terminated buffers first. Neither `driver` nor `attributes` must contain zero bytes
+/// themselves, otherwise the driver manager would see truncated arguments.
+///
+/// [1]: https://learn.microsoft.com/sql/odbc/reference/syntax/sqlconfigdatasource-function
+pub fn config_data_source(
+    request: u16,
+    driver: &str,
+    attributes: &[(&str, &str)],
+) -> Result<(), InstallerError> {
+    let driver = format!("{driver}\0");
+    let attributes = attribute_list(attributes);
+    let driver = SqlText::new(&driver);
+    let attributes = SqlText::new(&attributes);
+    // Safety: Both `driver` and `attributes` are terminated as `SQLConfigDataSource` expects them.
+    let success =
+        unsafe { sql_config_data_source(null_mut(), request, driver.ptr(), attributes.ptr()) };
+    if success != 0 {
+        Ok(())
+    } else {
+        Err(installer_error())
+    }
+}
+
+/// Formats `key=value` pairs as expected by `SQLConfigDataSource`. Each pair is terminated by a
+/// zero and the list itself is terminated by an additional zero.
+fn attribute_list(attributes: &[(&str, &str)]) -> String {
+    let mut list = String::new();
+    for (key, value) in attributes {
+        list.push_str(key);
+        list.push('=');
+        list.push_str(value);
+        list.push('\0');
+    }
+    list.push('\0');
+    list
+}
+
+/// Fetches the first error the installer recorded for the last call into its API.
+fn installer_error() -> InstallerError {
+    let mut code = 0;
+    let mut message: Vec<SqlChar> = vec![0; 512];
+    let mut message_len = 0;
+    let ret = unsafe {
+        sql_installer_error(
+            1,
+            &mut code,
+            mut_buf_ptr(&mut message),
+            message.len().try_into().unwrap(),
+            &mut message_len,
+        )
+    };
+    if ret == SQL_NO_DATA || ret < 0 {
+        message.clear();
+    } else {
+        message.truncate((message_len as usize).min(message.len() - 1));
+    }
+    InstallerError { code, message }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::attribute_list;
+
+    #[test]
+    fn format_attribute_list() {
+        assert_eq!(
+            "DSN=Inventory\0Database=inventory.db\0\0",
+            attribute_list(&[("DSN", "Inventory"), ("Database", "inventory.db")])
+        );
+        assert_eq!("\0", attribute_list(&[]));
+    }
+}
@@ -60,6 +60,23 @@ pub fn slice_to_cow_utf8(text: &[u16]) -> Cow<str> {
     text.unwrap().into()
 }
 
+/// Encodes `text` as narrow or wide characters, depending on the features set. Used to construct
+/// diagnostic messages in tests.
+#[cfg(all(
+    test,
+    any(feature = "wide", all(not(feature = "narrow"), target_os = "windows"))
+))]
+pub fn to_vec_sql_char(text: &str) -> Vec<u16> {
+    text.encode_utf16().collect()
+}
+#[cfg(all(
+    test,
+    not(any(feature = "wide", all(not(feature = "narrow"), target_os = "windows")))
+))]
+pub fn to_vec_sql_char(text: &str) -> Vec<u8> {
+    text.bytes().collect()
+}
+
 #[cfg(any(feature = "wide", all(not(feature = "narrow"), target_os = "windows")))]
 fn sz_to_utf8(buffer: &[u16]) -> String {
     let c_str = U16CStr::from_slice_truncate(buffer).unwrap();
@@ -11,10 +11,11 @@ use super::{
 };
 use log::debug;
 use odbc_sys::{
-    Desc, FreeStmtOption, HDbc, HStmt, Handle, HandleType, Len, ParamType, Pointer, SQLBindCol,
-    SQLBindParameter, SQLCloseCursor, SQLDescribeParam, SQLExecute, SQLFetch, SQLFreeStmt,
-    SQLGetData, SQLMoreResults, SQLNumParams, SQLNumResultCols, SQLParamData, SQLPutData,
-    SQLRowCount, SqlDataType, SqlReturn, StatementAttribute, IS_POINTER,
+    Desc, FetchOrientation, FreeStmtOption, HDbc, HStmt, Handle, HandleType, Len, Lock, Operation,
+    ParamType, Pointer, SQLBindCol, SQLBindParameter, SQLCancel, SQLCloseCursor, SQLDescribeParam,
+    SQLExecute, SQLFetch, SQLFetchScroll, SQLFreeStmt, SQLGetData, SQLMoreResults, SQLNumParams,
+    SQLNumResultCols, SQLParamData, SQLPutData, SQLRowCount, SQLSetPos, SetPosIRow, SqlDataType,
+    SqlReturn, StatementAttribute, IS_POINTER,
 };
 use std::{ffi::c_void, marker::PhantomData, mem::ManuallyDrop, num::NonZeroUsize, ptr::null_mut};
 
@@ -25,16 +26,16 @@ use odbc_sys::SQLCompleteAsync;
 use odbc_sys::{
     SQLColAttribute as sql_col_attribute, SQLColumns as sql_columns,
     SQLDescribeCol as sql_describe_col, SQLExecDirect as sql_exec_direc,
-    SQLForeignKeys as sql_foreign_keys, SQLPrepare as sql_prepare,
-    SQLSetStmtAttr as sql_set_stmt_attr, SQLTables as sql_tables,
+    SQLForeignKeys as sql_foreign_keys, SQLGetStmtAttr as sql_get_stmt_attr,
+    SQLPrepare as sql_prepare, SQLSetStmtAttr as sql_set_stmt_attr, SQLTables as sql_tables,
 };
 
 #[cfg(any(feature = "wide", all(not(feature = "narrow"), target_os = "windows")))]
 use odbc_sys::{
     SQLColAttributeW as sql_col_attribute, SQLColumnsW as sql_columns,
     SQLDescribeColW as sql_describe_col, SQLExecDirectW as sql_exec_direc,
-    SQLForeignKeysW as sql_foreign_keys, SQLPrepareW as sql_prepare,
-    SQLSetStmtAttrW as sql_set_stmt_attr, SQLTablesW as sql_tables,
+    SQLForeignKeysW as sql_foreign_keys, SQLGetStmtAttrW as sql_get_stmt_attr,
+    SQLPrepareW as sql_prepare, SQLSetStmtAttrW as sql_set_stmt_attr, SQLTablesW as sql_tables,
 };
 
 /// An owned valid (i.e. successfully allocated) ODBC statement handle.
@@ -206,6 +207,44 @@ pub trait Statement: AsHandle {
         SQLFetch(self.as_sys()).into_sql_result("SQLFetch")
     }
 
+    /// Fetches the row set specified by `orientation` and `offset`. E.g. an absolute orientation
+    /// with an offset of `0` positions the cursor before the start of the result set. Only
+    /// scrollable cursors support orientations other than [`FetchOrientation::Next`].
+    ///
+    /// # Safety
+    ///
+    /// Fetch dereferences bound column pointers.
+    unsafe fn fetch_scroll(
+        &mut self,
+        orientation: FetchOrientation,
+        offset: isize,
+    ) -> SqlResult<()> {
+        SQLFetchScroll(self.as_sys(), orientation, offset).into_sql_result("SQLFetchScroll")
+    }
+
+    /// Performs `operation` on the row with the one based `row_number` within the current row set.
+    ///
+    /// * [`Operation::POSITION`] positions the cursor on the row, so subsequent calls to
+    ///   [`Self::get_data`] retrieve values of this row. This requires the driver to support
+    ///   `SQLGetData` for block cursors (`SQL_GD_BLOCK`).
+    /// * [`Operation::UPDATE`] writes the values in the bound buffers back to the data source, and
+    ///   [`Operation::DELETE`] deletes the row. Both require a cursor with a concurrency other than
+    ///   read-only.
+    ///
+    /// # Safety
+    ///
+    /// The statement must be in cursor state and `row_number` must not exceed the number of rows in
+    /// the current row set. For updates, the buffers bound to the columns must be valid.
+    unsafe fn set_pos(&mut self, row_number: usize, operation: Operation) -> SqlResult<()> {
+        SQLSetPos(
+            self.as_sys(),
+            row_number as SetPosIRow,
+            operation,
+            Lock::NO_CHANGE,
+        )
+        .into_sql_result("SQLSetPos")
+    }
+
     /// Retrieves data for a single column in the result set or for a single parameter.
     fn get_data(&mut self, col_or_param_num: u16, target: &mut impl CDataMut) -> SqlResult<()> {
         unsafe {
@@ -344,6 +383,13 @@ pub trait Statement: AsHandle {
         unsafe { SQLCloseCursor(self.as_sys()) }.into_sql_result("SQLCloseCursor")
     }
 
+    /// Cancels the processing on the statement. ODBC explicitly allows calling this from another
+    /// thread, while a function is executed synchronously on the statement. The canceled function
+    /// returns an error with SQLSTATE HY008 (Operation canceled).
+    fn cancel(&self) -> SqlResult<()> {
+        unsafe { SQLCancel(self.as_sys()) }.into_sql_result("SQLCancel")
+    }
+
     /// Send an SQL statement to the data source for preparation. The application can include one or
     /// more parameter markers in the SQL statement. To include a parameter marker, the application
     /// embeds a question mark (?) into the SQL string at the appropriate position.
@@ -451,6 +497,20 @@ pub trait Statement: AsHandle {
         .into_sql_result("SQLSetStmtAttr")
     }
 
+    /// Number of seconds to wait for an SQL statement to execute before returning to the
+    /// application. `0` means there is no timeout.
+    fn set_query_timeout_sec(&mut self, timeout_sec: u32) -> SqlResult<()> {
+        // Safety: `QueryTimeout` is an integer attribute and does not affect bound buffers.
+        unsafe { self.set_attribute_u32(StatementAttribute::QueryTimeout, timeout_sec) }
+    }
+
+    /// Number of seconds to wait for an SQL statement to execute before returning to the
+    /// application. `0` means there is no timeout.
+    fn query_timeout_sec(&self) -> SqlResult<u32> {
+        // Safety: `QueryTimeout` is an integer attribute.
+        unsafe { self.attribute_u32(StatementAttribute::QueryTimeout) }
+    }
+
     fn set_metadata_id(&mut self, metadata_id: bool) -> SqlResult<()> {
         unsafe {
             sql_set_stmt_attr(
@@ -483,6 +543,42 @@ pub trait Statement: AsHandle {
         }
     }
 
+    /// Sets an integer valued statement attribute.
+    ///
+    /// # Safety
+    ///
+    /// * `attribute` must identify an attribute, whose value is an integer, rather than a pointer.
+    /// * Changing the attribute must not invalidate the assumptions made by buffers bound to the
+    ///   statement, e.g. by changing the row array size.
+    unsafe fn set_attribute_u32(
+        &mut self,
+        attribute: StatementAttribute,
+        value: u32,
+    ) -> SqlResult<()> {
+        sql_set_stmt_attr(self.as_sys(), attribute, value as usize as Pointer, 0)
+            .into_sql_result("SQLSetStmtAttr")
+    }
+
+    /// Fetches an integer valued statement attribute. Most statement attributes are `SQLULEN`, so
+    /// the value is read into a buffer of that size.
+    ///
+    /// # Safety
+    ///
+    /// `attribute` must identify an attribute, whose value is an integer or a pointer. Other types
+    /// may cause the driver to write beyond the value.
+    unsafe fn attribute_u32(&self, attribute: StatementAttribute) -> SqlResult<u32> {
+        let mut out: usize = 0;
+        sql_get_stmt_attr(
+            self.as_sys(),
+            attribute,
+            &mut out as *mut usize as Pointer,
+            0,
+            null_mut(),
+        )
+        .into_sql_result("SQLGetStmtAttr")
+        .on_success(|| out as u32)
+    }
+
     /// Binds a buffer holding an input parameter to a parameter marker in an SQL statement. This
     /// specialized version takes a constant reference to parameter, but is therefore limited to
     /// binding input parameters. See [`Statement::bind_parameter`] for the version which can bind
@@ -521,6 +617,20 @@ pub trait Statement: AsHandle {
         .into_sql_result("SQLBindParameter")
     }
 
+    /// Same as [`Statement::bind_input_parameter`], but marks the value as sensitive. Statements
+    /// logging their parameters must not emit its value. Used to bind [`crate::Sensitive`].
+    ///
+    /// # Safety
+    ///
+    /// See [`Statement::bind_input_parameter`].
+    unsafe fn bind_sensitive_input_parameter(
+        &mut self,
+        parameter_number: u16,
+        parameter: &(impl HasDataType + CData + ?Sized),
+    ) -> SqlResult<()> {
+        self.bind_input_parameter(parameter_number, parameter)
+    }
+
     /// Binds a buffer holding a single parameter to a parameter marker in an SQL statement. To bind
     /// input parameters using constant references see [`Statement::bind_input_parameter`].
     ///
@@ -604,6 +714,42 @@ pub trait Statement: AsHandle {
         })
     }
 
+    /// `true` if the column is autoincrementing, `false` if it is not or if it is not a numeric
+    /// type.
+    ///
+    /// `column_number`: Index of the column, starting at 1.
+    fn is_auto_unique_value_column(&self, column_number: u16) -> SqlResult<bool> {
+        unsafe { self.numeric_col_attribute(Desc::AutoUniqueValue, column_number) }.map(|out| {
+            match out {
+                0 => false,
+                1 => true,
+                _ => panic!("Auto unique value column attribute must be either 0 or 1."),
+            }
+        })
+    }
+
+    /// `true` if the column is treated as case sensitive for collations and comparisons, `false`
+    /// if it is not or if it is not a character column.
+    ///
+    /// `column_number`: Index of the column, starting at 1.
+    fn is_case_sensitive_column(&self, column_number: u16) -> SqlResult<bool> {
+        unsafe { self.numeric_col_attribute(Desc::CaseSensitive, column_number) }.map(|out| {
+            match out {
+                0 => false,
+                1 => true,
+                _ => panic!("Case sensitive column attribute must be either 0 or 1."),
+            }
+        })
+    }
+
+    /// Describes how the column can be used in a `WHERE` clause. Returns one of `SQL_PRED_NONE`
+    /// (0), `SQL_PRED_CHAR` (1), `SQL_PRED_BASIC` (2) or `SQL_PRED_SEARCHABLE` (3).
+    ///
+    /// `column_number`: Index of the column, starting at 1.
+    fn col_searchable(&self, column_number: u16) -> SqlResult<Len> {
+        unsafe { self.numeric_col_attribute(Desc::Searchable, column_number) }
+    }
+
     /// Returns a number identifying the SQL type of the column in the result set.
     ///
     /// `column_number`: Index of the column, starting at 1.
@@ -897,6 +1043,41 @@ pub trait Statement: AsHandle {
         }
     }
 
+    /// Sends the values of all parameters bound with [`Self::bind_delayed_input_parameter`] to the
+    /// data source. Call this after [`Self::execute`] or [`Self::exec_direct`] returned
+    /// [`SqlResult::NeedData`]. This drives the loop over [`Self::param_data`], which yields the
+    /// parameters in the order the driver requests them.
+    ///
+    /// For each requested parameter `put_value` is invoked with the stream pointer the parameter
+    /// has been bound with (see [`DelayedInput::stream_ptr`]) and this statement. `put_value` is
+    /// expected to send the entire value of the parameter, using one or more calls to
+    /// [`Self::put_binary_batch`], and to return the result of the last one. If it returns anything
+    /// but success, the loop is aborted and that result is returned. Otherwise the result of the
+    /// final call to `SQLParamData` is returned, which e.g. is [`SqlResult::NoData`] for searched
+    /// updates which did not affect any row.
+    ///
+    /// This is the same loop [`crate::Connection::execute`] runs for [`crate::parameter::Blob`]s.
+    /// Use it if you bind your own implementations of [`DelayedInput`].
+    fn put_data_at_exec(
+        &mut self,
+        mut put_value: impl FnMut(Pointer, &mut Self) -> SqlResult<()>,
+    ) -> SqlResult<()>
+    where
+        Self: Sized,
+    {
+        loop {
+            let stream_ptr = match self.param_data() {
+                SqlResult::Success(Some(stream_ptr))
+                | SqlResult::SuccessWithInfo(Some(stream_ptr)) => stream_ptr,
+                other => return other.map(|_| ()),
+            };
+            match put_value(stream_ptr, self) {
+                SqlResult::Success(()) | SqlResult::SuccessWithInfo(()) => (),
+                other => return other,
+            }
+        }
+    }
+
     /// Number of rows affected by an `UPDATE`, `INSERT`, or `DELETE` statement.
     ///
     /// See:
@@ -970,6 +1151,25 @@ pub trait Statement: AsHandle {
             .on_success(|| Descriptor::new(hdesc))
         }
     }
+
+    /// Application Parameter Descriptor (APD) associated with the statement handle. It describes
+    /// the buffers bound as parameters. E.g. it can be used to specify precision and scale of
+    /// numeric structs bound as input parameters.
+    fn application_parameter_descriptor(&mut self) -> SqlResult<Descriptor<'_>> {
+        unsafe {
+            let mut hdesc: odbc_sys::HDesc = null_mut();
+            let hdesc_out = &mut hdesc as *mut odbc_sys::HDesc as Pointer;
+            odbc_sys::SQLGetStmtAttr(
+                self.as_sys(),
+                odbc_sys::StatementAttribute::AppParamDesc,
+                hdesc_out,
+                0,
+                null_mut(),
+            )
+            .into_sql_result("SQLGetStmtAttr")
+            .on_success(|| Descriptor::new(hdesc))
+        }
+    }
 }
 
 impl Statement for StatementImpl<'_> {
@@ -0,0 +1,57 @@
+use std::{
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+/// Callback registered with [`crate::Connection::set_instrumentation`]. Shared with the prepared
+/// statements and cursors created by the connection, so they can report their timings, too.
+pub(crate) type Instrumentation = Arc<dyn Fn(&ExecEvent) + Send + Sync>;
+
+/// Invokes `operation` and reports the time it took to `instrumentation`, if any. Nothing is
+/// measured if `instrumentation` is `None`.
+pub(crate) fn instrumented<T>(
+    instrumentation: Option<&Instrumentation>,
+    phase: ExecPhase,
+    sql: Option<&str>,
+    operation: impl FnOnce() -> T,
+) -> T {
+    let Some(callback) = instrumentation else {
+        return operation();
+    };
+    let start = Instant::now();
+    let result = operation();
+    callback(&ExecEvent {
+        phase,
+        duration: start.elapsed(),
+        sql,
+    });
+    result
+}
+
+/// Phase of the statement lifecycle an [`ExecEvent`] has been measured for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExecPhase {
+    /// Preparing a statement, e.g. using [`crate::Connection::prepare`].
+    Prepare,
+    /// Executing a statement, either directly, e.g. using [`crate::Connection::execute`], or a
+    /// prepared one using [`crate::Prepared::execute`].
+    Execute,
+    /// Fetching a row or row set from a cursor, e.g. using [`crate::Cursor::next_row`] or
+    /// [`crate::BlockCursor::fetch`]. The SQL text of the statement is not reported for this
+    /// phase.
+    Fetch,
+}
+
+/// Timing information passed to the callback registered with
+/// [`crate::Connection::set_instrumentation`].
+#[derive(Debug, Clone, Copy)]
+pub struct ExecEvent<'a> {
+    /// Phase the duration has been measured for.
+    pub phase: ExecPhase,
+    /// Wall clock time spent in the phase. Includes the time the driver spent waiting for the data
+    /// source.
+    pub duration: Duration,
+    /// Text of the statement, if known. Parameter values are never part of it, only their
+    /// placeholders (`?`).
+    pub sql: Option<&'a str>,
+}
@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use widestring::{U16Str, U16String};
 
 use crate::{
@@ -18,6 +20,68 @@ use crate::parameter::{VarCharBox, VarCharSlice};
 /// idiomatic Rust types, to convert, enrich and marshal them into values which can be bound to
 /// ODBC. This also provides a safe extension point for all kinds of parameters, as only the
 /// implementation of `Parameters` is unsafe.
+///
+/// # Floating point numbers
+///
+/// `f32` and `f64` are bound as `REAL` and `DOUBLE` respectively. This is also true for references
+/// to them, which are copied into the parameter. `Option<f32>` and `Option<f64>` are converted into
+/// a [`Nullable`], so `None` is bound as `NULL` using the indicator.
+///
+/// ```
+/// use odbc_api::{Connection, Error, IntoParameter};
+///
+/// fn insert_measurement(
+///     conn: &Connection<'_>,
+///     temperature: &f32,
+///     humidity: Option<f64>,
+/// ) -> Result<(), Error> {
+///     conn.execute(
+///         "INSERT INTO Measurements (temperature, humidity) VALUES (?, ?)",
+///         (&temperature.into_parameter(), &humidity.into_parameter()),
+///     )?;
+///     Ok(())
+/// }
+/// ```
+///
+/// # Binary data
+///
+/// `&[u8]` and `Vec<u8>` are bound as `VARBINARY`. So are `Option<&[u8]>` and `Option<Vec<u8>>`,
+/// with `None` being bound as `NULL`. Wrap the parameter in a [`crate::parameter::BinaryParam`] to
+/// bind large values as `LONGVARBINARY`, or to specify the relational type explicitly.
+///
+/// ```
+/// use odbc_api::{Connection, Error, IntoParameter, parameter::BinaryParam};
+///
+/// fn insert_file(conn: &Connection<'_>, content: Option<&[u8]>) -> Result<(), Error> {
+///     conn.execute(
+///         "INSERT INTO Files (content) VALUES (?)",
+///         &BinaryParam::new(content.into_parameter()),
+///     )?;
+///     Ok(())
+/// }
+/// ```
+///
+/// # Durations
+///
+/// [`Duration`] is bound as a `BIGINT` holding the number of whole seconds. This is portable across
+/// data sources, even those lacking an interval type. Any sub-second part of the duration is
+/// truncated, so `Duration::from_millis(1999)` is bound as `1`. If you need more precision, convert
+/// the duration into the unit of your choice before binding it, e.g. using
+/// [`Duration::as_millis`]. To insert into a Postgres `INTERVAL` column you can bind text instead,
+/// e.g. `format!("{} seconds", duration.as_secs_f64())`.
+///
+/// ```
+/// use odbc_api::{Connection, Error, IntoParameter};
+/// use std::time::Duration;
+///
+/// fn insert_timeout(conn: &Connection<'_>, timeout: Duration) -> Result<(), Error> {
+///     conn.execute(
+///         "INSERT INTO Timeouts (seconds) VALUES (?)",
+///         &timeout.into_parameter(),
+///     )?;
+///     Ok(())
+/// }
+/// ```
 pub trait IntoParameter {
     type Parameter: InputParameter;
 
@@ -183,6 +247,46 @@ impl IntoParameter for Option<U16String> {
     }
 }
 
+impl IntoParameter for &f32 {
+    type Parameter = f32;
+
+    fn into_parameter(self) -> Self::Parameter {
+        *self
+    }
+}
+
+impl IntoParameter for &f64 {
+    type Parameter = f64;
+
+    fn into_parameter(self) -> Self::Parameter {
+        *self
+    }
+}
+
+/// # Panics
+///
+/// If the number of seconds exceeds `i64::MAX`.
+impl IntoParameter for Duration {
+    type Parameter = i64;
+
+    fn into_parameter(self) -> Self::Parameter {
+        self.as_secs()
+            .try_into()
+            .expect("Duration in seconds must fit into a 64Bit signed integer.")
+    }
+}
+
+impl IntoParameter for Option<Duration> {
+    type Parameter = Nullable<i64>;
+
+    fn into_parameter(self) -> Self::Parameter {
+        match self {
+            Some(duration) => Nullable::new(duration.into_parameter()),
+            None => Nullable::null(),
+        }
+    }
+}
+
 impl<T> IntoParameter for Option<T>
 where
     T: Pod + InputParameter,
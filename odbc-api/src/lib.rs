@@ -6,6 +6,7 @@
 
 mod columnar_bulk_inserter;
 mod connection;
+mod connection_string;
 mod conversion;
 mod cursor;
 mod driver_complete_option;
@@ -13,44 +14,64 @@ mod environment;
 mod error;
 mod execute;
 mod fixed_sized;
+mod instrumentation;
 mod into_parameter;
 mod narrow;
 mod nullable;
 mod parameter_collection;
+mod parameter_log;
 mod preallocated;
 mod prepared;
+mod query;
 mod result_set_metadata;
 mod sleep;
 mod statement_connection;
+mod temp_table;
+mod wide;
 
 pub mod buffers;
+#[cfg(feature = "chrono")]
+pub mod chrono;
 pub mod guide;
 pub mod handles;
+#[cfg(feature = "ndarray")]
+pub mod ndarray;
 pub mod parameter;
 
 pub use self::{
-    columnar_bulk_inserter::{BoundInputSlice, ColumnarBulkInserter},
-    connection::{escape_attribute_value, Connection, ConnectionOptions},
+    columnar_bulk_inserter::{BoundInputSlice, ColumnarBulkInserter, InsertRow},
+    connection::{
+        escape_attribute_value, Concurrency, Connection, ConnectionOptions, CursorType, Dbms,
+        StatementOptions,
+    },
+    connection_string::ConnectionStringBuilder,
     conversion::decimal_text_to_i128,
     cursor::{
         BlockCursor, BlockCursorPolling, ConcurrentBlockCursor, Cursor, CursorImpl, CursorPolling,
-        CursorRow, RowSetBuffer, TruncationInfo,
+        CursorRow, ExpectCursor, NextResultSetError, RowSetBuffer, TruncationInfo,
+        UpdatableBlockCursor,
     },
     driver_complete_option::DriverCompleteOption,
-    environment::{environment, DataSourceInfo, DriverInfo, Environment},
-    error::{Error, TooLargeBufferSize},
+    environment::{
+        environment, DataSourceAction, DataSourceInfo, DriverCapabilities, DriverInfo,
+        DriverManagerInfo, Environment,
+    },
+    error::{EnvironmentSetupError, Error, TooLargeBufferSize},
     fixed_sized::Bit,
     handles::{ColumnDescription, DataType, Nullability},
+    instrumentation::{ExecEvent, ExecPhase},
     into_parameter::IntoParameter,
     narrow::Narrow,
     nullable::Nullable,
-    parameter::{InOut, Out, OutputParameter},
+    parameter::{InOut, Out, OutputParameter, Sensitive},
     parameter_collection::{ParameterCollection, ParameterCollectionRef, ParameterTupleElement},
-    preallocated::{Preallocated, PreallocatedPolling},
+    preallocated::{CancellableStatement, Preallocated, PreallocatedPolling, StatementCanceller},
     prepared::Prepared,
-    result_set_metadata::ResultSetMetadata,
+    result_set_metadata::{ResultSetMetadata, Searchable},
     sleep::Sleep,
     statement_connection::StatementConnection,
+    temp_table::TempTable,
+    wide::Wide,
 };
 
 /// Reexports `odbc-sys` as sys to enable applications to always use the same version as this
@@ -58,6 +79,22 @@ pub use self::{
 pub use odbc_sys as sys;
 pub use widestring::{U16Str, U16String};
 
+#[cfg(feature = "async-io")]
+pub use self::sleep::sleep_with;
+
+#[cfg(feature = "serde")]
+pub use self::cursor::RowDeserializer;
+
+// Used by the `query!` macro to count placeholders at compile time.
+#[cfg(feature = "query_macro")]
+#[doc(hidden)]
+pub use self::query::count_placeholders as __count_placeholders;
+
+/// Reexports `encoding_rs`, so applications can specify encodings using the same version as this
+/// crate.
+#[cfg(feature = "encoding_rs")]
+pub use encoding_rs;
+
 // Reexport fetch if derive feature is enabled
 #[cfg(feature = "derive")]
 pub use odbc_api_derive::Fetch;
@@ -0,0 +1,106 @@
+//! Fetching result sets consisting only of numeric columns into two dimensional
+//! [`ndarray`](::ndarray) arrays. Requires the `ndarray` feature.
+//!
+//! Each column of the result set is bound as a nullable `f64` buffer, so the driver converts
+//! integers and decimals into floating point numbers. Rows are fetched in batches and copied into
+//! an [`Array2`] with one row per row of the result set and one column per column.
+//!
+//! `NULL` values are represented as `NaN`. Should you need to tell `NULL` apart from `NaN` values
+//! stored in the data source, use [`fetch_f64_array_with_validity`], which in addition returns a
+//! mask with `false` for each `NULL`.
+//!
+//! ```no_run
+//! use odbc_api::{Connection, Error, ndarray::fetch_f64_array};
+//!
+//! fn mean_price(conn: &Connection<'_>) -> Result<f64, Error> {
+//!     let cursor = conn
+//!         .execute("SELECT price, amount FROM Orders", ())?
+//!         .expect("SELECT yields a cursor");
+//!     let orders = fetch_f64_array(cursor, 1000)?;
+//!     Ok(orders.column(0).mean().unwrap_or(f64::NAN))
+//! }
+//! ```
+
+use std::iter::repeat_n;
+
+use ::ndarray::Array2;
+use odbc_sys::NULL_DATA;
+
+use crate::{
+    buffers::{BufferDesc, ColumnarAnyBuffer},
+    Cursor, Error,
+};
+
+/// Fetches all rows of `cursor` into an array of shape `(num_rows, num_columns)`. `NULL` values are
+/// represented as `NaN`. `batch_size` is the number of rows fetched in one round trip to the data
+/// source.
+///
+/// Panics if `batch_size` is `0`.
+pub fn fetch_f64_array(cursor: impl Cursor, batch_size: usize) -> Result<Array2<f64>, Error> {
+    let (num_columns, values) = fetch_row_major(cursor, batch_size, None)?;
+    Ok(to_array(num_columns, values))
+}
+
+/// Like [`fetch_f64_array`], but additionally returns a validity mask of the same shape. It is
+/// `false` for each `NULL` and `true` for any other value. The corresponding element in the array
+/// of values is `NaN` for each `NULL`.
+///
+/// Panics if `batch_size` is `0`.
+pub fn fetch_f64_array_with_validity(
+    cursor: impl Cursor,
+    batch_size: usize,
+) -> Result<(Array2<f64>, Array2<bool>), Error> {
+    let mut validity = Vec::new();
+    let (num_columns, values) = fetch_row_major(cursor, batch_size, Some(&mut validity))?;
+    Ok((
+        to_array(num_columns, values),
+        to_array(num_columns, validity),
+    ))
+}
+
+/// Fetches all values of the result set in row major order. Returns the number of columns
+/// alongside the values.
+fn fetch_row_major(
+    mut cursor: impl Cursor,
+    batch_size: usize,
+    mut validity: Option<&mut Vec<bool>>,
+) -> Result<(usize, Vec<f64>), Error> {
+    let num_columns: usize = cursor.num_result_cols()?.try_into().unwrap();
+    let descs = repeat_n(BufferDesc::F64 { nullable: true }, num_columns);
+    let buffer = ColumnarAnyBuffer::from_descs(batch_size, descs);
+    let mut block_cursor = cursor.bind_buffer(buffer)?;
+
+    let mut values = Vec::new();
+    while let Some(batch) = block_cursor.fetch()? {
+        let columns: Vec<_> = (0..num_columns)
+            .map(|index| {
+                batch
+                    .column(index)
+                    .as_nullable_slice::<f64>()
+                    .expect("All columns must be bound as nullable f64.")
+                    .raw_values()
+            })
+            .collect();
+        values.reserve(batch.num_rows() * num_columns);
+        for row_index in 0..batch.num_rows() {
+            for &(column_values, indicators) in &columns {
+                let is_null = indicators[row_index] == NULL_DATA;
+                values.push(if is_null {
+                    f64::NAN
+                } else {
+                    column_values[row_index]
+                });
+                if let Some(validity) = validity.as_deref_mut() {
+                    validity.push(!is_null);
+                }
+            }
+        }
+    }
+    Ok((num_columns, values))
+}
+
+fn to_array<T>(num_columns: usize, elements: Vec<T>) -> Array2<T> {
+    let num_rows = elements.len().checked_div(num_columns).unwrap_or(0);
+    Array2::from_shape_vec((num_rows, num_columns), elements)
+        .expect("Number of elements must be a multiple of the number of columns.")
+}
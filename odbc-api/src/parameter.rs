@@ -71,6 +71,9 @@
 //! query the parameter types beforehand, but my advice is not trust the information blindly if you
 //! cannot test this with your driver beforehand.
 //!
+//! For binary data [`BinaryParam`] picks between `VARBINARY` and `LONGVARBINARY` depending on the
+//! length of the value, or lets you state the type explicitly.
+//!
 //! ## Passing a fixed number of parameters
 //!
 //! To pass multiple but a fixed number of parameters to a query you can use tuples.
@@ -197,6 +200,11 @@
 //! mutable reference. An instance of [`BlobParam`] is usually created by calling
 //! [`Blob::as_blob_param`] from a wrapper implenting [`Blob`].
 //!
+//! A single statement may have more than one [`BlobParam`] bound. At execution time the driver
+//! requests the data for each of them in turn, and each blob is streamed completely before the next
+//! one is requested. The order is chosen by the driver and need not match the order of the
+//! placeholders.
+//!
 //! ### Inserting long binary data from a file.
 //!
 //! [`BlobRead::from_path`] is the most convinient way to turn a file path into a [`Blob`]
@@ -335,11 +343,13 @@
 //! work? Well, in that case please open an issue or a pull request. [`crate::IntoParameter`] can usually be
 //! implemented entirely in safe code, and is a suitable spot to enable support for your custom
 //! types.
+mod binary_param;
 mod blob;
 mod c_string;
 mod varcell;
 
 pub use self::{
+    binary_param::BinaryParam,
     blob::{Blob, BlobParam, BlobRead, BlobSlice},
     varcell::{
         Binary, Text, VarBinary, VarBinaryArray, VarBinaryBox, VarBinarySlice, VarBinarySliceMut,
@@ -348,14 +358,14 @@ pub use self::{
     },
 };
 
-use std::ffi::c_void;
+use std::{ffi::c_void, num::NonZeroUsize};
 
 use odbc_sys::CDataType;
 
 use crate::{
     fixed_sized::Pod,
     handles::{CData, CDataMut, HasDataType},
-    DataType,
+    DataType, IntoParameter,
 };
 
 /// A CData representing a single value rather than an entire buffer of a range of values.
@@ -383,6 +393,12 @@ pub unsafe trait CElement: CData {
     fn assert_completness(&self);
 }
 
+/// A single value ODBC can write into, e.g. using [`crate::CursorRow::get_data`]. Allows for passing
+/// targets of different types to [`crate::CursorRow::get_all`].
+pub trait CElementMut: CElement + CDataMut {}
+
+impl<T> CElementMut for T where T: CElement + CDataMut {}
+
 /// Can be used to fill in a field value indicated by a placeholder (`?`) then executing an SQL
 /// statement.
 pub trait InputParameter: HasDataType + CElement {}
@@ -448,6 +464,25 @@ pub struct InOut<'a, T>(pub &'a mut T);
 /// ```
 pub struct Out<'a, T>(pub &'a mut T);
 
+/// Wraps a reference to an input parameter, whose value must not show up in logs. Values of
+/// sensitive parameters are replaced by `<redacted>` if parameter logging is enabled. See
+/// [`crate::Connection::enable_parameter_logging`].
+///
+/// # Example
+///
+/// ```no_run
+/// use odbc_api::{Connection, Error, IntoParameter, Sensitive};
+///
+/// fn login(conn: &mut Connection<'_>, user: &str, password: &str) -> Result<(), Error> {
+///     conn.enable_parameter_logging();
+///     let password = password.into_parameter();
+///     let params = (&user.into_parameter(), Sensitive(&password));
+///     conn.execute("CALL Login(?, ?)", params)?;
+///     Ok(())
+/// }
+/// ```
+pub struct Sensitive<'a, T: ?Sized>(pub &'a T);
+
 /// Annotates an instance of an inner type with an SQL Data type in order to indicate how it should
 /// be bound as a parameter to an SQL Statement.
 ///
@@ -500,6 +535,41 @@ pub struct WithDataType<T> {
     pub data_type: DataType,
 }
 
+impl<T> WithDataType<T> {
+    /// Binds `value` as `data_type`.
+    pub fn new(value: T, data_type: DataType) -> Self {
+        Self { value, data_type }
+    }
+
+    /// Binds `value` as `INTEGER`. E.g. to insert text into an integer column and let the driver
+    /// do the conversion.
+    ///
+    /// ```
+    /// use odbc_api::{parameter::WithDataType, DataType, IntoParameter};
+    ///
+    /// let parameter = WithDataType::as_integer("42".into_parameter());
+    /// assert_eq!(DataType::Integer, parameter.data_type);
+    /// ```
+    pub fn as_integer(value: T) -> Self {
+        Self::new(value, DataType::Integer)
+    }
+
+    /// Binds `value` as `VARCHAR(length)`.
+    pub fn as_varchar(value: T, length: usize) -> Self {
+        Self::new(
+            value,
+            DataType::Varchar {
+                length: NonZeroUsize::new(length),
+            },
+        )
+    }
+
+    /// Binds `value` as `LONGVARCHAR` without an upper bound for its length.
+    pub fn as_long_varchar(value: T) -> Self {
+        Self::new(value, DataType::LongVarchar { length: None })
+    }
+}
+
 unsafe impl<T> CData for WithDataType<T>
 where
     T: CData,
@@ -579,3 +649,58 @@ unsafe impl CElement for Box<dyn InputParameter> {
         self.as_ref().assert_completness()
     }
 }
+
+// Allow for targets of `get_all` whose type is only known at runtime.
+unsafe impl CData for &mut dyn CElementMut {
+    fn cdata_type(&self) -> CDataType {
+        (**self).cdata_type()
+    }
+
+    fn indicator_ptr(&self) -> *const isize {
+        (**self).indicator_ptr()
+    }
+
+    fn value_ptr(&self) -> *const c_void {
+        (**self).value_ptr()
+    }
+
+    fn buffer_length(&self) -> isize {
+        (**self).buffer_length()
+    }
+}
+
+unsafe impl CDataMut for &mut dyn CElementMut {
+    fn mut_indicator_ptr(&mut self) -> *mut isize {
+        (**self).mut_indicator_ptr()
+    }
+
+    fn mut_value_ptr(&mut self) -> *mut c_void {
+        (**self).mut_value_ptr()
+    }
+}
+
+unsafe impl CElement for &mut dyn CElementMut {
+    fn assert_completness(&self) {
+        (**self).assert_completness()
+    }
+}
+
+/// Converts an optional value into a parameter, which is bound as `NULL` if `value` is `None`.
+/// Same as calling [`crate::IntoParameter::into_parameter`] on the `Option`, but makes the intent
+/// to bind `NULL` explicit at the call site, which helps readability e.g. in query builders.
+///
+/// ```no_run
+/// use odbc_api::{Connection, Error, parameter::maybe};
+///
+/// fn insert_order(conn: &Connection<'_>, user_id: Option<i32>) -> Result<(), Error> {
+///     // Orders of anonymous users are inserted with a `user_id` of `NULL`.
+///     conn.execute("INSERT INTO Orders (user_id) VALUES (?)", &maybe(user_id))?;
+///     Ok(())
+/// }
+/// ```
+pub fn maybe<T>(value: Option<T>) -> <Option<T> as IntoParameter>::Parameter
+where
+    Option<T>: IntoParameter,
+{
+    value.into_parameter()
+}
@@ -0,0 +1,139 @@
+use std::{borrow::Borrow, ffi::c_void, num::NonZeroUsize};
+
+use odbc_sys::CDataType;
+
+use crate::{
+    handles::{CData, HasDataType},
+    DataType,
+};
+
+use super::{CElement, VarBinary};
+
+/// Values longer than this are bound as `LONGVARBINARY` by [`BinaryParam::new`]. Maximum length of
+/// a `VARBINARY` column in Microsoft SQL Server.
+const MAX_VARBINARY_LEN: usize = 8000;
+
+/// Binary input parameter, which gives control over the relational type it is bound as. Binding a
+/// `&[u8]` or `Vec<u8>` directly, always states `VARBINARY` as the type of the parameter. Some
+/// drivers however reject large values, unless they are bound as `LONGVARBINARY`, or expect a
+/// different type for the column in question altogether.
+///
+/// ```
+/// use odbc_api::{Connection, Error, IntoParameter, parameter::BinaryParam};
+///
+/// fn insert_image(conn: &Connection<'_>, image: Option<Vec<u8>>) -> Result<(), Error> {
+///     // Bound as `LONGVARBINARY` if the image is large.
+///     let image = BinaryParam::new(image.into_parameter());
+///     conn.execute("INSERT INTO Images (content) VALUES (?)", &image)?;
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Copy)]
+pub struct BinaryParam<B> {
+    value: VarBinary<B>,
+    data_type: DataType,
+}
+
+impl<B> BinaryParam<B>
+where
+    B: Borrow<[u8]>,
+{
+    /// Binds `value` as `VARBINARY`. Values longer than 8000 bytes are bound as `LONGVARBINARY`
+    /// instead. `NULL` is bound as `VARBINARY`.
+    pub fn new(value: VarBinary<B>) -> Self {
+        let data_type = match value.len_in_bytes() {
+            Some(len) if len > MAX_VARBINARY_LEN => DataType::LongVarbinary {
+                length: NonZeroUsize::new(len),
+            },
+            _ => value.data_type(),
+        };
+        Self { value, data_type }
+    }
+
+    /// Binds `value` as `data_type`, regardless of its length. Useful for columns the driver types
+    /// unexpectedly.
+    pub fn with_data_type(value: VarBinary<B>, data_type: DataType) -> Self {
+        Self { value, data_type }
+    }
+
+    /// Binds `value` as `LONGVARBINARY`, regardless of its length.
+    pub fn long(value: VarBinary<B>) -> Self {
+        let data_type = DataType::LongVarbinary {
+            length: value.len_in_bytes().and_then(NonZeroUsize::new),
+        };
+        Self { value, data_type }
+    }
+}
+
+unsafe impl<B> CData for BinaryParam<B>
+where
+    VarBinary<B>: CData,
+{
+    fn cdata_type(&self) -> CDataType {
+        self.value.cdata_type()
+    }
+
+    fn indicator_ptr(&self) -> *const isize {
+        self.value.indicator_ptr()
+    }
+
+    fn value_ptr(&self) -> *const c_void {
+        self.value.value_ptr()
+    }
+
+    fn buffer_length(&self) -> isize {
+        self.value.buffer_length()
+    }
+}
+
+impl<B> HasDataType for BinaryParam<B> {
+    fn data_type(&self) -> DataType {
+        self.data_type
+    }
+}
+
+unsafe impl<B> CElement for BinaryParam<B>
+where
+    VarBinary<B>: CElement,
+{
+    fn assert_completness(&self) {
+        self.value.assert_completness()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+
+    use crate::{handles::HasDataType, parameter::VarBinarySlice, DataType, IntoParameter};
+
+    use super::BinaryParam;
+
+    #[test]
+    fn bind_short_values_as_varbinary() {
+        let param = BinaryParam::new([1u8, 2, 3].as_slice().into_parameter());
+        assert_eq!(
+            DataType::Varbinary {
+                length: NonZeroUsize::new(3)
+            },
+            param.data_type()
+        );
+    }
+
+    #[test]
+    fn bind_large_values_as_long_varbinary() {
+        let param = BinaryParam::new(vec![0u8; 10_000].into_parameter());
+        assert_eq!(
+            DataType::LongVarbinary {
+                length: NonZeroUsize::new(10_000)
+            },
+            param.data_type()
+        );
+    }
+
+    #[test]
+    fn bind_null_as_varbinary() {
+        let param = BinaryParam::new(VarBinarySlice::NULL);
+        assert_eq!(VarBinarySlice::NULL.data_type(), param.data_type());
+    }
+}
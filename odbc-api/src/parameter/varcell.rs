@@ -12,6 +12,7 @@ use widestring::{U16Str, U16String};
 
 use crate::{
     buffers::{FetchRowMember, Indicator},
+    error::TooLargeBufferSize,
     handles::{CData, CDataMut, HasDataType},
     DataType, OutputParameter,
 };
@@ -608,6 +609,27 @@ impl<const LENGTH: usize, K: VarKind> VarCell<[K::Element; LENGTH], K> {
             kind: PhantomData,
         }
     }
+
+    /// Construct from a slice. In contrast to [`Self::new`] values longer than `LENGTH` are not
+    /// truncated, but cause an error. This allows to detect values which are too large up front,
+    /// since truncated arrays can not be bound as input parameters.
+    ///
+    /// ```
+    /// use odbc_api::parameter::VarCharArray;
+    ///
+    /// assert!(VarCharArray::<5>::try_new(b"Hello").is_ok());
+    /// let error = VarCharArray::<5>::try_new(b"Hello, World!").err().unwrap();
+    /// assert_eq!(13, error.num_elements);
+    /// ```
+    pub fn try_new(elements: &[K::Element]) -> Result<Self, TooLargeBufferSize> {
+        if elements.len() > LENGTH {
+            return Err(TooLargeBufferSize {
+                num_elements: elements.len(),
+                element_size: size_of::<K::Element>(),
+            });
+        }
+        Ok(Self::new(elements))
+    }
 }
 
 /// Figures out, wether or not the buffer ends with a fixed number of zeroes.
@@ -676,7 +698,7 @@ unsafe impl<const LENGTH: usize> FetchRowMember for VarBinaryArray<LENGTH> {
 #[cfg(test)]
 mod tests {
 
-    use super::{Indicator, VarCharSlice};
+    use super::{Indicator, VarBinaryArray, VarCharArray, VarCharSlice};
 
     #[test]
     fn must_accept_fitting_values_and_correctly_truncated_ones() {
@@ -692,4 +714,23 @@ mod tests {
         // Not fine, value is too long, but not terminated by zero
         VarCharSlice::from_buffer(b"12345", Indicator::Length(10));
     }
+
+    #[test]
+    fn try_new_accepts_fitting_values() {
+        let array = VarCharArray::<5>::try_new(b"12345").unwrap();
+        assert_eq!(Some(&b"12345"[..]), array.as_bytes());
+
+        let array = VarBinaryArray::<5>::try_new(&[1, 2, 3]).unwrap();
+        assert_eq!(Some(&[1u8, 2, 3][..]), array.as_bytes());
+    }
+
+    #[test]
+    fn try_new_rejects_values_which_would_be_truncated() {
+        let error = VarCharArray::<5>::try_new(b"123456").err().unwrap();
+        assert_eq!(6, error.num_elements);
+        assert_eq!(1, error.element_size);
+
+        let error = VarBinaryArray::<2>::try_new(&[1, 2, 3]).err().unwrap();
+        assert_eq!(3, error.num_elements);
+    }
 }
@@ -1,4 +1,10 @@
-use crate::{handles::Statement, parameter::InputParameter, Error};
+use std::collections::HashMap;
+
+use crate::{
+    handles::Statement,
+    parameter::{InputParameter, VarCharSlice},
+    Error,
+};
 
 mod tuple;
 
@@ -17,6 +23,13 @@ pub unsafe trait InputParameterCollection {
     /// executed.
     fn parameter_set_size(&self) -> usize;
 
+    /// Number of parameters bound to the statement, if known. Used to validate the number of
+    /// placeholders in a query, e.g. by [`crate::Connection::execute_checked`]. `None` skips the
+    /// validation.
+    fn num_parameters(&self) -> Option<usize> {
+        None
+    }
+
     /// # Safety
     ///
     /// On execution a statement may want to read/write to the bound paramaters. It is the callers
@@ -33,6 +46,10 @@ where
         (**self).parameter_set_size()
     }
 
+    fn num_parameters(&self) -> Option<usize> {
+        (**self).num_parameters()
+    }
+
     unsafe fn bind_parameters_to(&mut self, stmt: &mut impl Statement) -> Result<(), Error> {
         self.bind_input_parameters_to(stmt)
     }
@@ -46,6 +63,10 @@ where
         1
     }
 
+    fn num_parameters(&self) -> Option<usize> {
+        Some(1)
+    }
+
     unsafe fn bind_input_parameters_to(&self, stmt: &mut impl Statement) -> Result<(), Error> {
         self.assert_completness();
         stmt.bind_input_parameter(1, self).into_result(stmt)
@@ -60,6 +81,10 @@ where
         1
     }
 
+    fn num_parameters(&self) -> Option<usize> {
+        Some(self.len())
+    }
+
     unsafe fn bind_input_parameters_to(&self, stmt: &mut impl Statement) -> Result<(), Error> {
         for (index, parameter) in self.iter().enumerate() {
             parameter.assert_completness();
@@ -70,6 +95,67 @@ where
     }
 }
 
+/// Bound to positions which are not specified in a sparse parameter map.
+static NULL_PARAMETER: VarCharSlice<'static> = VarCharSlice::NULL;
+
+/// Sparse parameters. Keys are the one based positions of the placeholders. Positions without an
+/// entry are bound as `NULL`. This is useful e.g. for dynamic query builders, there optional
+/// filters collapse to `NULL`.
+///
+/// The positions filled with `NULL` range up to the largest key, so the map must contain an entry
+/// for the last placeholder of the statement. Use e.g. [`crate::Connection::execute_checked`] to
+/// validate this. Unspecified positions are bound as `NULL` text, which most data sources
+/// implicitly convert to the type of the placeholder. A key of `0` or larger than `u16::MAX` causes
+/// [`Error::InvalidParameterPosition`].
+///
+/// ```no_run
+/// use odbc_api::{Connection, Error};
+/// use std::collections::HashMap;
+///
+/// fn insert_sparse(conn: &Connection<'_>) -> Result<(), Error> {
+///     let mut params = HashMap::new();
+///     params.insert(1, 1);
+///     params.insert(3, 3);
+///     // Inserts (1, NULL, 3)
+///     conn.execute("INSERT INTO MyTable (a, b, c) VALUES (?, ?, ?)", &params)?;
+///     Ok(())
+/// }
+/// ```
+unsafe impl<T> InputParameterCollection for HashMap<usize, T>
+where
+    T: InputParameter,
+{
+    fn parameter_set_size(&self) -> usize {
+        1
+    }
+
+    fn num_parameters(&self) -> Option<usize> {
+        Some(self.keys().copied().max().unwrap_or(0))
+    }
+
+    unsafe fn bind_input_parameters_to(&self, stmt: &mut impl Statement) -> Result<(), Error> {
+        if self.contains_key(&0) {
+            return Err(Error::InvalidParameterPosition { position: 0 });
+        }
+        let max_key = self.keys().copied().max().unwrap_or(0);
+        if max_key > u16::MAX as usize {
+            return Err(Error::InvalidParameterPosition { position: max_key });
+        }
+        for position in 1..=max_key {
+            let parameter_number = position as u16;
+            if let Some(parameter) = self.get(&position) {
+                parameter.assert_completness();
+                stmt.bind_input_parameter(parameter_number, parameter)
+                    .into_result(stmt)?;
+            } else {
+                stmt.bind_input_parameter(parameter_number, &NULL_PARAMETER)
+                    .into_result(stmt)?;
+            }
+        }
+        Ok(())
+    }
+}
+
 /// SQL Parameters used to execute a query.
 ///
 /// ODBC allows to place question marks (`?`) in the statement text as placeholders. For each such
@@ -152,6 +238,12 @@ pub unsafe trait ParameterCollectionRef {
     /// executed.
     fn parameter_set_size(&self) -> usize;
 
+    /// Number of parameters bound to the statement, if known. `None` skips validating the number
+    /// of placeholders in [`crate::Connection::execute_checked`].
+    fn num_parameters(&self) -> Option<usize> {
+        None
+    }
+
     /// # Safety
     ///
     /// On execution a statement may want to read/write to the bound paramaters. It is the callers
@@ -168,6 +260,10 @@ where
         (**self).parameter_set_size()
     }
 
+    fn num_parameters(&self) -> Option<usize> {
+        (**self).num_parameters()
+    }
+
     unsafe fn bind_parameters_to(&mut self, stmt: &mut impl Statement) -> Result<(), Error> {
         (**self).bind_parameters_to(stmt)
     }
@@ -185,6 +281,11 @@ pub unsafe trait ParameterCollection {
     /// executed.
     fn parameter_set_size(&self) -> usize;
 
+    /// Number of parameters bound to the statement, if known.
+    fn num_parameters(&self) -> Option<usize> {
+        None
+    }
+
     /// Bind the parameters to a statement
     ///
     /// # Safety
@@ -203,6 +304,10 @@ where
         (*self).parameter_set_size()
     }
 
+    fn num_parameters(&self) -> Option<usize> {
+        (*self).num_parameters()
+    }
+
     unsafe fn bind_parameters_to(&mut self, stmt: &mut impl Statement) -> Result<(), Error> {
         self.bind_input_parameters_to(stmt)
     }
@@ -2,7 +2,9 @@
 //! trait.
 
 use super::ParameterCollectionRef;
-use crate::{handles::Statement, parameter::InputParameter, Error, InOut, Out, OutputParameter};
+use crate::{
+    handles::Statement, parameter::InputParameter, Error, InOut, Out, OutputParameter, Sensitive,
+};
 
 macro_rules! impl_bind_parameters {
     ($offset:expr, $stmt:ident) => (
@@ -16,6 +18,11 @@ macro_rules! impl_bind_parameters {
     );
 }
 
+macro_rules! count_idents {
+    () => (0);
+    ($head:ident $($tail:ident)*) => (1 + count_idents!($($tail)*));
+}
+
 macro_rules! impl_parameters_for_tuple{
     ($($t:ident)*) => (
         #[allow(unused_parens)]
@@ -27,6 +34,10 @@ macro_rules! impl_parameters_for_tuple{
                 1
             }
 
+            fn num_parameters(&self) -> Option<usize> {
+                Some(count_idents!($($t)*))
+            }
+
             unsafe fn bind_parameters_to(&mut self, stmt: &mut impl Statement) -> Result<(), Error> {
                 let ($($t,)*) = self;
                 impl_bind_parameters!(0, stmt $($t)*)
@@ -117,6 +128,22 @@ where
     }
 }
 
+/// References wrapped in `Sensitive` are bound as input parameters, whose values are not logged.
+unsafe impl<T: ?Sized> ParameterTupleElement for Sensitive<'_, T>
+where
+    T: InputParameter,
+{
+    unsafe fn bind_to(
+        &mut self,
+        parameter_number: u16,
+        stmt: &mut impl Statement,
+    ) -> Result<(), Error> {
+        self.0.assert_completness();
+        stmt.bind_sensitive_input_parameter(parameter_number, self.0)
+            .into_result(stmt)
+    }
+}
+
 /// Mutable references wrapped in `Out` are bound as output parameters.
 unsafe impl<T> ParameterTupleElement for Out<'_, T>
 where
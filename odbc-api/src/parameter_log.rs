@@ -0,0 +1,189 @@
+use std::{ffi::c_void, slice};
+
+use log::trace;
+use odbc_sys::{CDataType, Date, HStmt, Handle, HandleType, ParamType, Time, Timestamp, NULL_DATA};
+
+use crate::{
+    handles::{AsHandle, CData, CDataMut, DelayedInput, HasDataType, SqlResult, Statement},
+    Error, ParameterCollectionRef,
+};
+
+/// Wraps a parameter collection, in order to emit the value of each parameter bound to the
+/// statement as a `trace` record. See [`crate::Connection::enable_parameter_logging`].
+/// Values of parameters bound as [`crate::Sensitive`] are redacted.
+pub struct LoggedParameters<P> {
+    params: P,
+}
+
+impl<P> LoggedParameters<P> {
+    pub fn new(params: P) -> Self {
+        Self { params }
+    }
+}
+
+unsafe impl<P> ParameterCollectionRef for LoggedParameters<P>
+where
+    P: ParameterCollectionRef,
+{
+    fn parameter_set_size(&self) -> usize {
+        self.params.parameter_set_size()
+    }
+
+    fn num_parameters(&self) -> Option<usize> {
+        self.params.num_parameters()
+    }
+
+    unsafe fn bind_parameters_to(&mut self, stmt: &mut impl Statement) -> Result<(), Error> {
+        let mut stmt = LoggingStatement { inner: stmt };
+        self.params.bind_parameters_to(&mut stmt)
+    }
+}
+
+/// Forwards everything to the inner statement, but logs parameters then they are bound.
+struct LoggingStatement<'a, S> {
+    inner: &'a mut S,
+}
+
+unsafe impl<S> AsHandle for LoggingStatement<'_, S>
+where
+    S: Statement,
+{
+    fn as_handle(&self) -> Handle {
+        self.inner.as_handle()
+    }
+
+    fn handle_type(&self) -> HandleType {
+        self.inner.handle_type()
+    }
+}
+
+impl<S> Statement for LoggingStatement<'_, S>
+where
+    S: Statement,
+{
+    fn as_sys(&self) -> HStmt {
+        self.inner.as_sys()
+    }
+
+    unsafe fn bind_input_parameter(
+        &mut self,
+        parameter_number: u16,
+        parameter: &(impl HasDataType + CData + ?Sized),
+    ) -> SqlResult<()> {
+        trace!("Parameter {parameter_number}: {}", format_value(parameter));
+        self.inner.bind_input_parameter(parameter_number, parameter)
+    }
+
+    unsafe fn bind_sensitive_input_parameter(
+        &mut self,
+        parameter_number: u16,
+        parameter: &(impl HasDataType + CData + ?Sized),
+    ) -> SqlResult<()> {
+        trace!("Parameter {parameter_number}: <redacted>");
+        self.inner
+            .bind_sensitive_input_parameter(parameter_number, parameter)
+    }
+
+    unsafe fn bind_parameter(
+        &mut self,
+        parameter_number: u16,
+        input_output_type: ParamType,
+        parameter: &mut (impl CDataMut + HasDataType),
+    ) -> SqlResult<()> {
+        // Buffers of output parameters are not initialized with anything meaningful.
+        if input_output_type == ParamType::Output {
+            trace!("Parameter {parameter_number}: <output>");
+        } else {
+            trace!("Parameter {parameter_number}: {}", format_value(parameter));
+        }
+        self.inner
+            .bind_parameter(parameter_number, input_output_type, parameter)
+    }
+
+    unsafe fn bind_delayed_input_parameter(
+        &mut self,
+        parameter_number: u16,
+        parameter: &mut (impl DelayedInput + HasDataType),
+    ) -> SqlResult<()> {
+        trace!("Parameter {parameter_number}: <stream>");
+        self.inner
+            .bind_delayed_input_parameter(parameter_number, parameter)
+    }
+}
+
+/// Human readable representation of the (first) value of a parameter. Types which are not
+/// understood are represented by their C data type.
+///
+/// # Safety
+///
+/// The pointers of `parameter` must be valid for its C data type and buffer length.
+unsafe fn format_value(parameter: &(impl CData + ?Sized)) -> String {
+    let indicator = parameter.indicator_ptr();
+    let indicator = if indicator.is_null() {
+        None
+    } else {
+        Some(*indicator)
+    };
+    if indicator == Some(NULL_DATA) {
+        return "NULL".to_owned();
+    }
+    let ptr = parameter.value_ptr();
+    let buffer_length = parameter.buffer_length();
+    match parameter.cdata_type() {
+        CDataType::Char => {
+            let text = elements::<u8>(ptr, indicator, buffer_length);
+            format!("'{}'", String::from_utf8_lossy(text))
+        }
+        CDataType::WChar => {
+            let text = elements::<u16>(ptr, indicator, buffer_length);
+            format!("'{}'", String::from_utf16_lossy(text))
+        }
+        CDataType::Binary => {
+            let bytes = elements::<u8>(ptr, indicator, buffer_length);
+            format!("<{} bytes>", bytes.len())
+        }
+        CDataType::STinyInt => read::<i8>(ptr).to_string(),
+        CDataType::UTinyInt | CDataType::Bit => read::<u8>(ptr).to_string(),
+        CDataType::SShort => read::<i16>(ptr).to_string(),
+        CDataType::UShort => read::<u16>(ptr).to_string(),
+        CDataType::SLong => read::<i32>(ptr).to_string(),
+        CDataType::ULong => read::<u32>(ptr).to_string(),
+        CDataType::SBigInt => read::<i64>(ptr).to_string(),
+        CDataType::UBigInt => read::<u64>(ptr).to_string(),
+        CDataType::Float => read::<f32>(ptr).to_string(),
+        CDataType::Double => read::<f64>(ptr).to_string(),
+        CDataType::TypeDate => format!("{:?}", read::<Date>(ptr)),
+        CDataType::TypeTime => format!("{:?}", read::<Time>(ptr)),
+        CDataType::TypeTimestamp => format!("{:?}", read::<Timestamp>(ptr)),
+        other => format!("<{other:?}>"),
+    }
+}
+
+unsafe fn read<T>(ptr: *const c_void) -> T {
+    (ptr as *const T).read_unaligned()
+}
+
+/// Elements of a variable sized value. Its length in bytes is taken from the indicator, or, if it
+/// is not specified, determined by the terminating zero.
+unsafe fn elements<'a, T>(
+    ptr: *const c_void,
+    indicator: Option<isize>,
+    buffer_length: isize,
+) -> &'a [T]
+where
+    T: Default + PartialEq,
+{
+    if ptr.is_null() {
+        return &[];
+    }
+    let capacity = buffer_length.max(0) as usize / size_of::<T>();
+    let buffer = slice::from_raw_parts(ptr as *const T, capacity);
+    match indicator {
+        Some(len) if len >= 0 => &buffer[..(len as usize / size_of::<T>()).min(capacity)],
+        _ => {
+            let zero = T::default();
+            let len = buffer.iter().position(|c| *c == zero).unwrap_or(capacity);
+            &buffer[..len]
+        }
+    }
+}
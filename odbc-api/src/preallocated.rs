@@ -1,7 +1,12 @@
+use std::time::Duration;
+
+use odbc_sys::StatementAttribute;
+
 use crate::{
+    connection::TransactionTracker,
     execute::{
         execute_columns, execute_foreign_keys, execute_tables, execute_with_parameters,
-        execute_with_parameters_polling,
+        execute_with_parameters_polling, execute_with_timeout, row_count,
     },
     handles::{AsStatementRef, SqlText, Statement, StatementImpl, StatementRef},
     CursorImpl, CursorPolling, Error, ParameterCollectionRef, Sleep,
@@ -36,6 +41,9 @@ use crate::{
 pub struct Preallocated<'open_connection> {
     /// A valid statement handle.
     statement: StatementImpl<'open_connection>,
+    /// Marked each time a statement is executed, since this may start a transaction on the
+    /// connection.
+    transaction: TransactionTracker,
 }
 
 impl<'o> Preallocated<'o> {
@@ -48,7 +56,16 @@ impl<'o> Preallocated<'o> {
     /// arguments. The statement must not be prepared, but in the state of a "freshly" allocated
     /// handle.
     pub unsafe fn new(statement: StatementImpl<'o>) -> Self {
-        Self { statement }
+        Self {
+            statement,
+            transaction: TransactionTracker::default(),
+        }
+    }
+
+    /// Shares the transaction state with the connection the statement has been allocated on.
+    pub(crate) fn with_transaction(mut self, transaction: TransactionTracker) -> Self {
+        self.transaction = transaction;
+        self
     }
 
     /// Executes a statement. This is the fastest way to sequentially execute different SQL
@@ -93,13 +110,29 @@ impl<'o> Preallocated<'o> {
     /// ```
     pub fn execute(
         &mut self,
-        query: &str,
+        query: impl AsRef<str>,
         params: impl ParameterCollectionRef,
     ) -> Result<Option<CursorImpl<&mut StatementImpl<'o>>>, Error> {
-        let query = SqlText::new(query);
+        let query = SqlText::new(query.as_ref());
+        self.transaction.mark();
         execute_with_parameters(move || Ok(&mut self.statement), Some(&query), params)
     }
 
+    /// Like [`Self::execute`], but the data source gives up executing the statement once `timeout`
+    /// has expired, in which case [`Error::QueryTimeout`] is returned. See
+    /// [`crate::Connection::execute_with_timeout`]. The timeout only applies to this execution.
+    pub fn execute_with_timeout(
+        &mut self,
+        query: impl AsRef<str>,
+        params: impl ParameterCollectionRef,
+        timeout: Duration,
+    ) -> Result<Option<CursorImpl<StatementRef<'_>>>, Error> {
+        let query = SqlText::new(query.as_ref());
+        self.transaction.mark();
+        let stmt = self.statement.as_stmt_ref();
+        execute_with_timeout(stmt, Some(&query), params, timeout)
+    }
+
     /// Transfer ownership to the underlying statement handle.
     ///
     /// The resulting type is one level of indirection away from the raw pointer of the ODBC API. It
@@ -132,6 +165,7 @@ impl<'o> Preallocated<'o> {
         table_name: &str,
         table_type: &str,
     ) -> Result<CursorImpl<&mut StatementImpl<'o>>, Error> {
+        self.transaction.mark();
         execute_tables(
             &mut self.statement,
             &SqlText::new(catalog_name),
@@ -157,6 +191,7 @@ impl<'o> Preallocated<'o> {
         table_name: &str,
         column_name: &str,
     ) -> Result<CursorImpl<&mut StatementImpl<'o>>, Error> {
+        self.transaction.mark();
         execute_columns(
             &mut self.statement,
             &SqlText::new(catalog_name),
@@ -179,6 +214,7 @@ impl<'o> Preallocated<'o> {
         fk_schema_name: &str,
         fk_table_name: &str,
     ) -> Result<CursorImpl<&mut StatementImpl<'o>>, Error> {
+        self.transaction.mark();
         execute_foreign_keys(
             &mut self.statement,
             &SqlText::new(pk_catalog_name),
@@ -217,17 +253,55 @@ impl<'o> Preallocated<'o> {
     /// }
     /// ```
     pub fn row_count(&mut self) -> Result<Option<usize>, Error> {
+        row_count(self.statement.as_stmt_ref())
+    }
+
+    /// Sets an integer valued statement attribute via `SQLSetStmtAttr`, e.g. `SQL_ATTR_CURSOR_TYPE`
+    /// or `SQL_ATTR_CONCURRENCY`. This is an escape hatch for attributes this crate does not offer
+    /// a dedicated method for.
+    ///
+    /// ```no_run
+    /// use odbc_api::{Connection, Error, sys::StatementAttribute};
+    ///
+    /// /// `SQL_CURSOR_STATIC`
+    /// const CURSOR_STATIC: u32 = 3;
+    ///
+    /// fn static_cursor(conn: &Connection<'_>) -> Result<(), Error> {
+    ///     let mut stmt = conn.preallocate()?;
+    ///     // Safety: Cursor type is an integer attribute and no buffers are bound yet.
+    ///     unsafe {
+    ///         stmt.set_stmt_attr_u32(StatementAttribute::CursorType, CURSOR_STATIC)?;
+    ///     }
+    ///     stmt.execute("SELECT * FROM Movies", ())?;
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Safety
+    ///
+    /// * `attribute` must identify an attribute, whose value is an integer. Attributes, which are
+    ///   pointers, would cause the driver to dereference `value`.
+    /// * Attributes which affect bound buffers, like the row array size, must not be changed.
+    pub unsafe fn set_stmt_attr_u32(
+        &mut self,
+        attribute: StatementAttribute,
+        value: u32,
+    ) -> Result<(), Error> {
+        self.statement
+            .set_attribute_u32(attribute, value)
+            .into_result(&self.statement)
+    }
+
+    /// Reads an integer valued statement attribute via `SQLGetStmtAttr`. See
+    /// [`Self::set_stmt_attr_u32`].
+    ///
+    /// # Safety
+    ///
+    /// `attribute` must identify an attribute, whose value is an integer or a pointer.
+    pub unsafe fn stmt_attr_u32(&self, attribute: StatementAttribute) -> Result<u32, Error> {
         self.statement
-            .row_count()
+            .attribute_u32(attribute)
             .into_result(&self.statement)
-            .map(|count| {
-                // ODBC returns -1 in case a row count is not available
-                if count == -1 {
-                    None
-                } else {
-                    Some(count.try_into().unwrap())
-                }
-            })
     }
 
     /// Call this method to enable asynchronous polling mode on the statement
@@ -235,7 +309,40 @@ impl<'o> Preallocated<'o> {
         self.statement
             .set_async_enable(true)
             .into_result(&self.statement)?;
-        Ok(PreallocatedPolling::new(self.statement))
+        Ok(PreallocatedPolling::new(self.statement, self.transaction))
+    }
+
+    /// Calls `f` with a [`StatementCanceller`], which can be sent to another thread to cancel a
+    /// long running execution or fetch on this statement. Use the [`CancellableStatement`] passed
+    /// to `f` for executing queries in the meantime.
+    ///
+    /// ```no_run
+    /// use odbc_api::{Connection, Error};
+    /// use std::{thread, time::Duration};
+    ///
+    /// fn execute_with_timeout(conn: &Connection<'_>, query: &str) -> Result<(), Error> {
+    ///     let mut statement = conn.preallocate()?;
+    ///     statement.with_canceller(|statement, canceller| {
+    ///         thread::scope(|s| {
+    ///             s.spawn(move || {
+    ///                 thread::sleep(Duration::from_secs(30));
+    ///                 // Nothing to cancel anymore, if the query has already finished.
+    ///                 let _ = canceller.cancel();
+    ///             });
+    ///             statement.execute(query, ()).map(|_| ())
+    ///         })
+    ///     })
+    /// }
+    /// ```
+    pub fn with_canceller<R>(
+        &mut self,
+        f: impl FnOnce(&mut CancellableStatement<'_, 'o>, StatementCanceller<'_>) -> R,
+    ) -> R {
+        // Safety: The canceller can not outlive `f`. `f` has no access to the owned statement
+        // handle, so it can not be freed (e.g. by replacing it) while the canceller is alive.
+        let statement = unsafe { StatementRef::new(self.statement.as_sys()) };
+        let canceller = StatementCanceller { statement };
+        f(&mut CancellableStatement { preallocated: self }, canceller)
     }
 }
 
@@ -245,16 +352,86 @@ impl AsStatementRef for Preallocated<'_> {
     }
 }
 
+/// A [`Preallocated`] statement which can be cancelled from another thread using a
+/// [`StatementCanceller`]. See [`Preallocated::with_canceller`].
+///
+/// Contrary to [`Preallocated`] it does not give access to the owned statement handle, since the
+/// handle must stay alive as long as the canceller does.
+pub struct CancellableStatement<'a, 'o> {
+    preallocated: &'a mut Preallocated<'o>,
+}
+
+impl CancellableStatement<'_, '_> {
+    /// See [`Preallocated::execute`].
+    pub fn execute(
+        &mut self,
+        query: impl AsRef<str>,
+        params: impl ParameterCollectionRef,
+    ) -> Result<Option<CursorImpl<StatementRef<'_>>>, Error> {
+        let query = SqlText::new(query.as_ref());
+        self.preallocated.transaction.mark();
+        let stmt = self.preallocated.statement.as_stmt_ref();
+        execute_with_parameters(move || Ok(stmt), Some(&query), params)
+    }
+
+    /// See [`Preallocated::execute_with_timeout`].
+    pub fn execute_with_timeout(
+        &mut self,
+        query: impl AsRef<str>,
+        params: impl ParameterCollectionRef,
+        timeout: Duration,
+    ) -> Result<Option<CursorImpl<StatementRef<'_>>>, Error> {
+        self.preallocated
+            .execute_with_timeout(query, params, timeout)
+    }
+
+    /// See [`Preallocated::row_count`].
+    pub fn row_count(&mut self) -> Result<Option<usize>, Error> {
+        self.preallocated.row_count()
+    }
+}
+
+impl AsStatementRef for CancellableStatement<'_, '_> {
+    fn as_stmt_ref(&mut self) -> StatementRef<'_> {
+        self.preallocated.as_stmt_ref()
+    }
+}
+
+/// Cancels whatever is currently executed on a statement. Intended to be sent to another thread,
+/// in order to stop a long running query executed synchronously. See
+/// [`Preallocated::with_canceller`].
+pub struct StatementCanceller<'s> {
+    statement: StatementRef<'s>,
+}
+
+/// ODBC explicitly allows calling `SQLCancel` on a statement from another thread, while a function
+/// is executing on it.
+unsafe impl Send for StatementCanceller<'_> {}
+
+impl StatementCanceller<'_> {
+    /// Cancels the execution or fetch currently running on the statement. The canceled call
+    /// returns an [`Error::Diagnostics`] with [`crate::handles::State::OPERATION_CANCELED`]. If
+    /// nothing is executed on the statement, this has no effect.
+    pub fn cancel(&self) -> Result<(), Error> {
+        self.statement.cancel().into_result(&self.statement)
+    }
+}
+
 /// Asynchronous sibling of [`Preallocated`] using polling mode for execution. Can be obtained using
 /// [`Preallocated::into_polling`].
 pub struct PreallocatedPolling<'open_connection> {
     /// A valid statement handle in polling mode
     statement: StatementImpl<'open_connection>,
+    /// See [`Preallocated`].
+    transaction: TransactionTracker,
 }
 
 impl<'o> PreallocatedPolling<'o> {
-    fn new(statement: StatementImpl<'o>) -> Self {
-        Self { statement }
+    fn new(statement: StatementImpl<'o>, transaction: TransactionTracker) -> Self {
+        Self {
+            statement,
+            transaction,
+        }
     }
 
     /// Executes a statement. This is the fastest way to sequentially execute different SQL
@@ -300,11 +477,12 @@ impl<'o> PreallocatedPolling<'o> {
     /// ```
     pub async fn execute(
         &mut self,
-        query: &str,
+        query: impl AsRef<str>,
         params: impl ParameterCollectionRef,
         sleep: impl Sleep,
     ) -> Result<Option<CursorPolling<&mut StatementImpl<'o>>>, Error> {
-        let query = SqlText::new(query);
+        let query = SqlText::new(query.as_ref());
+        self.transaction.mark();
         execute_with_parameters_polling(
             move || Ok(&mut self.statement),
             Some(&query),
@@ -1,19 +1,59 @@
+use std::time::Duration;
+
+use odbc_sys::StatementAttribute;
+
 use crate::{
     buffers::{AnyBuffer, BufferDesc, ColumnBuffer, TextColumn},
-    execute::execute_with_parameters,
+    connection::TransactionTracker,
+    execute::{execute_with_parameters, execute_with_timeout, row_count},
     handles::{AsStatementRef, HasDataType, ParameterDescription, Statement, StatementRef},
-    ColumnarBulkInserter, CursorImpl, Error, ParameterCollectionRef, ResultSetMetadata,
+    instrumentation::{instrumented, ExecPhase, Instrumentation},
+    ColumnDescription, ColumnarBulkInserter, CursorImpl, Error, ParameterCollectionRef,
+    ResultSetMetadata,
 };
 
 /// A prepared query. Prepared queries are useful if the similar queries should executed more than
 /// once. See [`crate::Connection::prepare`].
 pub struct Prepared<S> {
     statement: S,
+    /// Descriptions of the result set columns. Filled on first access by
+    /// [`Prepared::cached_columns`]. Since the SQL text of a prepared statement can not change,
+    /// these never need to be invalidated. Only filled if the driver reports any columns, as some
+    /// drivers can not describe the result set before execution.
+    column_descriptions: Option<Vec<ColumnDescription>>,
+    /// Callback registered with the connection at the time the statement has been prepared,
+    /// together with the SQL text reported to it.
+    instrumentation: Option<(Instrumentation, String)>,
+    /// Marked each time the statement is executed, since this may start a transaction on the
+    /// connection.
+    transaction: TransactionTracker,
 }
 
 impl<S> Prepared<S> {
     pub(crate) fn new(statement: S) -> Self {
-        Self { statement }
+        Self {
+            statement,
+            column_descriptions: None,
+            instrumentation: None,
+            transaction: TransactionTracker::default(),
+        }
+    }
+
+    /// Reports executions of this statement, and fetches from the resulting cursors, to
+    /// `instrumentation`.
+    pub(crate) fn with_instrumentation(
+        mut self,
+        instrumentation: Option<Instrumentation>,
+        sql: &str,
+    ) -> Self {
+        self.instrumentation = instrumentation.map(|callback| (callback, sql.to_owned()));
+        self
+    }
+
+    /// Shares the transaction state with the connection the statement has been prepared on.
+    pub(crate) fn with_transaction(mut self, transaction: TransactionTracker) -> Self {
+        self.transaction = transaction;
+        self
     }
 
     /// Transfer ownership to the underlying statement handle.
@@ -45,21 +85,73 @@ where
         params: impl ParameterCollectionRef,
     ) -> Result<Option<CursorImpl<StatementRef<'_>>>, Error> {
         let stmt = self.statement.as_stmt_ref();
-        execute_with_parameters(move || Ok(stmt), None, params)
+        self.transaction.mark();
+        let (callback, sql) = split_instrumentation(&self.instrumentation);
+        let maybe_cursor = instrumented(callback, ExecPhase::Execute, sql, || {
+            execute_with_parameters(move || Ok(stmt), None, params)
+        })?;
+        Ok(maybe_cursor.map(|cursor| cursor.with_instrumentation(callback.cloned())))
     }
 
-    /// Describes parameter marker associated with a prepared SQL statement.
+    /// Like [`Self::execute`], but the data source gives up executing the statement once `timeout`
+    /// has expired, in which case [`Error::QueryTimeout`] is returned. See
+    /// [`crate::Connection::execute_with_timeout`]. The timeout only applies to this execution, so
+    /// it does not affect later calls to [`Self::execute`].
+    pub fn execute_with_timeout(
+        &mut self,
+        params: impl ParameterCollectionRef,
+        timeout: Duration,
+    ) -> Result<Option<CursorImpl<StatementRef<'_>>>, Error> {
+        let stmt = self.statement.as_stmt_ref();
+        self.transaction.mark();
+        let (callback, sql) = split_instrumentation(&self.instrumentation);
+        let maybe_cursor = instrumented(callback, ExecPhase::Execute, sql, || {
+            execute_with_timeout(stmt, None, params, timeout)
+        })?;
+        Ok(maybe_cursor.map(|cursor| cursor.with_instrumentation(callback.cloned())))
+    }
+
+    /// Describes parameter marker associated with a prepared SQL statement. Calls
+    /// `SQLDescribeParam` only for the one parameter specified, so this is cheaper than
+    /// [`Self::parameter_descriptions`] if you are only interested in one of them.
     ///
     /// # Parameters
     ///
     /// * `parameter_number`: Parameter marker number ordered sequentially in increasing parameter
     ///   order, starting at 1.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use odbc_api::{Connection, DataType, Error};
+    ///
+    /// /// Data type the database expects for the name of a new drink.
+    /// fn drink_type(connection: &Connection<'_>) -> Result<DataType, Error> {
+    ///     let sql = "INSERT INTO NationalDrink (country, drink) VALUES (?, ?)";
+    ///     let mut prepared = connection.prepare(sql)?;
+    ///     let description = prepared.describe_param(2)?;
+    ///     Ok(description.data_type)
+    /// }
+    /// ```
     pub fn describe_param(&mut self, parameter_number: u16) -> Result<ParameterDescription, Error> {
         let stmt = self.as_stmt_ref();
 
         stmt.describe_param(parameter_number).into_result(&stmt)
     }
 
+    /// `true` if executing the prepared statement is going to produce a result set, e.g. because
+    /// it is a `SELECT` statement. `false` if it is e.g. an `INSERT` or `UPDATE`. This allows to
+    /// route statements before executing them.
+    ///
+    /// This is based on [`ResultSetMetadata::num_result_cols`], which is the best signal ODBC
+    /// offers before execution, yet it is not always reliable. Drivers may not be able to tell
+    /// the shape of the result of batches or calls to stored procedures, until they are executed
+    /// and report `0` columns. Some drivers do not describe the result until execution at all.
+    /// In case of doubt, inspect the result of [`Self::execute`] instead.
+    pub fn produces_result_set(&mut self) -> Result<bool, Error> {
+        Ok(self.num_result_cols()? > 0)
+    }
+
     /// Number of placeholders which must be provided with [`Self::execute`] in order to execute
     /// this statement. This is equivalent to the number of placeholders used in the SQL string
     /// used to prepare the statement.
@@ -113,8 +205,10 @@ where
     where
         C: ColumnBuffer + HasDataType,
     {
+        let transaction = self.transaction.clone();
         // We know that statement is a prepared statement.
-        ColumnarBulkInserter::new(self.into_statement(), parameter_buffers)
+        let inserter = ColumnarBulkInserter::new(self.into_statement(), parameter_buffers)?;
+        Ok(inserter.with_transaction(transaction))
     }
 
     /// Use this to insert rows of string input into the database.
@@ -248,7 +342,72 @@ where
             .into_iter()
             .map(|desc| AnyBuffer::from_desc(capacity, desc))
             .collect();
-        unsafe { ColumnarBulkInserter::new(stmt, parameter_buffers) }
+        let inserter = unsafe { ColumnarBulkInserter::new(stmt, parameter_buffers) }?;
+        Ok(inserter.with_transaction(self.transaction.clone()))
+    }
+
+    /// Binds buffers for a single set of parameters to the prepared statement once. Afterwards the
+    /// statement can be executed many times, changing the values in place between executions
+    /// without binding them again. Values are written with [`ColumnarBulkInserter::column_mut`] at
+    /// row index `0`. Text and nullable values are `NULL` until written.
+    ///
+    /// ```no_run
+    /// use odbc_api::{Connection, Error, buffers::BufferDesc};
+    ///
+    /// fn insert_one_by_one(conn: &Connection<'_>, ids: &[i32]) -> Result<(), Error> {
+    ///     let mut prepared = conn.prepare("INSERT INTO Ids (id) VALUES (?)")?;
+    ///     let mut params = prepared.bind_input_buffers([BufferDesc::I32 { nullable: false }])?;
+    ///     for &id in ids {
+    ///         params.column_mut(0).as_slice::<i32>().unwrap()[0] = id;
+    ///         params.execute()?;
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn bind_input_buffers(
+        &mut self,
+        descriptions: impl IntoIterator<Item = BufferDesc>,
+    ) -> Result<ColumnarBulkInserter<StatementRef<'_>, AnyBuffer>, Error> {
+        let mut inserter = self.column_inserter(1, descriptions)?;
+        inserter.set_num_rows(1);
+        Ok(inserter)
+    }
+
+    /// Descriptions of all columns in the result set of the prepared statement. The driver is only
+    /// queried on the first call. Subsequent calls to this method, or to
+    /// [`ResultSetMetadata::describe_col`], are served from a cache. Empty if the statement does
+    /// not produce a result set. An empty result is not cached, since some drivers report `0`
+    /// columns until the statement is executed. Calling this after execution then queries the
+    /// driver again.
+    ///
+    /// ```
+    /// use odbc_api::{Connection, Error};
+    ///
+    /// fn print_column_names(conn: &Connection<'_>) -> Result<(), Error> {
+    ///     let mut prepared = conn.prepare("SELECT a, b FROM MyTable")?;
+    ///     for column in prepared.cached_columns()? {
+    ///         println!("{}", column.name_to_string().unwrap());
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn cached_columns(&mut self) -> Result<&[ColumnDescription], Error> {
+        if self.column_descriptions.is_none() {
+            let stmt = self.statement.as_stmt_ref();
+            let num_cols = stmt.num_result_cols().into_result(&stmt)?;
+            if num_cols == 0 {
+                return Ok(&[]);
+            }
+            let mut columns = Vec::with_capacity(num_cols as usize);
+            for column_number in 1..=(num_cols as u16) {
+                let mut column = ColumnDescription::default();
+                stmt.describe_col(column_number, &mut column)
+                    .into_result(&stmt)?;
+                columns.push(column);
+            }
+            self.column_descriptions = Some(columns);
+        }
+        Ok(self.column_descriptions.as_deref().unwrap())
     }
 
     /// Number of rows affected by the last `INSERT`, `UPDATE` or `DELETE` statement. May return
@@ -279,19 +438,63 @@ where
     /// }
     /// ```
     pub fn row_count(&mut self) -> Result<Option<usize>, Error> {
+        row_count(self.statement.as_stmt_ref())
+    }
+
+    /// Sets an integer valued statement attribute via `SQLSetStmtAttr`. See
+    /// [`crate::Preallocated::set_stmt_attr_u32`].
+    ///
+    /// # Safety
+    ///
+    /// * `attribute` must identify an attribute, whose value is an integer.
+    /// * Attributes which affect bound buffers, like the row array size, must not be changed.
+    pub unsafe fn set_stmt_attr_u32(
+        &mut self,
+        attribute: StatementAttribute,
+        value: u32,
+    ) -> Result<(), Error> {
+        let mut stmt = self.statement.as_stmt_ref();
+        stmt.set_attribute_u32(attribute, value).into_result(&stmt)
+    }
+
+    /// Reads an integer valued statement attribute via `SQLGetStmtAttr`. See
+    /// [`crate::Preallocated::set_stmt_attr_u32`].
+    ///
+    /// # Safety
+    ///
+    /// `attribute` must identify an attribute, whose value is an integer or a pointer.
+    pub unsafe fn stmt_attr_u32(&mut self, attribute: StatementAttribute) -> Result<u32, Error> {
         let stmt = self.statement.as_stmt_ref();
-        stmt.row_count().into_result(&stmt).map(|count| {
-            // ODBC returns -1 in case a row count is not available
-            if count == -1 {
-                None
-            } else {
-                Some(count.try_into().unwrap())
-            }
-        })
+        stmt.attribute_u32(attribute).into_result(&stmt)
     }
 }
 
-impl<S> ResultSetMetadata for Prepared<S> where S: AsStatementRef {}
+impl<S> ResultSetMetadata for Prepared<S>
+where
+    S: AsStatementRef,
+{
+    /// Served from the cache filled by [`Prepared::cached_columns`], so repeated calls do not
+    /// query the driver again. Out of range column numbers are still passed on to the driver, in
+    /// order to obtain a meaningful diagnostic.
+    fn describe_col(
+        &mut self,
+        column_number: u16,
+        column_description: &mut ColumnDescription,
+    ) -> Result<(), Error> {
+        let columns = self.cached_columns()?;
+        if let Some(cached) = (column_number as usize)
+            .checked_sub(1)
+            .and_then(|index| columns.get(index))
+        {
+            column_description.clone_from(cached);
+            Ok(())
+        } else {
+            let stmt = self.as_stmt_ref();
+            stmt.describe_col(column_number, column_description)
+                .into_result(&stmt)
+        }
+    }
+}
 
 impl<S> AsStatementRef for Prepared<S>
 where
@@ -301,3 +504,13 @@ where
         self.statement.as_stmt_ref()
     }
 }
+
+/// Callback and SQL text to pass to [`instrumented`].
+fn split_instrumentation(
+    instrumentation: &Option<(Instrumentation, String)>,
+) -> (Option<&Instrumentation>, Option<&str>) {
+    match instrumentation {
+        Some((callback, sql)) => (Some(callback), Some(sql.as_str())),
+        None => (None, None),
+    }
+}
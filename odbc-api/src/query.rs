@@ -0,0 +1,125 @@
+/// Number of placeholders (`?`) in `query`. Ignores question marks in string literals, quoted
+/// identifiers, line comments (`--`) and block comments (`/* */`).
+///
+/// This is a `const fn`, so the `query!` macro is able to count the placeholders at compile time.
+/// All characters of interest are ASCII, so the text can be scanned byte by byte, without
+/// decoding UTF-8.
+pub const fn count_placeholders(query: &str) -> usize {
+    let mut num_placeholders = 0;
+    let mut start = 0;
+    while let Some(position) = next_placeholder(query, start) {
+        num_placeholders += 1;
+        start = position + 1;
+    }
+    num_placeholders
+}
+
+/// Byte position of the first placeholder (`?`) in `query` at or after `start`. Skips the same
+/// literals, quoted identifiers and comments as [`count_placeholders`]. `start` must not point into
+/// any of these.
+pub(crate) const fn next_placeholder(query: &str, start: usize) -> Option<usize> {
+    let bytes = query.as_bytes();
+    let len = bytes.len();
+    let mut index = start;
+    while index < len {
+        match bytes[index] {
+            b'?' => return Some(index),
+            // An escaped quote ('') within a literal just ends and immediately reopens it, so it
+            // needs no special treatment.
+            quote @ (b'\'' | b'"') => {
+                index += 1;
+                while index < len && bytes[index] != quote {
+                    index += 1;
+                }
+            }
+            b'-' if index + 1 < len && bytes[index + 1] == b'-' => {
+                while index < len && bytes[index] != b'\n' {
+                    index += 1;
+                }
+            }
+            b'/' if index + 1 < len && bytes[index + 1] == b'*' => {
+                index += 2;
+                while index < len
+                    && !(bytes[index] == b'*' && index + 1 < len && bytes[index + 1] == b'/')
+                {
+                    index += 1;
+                }
+                // Skip the `*`. The `/` is skipped below.
+                index += 1;
+            }
+            _ => (),
+        }
+        index += 1;
+    }
+    None
+}
+
+/// Executes an SQL statement using [`crate::Connection::execute`], but verifies at compile time
+/// that the number of parameters matches the number of placeholders (`?`) in the query. Question
+/// marks within quoted string literals, quoted identifiers and comments are not counted. The query
+/// must be a string literal. Nothing is checked against the database, so the types of the
+/// parameters are still up to the driver to judge at runtime.
+///
+/// Expands to a call of `execute` with a tuple of the parameters, so it returns the same as
+/// [`crate::Connection::execute`]. Requires the `query_macro` feature.
+///
+/// ```no_run
+/// use odbc_api::{query, Connection, Error, IntoParameter};
+///
+/// fn insert_birthday(conn: &Connection<'_>, name: &str, year: i16) -> Result<(), Error> {
+///     query!(
+///         conn,
+///         "INSERT INTO Birthdays (name, year) VALUES (?, ?)",
+///         &name.into_parameter(),
+///         &year
+///     )?;
+///     Ok(())
+/// }
+/// ```
+///
+/// A mismatch between placeholders and parameters is a compile time error.
+#[cfg(feature = "query_macro")]
+#[macro_export]
+macro_rules! query {
+    (@unit $param:expr) => {
+        ()
+    };
+    ($connection:expr, $query:literal $(, $param:expr)* $(,)?) => {{
+        const NUM_PARAMS: usize = <[()]>::len(&[$($crate::query!(@unit $param)),*]);
+        const _: () = assert!(
+            $crate::__count_placeholders($query) == NUM_PARAMS,
+            "Number of parameters does not match the number of placeholders in the query."
+        );
+        $connection.execute($query, ($($param,)*))
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::count_placeholders;
+
+    #[test]
+    fn count_placeholders_outside_of_literals_and_comments() {
+        let query = "SELECT a, '?', \"b?\" FROM T -- filter by ?\n\
+            WHERE b = ? /* and c = ? */ AND d = 'it''s ?' AND e = ?";
+
+        assert_eq!(2, count_placeholders(query));
+    }
+
+    #[test]
+    fn count_placeholders_in_query_without_any() {
+        assert_eq!(0, count_placeholders("SELECT 42"));
+    }
+
+    #[test]
+    fn count_placeholders_after_comment_starting_with_slash() {
+        assert_eq!(1, count_placeholders("/*/ ? */ SELECT ? /* unterminated ?"));
+    }
+
+    #[test]
+    fn count_placeholders_at_compile_time() {
+        const NUM_PLACEHOLDERS: usize = count_placeholders("SELECT ? -- ?");
+
+        assert_eq!(1, NUM_PLACEHOLDERS);
+    }
+}
@@ -3,10 +3,57 @@ use std::num::NonZeroUsize;
 use odbc_sys::SqlDataType;
 
 use crate::{
+    buffers::BufferDesc,
     handles::{slice_to_utf8, AsStatementRef, SqlChar, Statement},
     ColumnDescription, DataType, Error,
 };
 
+/// Describes how a column can be used in the `WHERE` clause of a query. See
+/// [`ResultSetMetadata::col_searchable`].
+#[derive(Clone, Copy, Hash, Debug, Eq, PartialEq)]
+pub enum Searchable {
+    /// The column can not be used in a `WHERE` clause (`SQL_PRED_NONE`).
+    None,
+    /// The column can only be used with `LIKE` in a `WHERE` clause (`SQL_PRED_CHAR`).
+    Char,
+    /// The column can be used with all comparison operators except `LIKE` (`SQL_PRED_BASIC`).
+    Basic,
+    /// The column can be used with any comparison operator (`SQL_PRED_SEARCHABLE`).
+    Searchable,
+}
+
+impl Searchable {
+    /// Construct a new instance from the value reported for `SQL_DESC_SEARCHABLE`.
+    ///
+    /// ```
+    /// use odbc_api::Searchable;
+    ///
+    /// assert_eq!(Searchable::None, Searchable::new(0));
+    /// assert_eq!(Searchable::Char, Searchable::new(1));
+    /// assert_eq!(Searchable::Basic, Searchable::new(2));
+    /// assert_eq!(Searchable::Searchable, Searchable::new(3));
+    /// ```
+    pub fn new(searchable: isize) -> Self {
+        match searchable {
+            0 => Searchable::None,
+            1 => Searchable::Char,
+            2 => Searchable::Basic,
+            3 => Searchable::Searchable,
+            other => panic!("ODBC returned invalid value for searchable column attribute: {other}"),
+        }
+    }
+
+    /// `true` if the column can be used with `LIKE`.
+    pub fn supports_like(self) -> bool {
+        matches!(self, Searchable::Char | Searchable::Searchable)
+    }
+
+    /// `true` if the column can be used with comparison operators other than `LIKE`.
+    pub fn supports_comparison(self) -> bool {
+        matches!(self, Searchable::Basic | Searchable::Searchable)
+    }
+}
+
 /// Provides Metadata of the resulting the result set. Implemented by `Cursor` types and prepared
 /// queries. Fetching metadata from a prepared query might be expensive (driver dependent), so your
 /// application should fetch the Metadata it requires from the `Cursor` if possible.
@@ -53,6 +100,37 @@ pub trait ResultSetMetadata: AsStatementRef {
         stmt.is_unsigned_column(column_number).into_result(&stmt)
     }
 
+    /// `true` if the column is an identity or auto increment column. `false` if it is not, or if it
+    /// is not a numeric type. Uses `SQL_DESC_AUTO_UNIQUE_VALUE`.
+    ///
+    /// `column_number`: Index of the column, starting at 1.
+    fn col_is_auto_increment(&mut self, column_number: u16) -> Result<bool, Error> {
+        let stmt = self.as_stmt_ref();
+        stmt.is_auto_unique_value_column(column_number)
+            .into_result(&stmt)
+    }
+
+    /// `true` if the column is treated as case sensitive for collations and comparisons. `false` if
+    /// it is not, or if it is not a character column. Uses `SQL_DESC_CASE_SENSITIVE`.
+    ///
+    /// `column_number`: Index of the column, starting at 1.
+    fn col_case_sensitive(&mut self, column_number: u16) -> Result<bool, Error> {
+        let stmt = self.as_stmt_ref();
+        stmt.is_case_sensitive_column(column_number)
+            .into_result(&stmt)
+    }
+
+    /// Describes which predicates the column can be used with in a `WHERE` clause. Uses
+    /// `SQL_DESC_SEARCHABLE`.
+    ///
+    /// `column_number`: Index of the column, starting at 1.
+    fn col_searchable(&mut self, column_number: u16) -> Result<Searchable, Error> {
+        let stmt = self.as_stmt_ref();
+        stmt.col_searchable(column_number)
+            .into_result(&stmt)
+            .map(Searchable::new)
+    }
+
     /// Size in bytes of the columns. For variable sized types this is the maximum size, excluding a
     /// terminating zero.
     ///
@@ -183,6 +261,48 @@ pub trait ResultSetMetadata: AsStatementRef {
         };
         Ok(dt)
     }
+
+    /// Number of bytes required to buffer a single row of the result set, if each column is bound
+    /// to the buffer suggested for its type by [`BufferDesc::from_data_type`]. Columns for which no
+    /// buffer is suggested, e.g. text without an upper bound, are accounted for with the size of
+    /// [`BufferDesc::LongText`].
+    ///
+    /// Useful to check the memory requirements of a columnar buffer, before allocating it. Some
+    /// drivers report huge sizes for some column types, which could otherwise lead to an out of
+    /// memory error.
+    fn total_row_buffer_size(&mut self) -> Result<usize, Error> {
+        let mut column_description = ColumnDescription::default();
+        let mut size_of_row: usize = 0;
+        for column_number in 1..=(self.num_result_cols()? as u16) {
+            self.describe_col(column_number, &mut column_description)?;
+            let buffer_desc = BufferDesc::from_data_type(
+                column_description.data_type,
+                column_description.could_be_nullable(),
+            )
+            .unwrap_or(BufferDesc::LongText);
+            size_of_row = size_of_row.saturating_add(buffer_desc.bytes_per_row());
+        }
+        Ok(size_of_row)
+    }
+
+    /// Number of bytes required to buffer `batch_size` rows of the result set. See
+    /// [`Self::total_row_buffer_size`].
+    ///
+    /// ```
+    /// use odbc_api::{Cursor, Error};
+    ///
+    /// /// Picks a batch size, so the buffer does not exceed the memory limit.
+    /// fn batch_size(cursor: &mut impl Cursor, memory_limit: usize) -> Result<usize, Error> {
+    ///     let mut batch_size = 1000;
+    ///     while batch_size > 1 && cursor.estimate_batch_memory(batch_size)? > memory_limit {
+    ///         batch_size /= 2;
+    ///     }
+    ///     Ok(batch_size)
+    /// }
+    /// ```
+    fn estimate_batch_memory(&mut self, batch_size: usize) -> Result<usize, Error> {
+        Ok(self.total_row_buffer_size()?.saturating_mul(batch_size))
+    }
 }
 
 /// Buffer sizes able to hold the display size of each column in utf-8 encoding. You may call this
@@ -211,6 +331,31 @@ pub fn utf8_display_sizes(
     Ok(it)
 }
 
+/// Buffer sizes able to hold the display size of each column in utf-16 encoding, measured in two
+/// byte code units. [`buffers::WTextRowSet::for_cursor`] will invoke this function for you.
+///
+/// # Parameters
+///
+/// * `metadata`: Used to query the display size for each column of the row set. For character
+///   data the length in characters is multiplied by 2 in order to have enough space for characters
+///   represented by surrogate pairs.
+pub fn utf16_display_sizes(
+    metadata: &mut impl ResultSetMetadata,
+) -> Result<impl Iterator<Item = Result<Option<NonZeroUsize>, Error>> + '_, Error> {
+    let num_cols: u16 = metadata.num_result_cols()?.try_into().unwrap();
+    let it = (1..(num_cols + 1)).map(move |col_index| {
+        // Ask driver for buffer length
+        let max_str_len = if let Some(encoded_len) = metadata.col_data_type(col_index)?.utf16_len()
+        {
+            Some(encoded_len)
+        } else {
+            metadata.col_display_size(col_index)?
+        };
+        Ok(max_str_len)
+    });
+    Ok(it)
+}
+
 /// An iterator calling `col_name` for each column_name and converting the result into UTF-8. See
 /// [`ResultSetMetada::column_names`].
 pub struct ColumnNamesIt<'c, C: ?Sized> {
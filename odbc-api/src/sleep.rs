@@ -1,12 +1,16 @@
 use std::future::Future;
 
+#[cfg(feature = "async-io")]
+use std::time::Duration;
+
 use crate::handles::SqlResult;
 
 /// Governs the behaviour of of polling in async functions.
 ///
 /// There is a generic implementation for any function retuning a future. This allows e.g. to pass
 /// `|| tokio::time::sleep(Duration::from_millis(50))` to functions expecting sleep. That is if
-/// you use `tokio` as your async runtime, of course.
+/// you use `tokio` as your async runtime, of course. If you use `async-std` or `smol` you can
+/// activate the `async-io` feature and use [`crate::sleep_with`].
 pub trait Sleep {
     type Poll: Future;
 
@@ -26,6 +30,30 @@ where
     }
 }
 
+/// Creates a [`Sleep`] implementation waiting for `duration` in between polls. It is based on the
+/// timers of `async-io`, which drive `async-std` and `smol`. It does not block the system thread and
+/// does not depend on `tokio`.
+///
+/// # Example
+///
+/// ```no_run
+/// use odbc_api::{sleep_with, Connection, Error};
+/// use std::time::Duration;
+///
+/// async fn insert_answer(conn: &Connection<'_>) -> Result<(), Error> {
+///     let sleep = sleep_with(Duration::from_millis(50));
+///     conn.execute_polling("INSERT INTO Answers (a) VALUES (42)", (), sleep).await?;
+///     Ok(())
+/// }
+/// ```
+///
+/// The returned closure is `Copy`, so it can be passed by value or by `&mut` reference
+/// repeatedly, e.g. to both `execute_polling` and subsequent calls to `fetch`.
+#[cfg(feature = "async-io")]
+pub fn sleep_with(duration: Duration) -> impl Fn() -> async_io::Timer + Copy {
+    move || async_io::Timer::after(duration)
+}
+
 pub async fn wait_for<F, O>(mut f: F, sleep: &mut impl Sleep) -> SqlResult<O>
 where
     F: FnMut() -> SqlResult<O>,
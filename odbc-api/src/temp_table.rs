@@ -0,0 +1,39 @@
+use std::thread::panicking;
+
+use crate::Connection;
+
+/// A temporary table created with [`Connection::create_temp_table`]. The table is dropped, once
+/// this guard goes out of scope.
+pub struct TempTable<'c> {
+    connection: &'c Connection<'c>,
+    /// Quoted name of the table.
+    name: String,
+}
+
+impl<'c> TempTable<'c> {
+    pub(crate) fn new(connection: &'c Connection<'c>, name: String) -> Self {
+        Self { connection, name }
+    }
+
+    /// Quoted name of the table, ready to be used in SQL statements. On Microsoft SQL Server this
+    /// includes the leading `#` marking the table as temporary.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl Drop for TempTable<'_> {
+    fn drop(&mut self) {
+        let drop_table = format!("DROP TABLE IF EXISTS {}", self.name);
+        if let Err(e) = self.connection.execute(&drop_table, ()) {
+            // Avoid panicking, if we already have a panic. We don't want to mask the original
+            // error.
+            if !panicking() {
+                panic!(
+                    "Unexpected error dropping temporary table {}: {e:?}",
+                    self.name
+                )
+            }
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use crate::{parameter::VarWCharBox, IntoParameter};
+
+/// Newtype wrapper intend to be used around `String`s or `str` slices to bind them always as wide
+/// (UTF-16) text independent of wether the `narrow` feature is set or not. The text is transcoded
+/// into UTF-16 and bound as `SQL_C_WCHAR`. Useful to insert non-ASCII text into `NVARCHAR` columns,
+/// if the narrow encoding of the data source is not UTF-8.
+///
+/// ```
+/// use odbc_api::{Connection, Error, IntoParameter, Wide};
+///
+/// fn insert_greeting(conn: &Connection<'_>) -> Result<(), Error> {
+///     conn.execute(
+///         "INSERT INTO Greetings (text) VALUES (?)",
+///         &Wide("您好").into_parameter(),
+///     )?;
+///     Ok(())
+/// }
+/// ```
+pub struct Wide<T>(pub T);
+
+impl IntoParameter for Wide<&str> {
+    type Parameter = VarWCharBox;
+
+    fn into_parameter(self) -> Self::Parameter {
+        VarWCharBox::from_str_slice(self.0)
+    }
+}
+
+impl IntoParameter for Wide<Option<&str>> {
+    type Parameter = VarWCharBox;
+
+    fn into_parameter(self) -> Self::Parameter {
+        match self.0 {
+            Some(str) => Wide(str).into_parameter(),
+            None => VarWCharBox::null(),
+        }
+    }
+}
+
+impl IntoParameter for Option<Wide<&str>> {
+    type Parameter = VarWCharBox;
+
+    fn into_parameter(self) -> Self::Parameter {
+        match self {
+            Some(str) => Wide(str.0).into_parameter(),
+            None => VarWCharBox::null(),
+        }
+    }
+}
+
+impl IntoParameter for Wide<String> {
+    type Parameter = VarWCharBox;
+
+    fn into_parameter(self) -> Self::Parameter {
+        VarWCharBox::from_str_slice(&self.0)
+    }
+}
+
+impl IntoParameter for Wide<Option<String>> {
+    type Parameter = VarWCharBox;
+
+    fn into_parameter(self) -> Self::Parameter {
+        match self.0 {
+            Some(str) => Wide(str).into_parameter(),
+            None => VarWCharBox::null(),
+        }
+    }
+}
+
+impl IntoParameter for Option<Wide<String>> {
+    type Parameter = VarWCharBox;
+
+    fn into_parameter(self) -> Self::Parameter {
+        match self {
+            Some(str) => Wide(str.0).into_parameter(),
+            None => VarWCharBox::null(),
+        }
+    }
+}
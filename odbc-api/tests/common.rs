@@ -1,3 +1,6 @@
+// Queries are passed as `&String` throughout the tests, which is fine for `impl AsRef<str>`.
+#![allow(clippy::needless_borrows_for_generic_args)]
+
 use std::iter::repeat;
 
 use odbc_api::{
@@ -1,8 +1,11 @@
+// Queries are passed as `&String` throughout the tests, which is fine for `impl AsRef<str>`.
+#![allow(clippy::needless_borrows_for_generic_args)]
+
 mod common;
 mod connection_strings;
 
 use stdext::function_name;
-use sys::{CDataType, Numeric, Pointer, SqlDataType, Timestamp, NULL_DATA};
+use sys::{CDataType, Date, Numeric, Pointer, SqlDataType, Timestamp, NULL_DATA};
 use tempfile::NamedTempFile;
 use test_case::test_case;
 
@@ -15,27 +18,36 @@ use connection_strings::{
 use odbc_api::Fetch;
 use odbc_api::{
     buffers::{
-        BufferDesc, ColumnarAnyBuffer, ColumnarBuffer, Indicator, Item, RowVec, TextColumn,
-        TextRowSet,
+        AnyBuffer, BufferDesc, ColumnarAnyBuffer, ColumnarBuffer, Indicator, Item,
+        NullableSliceMut, RowVec, StringRowVec, TextColumn, TextRowSet, WTextRowSet,
     },
     decimal_text_to_i128, environment,
-    handles::{CData, CDataMut, OutputStringBuffer, ParameterDescription, Statement},
+    handles::{
+        AsStatementRef, CData, CDataMut, DelayedInput, HasDataType, OutputStringBuffer,
+        ParameterDescription, SqlResult, SqlText, State, Statement,
+    },
     parameter::{
-        Blob, BlobRead, BlobSlice, InputParameter, VarBinaryArray, VarCharArray, VarCharSlice,
-        VarCharSliceMut, VarWCharArray, WithDataType,
+        maybe, BinaryParam, Blob, BlobRead, BlobSlice, InputParameter, VarBinaryArray,
+        VarCharArray, VarCharSlice, VarCharSliceMut, VarWCharArray, WithDataType,
     },
-    sys, Bit, ColumnDescription, ConcurrentBlockCursor, Connection, ConnectionOptions, Cursor,
-    DataType, Error, InOut, IntoParameter, Narrow, Nullability, Nullable, Out, Preallocated,
-    ResultSetMetadata, RowSetBuffer, TruncationInfo, U16Str, U16String,
+    sys, Bit, ColumnDescription, ColumnarBulkInserter, Concurrency, ConcurrentBlockCursor,
+    Connection, ConnectionOptions, ConnectionStringBuilder, Cursor, CursorImpl, CursorType,
+    DataSourceInfo, DataType, Dbms, Environment, Error, ExecPhase, ExpectCursor, InOut, InsertRow,
+    IntoParameter, Narrow, NextResultSetError, Nullability, Nullable, Out, Preallocated,
+    ResultSetMetadata, RowSetBuffer, Searchable, StatementOptions, TruncationInfo, U16Str,
+    U16String, Wide,
 };
 
 use std::{
+    collections::HashMap,
     ffi::CString,
     io::{self, Write},
     iter,
     num::NonZeroUsize,
     ptr::null_mut,
-    str, thread,
+    str,
+    sync::{Arc, Mutex},
+    thread,
     time::Duration,
 };
 
@@ -72,6 +84,38 @@ macro_rules! table_name {
     };
 }
 
+#[test]
+fn environment_returns_same_instance() {
+    let first = environment().unwrap();
+    let second = environment().unwrap();
+
+    assert!(std::ptr::eq(first, second));
+}
+
+/// Use the generic accessors for connection attributes, with an attribute the crate also offers a
+/// dedicated setter for.
+#[test_case(MSSQL; "Microsoft SQL Server")]
+fn set_and_get_login_timeout_via_generic_connection_attributes(profile: &Profile) {
+    // Given
+    let conn = profile.connection().unwrap();
+    let login_timeout = sys::ConnectionAttribute::LoginTimeout as i32;
+
+    // When
+    unsafe { conn.set_connect_attr_u32(login_timeout, 42) }.unwrap();
+    let actual = unsafe { conn.connect_attr_u32(login_timeout) }.unwrap();
+
+    // Then
+    assert_eq!(42, actual);
+}
+
+#[test]
+fn driver_manager_info() {
+    let info = environment().unwrap().driver_manager_info().unwrap();
+
+    assert!(!info.name.is_empty());
+    assert!(!info.version.is_empty());
+}
+
 #[test]
 fn bogus_connection_string() {
     // When
@@ -96,6 +140,64 @@ fn bogus_connection_string() {
     };
 }
 
+#[test]
+fn connect_with_misspelled_driver_mentions_installed_drivers() {
+    // Given a connection string with a typo in the driver name
+    let connection_string = MSSQL_CONNECTION.replace("SQL Server", "SQL Servr");
+
+    // When
+    let result = environment()
+        .unwrap()
+        .connect_with_connection_string(&connection_string, ConnectionOptions::default());
+
+    // Then
+    let error = result.err().unwrap();
+    assert!(matches!(error, Error::DriverNotInstalled { .. }));
+    let message = error.to_string();
+    assert!(message.contains("Did you mean 'ODBC Driver 18 for SQL Server'?"));
+    assert!(message.contains("Installed drivers:"));
+}
+
+#[test]
+fn interleave_fetches_of_two_cursors_with_mars() {
+    // Given a table and a connection with Multiple Active Result Sets enabled
+    let table_name = table_name!();
+    let (_conn, table) = Given::new(&table_name)
+        .column_types(&["INTEGER"])
+        .values_by_column(&[&[Some("1"), Some("2"), Some("3")]])
+        .build(MSSQL)
+        .unwrap();
+    let connection_string = format!("{MSSQL_CONNECTION}MARS_Connection=yes;");
+    let conn = environment()
+        .unwrap()
+        .connect_with_connection_string(&connection_string, ConnectionOptions::default())
+        .unwrap();
+
+    // When opening two cursors at once and fetching from them in turns
+    let query = table.sql_all_ordered_by_id();
+    let mut first = conn.execute(&query, ()).unwrap().unwrap();
+    let mut second = conn.execute(&query, ()).unwrap().unwrap();
+    fn next_value(cursor: &mut impl Cursor) -> i32 {
+        let mut value = 0i32;
+        cursor
+            .next_row()
+            .unwrap()
+            .unwrap()
+            .get_data(1, &mut value)
+            .unwrap();
+        value
+    }
+    let values = [
+        next_value(&mut first),
+        next_value(&mut second),
+        next_value(&mut first),
+        next_value(&mut second),
+    ];
+
+    // Then
+    assert_eq!([1, 1, 2, 2], values);
+}
+
 #[test_case(MSSQL; "Microsoft SQL Server")]
 #[test_case(MARIADB; "Maria DB")]
 #[test_case(SQLITE_3; "SQLite 3")]
@@ -105,6 +207,26 @@ fn connect_to_db(profile: &Profile) {
     assert!(!conn.is_dead().unwrap())
 }
 
+/// Parsing the connection strings of the test profiles and building them again must still allow to
+/// connect.
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+#[test_case(POSTGRES; "PostgreSQL")]
+fn connect_with_connection_string_builder(profile: &Profile) {
+    // Given
+    let builder = ConnectionStringBuilder::parse(profile.connection_string).unwrap();
+
+    // When
+    let conn = environment()
+        .unwrap()
+        .connect_with_builder(&builder, ConnectionOptions::default())
+        .unwrap();
+
+    // Then
+    assert!(!conn.is_dead().unwrap())
+}
+
 #[test_case(MSSQL, 4096; "Microsoft SQL Server")]
 #[test_case(MARIADB, 8192; "Maria DB")]
 #[test_case(SQLITE_3, 16384; "SQLite 3")]
@@ -375,6 +497,41 @@ fn connection_and_error_implements_std_error() {
     assert!(std_error.source().is_none());
 }
 
+/// The `id` column created by the test setup is an identity / auto increment column.
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+fn col_is_auto_increment(profile: &Profile) {
+    let table_name = table_name!();
+    let (conn, _table) = Given::new(&table_name)
+        .column_types(&["INT"])
+        .build(profile)
+        .unwrap();
+
+    let sql = format!("SELECT id, a FROM {table_name};");
+    let mut cursor = conn.execute(&sql, ()).unwrap().unwrap();
+
+    assert!(cursor.col_is_auto_increment(1).unwrap());
+    assert!(!cursor.col_is_auto_increment(2).unwrap());
+}
+
+#[test]
+fn col_case_sensitive_and_searchable_mssql() {
+    let table_name = table_name!();
+    let (conn, _table) = Given::new(&table_name)
+        .column_types(&["VARCHAR(255)"])
+        .build(MSSQL)
+        .unwrap();
+
+    let sql = format!("SELECT id, a FROM {table_name};");
+    let mut cursor = conn.execute(&sql, ()).unwrap().unwrap();
+
+    // Integers are not character columns, so they are not case sensitive.
+    assert!(!cursor.col_case_sensitive(1).unwrap());
+    let searchable = cursor.col_searchable(2).unwrap();
+    assert_eq!(Searchable::Searchable, searchable);
+    assert!(searchable.supports_like());
+}
+
 #[test_case(MSSQL; "Microsoft SQL Server")]
 #[test_case(MARIADB; "Maria DB")]
 #[test_case(SQLITE_3; "SQLite 3")]
@@ -729,146 +886,482 @@ fn upper_limit_for_varchar_max(profile: &Profile, large_text_type: &'static str)
     );
 }
 
-/// Bind a columnar buffer to a BINARY(5) column and fetch data.
-#[test_case(MSSQL; "Microsoft SQL Server")]
-// #[test_case(MARIADB; "Maria DB")] // different convert syntax
-// #[test_case(SQLITE_3; "SQLite 3")]
-fn columnar_fetch_binary(profile: &Profile) {
-    // Setup
+#[test_case(MSSQL, "VARCHAR(max)"; "Microsoft SQL Server")]
+#[test_case(MARIADB, "TEXT"; "Maria DB")]
+#[test_case(SQLITE_3, "TEXT"; "SQLite 3")]
+#[test_case(POSTGRES, "TEXT"; "PostgreSQL")]
+fn fetch_long_text_without_max_str_len(profile: &Profile, large_text_type: &'static str) {
+    // Given a value larger than the chunk bound for long text
     let table_name = table_name!();
+    let types = [large_text_type];
     let (conn, table) = Given::new(&table_name)
-        .column_types(&["BINARY(5)"])
+        .column_types(&types)
         .build(profile)
         .unwrap();
-    conn.execute(
-        &format!(
-            "INSERT INTO {table_name} (a) Values \
-        (CONVERT(Binary(5), 'Hello')),\
-        (CONVERT(Binary(5), 'World')),\
-        (NULL)"
-        ),
-        (),
-    )
-    .unwrap();
+    let long_text = "a".repeat(2000);
+    conn.execute(&table.sql_insert(), &"Hello".into_parameter())
+        .unwrap();
+    conn.execute(&table.sql_insert(), &long_text.as_str().into_parameter())
+        .unwrap();
+    conn.execute(&table.sql_insert(), &None::<&str>.into_parameter())
+        .unwrap();
 
-    // Retrieve values
-    let mut cursor = conn
+    // When
+    let cursor = conn
         .execute(&table.sql_all_ordered_by_id(), ())
         .unwrap()
         .unwrap();
-    let data_type = cursor.col_data_type(1).unwrap();
-    assert_eq!(
-        DataType::Binary {
-            length: NonZeroUsize::new(5)
-        },
-        data_type
-    );
-    let buffer_desc = BufferDesc::from_data_type(data_type, true).unwrap();
-    assert_eq!(BufferDesc::Binary { length: 5 }, buffer_desc);
-    let row_set_buffer = ColumnarAnyBuffer::try_from_descs(10, iter::once(buffer_desc)).unwrap();
-    let mut cursor = cursor.bind_buffer(row_set_buffer).unwrap();
-    let batch = cursor.fetch().unwrap().unwrap();
-    let mut col_it = batch.column(0).as_bin_view().unwrap().iter();
-    assert_eq!(Some(&b"Hello"[..]), col_it.next().unwrap());
-    assert_eq!(Some(&b"World"[..]), col_it.next().unwrap());
-    assert_eq!(Some(None), col_it.next()); // Expecting NULL
-    assert_eq!(None, col_it.next()); // Expecting iterator end.
+    let buffer = ColumnarAnyBuffer::from_descs(10, [BufferDesc::LongText]);
+    let mut cursor = cursor.bind_buffer(buffer).unwrap();
+    let batch = cursor.fetch_with_truncation_check(true).unwrap().unwrap();
+
+    // Then
+    let texts = batch.column(0).as_text_view().unwrap();
+    assert_eq!(Some(&b"Hello"[..]), texts.get(0));
+    assert_eq!(Some(long_text.as_bytes()), texts.get(1));
+    assert_eq!(None, texts.get(2));
+}
+
+/// Fetching the remainder of a long value moves the cursor within the row set. The second long
+/// column must not pick up values from the row the first one left the cursor at.
+#[test_case(MSSQL, "VARCHAR(max)"; "Microsoft SQL Server")]
+#[test_case(MARIADB, "TEXT"; "Maria DB")]
+#[test_case(SQLITE_3, "TEXT"; "SQLite 3")]
+#[test_case(POSTGRES, "TEXT"; "PostgreSQL")]
+fn fetch_two_long_text_columns_with_several_rows(profile: &Profile, large_text_type: &'static str) {
+    // Given long values in different rows of two columns
+    let table_name = table_name!();
+    let long_a = "a".repeat(2000);
+    let long_b = "b".repeat(3000);
+    let long_c = "c".repeat(1500);
+    let first_column = [Some("1"), Some(long_a.as_str()), Some("3")];
+    let second_column = [Some(long_b.as_str()), Some("2"), Some(long_c.as_str())];
+    let values: [&[Option<&str>]; 2] = [&first_column, &second_column];
+    let types = [large_text_type, large_text_type];
+    let (conn, table) = Given::new(&table_name)
+        .column_types(&types)
+        .values_by_column(&values)
+        .build(profile)
+        .unwrap();
+
+    // When
+    let cursor = conn
+        .execute(&table.sql_all_ordered_by_id(), ())
+        .unwrap()
+        .unwrap();
+    let buffer = ColumnarAnyBuffer::from_descs(10, [BufferDesc::LongText, BufferDesc::LongText]);
+    let mut cursor = cursor.bind_buffer(buffer).unwrap();
+    let batch = cursor.fetch_with_truncation_check(true).unwrap().unwrap();
+
+    // Then
+    assert_eq!(3, batch.num_rows());
+    let first = batch.column(0).as_text_view().unwrap();
+    assert_eq!(Some(&b"1"[..]), first.get(0));
+    assert_eq!(Some(long_a.as_bytes()), first.get(1));
+    assert_eq!(Some(&b"3"[..]), first.get(2));
+    let second = batch.column(1).as_text_view().unwrap();
+    assert_eq!(Some(long_b.as_bytes()), second.get(0));
+    assert_eq!(Some(&b"2"[..]), second.get(1));
+    assert_eq!(Some(long_c.as_bytes()), second.get(2));
 }
 
-/// Bind a columnar buffer to a DATETIME2 column and fetch data.
 #[test_case(MSSQL; "Microsoft SQL Server")]
-// #[test_case(MARIADB; "Maria DB")] No DATEIME2 type
+#[test_case(MARIADB; "Maria DB")]
 #[test_case(SQLITE_3; "SQLite 3")]
-fn columnar_fetch_timestamp(profile: &Profile) {
+#[test_case(POSTGRES; "PostgreSQL")]
+fn fetch_into_large_zeroed_buffer(profile: &Profile) {
+    // Given
     let table_name = table_name!();
-    let (conn, _table) = Given::new(&table_name)
-        .column_types(&["DATETIME2(3)"])
+    let (conn, table) = Given::new(&table_name)
+        .column_types(&["INTEGER", "VARCHAR(50)", "DATE"])
+        .values_by_column(&[
+            &[Some("1"), None],
+            &[Some("Hello"), None],
+            &[Some("2024-02-29"), None],
+        ])
         .build(profile)
         .unwrap();
-    conn.execute(
-        &format!(
-            "INSERT INTO {table_name} (a) Values \
-        ({{ ts '2021-03-20 15:24:12.12' }}),\
-        ({{ ts '2020-03-20 15:24:12' }}),\
-        ({{ ts '1970-01-01 00:00:00' }}),\
-        (NULL)"
-        ),
-        (),
-    )
-    .unwrap();
 
-    // Retrieve values
-    let mut cursor = conn
-        .execute(&format!("SELECT a FROM {table_name} ORDER BY Id"), ())
+    // When fetching into a buffer far larger than the result set
+    let cursor = conn
+        .execute(&table.sql_all_ordered_by_id(), ())
         .unwrap()
         .unwrap();
-    let data_type = cursor.col_data_type(1).unwrap();
-    assert_eq!(DataType::Timestamp { precision: 3 }, data_type);
-    let buffer_desc = BufferDesc::from_data_type(data_type, true).unwrap();
-    assert_eq!(BufferDesc::Timestamp { nullable: true }, buffer_desc);
-    let row_set_buffer = ColumnarAnyBuffer::try_from_descs(10, iter::once(buffer_desc)).unwrap();
-    let mut cursor = cursor.bind_buffer(row_set_buffer).unwrap();
+    let buffer = ColumnarAnyBuffer::try_from_descs(
+        10_000,
+        [
+            BufferDesc::I32 { nullable: true },
+            BufferDesc::Text { max_str_len: 4096 },
+            BufferDesc::Date { nullable: true },
+        ],
+    )
+    .unwrap();
+    let mut cursor = cursor.bind_buffer(buffer).unwrap();
     let batch = cursor.fetch().unwrap().unwrap();
-    let mut col_it = batch.column(0).as_nullable_slice().unwrap();
-    assert_eq!(
-        Some(&Timestamp {
-            year: 2021,
-            month: 3,
-            day: 20,
-            hour: 15,
-            minute: 24,
-            second: 12,
-            fraction: 120_000_000,
-        }),
-        col_it.next().unwrap()
-    );
-    assert_eq!(
-        Some(&Timestamp {
-            year: 2020,
-            month: 3,
-            day: 20,
-            hour: 15,
-            minute: 24,
-            second: 12,
-            fraction: 0,
-        }),
-        col_it.next().unwrap()
-    );
+
+    // Then
+    assert_eq!(2, batch.num_rows());
+    let ints: Vec<_> = batch
+        .column(0)
+        .as_nullable_slice::<i32>()
+        .unwrap()
+        .collect();
+    assert_eq!(vec![Some(&1), None], ints);
+    let texts = batch.column(1).as_text_view().unwrap();
+    assert_eq!(Some(&b"Hello"[..]), texts.get(0));
+    assert_eq!(None, texts.get(1));
+    let dates: Vec<_> = batch
+        .column(2)
+        .as_nullable_slice::<Date>()
+        .unwrap()
+        .collect();
     assert_eq!(
-        Some(&Timestamp {
-            year: 1970,
-            month: 1,
-            day: 1,
-            hour: 0,
-            minute: 0,
-            second: 0,
-            fraction: 0,
-        }),
-        col_it.next().unwrap()
+        vec![
+            Some(&Date {
+                year: 2024,
+                month: 2,
+                day: 29
+            }),
+            None
+        ],
+        dates
     );
-    assert_eq!(Some(None), col_it.next()); // Expecting NULL
-    assert_eq!(None, col_it.next()); // Expecting iterator end.
 }
 
-/// Insert values into a DATETIME2 column using a columnar buffer
 #[test_case(MSSQL; "Microsoft SQL Server")]
-// #[test_case(MARIADB; "Maria DB")] No DATEIME2 type
-// #[test_case(SQLITE_3; "SQLite 3")] default precision of 3 instead 7
-fn columnar_insert_timestamp(profile: &Profile) {
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+#[test_case(POSTGRES; "PostgreSQL")]
+fn wide_text_row_set_for_cursor(profile: &Profile) {
+    // Given
     let table_name = table_name!();
-    // Setup
     let (conn, table) = Given::new(&table_name)
-        .column_types(&["DATETIME2"])
+        .column_types(&["VARCHAR(20)", "INTEGER"])
+        .values_by_column(&[&[Some("Grüße"), None], &[Some("42"), Some("5")]])
         .build(profile)
         .unwrap();
 
-    // Fill buffer with values
-    let desc = BufferDesc::Timestamp { nullable: true };
-    let prepared = conn.prepare(&table.sql_insert()).unwrap();
-    let mut prebound = prepared.into_column_inserter(10, [desc]).unwrap();
+    // When
+    let mut cursor = conn
+        .execute(table.sql_all_ordered_by_id(), ())
+        .unwrap()
+        .unwrap();
+    let text_buffer = WTextRowSet::for_cursor(10, &mut cursor, Some(50)).unwrap();
+    let mut cursor = cursor.bind_buffer(text_buffer).unwrap();
+    let batch = cursor.fetch().unwrap().unwrap();
 
-    // Input values to insert. Note that the last element has > 5 chars and is going to trigger a
-    // reallocation of the underlying buffer.
+    // Then
+    assert_eq!(2, batch.num_rows());
+    assert_eq!(Some("Grüße".to_string()), batch.at_as_string(0, 0));
+    assert_eq!(
+        Some(U16String::from_str("Grüße").as_ustr()),
+        batch.at_as_u16str(0, 0)
+    );
+    assert_eq!(Some("42".to_string()), batch.at_as_string(1, 0));
+    assert_eq!(None, batch.at_as_string(0, 1));
+    assert_eq!(Some("5".to_string()), batch.at_as_string(1, 1));
+}
+
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+#[test_case(POSTGRES; "PostgreSQL")]
+fn text_row_set_for_cursor_with_names(profile: &Profile) {
+    // Given
+    let table_name = table_name!();
+    let (conn, _table) = Given::new(&table_name)
+        .column_types(&["VARCHAR(20)", "INTEGER"])
+        .values_by_column(&[&[Some("Hello")], &[Some("42")]])
+        .build(profile)
+        .unwrap();
+
+    // When
+    let mut cursor = conn
+        .execute(&format!("SELECT a, b FROM {table_name}"), ())
+        .unwrap()
+        .unwrap();
+    let (text_buffer, names) =
+        TextRowSet::for_cursor_with_names(10, &mut cursor, Some(50)).unwrap();
+    let expected_names: Vec<String> = cursor
+        .column_names()
+        .unwrap()
+        .collect::<Result<_, _>>()
+        .unwrap();
+    let mut cursor = cursor.bind_buffer(text_buffer).unwrap();
+    let batch = cursor.fetch().unwrap().unwrap();
+
+    // Then
+    assert_eq!(expected_names, names);
+    assert_eq!(vec!["a".to_string(), "b".to_string()], names);
+    assert_eq!(Some("Hello"), batch.at_as_str(0, 0).unwrap());
+    assert_eq!(Some("42"), batch.at_as_str(1, 0).unwrap());
+}
+
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+#[test_case(POSTGRES; "PostgreSQL")]
+fn text_row_set_for_cursor_with_desc(profile: &Profile) {
+    // Given
+    let table_name = table_name!();
+    let (conn, _table) = Given::new(&table_name)
+        .column_types(&["VARCHAR(20)", "INTEGER"])
+        .values_by_column(&[&[Some("Hello")], &[Some("42")]])
+        .build(profile)
+        .unwrap();
+    let mut cursor = conn
+        .execute(&format!("SELECT a, b FROM {table_name}"), ())
+        .unwrap()
+        .unwrap();
+    let mut descriptions = vec![ColumnDescription::default(), ColumnDescription::default()];
+    cursor.describe_col(1, &mut descriptions[0]).unwrap();
+    cursor.describe_col(2, &mut descriptions[1]).unwrap();
+
+    // When
+    let text_buffer =
+        TextRowSet::for_cursor_with_desc(10, &mut cursor, &descriptions, Some(50)).unwrap();
+    let mut cursor = cursor.bind_buffer(text_buffer).unwrap();
+    let batch = cursor.fetch().unwrap().unwrap();
+
+    // Then
+    assert_eq!(Some("Hello"), batch.at_as_str(0, 0).unwrap());
+    assert_eq!(Some("42"), batch.at_as_str(1, 0).unwrap());
+}
+
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+#[test_case(POSTGRES; "PostgreSQL")]
+fn text_row_set_for_cursor_with_too_few_descriptions(profile: &Profile) {
+    // Given
+    let table_name = table_name!();
+    let (conn, _table) = Given::new(&table_name)
+        .column_types(&["VARCHAR(20)", "INTEGER"])
+        .build(profile)
+        .unwrap();
+    let mut cursor = conn
+        .execute(&format!("SELECT a, b FROM {table_name}"), ())
+        .unwrap()
+        .unwrap();
+    let mut descriptions = vec![ColumnDescription::default()];
+    cursor.describe_col(1, &mut descriptions[0]).unwrap();
+
+    // When
+    let result = TextRowSet::for_cursor_with_desc(10, &mut cursor, &descriptions, Some(50));
+
+    // Then
+    assert!(matches!(
+        result,
+        Err(Error::ColumnDescriptionCountMismatch {
+            num_columns: 2,
+            num_descriptions: 1
+        })
+    ));
+}
+
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+#[test_case(POSTGRES; "PostgreSQL")]
+fn shrink_text_row_set_after_fetch(profile: &Profile) {
+    // Given short values, fetched into a buffer with a large maximum string length
+    let table_name = table_name!();
+    let (conn, table) = Given::new(&table_name)
+        .column_types(&["VARCHAR(1000)"])
+        .values_by_column(&[&[Some("a"), None, Some("bcd")]])
+        .build(profile)
+        .unwrap();
+    let cursor = conn
+        .execute(&table.sql_all_ordered_by_id(), ())
+        .unwrap()
+        .unwrap();
+    let mut buffer = TextRowSet::from_max_str_lens(10, [1000]).unwrap();
+    let mut block_cursor = cursor.bind_buffer(&mut buffer).unwrap();
+    block_cursor.fetch().unwrap().unwrap();
+    drop(block_cursor);
+
+    // When
+    buffer.shrink_to_fit();
+
+    // Then
+    assert_eq!(3, buffer.max_len(0));
+    assert_eq!(3, buffer.num_rows());
+    assert_eq!(Some("a"), buffer.at_as_str(0, 0).unwrap());
+    assert_eq!(None, buffer.at_as_str(0, 1).unwrap());
+    assert_eq!(Some("bcd"), buffer.at_as_str(0, 2).unwrap());
+}
+
+/// Bind a columnar buffer to a BINARY(5) column and fetch data.
+#[test_case(MSSQL; "Microsoft SQL Server")]
+// #[test_case(MARIADB; "Maria DB")] // different convert syntax
+// #[test_case(SQLITE_3; "SQLite 3")]
+fn columnar_fetch_binary(profile: &Profile) {
+    // Setup
+    let table_name = table_name!();
+    let (conn, table) = Given::new(&table_name)
+        .column_types(&["BINARY(5)"])
+        .build(profile)
+        .unwrap();
+    conn.execute(
+        &format!(
+            "INSERT INTO {table_name} (a) Values \
+        (CONVERT(Binary(5), 'Hello')),\
+        (CONVERT(Binary(5), 'World')),\
+        (NULL)"
+        ),
+        (),
+    )
+    .unwrap();
+
+    // Retrieve values
+    let mut cursor = conn
+        .execute(&table.sql_all_ordered_by_id(), ())
+        .unwrap()
+        .unwrap();
+    let data_type = cursor.col_data_type(1).unwrap();
+    assert_eq!(
+        DataType::Binary {
+            length: NonZeroUsize::new(5)
+        },
+        data_type
+    );
+    let buffer_desc = BufferDesc::from_data_type(data_type, true).unwrap();
+    assert_eq!(BufferDesc::Binary { length: 5 }, buffer_desc);
+    let row_set_buffer = ColumnarAnyBuffer::try_from_descs(10, iter::once(buffer_desc)).unwrap();
+    let mut cursor = cursor.bind_buffer(row_set_buffer).unwrap();
+    let batch = cursor.fetch().unwrap().unwrap();
+    let mut col_it = batch.column(0).as_bin_view().unwrap().iter();
+    assert_eq!(Some(&b"Hello"[..]), col_it.next().unwrap());
+    assert_eq!(Some(&b"World"[..]), col_it.next().unwrap());
+    assert_eq!(Some(None), col_it.next()); // Expecting NULL
+    assert_eq!(None, col_it.next()); // Expecting iterator end.
+}
+
+/// Bind a columnar buffer to a DATETIME2 column and fetch data.
+#[test_case(MSSQL; "Microsoft SQL Server")]
+// #[test_case(MARIADB; "Maria DB")] No DATEIME2 type
+#[test_case(SQLITE_3; "SQLite 3")]
+fn columnar_fetch_timestamp(profile: &Profile) {
+    let table_name = table_name!();
+    let (conn, _table) = Given::new(&table_name)
+        .column_types(&["DATETIME2(3)"])
+        .build(profile)
+        .unwrap();
+    conn.execute(
+        &format!(
+            "INSERT INTO {table_name} (a) Values \
+        ({{ ts '2021-03-20 15:24:12.12' }}),\
+        ({{ ts '2020-03-20 15:24:12' }}),\
+        ({{ ts '1970-01-01 00:00:00' }}),\
+        (NULL)"
+        ),
+        (),
+    )
+    .unwrap();
+
+    // Retrieve values
+    let mut cursor = conn
+        .execute(&format!("SELECT a FROM {table_name} ORDER BY Id"), ())
+        .unwrap()
+        .unwrap();
+    let data_type = cursor.col_data_type(1).unwrap();
+    assert_eq!(DataType::Timestamp { precision: 3 }, data_type);
+    let buffer_desc = BufferDesc::from_data_type(data_type, true).unwrap();
+    assert_eq!(BufferDesc::Timestamp { nullable: true }, buffer_desc);
+    let row_set_buffer = ColumnarAnyBuffer::try_from_descs(10, iter::once(buffer_desc)).unwrap();
+    let mut cursor = cursor.bind_buffer(row_set_buffer).unwrap();
+    let batch = cursor.fetch().unwrap().unwrap();
+    let mut col_it = batch.column(0).as_nullable_slice().unwrap();
+    assert_eq!(
+        Some(&Timestamp {
+            year: 2021,
+            month: 3,
+            day: 20,
+            hour: 15,
+            minute: 24,
+            second: 12,
+            fraction: 120_000_000,
+        }),
+        col_it.next().unwrap()
+    );
+    assert_eq!(
+        Some(&Timestamp {
+            year: 2020,
+            month: 3,
+            day: 20,
+            hour: 15,
+            minute: 24,
+            second: 12,
+            fraction: 0,
+        }),
+        col_it.next().unwrap()
+    );
+    assert_eq!(
+        Some(&Timestamp {
+            year: 1970,
+            month: 1,
+            day: 1,
+            hour: 0,
+            minute: 0,
+            second: 0,
+            fraction: 0,
+        }),
+        col_it.next().unwrap()
+    );
+    assert_eq!(Some(None), col_it.next()); // Expecting NULL
+    assert_eq!(None, col_it.next()); // Expecting iterator end.
+}
+
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+#[test_case(POSTGRES; "PostgreSQL")]
+fn execute_many_times_mutating_bound_input_buffers(profile: &Profile) {
+    // Given
+    let table_name = table_name!();
+    let (conn, table) = Given::new(&table_name)
+        .column_types(&["INTEGER"])
+        .build(profile)
+        .unwrap();
+    let mut prepared = conn.prepare(&table.sql_insert()).unwrap();
+
+    // When
+    let mut params = prepared
+        .bind_input_buffers([BufferDesc::I32 { nullable: false }])
+        .unwrap();
+    for i in 0..1000 {
+        params.column_mut(0).as_slice::<i32>().unwrap()[0] = i;
+        params.execute().unwrap();
+    }
+
+    // Then
+    let cursor = conn
+        .execute(&format!("SELECT COUNT(*), SUM(a) FROM {table_name}"), ())
+        .unwrap()
+        .unwrap();
+    let actual = cursor_to_string(cursor);
+    assert_eq!("1000,499500", actual);
+}
+
+/// Insert values into a DATETIME2 column using a columnar buffer
+#[test_case(MSSQL; "Microsoft SQL Server")]
+// #[test_case(MARIADB; "Maria DB")] No DATEIME2 type
+// #[test_case(SQLITE_3; "SQLite 3")] default precision of 3 instead 7
+fn columnar_insert_timestamp(profile: &Profile) {
+    let table_name = table_name!();
+    // Setup
+    let (conn, table) = Given::new(&table_name)
+        .column_types(&["DATETIME2"])
+        .build(profile)
+        .unwrap();
+
+    // Fill buffer with values
+    let desc = BufferDesc::Timestamp { nullable: true };
+    let prepared = conn.prepare(&table.sql_insert()).unwrap();
+    let mut prebound = prepared.into_column_inserter(10, [desc]).unwrap();
+
+    // Input values to insert. Note that the last element has > 5 chars and is going to trigger a
+    // reallocation of the underlying buffer.
     let input = [
         Some(Timestamp {
             year: 2020,
@@ -1157,12 +1650,77 @@ fn insert_str_as_sql_integer(profile: &Profile) {
     assert_eq!(expected, actual);
 }
 
-/// Frankly more about testing edge cases in the API than a real use case.
 #[test_case(MSSQL; "Microsoft SQL Server")]
-fn var_char_slice_mut_as_input_output_parameter(profile: &Profile) {
-    let conn = profile.connection().unwrap();
-    conn.execute(
-        r#"
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+#[test_case(POSTGRES; "PostgreSQL")]
+fn insert_str_with_data_type_as_integer(profile: &Profile) {
+    // Given
+    let table_name = table_name!();
+    let (conn, table) = Given::new(&table_name)
+        .column_types(&["INTEGER"])
+        .build(profile)
+        .unwrap();
+
+    // When
+    let parameter = WithDataType::as_integer("42".into_parameter());
+    conn.execute(&table.sql_insert(), &parameter).unwrap();
+
+    // Then
+    assert_eq!("42", table.content_as_string(&conn));
+}
+
+/// Execute the same query with different parameters, allowing the data source to reuse the plan.
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(SQLITE_3; "SQLite 3")]
+fn execute_reusing_plan(profile: &Profile) {
+    // Given
+    let table_name = table_name!();
+    let (conn, _table) = Given::new(&table_name)
+        .column_types(&["VARCHAR(10)", "INTEGER"])
+        .values_by_column(&[&[Some("one"), Some("two")], &[Some("1"), Some("2")]])
+        .build(profile)
+        .unwrap();
+    let query = format!("SELECT a FROM {table_name} WHERE b = ? AND a <> '?'");
+
+    // When
+    let mut actual = Vec::new();
+    for b in [1i32, 2] {
+        let params: [Box<dyn InputParameter>; 1] = [Box::new(b)];
+        let cursor = conn.execute_reusing_plan(&query, &params).unwrap().unwrap();
+        actual.push(cursor_to_string(cursor));
+    }
+
+    // Then
+    assert_eq!(vec!["one", "two"], actual);
+}
+
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(SQLITE_3; "SQLite 3")]
+fn execute_reusing_plan_with_wrong_number_of_parameters(profile: &Profile) {
+    // Given
+    let conn = profile.connection().unwrap();
+    let query = "SELECT ? -- Any question?";
+
+    // When
+    let result = conn.execute_reusing_plan(query, &[]);
+
+    // Then
+    assert!(matches!(
+        result,
+        Err(Error::ParameterCountMismatch {
+            expected: 1,
+            provided: 0
+        })
+    ));
+}
+
+/// Frankly more about testing edge cases in the API than a real use case.
+#[test_case(MSSQL; "Microsoft SQL Server")]
+fn var_char_slice_mut_as_input_output_parameter(profile: &Profile) {
+    let conn = profile.connection().unwrap();
+    conn.execute(
+        r#"
         IF EXISTS (SELECT name FROM sysobjects WHERE name = 'TestInOutText')  
         DROP PROCEDURE TestInOutText  
         "#,
@@ -1453,6 +2011,37 @@ fn prepared_statement(profile: &Profile) {
     }
 }
 
+/// Column descriptions of a prepared statement are cached after the first access.
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+#[test_case(POSTGRES; "PostgreSQL")]
+fn prepared_describe_col_is_cached(profile: &Profile) {
+    // Given
+    let table_name = table_name!();
+    let (conn, _table) = Given::new(&table_name)
+        .column_types(&["VARCHAR(13)", "INTEGER"])
+        .build(profile)
+        .unwrap();
+    let sql = format!("SELECT a, b FROM {table_name};");
+    let mut prepared = conn.prepare(&sql).unwrap();
+
+    // When
+    let mut first = ColumnDescription::default();
+    prepared.describe_col(2, &mut first).unwrap();
+    let mut second = ColumnDescription::default();
+    prepared.describe_col(2, &mut second).unwrap();
+
+    // Then
+    assert_eq!(first, second);
+    let cached = prepared.cached_columns().unwrap();
+    assert_eq!(2, cached.len());
+    assert_eq!(&first, &cached[1]);
+    assert_eq!("b", cached[1].name_to_string().unwrap());
+    // Column numbers out of range are still reported as errors
+    assert!(prepared.describe_col(3, &mut second).is_err());
+}
+
 /// Reuse a preallocated handle, two times in a row.
 #[test_case(MSSQL; "Microsoft SQL Server")]
 #[test_case(MARIADB; "Maria DB")]
@@ -1580,6 +2169,31 @@ fn bind_optional_integer_parameter(profile: &Profile) {
     assert_eq!("", actual);
 }
 
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+#[test_case(POSTGRES; "PostgreSQL")]
+fn insert_optional_values_using_maybe(profile: &Profile) {
+    // Given
+    let table_name = table_name!();
+    let (conn, table) = Given::new(&table_name)
+        .column_types(&["INTEGER"])
+        .build(profile)
+        .unwrap();
+
+    // When
+    let insert = table.sql_insert();
+    conn.execute(&insert, &maybe(None::<i32>)).unwrap();
+    conn.execute(&insert, &maybe(Some(5))).unwrap();
+
+    // Then
+    let cursor = conn
+        .execute(table.sql_all_ordered_by_id(), ())
+        .unwrap()
+        .unwrap();
+    assert_eq!("NULL\n5", cursor_to_string(cursor));
+}
+
 #[test_case(MSSQL; "Microsoft SQL Server")]
 #[test_case(MARIADB; "Maria DB")]
 // #[test_case(SQLITE_3; "SQLite 3")] SQLite will work only if increasing length to VARCHAR(2).
@@ -1884,6 +2498,28 @@ fn metadata_from_prepared_insert_query(profile: &Profile) {
     assert_eq!(0, prepared.num_result_cols().unwrap());
 }
 
+/// SQLite has no dedicated decimal type and reports no meaningful precision.
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(POSTGRES; "PostgreSQL")]
+fn precision_and_scale_from_prepared_query(profile: &Profile) {
+    // Given
+    let table_name = table_name!();
+    let (conn, table) = Given::new(&table_name)
+        .column_types(&["DECIMAL(10,3)"])
+        .build(profile)
+        .unwrap();
+
+    // When
+    let mut prepared = conn.prepare(table.sql_all_ordered_by_id()).unwrap();
+    let precision = prepared.col_precision(1).unwrap();
+    let scale = prepared.col_scale(1).unwrap();
+
+    // Then
+    assert_eq!(10, precision);
+    assert_eq!(3, scale);
+}
+
 #[test_case(MSSQL, &[
     ParameterDescription {data_type: DataType::Integer, nullability: Nullability::Nullable},
     ParameterDescription {
@@ -1924,6 +2560,26 @@ fn describe_parameters_of_prepared_statement(
     assert_eq!(2, prepared.num_params().unwrap());
 }
 
+#[test_case(MSSQL, DataType::Varchar { length: NonZeroUsize::new(13) }; "Microsoft SQL Server")]
+// Maria DB describes all parameters as VARCHAR of maximum length, PostgreSQL and SQLite 3 expose
+// different behaviours with various platforms and drivers.
+fn describe_single_parameter_of_prepared_statement(profile: &Profile, expected: DataType) {
+    // Given
+    let table_name = table_name!();
+    let (conn, _table) = Given::new(&table_name)
+        .column_types(&["INTEGER", "VARCHAR(13)"])
+        .build(profile)
+        .unwrap();
+    let sql = format!("INSERT INTO {table_name} (a, b) VALUES (?, ?);");
+    let mut prepared = conn.prepare(&sql).unwrap();
+
+    // When
+    let description = prepared.describe_param(2).unwrap();
+
+    // Then
+    assert_eq!(expected, description.data_type);
+}
+
 #[test_case(MSSQL; "Microsoft SQL Server")]
 #[test_case(MARIADB; "Maria DB")]
 #[test_case(SQLITE_3; "SQLite 3")]
@@ -2080,6 +2736,104 @@ fn bulk_insert_with_multiple_batches(profile: &Profile) {
     assert_eq!(expected, actual);
 }
 
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+#[test_case(POSTGRES; "PostgreSQL")]
+fn bulk_insert_rows_from_iterator(profile: &Profile) {
+    // Given
+    struct Id(i32);
+
+    impl InsertRow<AnyBuffer> for Id {
+        fn write_row<S: AsStatementRef>(
+            self,
+            inserter: &mut ColumnarBulkInserter<S, AnyBuffer>,
+            row_index: usize,
+        ) -> Result<(), Error> {
+            inserter.column_mut(0).as_slice::<i32>().unwrap()[row_index] = self.0;
+            Ok(())
+        }
+    }
+
+    let table_name = table_name!();
+    let (conn, table) = Given::new(&table_name)
+        .column_types(&["INTEGER"])
+        .build(profile)
+        .unwrap();
+    let prepared = conn.prepare(table.sql_insert()).unwrap();
+    let mut inserter = prepared
+        .into_column_inserter(100, [BufferDesc::I32 { nullable: false }])
+        .unwrap();
+
+    // When
+    let affected_rows = inserter.extend_rows((0..1000).map(Id)).unwrap();
+
+    // Then
+    assert_eq!(0, inserter.num_rows());
+    if let Some(affected_rows) = affected_rows {
+        assert_eq!(1000, affected_rows);
+    }
+    let cursor = conn
+        .execute(&format!("SELECT COUNT(*), SUM(a) FROM {table_name}"), ())
+        .unwrap()
+        .unwrap();
+    assert_eq!("1000,499500", cursor_to_string(cursor));
+}
+
+/// A row failing to be written is not sent, and the error tells how many rows already have been
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+#[test_case(POSTGRES; "PostgreSQL")]
+fn bulk_insert_rows_from_iterator_stops_at_failing_row(profile: &Profile) {
+    // Given
+    struct Id(i32);
+
+    impl InsertRow<AnyBuffer> for Id {
+        fn write_row<S: AsStatementRef>(
+            self,
+            inserter: &mut ColumnarBulkInserter<S, AnyBuffer>,
+            row_index: usize,
+        ) -> Result<(), Error> {
+            if self.0 == 150 {
+                return Err(Error::FailedReadingInput(io::Error::other("Invalid row")));
+            }
+            inserter.column_mut(0).as_slice::<i32>().unwrap()[row_index] = self.0;
+            Ok(())
+        }
+    }
+
+    let table_name = table_name!();
+    let (conn, table) = Given::new(&table_name)
+        .column_types(&["INTEGER"])
+        .build(profile)
+        .unwrap();
+    let prepared = conn.prepare(table.sql_insert()).unwrap();
+    let mut inserter = prepared
+        .into_column_inserter(100, [BufferDesc::I32 { nullable: false }])
+        .unwrap();
+
+    // When
+    let result = inserter.extend_rows((0..1000).map(Id));
+
+    // Then
+    let Err(Error::ExtendRowsFailed {
+        rows_flushed,
+        source,
+    }) = result
+    else {
+        panic!("Expected extend_rows to fail")
+    };
+    assert_eq!(100, rows_flushed);
+    assert!(matches!(*source, Error::FailedReadingInput(_)));
+    assert_eq!(50, inserter.num_rows());
+    let cursor = conn
+        .execute(&format!("SELECT COUNT(*) FROM {table_name}"), ())
+        .unwrap()
+        .unwrap();
+    assert_eq!("100", cursor_to_string(cursor));
+}
+
 #[test_case(MSSQL; "Microsoft SQL Server")]
 #[test_case(MARIADB; "Maria DB")]
 #[test_case(SQLITE_3; "SQLite 3")]
@@ -2102,6 +2856,64 @@ fn send_connection(profile: &Profile) {
     assert_eq!("1\n2\n3", actual)
 }
 
+#[test]
+fn cancel_long_running_statement_from_other_thread() {
+    // Given a statement, whose execution would take ten seconds
+    let conn = MSSQL.connection().unwrap();
+    let mut statement = conn.preallocate().unwrap();
+
+    // When cancelling it from another thread
+    let result = statement.with_canceller(|statement, canceller| {
+        thread::scope(|s| {
+            s.spawn(move || {
+                thread::sleep(Duration::from_millis(500));
+                canceller.cancel().unwrap();
+            });
+            statement
+                .execute("WAITFOR DELAY '00:00:10'", ())
+                .map(|_| ())
+        })
+    });
+
+    // Then
+    assert!(matches!(
+        result,
+        Err(Error::Diagnostics { record, .. }) if record.state == State::OPERATION_CANCELED
+    ));
+}
+
+#[test]
+fn query_timeout_expires() {
+    // Given a statement, whose execution would take ten seconds
+    let conn = MSSQL.connection().unwrap();
+
+    // When
+    let result = conn
+        .execute_with_timeout("WAITFOR DELAY '00:00:10'", (), Duration::from_millis(500))
+        .map(|_| ());
+
+    // Then
+    assert!(matches!(result, Err(Error::QueryTimeout(_))));
+}
+
+/// The timeout should only apply to the execution it has been passed to.
+#[test]
+fn query_timeout_is_reset_after_execution() {
+    // Given
+    let conn = MSSQL.connection().unwrap();
+    let mut prepared = conn.prepare("SELECT 42").unwrap();
+
+    // When
+    prepared
+        .execute_with_timeout((), Duration::from_secs(5))
+        .unwrap();
+    let stmt = prepared.as_stmt_ref();
+    let timeout = stmt.query_timeout_sec().into_result(&stmt).unwrap();
+
+    // Then
+    assert_eq!(0, timeout);
+}
+
 #[test_case(MSSQL; "Microsoft SQL Server")]
 #[test_case(MARIADB; "Maria DB")]
 #[test_case(SQLITE_3; "SQLite 3")]
@@ -2372,6 +3184,115 @@ fn manual_commit_mode(profile: &Profile) {
     conn.commit().unwrap();
 }
 
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+fn select_in_manual_commit_mode_requires_commit(profile: &Profile) {
+    // Given
+    let table_name = table_name!();
+    let (conn, table) = Given::new(&table_name)
+        .column_types(&["INTEGER"])
+        .build(profile)
+        .unwrap();
+    conn.set_autocommit(false).unwrap();
+    let before_select = conn.in_transaction().unwrap();
+
+    // When
+    conn.execute(table.sql_all_ordered_by_id(), ()).unwrap();
+    let after_select = conn.in_transaction().unwrap();
+    let committed = conn.commit_if_needed().unwrap();
+    let after_commit = conn.in_transaction().unwrap();
+
+    // Then
+    assert!(!before_select);
+    assert!(after_select);
+    assert!(committed);
+    assert!(!after_commit);
+    assert!(!conn.commit_if_needed().unwrap());
+}
+
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+fn executing_prepared_statement_after_commit_starts_transaction(profile: &Profile) {
+    // Given
+    let table_name = table_name!();
+    let (conn, table) = Given::new(&table_name)
+        .column_types(&["INTEGER"])
+        .build(profile)
+        .unwrap();
+    conn.set_autocommit(false).unwrap();
+    let mut prepared = conn.prepare(table.sql_insert()).unwrap();
+    let mut preallocated = conn.preallocate().unwrap();
+    conn.commit().unwrap();
+
+    // When
+    prepared.execute(&42).unwrap();
+    let after_prepared = conn.in_transaction().unwrap();
+    conn.commit().unwrap();
+    preallocated.execute(&table.sql_insert(), &43).unwrap();
+    let after_preallocated = conn.in_transaction().unwrap();
+    let committed = conn.commit_if_needed().unwrap();
+
+    // Then
+    assert!(after_prepared);
+    assert!(after_preallocated);
+    assert!(committed);
+    assert_eq!("42\n43", table.content_as_string(&conn));
+    conn.commit().unwrap();
+}
+
+/// Roll back open transactions of two connections with a single call on the environment.
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+// #[test_case(SQLITE_3; "SQLite 3")] Two concurrent writers in manual commit mode lock the database
+#[test_case(POSTGRES; "PostgreSQL")]
+fn rollback_all(profile: &Profile) {
+    // Given two empty tables
+    let table_name_a = format!("{}_a", table_name!());
+    let table_name_b = format!("{}_b", table_name!());
+    let (conn, table_a) = Given::new(&table_name_a)
+        .column_types(&["INTEGER"])
+        .build(profile)
+        .unwrap();
+    let (_, table_b) = Given::new(&table_name_b)
+        .column_types(&["INTEGER"])
+        .build(profile)
+        .unwrap();
+    // Use a dedicated environment, so rolling back does not affect connections of other tests
+    // running in parallel.
+    let env = Environment::new().unwrap();
+    let conn_a = env
+        .connect_with_connection_string(profile.connection_string, ConnectionOptions::default())
+        .unwrap();
+    let conn_b = env
+        .connect_with_connection_string(profile.connection_string, ConnectionOptions::default())
+        .unwrap();
+    conn_a.set_autocommit(false).unwrap();
+    conn_b.set_autocommit(false).unwrap();
+
+    // When inserting on both connections and rolling back via the environment
+    conn_a
+        .execute(&format!("INSERT INTO {table_name_a} (a) VALUES (1)"), ())
+        .unwrap();
+    conn_b
+        .execute(&format!("INSERT INTO {table_name_b} (a) VALUES (2)"), ())
+        .unwrap();
+    match env.rollback_all() {
+        Err(Error::UnsupportedEnvironmentTransaction(_)) => {
+            // Driver does not support ending transactions on environment level. Clean up and skip
+            conn_a.rollback().unwrap();
+            conn_b.rollback().unwrap();
+            return;
+        }
+        other => other.unwrap(),
+    }
+
+    // Then both inserts are rolled back
+    assert_eq!("", table_a.content_as_string(&conn));
+    assert_eq!("", table_b.content_as_string(&conn));
+}
+
 /// This test checks the behaviour if a connections goes out of scope with a transaction still
 /// open.
 #[test_case(MSSQL; "Microsoft SQL Server")]
@@ -2581,30 +3502,57 @@ fn get_text(profile: &Profile) {
     assert_eq!(&b"Hello, World!"[..], &actual);
 }
 
-/// Use get_text to retrieve a string. Use a buffer which is one terminating zero short to get the
-/// entire value.
+/// Use append_text to concatenate text of several rows into one reused `String`.
 #[test_case(MSSQL; "Microsoft SQL Server")]
 #[test_case(MARIADB; "Maria DB")]
 #[test_case(SQLITE_3; "SQLite 3")]
 #[test_case(POSTGRES; "PostgreSQL")]
-fn get_wide_text(profile: &Profile) {
+fn append_text(profile: &Profile) {
     let table_name = table_name!();
     let (conn, table) = Given::new(&table_name)
         .column_types(&["Varchar(50)"])
+        .values_by_column(&[&[Some("Hello"), None, Some(", "), Some("Wörld!")]])
         .build(profile)
         .unwrap();
-    conn.execute(&table.sql_insert(), &"Hello, World!".into_parameter())
-        .unwrap();
     let mut cursor = conn
-        .execute(&format!("SELECT a FROM {table_name} ORDER BY id"), ())
+        .execute(&table.sql_all_ordered_by_id(), ())
         .unwrap()
         .unwrap();
 
-    let mut row = cursor.next_row().unwrap().unwrap();
-    // We want to hit an edge case there there has been a sign error then calculating buffer size
-    // with terminating zero.
-    let mut actual = Vec::with_capacity("Hello, World!".len() - 1);
-    let is_not_null = row.get_wide_text(1, &mut actual).unwrap();
+    let mut actual = String::new();
+    let mut is_not_null = Vec::new();
+    while let Some(mut row) = cursor.next_row().unwrap() {
+        is_not_null.push(row.append_text(1, &mut actual).unwrap());
+    }
+
+    assert_eq!(vec![true, false, true, true], is_not_null);
+    assert_eq!("Hello, Wörld!", actual);
+}
+
+/// Use get_text to retrieve a string. Use a buffer which is one terminating zero short to get the
+/// entire value.
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+#[test_case(POSTGRES; "PostgreSQL")]
+fn get_wide_text(profile: &Profile) {
+    let table_name = table_name!();
+    let (conn, table) = Given::new(&table_name)
+        .column_types(&["Varchar(50)"])
+        .build(profile)
+        .unwrap();
+    conn.execute(&table.sql_insert(), &"Hello, World!".into_parameter())
+        .unwrap();
+    let mut cursor = conn
+        .execute(&format!("SELECT a FROM {table_name} ORDER BY id"), ())
+        .unwrap()
+        .unwrap();
+
+    let mut row = cursor.next_row().unwrap().unwrap();
+    // We want to hit an edge case there there has been a sign error then calculating buffer size
+    // with terminating zero.
+    let mut actual = Vec::with_capacity("Hello, World!".len() - 1);
+    let is_not_null = row.get_wide_text(1, &mut actual).unwrap();
 
     let actual = U16String::from_vec(actual).to_string().unwrap();
     assert!(is_not_null);
@@ -3047,6 +3995,67 @@ fn synchronized_access_to_driver_and_data_source_info() {
     }
 }
 
+#[test]
+fn user_and_system_data_sources_add_up_to_all_data_sources() {
+    // Given
+    let env = environment().unwrap();
+
+    // When
+    let mut all = env.data_sources().unwrap();
+    let mut user_and_system = env.user_data_sources().unwrap();
+    user_and_system.extend(env.system_data_sources().unwrap());
+
+    // Then
+    let by_name = |a: &DataSourceInfo, b: &DataSourceInfo| a.server_name.cmp(&b.server_name);
+    all.sort_by(by_name);
+    user_and_system.sort_by(by_name);
+    assert_eq!(all, user_and_system);
+}
+
+#[test]
+#[cfg(feature = "odbcinst")]
+fn configure_temporary_data_source() {
+    use odbc_api::DataSourceAction;
+
+    // Given
+    let env = environment().unwrap();
+    let dsn = "OdbcApiTemporaryDsn";
+    #[cfg(target_os = "windows")]
+    let driver = "SQLite3 ODBC Driver";
+    #[cfg(not(target_os = "windows"))]
+    let driver = "SQLite3";
+
+    // When
+    env.configure_data_source(
+        DataSourceAction::Add,
+        driver,
+        &[("DSN", dsn), ("Database", "sqlite-test.db")],
+    )
+    .unwrap();
+    let listed = env
+        .user_data_sources()
+        .unwrap()
+        .iter()
+        .any(|info| info.server_name == dsn);
+    let conn = env
+        .connect(dsn, "", "", ConnectionOptions::default())
+        .unwrap();
+    let cursor = conn.execute("SELECT 42", ()).unwrap().unwrap();
+    let result = cursor_to_string(cursor);
+    drop(conn);
+    env.configure_data_source(DataSourceAction::Remove, driver, &[("DSN", dsn)])
+        .unwrap();
+
+    // Then
+    assert!(listed);
+    assert_eq!("42", result);
+    assert!(!env
+        .user_data_sources()
+        .unwrap()
+        .iter()
+        .any(|info| info.server_name == dsn));
+}
+
 // #[test_case(MSSQL; "Microsoft SQL Server")] Linux driver allocates 42 GiB
 #[test_case(MARIADB; "Maria DB")]
 #[test_case(SQLITE_3; "SQLite 3")]
@@ -3157,6 +4166,96 @@ fn send_blob_as_part_of_tuplebinary_vec(profile: &Profile) {
     assert_eq!(input, output_b);
 }
 
+/// Stream two large values as part of the same row.
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+#[test_case(POSTGRES; "PostgreSQL")]
+fn send_two_blobs_in_one_row(profile: &Profile) {
+    // Given
+    let table_name = table_name!();
+    let conn = profile
+        .setup_empty_table(&table_name, &[profile.blob_type, profile.blob_type])
+        .unwrap();
+    // Large vectors with successive numbers. Too large to send to the database in one go.
+    let input_a: Vec<_> = (0..12000).map(|i| (i % 256) as u8).collect();
+    let input_b: Vec<_> = (0..15000).map(|i| (i % 251) as u8).collect();
+
+    // When
+    let mut blob_a = BlobSlice::from_byte_slice(&input_a);
+    let mut blob_b = BlobSlice::from_byte_slice(&input_b);
+    let insert = format!("INSERT INTO {table_name} (a,b) VALUES (?,?)");
+    conn.execute(
+        &insert,
+        (&mut blob_a.as_blob_param(), &mut blob_b.as_blob_param()),
+    )
+    .unwrap();
+
+    // Then
+    let select = format!("SELECT a,b FROM {table_name}");
+    let mut result = conn.execute(&select, ()).unwrap().unwrap();
+    let mut row = result.next_row().unwrap().unwrap();
+    let mut output_a = Vec::new();
+    let mut output_b = Vec::new();
+    row.get_binary(1, &mut output_a).unwrap();
+    row.get_binary(2, &mut output_b).unwrap();
+
+    assert_eq!(input_a, output_a);
+    assert_eq!(input_b, output_b);
+}
+
+/// Decode text from a data source, which is not UTF-8 encoded. SQLite returns the bytes of a BLOB
+/// cast to TEXT unchanged, so we can emulate a Latin-1 encoded database.
+#[cfg(feature = "encoding_rs")]
+#[test_case(SQLITE_3; "SQLite 3")]
+fn get_text_with_latin_1_encoding(profile: &Profile) {
+    // Given "äöü" encoded in Latin-1
+    let conn = profile.connection().unwrap();
+    let mut cursor = conn
+        .execute("SELECT CAST(X'E4F6FC' AS TEXT)", ())
+        .unwrap()
+        .unwrap();
+    let mut row = cursor.next_row().unwrap().unwrap();
+
+    // When
+    let mut actual = String::new();
+    let is_not_null = row
+        .get_text_with_encoding(1, odbc_api::encoding_rs::WINDOWS_1252, &mut actual)
+        .unwrap();
+
+    // Then
+    assert!(is_not_null);
+    assert_eq!("äöü", actual);
+}
+
+/// Fetch all columns of a row with a single call
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+#[test_case(POSTGRES; "PostgreSQL")]
+fn get_all_columns_of_row(profile: &Profile) {
+    // Given
+    let table_name = table_name!();
+    let conn = profile
+        .setup_empty_table(&table_name, &["INTEGER", profile.blob_type])
+        .unwrap();
+    let insert = format!("INSERT INTO {table_name} (a,b) VALUES (?,?)");
+    conn.execute(&insert, (&42i32, &[1u8, 2, 3].into_parameter()))
+        .unwrap();
+
+    // When
+    let select = format!("SELECT a,b FROM {table_name}");
+    let mut cursor = conn.execute(&select, ()).unwrap().unwrap();
+    let mut row = cursor.next_row().unwrap().unwrap();
+    let mut output_a: i32 = 0;
+    let mut output_b = VarBinaryArray::<32>::NULL;
+    row.get_all(&mut [&mut output_a, &mut output_b]).unwrap();
+
+    // Then
+    assert_eq!(42, output_a);
+    assert_eq!(Some(&[1u8, 2, 3][..]), output_b.as_bytes());
+}
+
 #[test_case(MSSQL; "Microsoft SQL Server")]
 #[test_case(MARIADB; "Maria DB")]
 #[test_case(SQLITE_3; "SQLite 3")]
@@ -3184,6 +4283,84 @@ fn send_long_data_string(profile: &Profile) {
     assert_eq!(input, output);
 }
 
+/// Text parameter, whose value is sent at statement execution time by the application itself,
+/// rather than by a [`odbc_api::parameter::Blob`].
+struct TextAtExec {
+    indicator: isize,
+}
+
+unsafe impl DelayedInput for TextAtExec {
+    fn cdata_type(&self) -> CDataType {
+        CDataType::Char
+    }
+
+    fn indicator_ptr(&self) -> *const isize {
+        &self.indicator
+    }
+
+    fn stream_ptr(&mut self) -> Pointer {
+        self as *mut Self as Pointer
+    }
+}
+
+impl HasDataType for TextAtExec {
+    fn data_type(&self) -> DataType {
+        DataType::LongVarchar { length: None }
+    }
+}
+
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+#[test_case(POSTGRES; "PostgreSQL")]
+fn put_data_at_exec_manually(profile: &Profile) {
+    // Given
+    let table_name = table_name!();
+    let (conn, table) = Given::new(&table_name)
+        .column_types(&["VARCHAR(50)"])
+        .build(profile)
+        .unwrap();
+    let input = "Hello, World!";
+    let mut param = TextAtExec {
+        indicator: sys::len_data_at_exec(input.len().try_into().unwrap()),
+    };
+    let param_ptr = &mut param as *mut TextAtExec as Pointer;
+    let mut stmt = conn.preallocate().unwrap().into_statement();
+    let insert = table.sql_insert();
+    let insert = SqlText::new(&insert);
+
+    // When
+    let execute_result = unsafe {
+        stmt.bind_delayed_input_parameter(1, &mut param)
+            .into_result(&stmt)
+            .unwrap();
+        stmt.exec_direct(&insert)
+    };
+    let mut requested = Vec::new();
+    let put_result = stmt.put_data_at_exec(|stream_ptr, stmt| {
+        requested.push(stream_ptr);
+        let mut result = SqlResult::Success(());
+        for batch in input.as_bytes().chunks(4) {
+            result = stmt.put_binary_batch(batch);
+            if result.is_err() {
+                break;
+            }
+        }
+        result
+    });
+    put_result.into_result(&stmt).unwrap();
+    drop(stmt);
+
+    // Then
+    assert_eq!(SqlResult::NeedData, execute_result);
+    assert_eq!(vec![param_ptr], requested);
+    let cursor = conn
+        .execute(table.sql_all_ordered_by_id(), ())
+        .unwrap()
+        .unwrap();
+    assert_eq!(input, cursor_to_string(cursor));
+}
+
 #[test_case(MSSQL; "Microsoft SQL Server")]
 #[test_case(MARIADB; "Maria DB")]
 // #[test_case(SQLITE_3; "SQLite 3")] SQLite does not write anything to the database if there is no
@@ -3247,6 +4424,45 @@ fn send_long_data_binary_file(profile: &Profile) {
     assert_eq!(input, output);
 }
 
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+#[test_case(POSTGRES; "PostgreSQL")]
+fn io_error_reading_blob_is_error_source(profile: &Profile) {
+    // Given
+    /// Fails on each attempt to read from it.
+    struct BrokenRead;
+
+    impl io::Read for BrokenRead {
+        fn read(&mut self, _buf: &mut [u8]) -> io::Result<usize> {
+            Err(io::Error::other("Broken input"))
+        }
+    }
+
+    let table_name = table_name!();
+    let conn = profile
+        .setup_empty_table(&table_name, &[profile.blob_type])
+        .unwrap();
+    // Yields some bytes, before failing partway.
+    let read = io::BufReader::new(io::Read::chain(&[1u8, 2, 3][..], BrokenRead));
+    let mut blob = BlobRead::with_upper_bound(read, 100);
+
+    // When
+    let insert = format!("INSERT INTO {table_name} (a) VALUES (?)");
+    let error = conn
+        .execute(&insert, &mut blob.as_blob_param())
+        .err()
+        .unwrap();
+
+    // Then
+    assert!(matches!(error, Error::FailedReadingInput(_)));
+    let source = std::error::Error::source(&error)
+        .unwrap()
+        .downcast_ref::<io::Error>()
+        .unwrap();
+    assert_eq!("Broken input", source.to_string());
+}
+
 /// Demonstrate how to strip abstractions and access raw functionality as exposed by `odbc-sys`.
 #[test_case(MSSQL; "Microsoft SQL Server")]
 #[test_case(MARIADB; "Maria DB")]
@@ -3385,6 +4601,90 @@ fn database_management_system_name(profile: &Profile, expected_name: &'static st
     assert_eq!(expected_name, actual_name);
 }
 
+#[test_case(MSSQL, Dbms::MsSql; "Microsoft SQL Server")]
+#[test_case(MARIADB, Dbms::MySqlOrMaria; "Maria DB")]
+#[test_case(SQLITE_3, Dbms::Sqlite; "SQLite 3")]
+#[test_case(POSTGRES, Dbms::PostgreSql; "PostgreSQL")]
+fn detect_dbms_flavor(profile: &Profile, expected: Dbms) {
+    let conn = profile.connection().unwrap();
+    let actual = conn.dbms().unwrap();
+    assert_eq!(expected, actual);
+}
+
+#[test_case(MSSQL, &["id"]; "Microsoft SQL Server")]
+#[test_case(MARIADB, &[]; "Maria DB")]
+#[test_case(SQLITE_3, &[]; "SQLite 3")]
+#[test_case(POSTGRES, &["id"]; "PostgreSQL")]
+fn insert_returning_generated_id(profile: &Profile, returning_columns: &[&str]) {
+    // Given
+    let table_name = table_name!();
+    let (conn, _table) = Given::new(&table_name)
+        .column_types(&["VARCHAR(50)"])
+        .build(profile)
+        .unwrap();
+
+    // When
+    let mut ids = Vec::new();
+    for name in ["Jane", "John"] {
+        let mut cursor = conn
+            .insert_returning(
+                &table_name,
+                &["a"],
+                &name.into_parameter(),
+                returning_columns,
+            )
+            .unwrap();
+        let mut row = cursor.next_row().unwrap().unwrap();
+        let mut id = 0i64;
+        row.get_data(1, &mut id).unwrap();
+        ids.push(id);
+    }
+
+    // Then
+    assert_eq!(vec![1, 2], ids);
+}
+
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+fn insert_returning_specific_columns_is_not_supported(profile: &Profile) {
+    // Given
+    let table_name = table_name!();
+    let (conn, _table) = Given::new(&table_name)
+        .column_types(&["VARCHAR(50)"])
+        .build(profile)
+        .unwrap();
+
+    // When
+    let result = conn.insert_returning(&table_name, &["a"], &"Jane".into_parameter(), &["id"]);
+
+    // Then
+    assert!(matches!(
+        result,
+        Err(Error::ReturningColumnsNotSupported { .. })
+    ));
+}
+
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(POSTGRES; "PostgreSQL")]
+fn insert_returning_without_returning_columns_is_rejected(profile: &Profile) {
+    // Given
+    let table_name = table_name!();
+    let (conn, table) = Given::new(&table_name)
+        .column_types(&["VARCHAR(50)"])
+        .build(profile)
+        .unwrap();
+
+    // When
+    let result = conn.insert_returning(&table_name, &["a"], &"Jane".into_parameter(), &[]);
+
+    // Then
+    assert!(matches!(
+        result,
+        Err(Error::ReturningColumnsRequired { .. })
+    ));
+    assert_eq!("", table.content_as_string(&conn));
+}
+
 // Check the max name length for the catalogs, schemas, tables, and columns.
 #[test_case(MSSQL, 128, 128, 128, 128; "Microsoft SQL Server")]
 #[test_case(MARIADB, 256, 0, 256, 255; "Maria DB")]
@@ -3428,6 +4728,25 @@ fn current_catalog(profile: &Profile, expected_catalog: &str) {
     assert_eq!(conn.current_catalog().unwrap(), expected_catalog);
 }
 
+/// Drivers translate ODBC escape sequences into their native SQL dialect.
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+#[test_case(POSTGRES; "PostgreSQL")]
+fn native_sql_translates_timestamp_escape(profile: &Profile) {
+    // Given
+    let conn = profile.connection().unwrap();
+
+    // When
+    let native = conn
+        .native_sql("SELECT {ts '2021-03-20 15:24:12'}")
+        .unwrap();
+
+    // Then
+    assert!(!native.is_empty());
+    assert!(native.contains("2021-03-20 15:24:12"));
+}
+
 #[test_case(MSSQL, "dbo"; "Microsoft SQL Server")]
 #[test_case(MARIADB, ""; "Maria DB")]
 #[test_case(SQLITE_3, "dbo"; "SQLite 3")]
@@ -3566,21 +4885,77 @@ fn list_tables_preallocated(profile: &Profile, expected: &str) {
     assert_eq!(expected.to_lowercase(), actual);
 }
 
-/// List columns for various data sources
-#[test_case(MSSQL, "master,dbo,ListColumns,a,4,int,10,4,0,10,1,NULL,NULL,4,NULL,NULL,2,YES,0,0,0,0,NULL,NULL,NULL,NULL,NULL,NULL,38"; "Microsoft SQL Server")]
-#[test_case(MARIADB, "test_db,NULL,ListColumns,a,4,INT,10,4,0,10,1,,NULL,4,NULL,2,2,YES"; "Maria DB")]
-#[test_case(SQLITE_3, ",,ListColumns,a,4,INTEGER,9,10,10,0,1,NULL,NULL,4,NULL,16384,2,YES"; "SQLite 3")]
-// #[test_case(POSTGRES, ""; "PostgreSQL")] Fails in linux
-fn list_columns(profile: &Profile, expected: &str) {
-    // Table name is part of test expectation for this test
-    let table_name = "ListColumns";
-    let conn = profile.setup_empty_table(table_name, &["INTEGER"]).unwrap();
+/// Only describe the columns within a range of ordinal positions
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+fn list_columns_in_ordinal_range(profile: &Profile) {
+    // Given a table with the columns id, a, b, c, d
+    let table_name = table_name!();
+    let (conn, _table) = Given::new(&table_name)
+        .column_types(&["VARCHAR(10)", "INTEGER", "BIGINT", "INTEGER"])
+        .build(profile)
+        .unwrap();
 
-    let cursor = conn.columns("", "", table_name, "a").unwrap();
-    let actual = cursor_to_string(cursor).to_lowercase();
+    // When
+    let columns = conn.columns_range("", "", &table_name, 3..=4).unwrap();
 
-    assert_eq!(expected.to_lowercase(), actual);
-}
+    // Then
+    let actual: Vec<_> = columns
+        .iter()
+        .map(|(ordinal, column)| (*ordinal, column.name_to_string().unwrap().to_lowercase()))
+        .collect();
+    assert_eq!(vec![(3, "b".to_owned()), (4, "c".to_owned())], actual);
+    assert_eq!(DataType::Integer, columns[0].1.data_type);
+}
+
+/// An underscore in a table name must be escaped, or it matches any character.
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+fn list_tables_with_escaped_search_pattern(profile: &Profile) {
+    // Given two tables, one of whom would match the unescaped name as a pattern
+    let conn = profile
+        .setup_empty_table("Escaped_Pattern", &["INTEGER"])
+        .unwrap();
+    profile
+        .setup_empty_table("EscapedXPattern", &["INTEGER"])
+        .unwrap();
+
+    // When
+    let unescaped = conn.tables("", "", "Escaped_Pattern", "").unwrap();
+    let unescaped = cursor_to_string(unescaped);
+    let escaped = conn
+        .tables(
+            "",
+            "",
+            &conn.escape_search_pattern("Escaped_Pattern").unwrap(),
+            "",
+        )
+        .unwrap();
+    let escaped = cursor_to_string(escaped);
+
+    // Then
+    assert_eq!(2, unescaped.lines().count());
+    assert_eq!(1, escaped.lines().count());
+    assert!(escaped.to_lowercase().contains("escaped_pattern"));
+}
+
+/// List columns for various data sources
+#[test_case(MSSQL, "master,dbo,ListColumns,a,4,int,10,4,0,10,1,NULL,NULL,4,NULL,NULL,2,YES,0,0,0,0,NULL,NULL,NULL,NULL,NULL,NULL,38"; "Microsoft SQL Server")]
+#[test_case(MARIADB, "test_db,NULL,ListColumns,a,4,INT,10,4,0,10,1,,NULL,4,NULL,2,2,YES"; "Maria DB")]
+#[test_case(SQLITE_3, ",,ListColumns,a,4,INTEGER,9,10,10,0,1,NULL,NULL,4,NULL,16384,2,YES"; "SQLite 3")]
+// #[test_case(POSTGRES, ""; "PostgreSQL")] Fails in linux
+fn list_columns(profile: &Profile, expected: &str) {
+    // Table name is part of test expectation for this test
+    let table_name = "ListColumns";
+    let conn = profile.setup_empty_table(table_name, &["INTEGER"]).unwrap();
+
+    let cursor = conn.columns("", "", table_name, "a").unwrap();
+    let actual = cursor_to_string(cursor).to_lowercase();
+
+    assert_eq!(expected.to_lowercase(), actual);
+}
 
 /// List columns for various data sources, using a preallocated statement
 #[test_case(MSSQL, "master,dbo,ListColumnsPreallocated,a,4,int,10,4,0,10,1,NULL,NULL,4,NULL,NULL,2,YES,0,0,0,0,NULL,NULL,NULL,NULL,NULL,NULL,38"; "Microsoft SQL Server")]
@@ -3632,6 +5007,23 @@ fn list_columns_oom(profile: &Profile, expected_row_size_in_bytes: usize) {
     assert_eq!(expected_row_size_in_bytes, size_of_row)
 }
 
+#[test_case(MSSQL, 10039; "Microsoft SQL Server")]
+#[test_case(SQLITE_3, 986; "SQLite 3")]
+fn total_row_buffer_size_of_columns_listing(profile: &Profile, expected_row_size_in_bytes: usize) {
+    // Given a cursor listing the columns of a table. Same as in `list_columns_oom`.
+    let conn = profile.connection().unwrap();
+    let table_name = table_name!();
+    let mut cursor = conn.columns("", "", &table_name, "").unwrap();
+
+    // When
+    let row_size = cursor.total_row_buffer_size().unwrap();
+    let batch_size = cursor.estimate_batch_memory(100).unwrap();
+
+    // Then
+    assert_eq!(expected_row_size_in_bytes, row_size);
+    assert_eq!(100 * expected_row_size_in_bytes, batch_size);
+}
+
 /// Some drivers seem to have trouble binding buffers beyond `u16::MAX`. This has been seen failing
 /// in the wild with SAP anywhere, but that ODBC driver is not part of this test suite.
 #[test_case(MSSQL; "Microsoft SQL Server")]
@@ -3651,1564 +5043,3593 @@ fn row_array_size_66536(profile: &Profile) {
     assert!(cursor.bind_buffer(row_set_buffer).is_ok())
 }
 
+/// `SQL_C_DEFAULT` is not supported by every driver, so this test is not run against SQLite.
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(POSTGRES; "PostgreSQL")]
+fn fetch_integer_with_default_c_type(profile: &Profile) {
+    // Given
+    let table_name = table_name!();
+    let (conn, table) = Given::new(&table_name)
+        .column_types(&["INTEGER"])
+        .values_by_column(&[&[Some("42")]])
+        .build(profile)
+        .unwrap();
+
+    // When
+    let mut cursor = conn
+        .execute(&table.sql_all_ordered_by_id(), ())
+        .unwrap()
+        .unwrap();
+    let length = cursor.col_octet_length(1).unwrap().map_or(4, |l| l.get());
+    let buffer = ColumnarAnyBuffer::from_descs(1, [BufferDesc::Default { length }]);
+    let mut cursor = cursor.bind_buffer(buffer).unwrap();
+    let batch = cursor.fetch().unwrap().unwrap();
+
+    // Then
+    let bytes = batch.column(0).as_bin_view().unwrap().get(0).unwrap();
+    assert_eq!(42i32.to_ne_bytes().as_slice(), bytes);
+}
+
 #[test_case(MSSQL; "Microsoft SQL Server")]
 #[test_case(MARIADB; "Maria DB")]
 #[test_case(SQLITE_3; "SQLite 3")]
 #[test_case(POSTGRES; "PostgreSQL")]
-#[should_panic(expected = "SQLFreeHandle failed with error code: -1")]
-fn should_panic_if_connection_cannot_be_freed(profile: &Profile) {
-    let conn = profile.connection().unwrap();
+fn default_c_type_buffer_too_small_for_integer(profile: &Profile) {
+    // Given
+    let table_name = table_name!();
+    let (conn, table) = Given::new(&table_name)
+        .column_types(&["INTEGER"])
+        .build(profile)
+        .unwrap();
+    let cursor = conn
+        .execute(&table.sql_all_ordered_by_id(), ())
+        .unwrap()
+        .unwrap();
+    let buffer = ColumnarAnyBuffer::from_descs(1, [BufferDesc::Default { length: 2 }]);
 
-    // Since the types with their invariants in this crate helpfully prevent us from freeing a
-    // connected handles, we have to abandon the saftey rails in order to provoke the error.
-    let conn = conn.into_handle();
+    // When
+    let result = cursor.bind_buffer(buffer);
 
-    // We drop the connection, but it is still connected. => This is a programming error, we want
-    // the drop handler to panic.
-    drop(conn);
+    // Then
+    assert!(matches!(
+        result,
+        Err(Error::DefaultCTypeBufferTooSmall {
+            column_number: 1,
+            length: 2,
+            required: Some(4)
+        })
+    ));
 }
 
 #[test_case(MSSQL; "Microsoft SQL Server")]
 #[test_case(MARIADB; "Maria DB")]
 #[test_case(SQLITE_3; "SQLite 3")]
 #[test_case(POSTGRES; "PostgreSQL")]
-#[should_panic(expected = "original error")]
-fn panic_in_drop_handlers_should_not_mask_original_error(profile: &Profile) {
-    let conn = profile.connection().unwrap();
+fn expect_no_cursor_on_query_is_an_error(profile: &Profile) {
+    let table_name = table_name!();
+    let (conn, table) = Given::new(&table_name)
+        .column_types(&["INTEGER"])
+        .build(profile)
+        .unwrap();
 
-    // Since the types with their invariants in this crate helpfully prevent us from freeing a
-    // connected handles, we have to abandon the saftey rails in order to provoke the error.
-    let _conn = conn.into_handle();
+    let result = conn
+        .execute(&table.sql_all_ordered_by_id(), ())
+        .unwrap()
+        .expect_no_cursor();
 
-    // If this error is propagated upwards, the above connections drop handler will be called and
-    // fail. This tests wants to ensure the original error is not masked by that.
-    panic!("original error")
+    assert!(matches!(result, Err(Error::UnexpectedResultSet)));
 }
 
-/// Arrow uses the same binary format for the values of nullable slices, though null are represented
-/// as bitmask. Make it possible for bindings to efficiently copy the values array out of a
-/// nullable slice.
 #[test_case(MSSQL; "Microsoft SQL Server")]
 #[test_case(MARIADB; "Maria DB")]
 #[test_case(SQLITE_3; "SQLite 3")]
 #[test_case(POSTGRES; "PostgreSQL")]
-fn memcopy_values_from_nullable_slice(profile: &Profile) {
-    // Given
+fn expect_cursor_on_insert_is_an_error(profile: &Profile) {
     let table_name = table_name!();
-    let conn = profile
-        .setup_empty_table(&table_name, &["INTEGER"])
+    let (conn, table) = Given::new(&table_name)
+        .column_types(&["INTEGER"])
+        .build(profile)
         .unwrap();
-    conn.execute(
-        &format!("INSERT INTO {table_name} (a) VALUES (42), (NULL), (5);"),
-        (),
-    )
-    .unwrap();
 
-    // When
-    let cursor = conn
-        .execute(&format!("SELECT a FROM {table_name}"), ())
-        .unwrap() // Unwrap Result
-        .unwrap(); // Unwrap Option, we know a select statement to produce a cursor.
-    let buffer =
-        ColumnarAnyBuffer::try_from_descs(3, [BufferDesc::I32 { nullable: true }]).unwrap();
-    let mut cursor = cursor.bind_buffer(buffer).unwrap();
-    let batch = cursor.fetch().unwrap().unwrap();
-    let nullable_slice = batch.column(0).as_nullable_slice::<i32>().unwrap();
-    let (values, indicators) = nullable_slice.raw_values();
-    // Memcopy values.
-    let values = values.to_vec();
-    // Create array of bools indicating null values.
-    let nulls: Vec<bool> = indicators
-        .iter()
-        .map(|&indicator| indicator == sys::NULL_DATA)
-        .collect();
+    let result = conn
+        .execute(&table.sql_insert(), &42)
+        .unwrap()
+        .expect_cursor();
 
-    // Then
-    assert!(!nulls[0]);
-    assert_eq!(values[0], 42);
-    assert!(nulls[1]);
-    // We explicitly don't give any guarantees about the value of #values[1]`.
-    assert!(!nulls[2]);
-    assert_eq!(values[2], 5);
+    assert!(matches!(result, Err(Error::ExpectedResultSet)));
 }
 
 #[test_case(MSSQL; "Microsoft SQL Server")]
 #[test_case(MARIADB; "Maria DB")]
 #[test_case(SQLITE_3; "SQLite 3")]
 #[test_case(POSTGRES; "PostgreSQL")]
-fn text_column_view_should_allow_for_filling_arrow_arrays(profile: &Profile) {
+fn insert_optional_floats(profile: &Profile) {
     // Given
-    let table_name = "TextColumnViewShouldAllowForFillingArrowArrays";
-    let conn = profile
-        .setup_empty_table(table_name, &["VARCHAR(50)"])
+    let table_name = table_name!();
+    let (conn, table) = Given::new(&table_name)
+        .column_types(&["REAL", "FLOAT(53)"])
+        .build(profile)
         .unwrap();
+
+    // When
     conn.execute(
-        &format!(
-            "INSERT INTO {table_name} (a) VALUES \
-                ('abcd'), \
-                (NULL), \
-                ('efghij'), \
-                ('klm'), \
-                ('npqrstu')"
+        &table.sql_insert(),
+        (
+            &Some(1.5f32).into_parameter(),
+            &None::<f64>.into_parameter(),
         ),
-        (),
+    )
+    .unwrap();
+    conn.execute(
+        &table.sql_insert(),
+        (&(&2.5f32).into_parameter(), &(&0.25f64).into_parameter()),
     )
     .unwrap();
 
-    // When
-    let cursor = conn
-        .execute(&format!("SELECT a FROM {table_name}"), ())
+    // Then
+    let mut cursor = conn
+        .execute(&table.sql_all_ordered_by_id(), ())
         .unwrap()
         .unwrap();
-
-    let columnar_buffer =
-        ColumnarAnyBuffer::try_from_descs(10, [BufferDesc::Text { max_str_len: 50 }]).unwrap();
-
-    let mut cursor = cursor.bind_buffer(columnar_buffer).unwrap();
-    let batch = cursor.fetch().unwrap().unwrap();
-    let view = batch.column(0).as_text_view().unwrap();
-
-    let mut valid = Vec::with_capacity(view.len());
-    let mut offsets = Vec::with_capacity(view.len() + 1);
-
-    let mut offset: usize = 0;
-    for index in 0..view.len() {
-        offset += view.content_length_at(index).unwrap_or(0)
+    let mut actual = Vec::new();
+    while let Some(mut row) = cursor.next_row().unwrap() {
+        let mut a = Nullable::<f32>::null();
+        let mut b = Nullable::<f64>::null();
+        row.get_data(1, &mut a).unwrap();
+        row.get_data(2, &mut b).unwrap();
+        actual.push((a.into_opt(), b.into_opt()));
     }
+    assert_eq!(vec![(Some(1.5), None), (Some(2.5), Some(0.25))], actual);
+}
 
-    let mut consequtives_values = Vec::with_capacity(offset);
-    let raw_values_odbc = view.raw_value_buffer();
-    offset = 0;
-    for index in 0..view.len() {
-        offsets.push(offset);
-        if let Some(len) = view.content_length_at(index) {
-            valid.push(true);
-            offset += len;
-            let start_index = index * (view.max_len() + 1);
-            consequtives_values
-                .extend_from_slice(&raw_values_odbc[start_index..(start_index + len)])
-        } else {
-            valid.push(false);
-        }
-    }
+/// Positions missing in a parameter map are bound as NULL
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+fn sparse_parameters_from_hash_map(profile: &Profile) {
+    // Given
+    let table_name = table_name!();
+    let (conn, table) = Given::new(&table_name)
+        .column_types(&["INTEGER", "INTEGER", "INTEGER"])
+        .build(profile)
+        .unwrap();
+
+    // When
+    let mut params = HashMap::new();
+    params.insert(1, 1i32);
+    params.insert(3, 3i32);
+    conn.execute(&table.sql_insert(), &params).unwrap();
 
     // Then
-    assert_eq!(valid, [true, false, true, true, true]);
-    assert_eq!(offsets, [0, 4, 4, 10, 13]);
-    assert_eq!(consequtives_values, b"abcdefghijklmnpqrstu");
+    assert_eq!("1,NULL,3", table.content_as_string(&conn));
 }
 
+/// Placeholders after the largest position in a parameter map are reported as missing
 #[test_case(MSSQL; "Microsoft SQL Server")]
 #[test_case(MARIADB; "Maria DB")]
 #[test_case(SQLITE_3; "SQLite 3")]
 #[test_case(POSTGRES; "PostgreSQL")]
-fn detect_truncated_output_in_bulk_fetch(profile: &Profile) {
-    // Given a text entry with a length of ten.
+fn sparse_parameters_missing_last_position(profile: &Profile) {
+    // Given
     let table_name = table_name!();
-    let conn = profile
-        .setup_empty_table(&table_name, &["VARCHAR(10)"])
+    let (conn, table) = Given::new(&table_name)
+        .column_types(&["INTEGER", "INTEGER", "INTEGER"])
+        .build(profile)
         .unwrap();
-    conn.execute(
-        &format!("INSERT INTO {table_name} (a) VALUES ('0123456789')"),
-        (),
-    )
-    .unwrap();
 
-    // When fetching that field as part of a bulk, but with a buffer of only length 5.
-    let buffer_description = BufferDesc::Text { max_str_len: 5 };
-    let buffer = ColumnarAnyBuffer::try_from_descs(1, [buffer_description]).unwrap();
-    let query = format!("SELECT a FROM {table_name}");
-    let cursor = conn.execute(&query, ()).unwrap().unwrap();
-    let mut cursor = cursor.bind_buffer(buffer).unwrap();
+    // When
+    let mut params = HashMap::new();
+    params.insert(1, 1i32);
+    let result = conn.execute_checked(&table.sql_insert(), &params);
+
+    // Then
     assert!(matches!(
-        cursor.fetch_with_truncation_check(true),
-        Err(Error::TooLargeValueForBuffer {
-            indicator: Some(10),
-            buffer_index: 0,
+        result,
+        Err(Error::ParameterCountMismatch {
+            expected: 3,
+            provided: 1
         })
-    ))
+    ));
 }
 
+/// Parameter positions are one based, so a parameter map must not contain a key of zero
 #[test_case(MSSQL; "Microsoft SQL Server")]
 #[test_case(MARIADB; "Maria DB")]
 #[test_case(SQLITE_3; "SQLite 3")]
 #[test_case(POSTGRES; "PostgreSQL")]
-fn grow_batch_size_during_bulk_insert(profile: &Profile) {
-    // Given a table
+fn sparse_parameters_reject_position_zero(profile: &Profile) {
+    // Given
     let table_name = table_name!();
-    let conn = profile
-        .setup_empty_table(&table_name, &["INTEGER"])
+    let (conn, table) = Given::new(&table_name)
+        .column_types(&["INTEGER"])
+        .build(profile)
         .unwrap();
 
-    // When insert two batches with size one and two.
-    let mut prepared = conn
-        .prepare(&format!("INSERT INTO {table_name} (a) VALUES (?)"))
-        .unwrap();
-    let desc = BufferDesc::I32 { nullable: false };
-    // The first batch is inserted with capacity 1
-    let mut prebound = prepared.column_inserter(1, [desc]).unwrap();
-    prebound.set_num_rows(1);
-    let col = prebound.column_mut(0).as_slice::<i32>().unwrap();
-    col[0] = 1;
-    prebound.execute().unwrap();
-    // Second batch is larger than the first and does not fit into the capacity. Only way to resize
-    // is currently to destroy everything the ColumnarInserter, but luckily we only borrowed the
-    // statement.
-    let mut prebound = prepared.column_inserter(2, [desc]).unwrap();
-    prebound.set_num_rows(2);
-    let col = prebound.column_mut(0).as_slice::<i32>().unwrap();
-    col[0] = 2;
-    col[1] = 3;
-    prebound.execute().unwrap();
+    // When
+    let mut params = HashMap::new();
+    params.insert(0, 1i32);
+    let result = conn.execute(&table.sql_insert(), &params);
 
     // Then
-    let cursor = conn
-        .execute(&format!("SELECT a FROM {table_name} ORDER BY id"), ())
-        .unwrap()
-        .unwrap();
-    let actual = cursor_to_string(cursor);
-    assert_eq!("1\n2\n3", actual);
+    assert!(matches!(
+        result,
+        Err(Error::InvalidParameterPosition { position: 0 })
+    ));
 }
 
+/// Durations are bound as whole seconds, truncating any sub-second part.
 #[test_case(MSSQL; "Microsoft SQL Server")]
 #[test_case(MARIADB; "Maria DB")]
 #[test_case(SQLITE_3; "SQLite 3")]
 #[test_case(POSTGRES; "PostgreSQL")]
-fn bulk_inserter_owning_connection(profile: &Profile) {
-    // Given a table
+fn insert_duration_as_seconds(profile: &Profile) {
+    // Given
     let table_name = table_name!();
-    let conn = profile
-        .setup_empty_table(&table_name, &["INTEGER"])
+    let (conn, table) = Given::new(&table_name)
+        .column_types(&["BIGINT"])
+        .build(profile)
         .unwrap();
 
-    // When insert two batches with size one and two.
-    let mut prepared = conn
-        .into_prepared(&format!("INSERT INTO {table_name} (a) VALUES (?)"))
+    // When
+    conn.execute(
+        &table.sql_insert(),
+        &Duration::from_millis(90_500).into_parameter(),
+    )
+    .unwrap();
+    conn.execute(&table.sql_insert(), &None::<Duration>.into_parameter())
         .unwrap();
-    let desc = BufferDesc::I32 { nullable: false };
-    // Insert a batch
-    let mut prebound = prepared.column_inserter(1, [desc]).unwrap();
-    prebound.set_num_rows(1);
-    let col = prebound.column_mut(0).as_slice::<i32>().unwrap();
-    col[0] = 1;
-    prebound.execute().unwrap();
 
     // Then
-    let conn = profile.connection().unwrap();
-    let cursor = conn
-        .execute(&format!("SELECT a FROM {table_name} ORDER BY id"), ())
+    let mut cursor = conn
+        .execute(&table.sql_all_ordered_by_id(), ())
         .unwrap()
         .unwrap();
-    let actual = cursor_to_string(cursor);
-    assert_eq!("1", actual);
+    let mut actual = Vec::new();
+    while let Some(mut row) = cursor.next_row().unwrap() {
+        let mut seconds = Nullable::<i64>::null();
+        row.get_data(1, &mut seconds).unwrap();
+        actual.push(seconds.into_opt());
+    }
+    assert_eq!(vec![Some(90), None], actual);
 }
 
-/// Fire an insert statement adding two rows and verify that the count of changed rows is 2.
+/// Bind a buffer with a large capacity, but fetch fewer rows with each call to fetch.
 #[test_case(MSSQL; "Microsoft SQL Server")]
 #[test_case(MARIADB; "Maria DB")]
 #[test_case(SQLITE_3; "SQLite 3")]
 #[test_case(POSTGRES; "PostgreSQL")]
-fn row_count_one_shot_query(profile: &Profile) {
-    // Given
+fn bind_buffer_with_fetch_size_smaller_than_capacity(profile: &Profile) {
+    // Given a table with 25 rows
     let table_name = table_name!();
-    let (conn, _table) = Given::new(&table_name)
+    let (conn, table) = Given::new(&table_name)
         .column_types(&["INTEGER"])
         .build(profile)
         .unwrap();
-    let insert = format!("INSERT INTO {table_name} (a) VALUES (1), (2)");
+    let values: Vec<i32> = (1..=25).collect();
+    let mut prepared = conn.prepare(&table.sql_insert()).unwrap();
+    for value in &values {
+        prepared.execute(value).unwrap();
+    }
 
-    // When
-    let mut preallocated = conn.preallocate().unwrap();
-    preallocated.execute(&insert, ()).unwrap();
-    let row_count = preallocated.row_count().unwrap();
+    // When fetching with a fetch size of 10 into a buffer with capacity 100
+    let cursor = conn
+        .execute(&table.sql_all_ordered_by_id(), ())
+        .unwrap()
+        .unwrap();
+    let buffer = ColumnarAnyBuffer::from_descs(100, [BufferDesc::I32 { nullable: false }]);
+    let mut block_cursor = cursor.bind_buffer_with_fetch_size(buffer, 10).unwrap();
+    let mut batch_sizes = Vec::new();
+    let mut actual = Vec::new();
+    while let Some(batch) = block_cursor.fetch().unwrap() {
+        batch_sizes.push(batch.num_rows());
+        actual.extend_from_slice(batch.column(0).as_slice::<i32>().unwrap());
+    }
 
     // Then
-    assert_eq!(Some(2), row_count);
+    assert_eq!(10, block_cursor.row_array_size());
+    assert_eq!(vec![10, 10, 5], batch_sizes);
+    assert_eq!(values, actual);
 }
 
-/// Fire an insert statement adding two rows and verify that the count of changed rows is 2.
+/// Alternate between two buffers, swapping them in between fetches.
 #[test_case(MSSQL; "Microsoft SQL Server")]
 #[test_case(MARIADB; "Maria DB")]
 #[test_case(SQLITE_3; "SQLite 3")]
 #[test_case(POSTGRES; "PostgreSQL")]
-fn row_count_prepared_insert(profile: &Profile) {
-    // Given
+fn rebind_block_cursor_to_alternate_buffers(profile: &Profile) {
+    // Given a table with 5 rows
     let table_name = table_name!();
-    let (conn, _table) = Given::new(&table_name)
+    let (conn, table) = Given::new(&table_name)
         .column_types(&["INTEGER"])
+        .values_by_column(&[&[Some("1"), Some("2"), Some("3"), Some("4"), Some("5")]])
         .build(profile)
         .unwrap();
-    let insert = format!("INSERT INTO {table_name} (a) VALUES (?), (?)");
+    let cursor = conn
+        .execute(&table.sql_all_ordered_by_id(), ())
+        .unwrap()
+        .unwrap();
+    let first = ColumnarAnyBuffer::from_descs(2, [BufferDesc::I32 { nullable: false }]);
+    let mut spare = ColumnarAnyBuffer::from_descs(2, [BufferDesc::I32 { nullable: false }]);
 
-    // When
-    let mut prepared = conn.prepare(&insert).unwrap();
-    prepared.execute((&1, &2)).unwrap();
-    let row_count = prepared.row_count().unwrap();
+    // When fetching and swapping buffers after each fetch
+    let mut block_cursor = cursor.bind_buffer(first).unwrap();
+    let mut batches = Vec::new();
+    while block_cursor.fetch().unwrap().is_some() {
+        spare = block_cursor.rebind(spare).unwrap();
+        batches.push(spare.column(0).as_slice::<i32>().unwrap().to_vec());
+    }
 
-    // Then
-    assert_eq!(Some(2), row_count);
+    // Then each buffer holds the values of the batch fetched into it
+    assert_eq!(vec![vec![1, 2], vec![3, 4], vec![5]], batches);
 }
 
-#[test_case(MSSQL, None; "Microsoft SQL Server")]
-#[test_case(MARIADB, Some(0); "Maria DB")]
-#[test_case(SQLITE_3, Some(0); "SQLite 3")]
-#[test_case(POSTGRES, Some(0); "PostgreSQL")]
-fn row_count_create_table_preallocated(profile: &Profile, expectation: Option<usize>) {
-    // Given a name for a table which does not exist
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+#[test_case(POSTGRES; "PostgreSQL")]
+fn execute_checked_with_too_few_parameters(profile: &Profile) {
+    // Given
     let table_name = table_name!();
-    let conn = profile.connection().unwrap();
-    conn.execute(&format!("DROP TABLE IF EXISTS {table_name};"), ())
+    let conn = profile
+        .setup_empty_table(&table_name, &["INTEGER", "INTEGER"])
         .unwrap();
 
     // When
-    let mut preallocated = conn.preallocate().unwrap();
-    preallocated
-        .execute(&format!("CREATE TABLE {table_name} (a INTEGER);"), ())
-        .unwrap();
-    let row_count = preallocated.row_count().unwrap();
+    let insert = format!("INSERT INTO {table_name} (a, b) VALUES (?, ?)");
+    let result = conn.execute_checked(&insert, &1i32);
 
     // Then
-    assert_eq!(expectation, row_count);
+    assert!(matches!(
+        result,
+        Err(Error::ParameterCountMismatch {
+            expected: 2,
+            provided: 1
+        })
+    ));
 }
 
-#[test_case(MSSQL, Some(0); "Microsoft SQL Server")]
-#[test_case(MARIADB, Some(0); "Maria DB")]
-#[test_case(SQLITE_3, Some(0); "SQLite 3")]
-#[test_case(POSTGRES, Some(0); "PostgreSQL")]
-fn row_count_create_table_prepared(profile: &Profile, expectation: Option<usize>) {
-    // Given a name for a table which does not exist
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+#[test_case(POSTGRES; "PostgreSQL")]
+fn execute_checked_ignores_question_mark_in_string_literal(profile: &Profile) {
+    // Given
     let table_name = table_name!();
-    let conn = profile.connection().unwrap();
-    conn.execute(&format!("DROP TABLE IF EXISTS {table_name};"), ())
+    let (conn, table) = Given::new(&table_name)
+        .column_types(&["INTEGER", "VARCHAR(10)"])
+        .build(profile)
         .unwrap();
 
     // When
-    let mut prepared = conn
-        .prepare(&format!("CREATE TABLE {table_name} (a INTEGER);"))
-        .unwrap();
-    prepared.execute(()).unwrap();
-    let row_count = prepared.row_count().unwrap();
+    let insert = format!("INSERT INTO {table_name} (a, b) VALUES (?, 'Why?')");
+    conn.execute_checked(&insert, &42i32).unwrap();
 
     // Then
-    assert_eq!(expectation, row_count);
+    let actual = table.content_as_string(&conn);
+    assert_eq!("42,Why?", actual);
 }
 
+/// `query!` requires a string literal, so the table name is fixed rather than derived from the
+/// test name.
+#[cfg(feature = "query_macro")]
 #[test_case(MSSQL; "Microsoft SQL Server")]
 #[test_case(MARIADB; "Maria DB")]
 #[test_case(SQLITE_3; "SQLite 3")]
 #[test_case(POSTGRES; "PostgreSQL")]
-fn list_foreign_keys(profile: &Profile) {
-    // Other table references table
-    let pk_table_name = table_name!();
-    let fk_table_name = format!("other_{pk_table_name}");
-    let conn = profile.connection().unwrap();
-    conn.execute(&format!("DROP TABLE IF EXISTS {fk_table_name};"), ())
-        .unwrap();
-    conn.execute(&format!("DROP TABLE IF EXISTS {pk_table_name};"), ())
+fn insert_with_query_macro(profile: &Profile) {
+    use odbc_api::query;
+
+    // Given
+    let (conn, table) = Given::new("QueryMacro")
+        .column_types(&["INTEGER", "VARCHAR(10)"])
+        .build(profile)
         .unwrap();
-    conn.execute(
-        &format!("CREATE TABLE {pk_table_name} (id INTEGER, PRIMARY KEY(id));"),
-        (),
-    )
-    .unwrap();
-    conn.execute(
-        &format!(
-            "CREATE TABLE {fk_table_name} (ext_id INTEGER, FOREIGN KEY (ext_id) REFERENCES \
-            {pk_table_name}(id));"
-        ),
-        (),
+
+    // When
+    query!(
+        conn,
+        "INSERT INTO QueryMacro (a, b) VALUES (?, 'Why?') -- ?",
+        &42i32
     )
     .unwrap();
 
-    let mut cursor = conn
-        .foreign_keys("", "", "", "", "", &fk_table_name)
-        .unwrap();
-    let buffer = TextRowSet::for_cursor(10, &mut cursor, Some(256)).unwrap();
-    let mut cursor = cursor.bind_buffer(buffer).unwrap();
-    let batch = cursor.fetch().unwrap().unwrap();
-    let retrieved_pk_table_name = batch.at_as_str(2, 0).unwrap().unwrap();
-    let retrieved_fk_table_name = batch.at_as_str(6, 0).unwrap().unwrap();
-
-    assert_eq!(retrieved_pk_table_name, pk_table_name);
-    assert_eq!(retrieved_fk_table_name, fk_table_name);
-    assert_eq!(batch.num_rows(), 1);
+    // Then
+    let actual = table.content_as_string(&conn);
+    assert_eq!("42,Why?", actual);
 }
 
+/// Bind the first column to a buffer and fetch the trailing one using `get_data`.
 #[test_case(MSSQL; "Microsoft SQL Server")]
 #[test_case(MARIADB; "Maria DB")]
 #[test_case(SQLITE_3; "SQLite 3")]
 #[test_case(POSTGRES; "PostgreSQL")]
-fn list_foreign_keys_prealloc(profile: &Profile) {
-    // Other table references table
-    let pk_table_name = table_name!();
-    let fk_table_name = format!("other_{pk_table_name}");
-    let conn = profile.connection().unwrap();
-    conn.execute(&format!("DROP TABLE IF EXISTS {fk_table_name};"), ())
-        .unwrap();
-    conn.execute(&format!("DROP TABLE IF EXISTS {pk_table_name};"), ())
-        .unwrap();
-    conn.execute(
-        &format!("CREATE TABLE {pk_table_name} (id INTEGER, PRIMARY KEY(id));"),
-        (),
-    )
-    .unwrap();
-    conn.execute(
-        &format!(
-            "CREATE TABLE {fk_table_name} (ext_id INTEGER, FOREIGN KEY (ext_id) REFERENCES \
-            {pk_table_name}(id));"
-        ),
-        (),
-    )
-    .unwrap();
-
-    let mut stmt = conn.preallocate().unwrap();
-    let mut cursor = stmt
-        .foreign_keys("", "", "", "", "", &fk_table_name)
-        .unwrap();
-    let buffer = TextRowSet::for_cursor(10, &mut cursor, Some(256)).unwrap();
-    let mut cursor = cursor.bind_buffer(buffer).unwrap();
-    let batch = cursor.fetch().unwrap().unwrap();
-    let retrieved_pk_table_name = batch.at_as_str(2, 0).unwrap().unwrap();
-    let retrieved_fk_table_name = batch.at_as_str(6, 0).unwrap().unwrap();
-
-    assert_eq!(retrieved_pk_table_name, pk_table_name);
-    assert_eq!(retrieved_fk_table_name, fk_table_name);
-    assert_eq!(batch.num_rows(), 1);
-}
-
-// The two failing drivers confuse buffer and character lengths with each other. It could not be
-// worked around by allocating larger buffers.
-// #[test_case(MSSQL; "Microsoft SQL Server")]
-// #[test_case(MARIADB; "Maria DB")]
-
-// SQLite just does not recognize the last letter
-// #[test_case(SQLITE_3; "SQLite 3")]
-#[test_case(POSTGRES; "PostgreSQL")]
-fn describe_column_name_with_umlaut(profile: &Profile) {
+fn fetch_row_combines_bound_columns_with_get_data(profile: &Profile) {
+    // Given
     let table_name = table_name!();
-    // Given a table with an umlaut in a column name
-    let (conn, _table) = Given::new(&table_name)
-        .column_types(&["INTEGER"])
-        .column_names(&["hällo𐐏"])
+    let (conn, table) = Given::new(&table_name)
+        .column_types(&["INTEGER", "VARCHAR(10)"])
+        .values_by_column(&[&[Some("1"), Some("2")], &[Some("one"), Some("two")]])
         .build(profile)
         .unwrap();
-
-    // When executing a query with a result set containing that column and describing that column
-    let mut result_set = conn
-        .execute(&format!("SELECT hällo𐐏 FROM {table_name}"), ())
+    let cursor = conn
+        .execute(&table.sql_all_ordered_by_id(), ())
         .unwrap()
         .unwrap();
-    let mut desc = ColumnDescription::default();
-    result_set.describe_col(1, &mut desc).unwrap();
-    let column_name = desc.name_to_string().unwrap();
+    let buffer = ColumnarAnyBuffer::from_descs_and_indices(
+        1,
+        [(1, BufferDesc::I32 { nullable: false })].into_iter(),
+    );
+
+    // When
+    let mut block_cursor = cursor.bind_buffer(buffer).unwrap();
+    let mut rows = Vec::new();
+    let mut text = Vec::new();
+    while let Some((batch, mut row)) = block_cursor.fetch_row().unwrap() {
+        let id = batch.column(0).as_slice::<i32>().unwrap()[0];
+        row.get_text(2, &mut text).unwrap();
+        rows.push((id, String::from_utf8(text.clone()).unwrap()));
+    }
 
     // Then
-    assert_eq!("hällo𐐏", column_name);
+    assert_eq!(vec![(1, "one".to_string()), (2, "two".to_string())], rows);
 }
 
 #[test_case(MSSQL; "Microsoft SQL Server")]
-// #[test_case(MARIADB; "Maria DB")] Only allows one SQL Statement
-// #[test_case(SQLITE_3; "SQLite 3")] Only allows one SQL Statement
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
 #[test_case(POSTGRES; "PostgreSQL")]
-fn execute_two_select_statements(profile: &Profile) {
+#[should_panic(expected = "SQLFreeHandle failed with error code: -1")]
+fn should_panic_if_connection_cannot_be_freed(profile: &Profile) {
     let conn = profile.connection().unwrap();
 
-    let cursor = conn
-        .execute("SELECT 1 AS A; SELECT 2 AS B;", ())
-        .unwrap()
-        .unwrap();
+    // Since the types with their invariants in this crate helpfully prevent us from freeing a
+    // connected handles, we have to abandon the saftey rails in order to provoke the error.
+    let conn = conn.into_handle();
 
-    let maybe_cursor = cursor.more_results().unwrap();
-    assert!(maybe_cursor.is_some());
-    let cursor = maybe_cursor.unwrap();
-    let maybe_cursor = cursor.more_results().unwrap();
-    assert!(maybe_cursor.is_none());
+    // We drop the connection, but it is still connected. => This is a programming error, we want
+    // the drop handler to panic.
+    drop(conn);
+}
+
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+#[test_case(POSTGRES; "PostgreSQL")]
+#[should_panic(expected = "original error")]
+fn panic_in_drop_handlers_should_not_mask_original_error(profile: &Profile) {
+    let conn = profile.connection().unwrap();
+
+    // Since the types with their invariants in this crate helpfully prevent us from freeing a
+    // connected handles, we have to abandon the saftey rails in order to provoke the error.
+    let _conn = conn.into_handle();
+
+    // If this error is propagated upwards, the above connections drop handler will be called and
+    // fail. This tests wants to ensure the original error is not masked by that.
+    panic!("original error")
+}
+
+/// Arrow uses the same binary format for the values of nullable slices, though null are represented
+/// as bitmask. Make it possible for bindings to efficiently copy the values array out of a
+/// nullable slice.
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+#[test_case(POSTGRES; "PostgreSQL")]
+fn memcopy_values_from_nullable_slice(profile: &Profile) {
+    // Given
+    let table_name = table_name!();
+    let conn = profile
+        .setup_empty_table(&table_name, &["INTEGER"])
+        .unwrap();
+    conn.execute(
+        &format!("INSERT INTO {table_name} (a) VALUES (42), (NULL), (5);"),
+        (),
+    )
+    .unwrap();
+
+    // When
+    let cursor = conn
+        .execute(&format!("SELECT a FROM {table_name}"), ())
+        .unwrap() // Unwrap Result
+        .unwrap(); // Unwrap Option, we know a select statement to produce a cursor.
+    let buffer =
+        ColumnarAnyBuffer::try_from_descs(3, [BufferDesc::I32 { nullable: true }]).unwrap();
+    let mut cursor = cursor.bind_buffer(buffer).unwrap();
+    let batch = cursor.fetch().unwrap().unwrap();
+    let nullable_slice = batch.column(0).as_nullable_slice::<i32>().unwrap();
+    let (values, indicators) = nullable_slice.raw_values();
+    // Memcopy values.
+    let values = values.to_vec();
+    // Create array of bools indicating null values.
+    let nulls: Vec<bool> = indicators
+        .iter()
+        .map(|&indicator| indicator == sys::NULL_DATA)
+        .collect();
+
+    // Then
+    assert!(!nulls[0]);
+    assert_eq!(values[0], 42);
+    assert!(nulls[1]);
+    // We explicitly don't give any guarantees about the value of #values[1]`.
+    assert!(!nulls[2]);
+    assert_eq!(values[2], 5);
+}
+
+/// The slice of a non nullable column should cover only the rows fetched, not the entire capacity
+/// of the buffer.
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+#[test_case(POSTGRES; "PostgreSQL")]
+fn slice_of_fixed_sized_column_has_length_of_fetched_rows(profile: &Profile) {
+    // Given
+    let table_name = table_name!();
+    let (conn, table) = Given::new(&table_name)
+        .column_types(&["INTEGER"])
+        .values_by_column(&[&[Some("1"), Some("2"), Some("3")]])
+        .build(profile)
+        .unwrap();
+    let cursor = conn
+        .execute(&table.sql_all_ordered_by_id(), ())
+        .unwrap()
+        .unwrap();
+    let buffer =
+        ColumnarAnyBuffer::try_from_descs(10, [BufferDesc::I32 { nullable: false }]).unwrap();
+    let mut cursor = cursor.bind_buffer(buffer).unwrap();
+
+    // When
+    let batch = cursor.fetch().unwrap().unwrap();
+    let values = batch.column(0).as_slice::<i32>().unwrap();
+
+    // Then
+    assert_eq!(3, batch.num_rows());
+    assert_eq!(batch.num_rows(), values.len());
+    assert_eq!([1, 2, 3], values);
+}
+
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+#[test_case(POSTGRES; "PostgreSQL")]
+fn text_column_view_should_allow_for_filling_arrow_arrays(profile: &Profile) {
+    // Given
+    let table_name = "TextColumnViewShouldAllowForFillingArrowArrays";
+    let conn = profile
+        .setup_empty_table(table_name, &["VARCHAR(50)"])
+        .unwrap();
+    conn.execute(
+        &format!(
+            "INSERT INTO {table_name} (a) VALUES \
+                ('abcd'), \
+                (NULL), \
+                ('efghij'), \
+                ('klm'), \
+                ('npqrstu')"
+        ),
+        (),
+    )
+    .unwrap();
+
+    // When
+    let cursor = conn
+        .execute(&format!("SELECT a FROM {table_name}"), ())
+        .unwrap()
+        .unwrap();
+
+    let columnar_buffer =
+        ColumnarAnyBuffer::try_from_descs(10, [BufferDesc::Text { max_str_len: 50 }]).unwrap();
+
+    let mut cursor = cursor.bind_buffer(columnar_buffer).unwrap();
+    let batch = cursor.fetch().unwrap().unwrap();
+    let view = batch.column(0).as_text_view().unwrap();
+
+    let mut valid = Vec::with_capacity(view.len());
+    let mut offsets = Vec::with_capacity(view.len() + 1);
+
+    let mut offset: usize = 0;
+    for index in 0..view.len() {
+        offset += view.content_length_at(index).unwrap_or(0)
+    }
+
+    let mut consequtives_values = Vec::with_capacity(offset);
+    let raw_values_odbc = view.raw_value_buffer();
+    offset = 0;
+    for index in 0..view.len() {
+        offsets.push(offset);
+        if let Some(len) = view.content_length_at(index) {
+            valid.push(true);
+            offset += len;
+            let start_index = index * (view.max_len() + 1);
+            consequtives_values
+                .extend_from_slice(&raw_values_odbc[start_index..(start_index + len)])
+        } else {
+            valid.push(false);
+        }
+    }
+
+    // Then
+    assert_eq!(valid, [true, false, true, true, true]);
+    assert_eq!(offsets, [0, 4, 4, 10, 13]);
+    assert_eq!(consequtives_values, b"abcdefghijklmnpqrstu");
+}
+
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+#[test_case(POSTGRES; "PostgreSQL")]
+fn detect_truncated_output_in_bulk_fetch(profile: &Profile) {
+    // Given a text entry with a length of ten.
+    let table_name = table_name!();
+    let conn = profile
+        .setup_empty_table(&table_name, &["VARCHAR(10)"])
+        .unwrap();
+    conn.execute(
+        &format!("INSERT INTO {table_name} (a) VALUES ('0123456789')"),
+        (),
+    )
+    .unwrap();
+
+    // When fetching that field as part of a bulk, but with a buffer of only length 5.
+    let buffer_description = BufferDesc::Text { max_str_len: 5 };
+    let buffer = ColumnarAnyBuffer::try_from_descs(1, [buffer_description]).unwrap();
+    let query = format!("SELECT a FROM {table_name}");
+    let cursor = conn.execute(&query, ()).unwrap().unwrap();
+    let mut cursor = cursor.bind_buffer(buffer).unwrap();
+    assert!(matches!(
+        cursor.fetch_with_truncation_check(true),
+        Err(Error::TooLargeValueForBuffer {
+            indicator: Some(10),
+            buffer_index: 0,
+        })
+    ))
+}
+
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+#[test_case(POSTGRES; "PostgreSQL")]
+fn report_truncation_alongside_truncated_batch(profile: &Profile) {
+    // Given a text entry with a length of ten.
+    let table_name = table_name!();
+    let (conn, table) = Given::new(&table_name)
+        .column_types(&["VARCHAR(10)"])
+        .values_by_column(&[&[Some("0123456789")]])
+        .build(profile)
+        .unwrap();
+
+    // When fetching that field as part of a bulk, but with a buffer of only length 5.
+    let buffer = TextRowSet::from_max_str_lens(1, [5]).unwrap();
+    let cursor = conn
+        .execute(&table.sql_all_ordered_by_id(), ())
+        .unwrap()
+        .unwrap();
+    let mut cursor = cursor.bind_buffer(buffer).unwrap();
+    let (batch, truncation) = cursor.fetch_reporting_truncation().unwrap().unwrap();
+
+    // Then the truncated value is still accessible together with the truncation info
+    assert_eq!(Some("01234"), batch.at_as_str(0, 0).unwrap());
+    assert_eq!(
+        Some(TruncationInfo {
+            indicator: Some(10),
+            buffer_index: 0
+        }),
+        truncation
+    );
+    assert!(cursor.fetch_reporting_truncation().unwrap().is_none());
+}
+
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+#[test_case(POSTGRES; "PostgreSQL")]
+fn collect_truncations_of_all_columns(profile: &Profile) {
+    // Given two text columns with values of length ten and one column with short values.
+    let table_name = table_name!();
+    let (conn, table) = Given::new(&table_name)
+        .column_types(&["VARCHAR(10)", "VARCHAR(10)", "VARCHAR(10)"])
+        .values_by_column(&[&[Some("0123456789")], &[Some("ab")], &[Some("abcdefghij")]])
+        .build(profile)
+        .unwrap();
+
+    // When fetching them into buffers of length 5.
+    let buffer = TextRowSet::from_max_str_lens(1, [5, 5, 5]).unwrap();
+    let cursor = conn
+        .execute(&table.sql_all_ordered_by_id(), ())
+        .unwrap()
+        .unwrap();
+    let mut cursor = cursor.bind_buffer(buffer).unwrap();
+    let (_batch, truncations) = cursor.fetch_collect_truncations().unwrap().unwrap();
+
+    // Then both columns with truncated values are reported.
+    assert_eq!(
+        vec![
+            TruncationInfo {
+                indicator: Some(10),
+                buffer_index: 0
+            },
+            TruncationInfo {
+                indicator: Some(10),
+                buffer_index: 2
+            }
+        ],
+        truncations
+    );
+    assert!(cursor.fetch_collect_truncations().unwrap().is_none());
+}
+
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+#[test_case(POSTGRES; "PostgreSQL")]
+fn collect_truncation_warning_of_fetch(profile: &Profile) {
+    // Given a text entry with a length of ten.
+    let table_name = table_name!();
+    let (conn, table) = Given::new(&table_name)
+        .column_types(&["VARCHAR(10)"])
+        .values_by_column(&[&[Some("0123456789")]])
+        .build(profile)
+        .unwrap();
+
+    // When fetching it into a buffer of length 5, without checking for truncation.
+    let buffer = TextRowSet::from_max_str_lens(1, [5]).unwrap();
+    let cursor = conn
+        .execute(&table.sql_all_ordered_by_id(), ())
+        .unwrap()
+        .unwrap();
+    let mut cursor = cursor.bind_buffer(buffer).unwrap();
+    cursor.fetch().unwrap().unwrap();
+
+    // Then the warning of the driver about the truncation is collected
+    assert!(cursor
+        .warnings()
+        .iter()
+        .any(|record| record.state == State::STRING_DATA_RIGHT_TRUNCATION));
+    // and the warnings are cleared by the next call to fetch.
+    assert!(cursor.fetch().unwrap().is_none());
+    assert!(cursor.warnings().is_empty());
+}
+
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+#[test_case(POSTGRES; "PostgreSQL")]
+fn grow_batch_size_during_bulk_insert(profile: &Profile) {
+    // Given a table
+    let table_name = table_name!();
+    let conn = profile
+        .setup_empty_table(&table_name, &["INTEGER"])
+        .unwrap();
+
+    // When insert two batches with size one and two.
+    let mut prepared = conn
+        .prepare(&format!("INSERT INTO {table_name} (a) VALUES (?)"))
+        .unwrap();
+    let desc = BufferDesc::I32 { nullable: false };
+    // The first batch is inserted with capacity 1
+    let mut prebound = prepared.column_inserter(1, [desc]).unwrap();
+    prebound.set_num_rows(1);
+    let col = prebound.column_mut(0).as_slice::<i32>().unwrap();
+    col[0] = 1;
+    prebound.execute().unwrap();
+    // Second batch is larger than the first and does not fit into the capacity. Only way to resize
+    // is currently to destroy everything the ColumnarInserter, but luckily we only borrowed the
+    // statement.
+    let mut prebound = prepared.column_inserter(2, [desc]).unwrap();
+    prebound.set_num_rows(2);
+    let col = prebound.column_mut(0).as_slice::<i32>().unwrap();
+    col[0] = 2;
+    col[1] = 3;
+    prebound.execute().unwrap();
+
+    // Then
+    let cursor = conn
+        .execute(&format!("SELECT a FROM {table_name} ORDER BY id"), ())
+        .unwrap()
+        .unwrap();
+    let actual = cursor_to_string(cursor);
+    assert_eq!("1\n2\n3", actual);
+}
+
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+#[test_case(POSTGRES; "PostgreSQL")]
+fn bulk_inserter_owning_connection(profile: &Profile) {
+    // Given a table
+    let table_name = table_name!();
+    let conn = profile
+        .setup_empty_table(&table_name, &["INTEGER"])
+        .unwrap();
+
+    // When insert two batches with size one and two.
+    let mut prepared = conn
+        .into_prepared(&format!("INSERT INTO {table_name} (a) VALUES (?)"))
+        .unwrap();
+    let desc = BufferDesc::I32 { nullable: false };
+    // Insert a batch
+    let mut prebound = prepared.column_inserter(1, [desc]).unwrap();
+    prebound.set_num_rows(1);
+    let col = prebound.column_mut(0).as_slice::<i32>().unwrap();
+    col[0] = 1;
+    prebound.execute().unwrap();
+
+    // Then
+    let conn = profile.connection().unwrap();
+    let cursor = conn
+        .execute(&format!("SELECT a FROM {table_name} ORDER BY id"), ())
+        .unwrap()
+        .unwrap();
+    let actual = cursor_to_string(cursor);
+    assert_eq!("1", actual);
+}
+
+/// Fire an insert statement adding two rows and verify that the count of changed rows is 2.
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+#[test_case(POSTGRES; "PostgreSQL")]
+fn row_count_one_shot_query(profile: &Profile) {
+    // Given
+    let table_name = table_name!();
+    let (conn, _table) = Given::new(&table_name)
+        .column_types(&["INTEGER"])
+        .build(profile)
+        .unwrap();
+    let insert = format!("INSERT INTO {table_name} (a) VALUES (1), (2)");
+
+    // When
+    let mut preallocated = conn.preallocate().unwrap();
+    preallocated.execute(&insert, ()).unwrap();
+    let row_count = preallocated.row_count().unwrap();
+
+    // Then
+    assert_eq!(Some(2), row_count);
+}
+
+/// Fire an insert statement adding two rows and verify that the count of changed rows is 2.
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+#[test_case(POSTGRES; "PostgreSQL")]
+fn row_count_prepared_insert(profile: &Profile) {
+    // Given
+    let table_name = table_name!();
+    let (conn, _table) = Given::new(&table_name)
+        .column_types(&["INTEGER"])
+        .build(profile)
+        .unwrap();
+    let insert = format!("INSERT INTO {table_name} (a) VALUES (?), (?)");
+
+    // When
+    let mut prepared = conn.prepare(&insert).unwrap();
+    prepared.execute((&1, &2)).unwrap();
+    let row_count = prepared.row_count().unwrap();
+
+    // Then
+    assert_eq!(Some(2), row_count);
+}
+
+#[test_case(MSSQL, None; "Microsoft SQL Server")]
+#[test_case(MARIADB, Some(0); "Maria DB")]
+#[test_case(SQLITE_3, Some(0); "SQLite 3")]
+#[test_case(POSTGRES, Some(0); "PostgreSQL")]
+fn row_count_create_table_preallocated(profile: &Profile, expectation: Option<usize>) {
+    // Given a name for a table which does not exist
+    let table_name = table_name!();
+    let conn = profile.connection().unwrap();
+    conn.execute(&format!("DROP TABLE IF EXISTS {table_name};"), ())
+        .unwrap();
+
+    // When
+    let mut preallocated = conn.preallocate().unwrap();
+    preallocated
+        .execute(&format!("CREATE TABLE {table_name} (a INTEGER);"), ())
+        .unwrap();
+    let row_count = preallocated.row_count().unwrap();
+
+    // Then
+    assert_eq!(expectation, row_count);
+}
+
+#[test_case(MSSQL, Some(0); "Microsoft SQL Server")]
+#[test_case(MARIADB, Some(0); "Maria DB")]
+#[test_case(SQLITE_3, Some(0); "SQLite 3")]
+#[test_case(POSTGRES, Some(0); "PostgreSQL")]
+fn row_count_create_table_prepared(profile: &Profile, expectation: Option<usize>) {
+    // Given a name for a table which does not exist
+    let table_name = table_name!();
+    let conn = profile.connection().unwrap();
+    conn.execute(&format!("DROP TABLE IF EXISTS {table_name};"), ())
+        .unwrap();
+
+    // When
+    let mut prepared = conn
+        .prepare(&format!("CREATE TABLE {table_name} (a INTEGER);"))
+        .unwrap();
+    prepared.execute(()).unwrap();
+    let row_count = prepared.row_count().unwrap();
+
+    // Then
+    assert_eq!(expectation, row_count);
+}
+
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+#[test_case(POSTGRES; "PostgreSQL")]
+fn list_foreign_keys(profile: &Profile) {
+    // Other table references table
+    let pk_table_name = table_name!();
+    let fk_table_name = format!("other_{pk_table_name}");
+    let conn = profile.connection().unwrap();
+    conn.execute(&format!("DROP TABLE IF EXISTS {fk_table_name};"), ())
+        .unwrap();
+    conn.execute(&format!("DROP TABLE IF EXISTS {pk_table_name};"), ())
+        .unwrap();
+    conn.execute(
+        &format!("CREATE TABLE {pk_table_name} (id INTEGER, PRIMARY KEY(id));"),
+        (),
+    )
+    .unwrap();
+    conn.execute(
+        &format!(
+            "CREATE TABLE {fk_table_name} (ext_id INTEGER, FOREIGN KEY (ext_id) REFERENCES \
+            {pk_table_name}(id));"
+        ),
+        (),
+    )
+    .unwrap();
+
+    let mut cursor = conn
+        .foreign_keys("", "", "", "", "", &fk_table_name)
+        .unwrap();
+    let buffer = TextRowSet::for_cursor(10, &mut cursor, Some(256)).unwrap();
+    let mut cursor = cursor.bind_buffer(buffer).unwrap();
+    let batch = cursor.fetch().unwrap().unwrap();
+    let retrieved_pk_table_name = batch.at_as_str(2, 0).unwrap().unwrap();
+    let retrieved_fk_table_name = batch.at_as_str(6, 0).unwrap().unwrap();
+
+    assert_eq!(retrieved_pk_table_name, pk_table_name);
+    assert_eq!(retrieved_fk_table_name, fk_table_name);
+    assert_eq!(batch.num_rows(), 1);
+}
+
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+#[test_case(POSTGRES; "PostgreSQL")]
+fn list_foreign_keys_prealloc(profile: &Profile) {
+    // Other table references table
+    let pk_table_name = table_name!();
+    let fk_table_name = format!("other_{pk_table_name}");
+    let conn = profile.connection().unwrap();
+    conn.execute(&format!("DROP TABLE IF EXISTS {fk_table_name};"), ())
+        .unwrap();
+    conn.execute(&format!("DROP TABLE IF EXISTS {pk_table_name};"), ())
+        .unwrap();
+    conn.execute(
+        &format!("CREATE TABLE {pk_table_name} (id INTEGER, PRIMARY KEY(id));"),
+        (),
+    )
+    .unwrap();
+    conn.execute(
+        &format!(
+            "CREATE TABLE {fk_table_name} (ext_id INTEGER, FOREIGN KEY (ext_id) REFERENCES \
+            {pk_table_name}(id));"
+        ),
+        (),
+    )
+    .unwrap();
+
+    let mut stmt = conn.preallocate().unwrap();
+    let mut cursor = stmt
+        .foreign_keys("", "", "", "", "", &fk_table_name)
+        .unwrap();
+    let buffer = TextRowSet::for_cursor(10, &mut cursor, Some(256)).unwrap();
+    let mut cursor = cursor.bind_buffer(buffer).unwrap();
+    let batch = cursor.fetch().unwrap().unwrap();
+    let retrieved_pk_table_name = batch.at_as_str(2, 0).unwrap().unwrap();
+    let retrieved_fk_table_name = batch.at_as_str(6, 0).unwrap().unwrap();
+
+    assert_eq!(retrieved_pk_table_name, pk_table_name);
+    assert_eq!(retrieved_fk_table_name, fk_table_name);
+    assert_eq!(batch.num_rows(), 1);
+}
+
+// The two failing drivers confuse buffer and character lengths with each other. It could not be
+// worked around by allocating larger buffers.
+// #[test_case(MSSQL; "Microsoft SQL Server")]
+// #[test_case(MARIADB; "Maria DB")]
+
+// SQLite just does not recognize the last letter
+// #[test_case(SQLITE_3; "SQLite 3")]
+#[test_case(POSTGRES; "PostgreSQL")]
+fn describe_column_name_with_umlaut(profile: &Profile) {
+    let table_name = table_name!();
+    // Given a table with an umlaut in a column name
+    let (conn, _table) = Given::new(&table_name)
+        .column_types(&["INTEGER"])
+        .column_names(&["hällo𐐏"])
+        .build(profile)
+        .unwrap();
+
+    // When executing a query with a result set containing that column and describing that column
+    let mut result_set = conn
+        .execute(&format!("SELECT hällo𐐏 FROM {table_name}"), ())
+        .unwrap()
+        .unwrap();
+    let mut desc = ColumnDescription::default();
+    result_set.describe_col(1, &mut desc).unwrap();
+    let column_name = desc.name_to_string().unwrap();
+
+    // Then
+    assert_eq!("hällo𐐏", column_name);
+}
+
+#[test_case(MSSQL; "Microsoft SQL Server")]
+// #[test_case(MARIADB; "Maria DB")] Only allows one SQL Statement
+// #[test_case(SQLITE_3; "SQLite 3")] Only allows one SQL Statement
+#[test_case(POSTGRES; "PostgreSQL")]
+fn execute_two_select_statements(profile: &Profile) {
+    let conn = profile.connection().unwrap();
+
+    let cursor = conn
+        .execute("SELECT 1 AS A; SELECT 2 AS B;", ())
+        .unwrap()
+        .unwrap();
+
+    let maybe_cursor = cursor.more_results().unwrap();
+    assert!(maybe_cursor.is_some());
+    let cursor = maybe_cursor.unwrap();
+    let maybe_cursor = cursor.more_results().unwrap();
+    assert!(maybe_cursor.is_none());
+}
+
+#[test_case(MSSQL; "Microsoft SQL Server")]
+fn reuse_buffer_for_next_result_set(profile: &Profile) {
+    // Given two result sets with identical schema
+    let conn = profile.connection().unwrap();
+    let cursor = conn
+        .execute("SELECT 1 AS A UNION ALL SELECT 2; SELECT 3 AS A;", ())
+        .unwrap()
+        .unwrap();
+    let buffer = ColumnarAnyBuffer::from_descs(10, [BufferDesc::I32 { nullable: false }]);
+
+    // When
+    let mut block_cursor = cursor.bind_buffer(buffer).unwrap();
+    let first = block_cursor
+        .fetch()
+        .unwrap()
+        .unwrap()
+        .column(0)
+        .as_slice::<i32>()
+        .unwrap()
+        .to_vec();
+    let mut block_cursor = block_cursor.next_result_set().unwrap().unwrap();
+    let second = block_cursor
+        .fetch()
+        .unwrap()
+        .unwrap()
+        .column(0)
+        .as_slice::<i32>()
+        .unwrap()
+        .to_vec();
+    let after_last = block_cursor.next_result_set().unwrap();
+
+    // Then
+    assert_eq!(vec![1, 2], first);
+    assert_eq!(vec![3], second);
+    assert!(after_last.is_none());
+}
+
+#[test_case(MSSQL; "Microsoft SQL Server")]
+fn next_result_set_with_different_schema(profile: &Profile) {
+    // Given two result sets with different column types
+    let conn = profile.connection().unwrap();
+    let cursor = conn
+        .execute("SELECT 1 AS A; SELECT 'Hello' AS A;", ())
+        .unwrap()
+        .unwrap();
+    let buffer = ColumnarAnyBuffer::from_descs(10, [BufferDesc::I32 { nullable: false }]);
+
+    // When
+    let block_cursor = cursor.bind_buffer(buffer).unwrap();
+    let result = block_cursor.next_result_set();
+
+    // Then
+    let Err(NextResultSetError {
+        error,
+        buffer,
+        cursor,
+    }) = result
+    else {
+        panic!("Expected schema mismatch")
+    };
+    assert!(matches!(error, Error::ResultSetSchemaMismatch { .. }));
+    // Buffer and the cursor to the next result set are handed back to us
+    assert_eq!(10, buffer.row_array_size());
+    let mut cursor = cursor.unwrap();
+    let mut row = cursor.next_row().unwrap().unwrap();
+    let mut text = Vec::new();
+    row.get_text(1, &mut text).unwrap();
+    assert_eq!("Hello", String::from_utf8(text).unwrap());
+}
+
+#[test_case(MSSQL; "Microsoft SQL Server")]
+fn next_result_set_with_text_of_different_length(profile: &Profile) {
+    // Given two result sets with text columns of different length
+    let conn = profile.connection().unwrap();
+    let cursor = conn
+        .execute("SELECT 'a' AS A; SELECT 'abc' AS A;", ())
+        .unwrap()
+        .unwrap();
+    let buffer = TextRowSet::from_max_str_lens(10, [5]).unwrap();
+
+    // When
+    let block_cursor = cursor.bind_buffer(buffer).unwrap();
+    let mut block_cursor = block_cursor.next_result_set().unwrap().unwrap();
+    let batch = block_cursor.fetch().unwrap().unwrap();
+
+    // Then
+    assert_eq!(Some(&b"abc"[..]), batch.at(0, 0));
+}
+
+#[test_case(MSSQL; "Microsoft SQL Server")]
+// #[test_case(MARIADB; "Maria DB")] Only allows one SQL Statement
+// #[test_case(SQLITE_3; "SQLite 3")] Only allows one SQL Statement
+#[test_case(POSTGRES; "PostgreSQL")]
+fn execute_select_insert_select(profile: &Profile) {
+    let table_name = table_name!();
+    let (conn, _table) = Given::new(&table_name)
+        .column_types(&["INTEGER"])
+        .build(profile)
+        .unwrap();
+
+    let first_cursor = conn
+        .execute(
+            &format!("SELECT 1 AS A; INSERT INTO {table_name} (a) VALUES (2); SELECT 3 AS B;"),
+            (),
+        )
+        .unwrap()
+        .unwrap();
+
+    // The statement is not actually in a cursor state here, because the second statement is an
+    // Insert statement. Everything works fine though, if we do not try to fetch any values. If we
+    // fetch it would trigger a sequence error.
+    let second_cursor = first_cursor.more_results().unwrap();
+    assert!(second_cursor.is_some());
+    let second_cursor = second_cursor.unwrap();
+
+    let third_cursor = second_cursor.more_results().unwrap();
+    assert!(third_cursor.is_some());
+    let third_cursor = third_cursor.unwrap();
+
+    let fourth_cursor = third_cursor.more_results().unwrap();
+    assert!(fourth_cursor.is_none());
+}
+
+// #[test_case(MSSQL; "Microsoft SQL Server")] Without changing server configuration VARCHAR(50)
+// does not seem to store things in UTF-8, but rather use an ASCII encoding which can not represent
+// the chinese characters.
+// Window hosted MARIA DB seems to also have trouble storying utf-8 in a varchar(50). Runs fine
+// though with windows client and linux server
+// #[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+#[test_case(POSTGRES; "PostgreSQL")]
+fn chinese_text_argument(profile: &Profile) {
+    // Given a table
+    let table_name = table_name!();
+    let (conn, table) = Given::new(&table_name)
+        .column_types(&["VARCHAR(50)"])
+        .build(profile)
+        .unwrap();
+    let insert_sql = table.sql_insert();
+
+    // When
+    conn.execute(&insert_sql, &"您好".into_parameter()).unwrap();
+
+    // Then
+    let cursor = conn
+        .execute(&table.sql_all_ordered_by_id(), ())
+        .unwrap()
+        .unwrap();
+    #[cfg(any(feature = "wide", all(not(feature = "narrow"), target_os = "windows")))]
+    let actual = {
+        let buffer = ColumnarBuffer::<_>::new(vec![(1, TextColumn::<u16>::new(1, 50))]);
+        let mut cursor = cursor.bind_buffer(buffer).unwrap();
+        let batch = cursor.fetch().unwrap().unwrap();
+        let utf16 = batch.column(0).get(0).unwrap();
+        let utf16 = U16Str::from_slice(utf16);
+        utf16.to_string().unwrap()
+    };
+
+    // Fetching non narrow should always work, but does not for PostgreSQL with narrow compilation
+    // flag.
+    #[cfg(not(any(feature = "wide", all(not(feature = "narrow"), target_os = "windows"))))]
+    let actual = {
+        let buffer = ColumnarBuffer::<_>::new(vec![(1, TextColumn::<u8>::new(1, 50))]);
+        let mut cursor = cursor.bind_buffer(buffer).unwrap();
+        let batch = cursor.fetch().unwrap().unwrap();
+        batch.at_as_str(0, 0).unwrap().unwrap().to_string()
+    };
+    assert_eq!("您好", actual);
+}
+
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+// #[test_case(POSTGRES; "PostgreSQL")] Postgres does not recognize NVARCHAR as a type
+fn chinese_text_argument_nvarchar(profile: &Profile) {
+    // Given a table
+    let table_name = table_name!();
+    let (conn, table) = Given::new(&table_name)
+        .column_types(&["NVARCHAR(50)"])
+        .build(profile)
+        .unwrap();
+    let insert_sql = table.sql_insert();
+
+    // When
+    let arg = U16String::from_str("您好"); // Narrow build will fail for MSSQL without this line.
+    conn.execute(&insert_sql, &arg.into_parameter()).unwrap();
+
+    // Then
+    let cursor = conn
+        .execute(&table.sql_all_ordered_by_id(), ())
+        .unwrap()
+        .unwrap();
+    let buffer = ColumnarBuffer::<_>::new(vec![(1, TextColumn::<u16>::new(1, 50))]);
+    let mut cursor = cursor.bind_buffer(buffer).unwrap();
+    let batch = cursor.fetch().unwrap().unwrap();
+    let utf16 = batch.column(0).get(0).unwrap();
+    let utf16 = U16Str::from_slice(utf16);
+    let actual = utf16.to_string().unwrap();
+    assert_eq!("您好", actual);
+}
+
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+// #[test_case(POSTGRES; "PostgreSQL")] Postgres does not recognize NVARCHAR as a type
+fn bind_wide_parameter_to_nvarchar(profile: &Profile) {
+    // Given a table
+    let table_name = table_name!();
+    let (conn, table) = Given::new(&table_name)
+        .column_types(&["NVARCHAR(50)"])
+        .build(profile)
+        .unwrap();
+    let insert_sql = table.sql_insert();
+
+    // When
+    conn.execute(&insert_sql, &Wide("您好").into_parameter())
+        .unwrap();
+    conn.execute(&insert_sql, &Wide(None::<&str>).into_parameter())
+        .unwrap();
+    conn.execute(&insert_sql, &Some(Wide("Grüße")).into_parameter())
+        .unwrap();
+    conn.execute(&insert_sql, &Wide("您好".to_string()).into_parameter())
+        .unwrap();
+    conn.execute(
+        &insert_sql,
+        &Wide(Some("Grüße".to_string())).into_parameter(),
+    )
+    .unwrap();
+    conn.execute(&insert_sql, &None::<Wide<String>>.into_parameter())
+        .unwrap();
+
+    // Then
+    let cursor = conn
+        .execute(&table.sql_all_ordered_by_id(), ())
+        .unwrap()
+        .unwrap();
+    let buffer = ColumnarBuffer::<_>::new(vec![(1, TextColumn::<u16>::new(6, 50))]);
+    let mut cursor = cursor.bind_buffer(buffer).unwrap();
+    let batch = cursor.fetch().unwrap().unwrap();
+    let actual: Vec<_> = (0..batch.num_rows())
+        .map(|index| {
+            batch
+                .column(0)
+                .get(index)
+                .map(|utf16| U16Str::from_slice(utf16).to_string().unwrap())
+        })
+        .collect();
+    let expected = [
+        Some("您好"),
+        None,
+        Some("Grüße"),
+        Some("您好"),
+        Some("Grüße"),
+        None,
+    ];
+    assert_eq!(expected.map(|s| s.map(str::to_owned)).to_vec(), actual);
+}
+
+/// Inspired by <https://github.com/pacman82/odbc-api/issues/428>
+/// Microsoft driver seems to report wrong sizes for the remaining strings if a text column can not
+/// be fetched on first try.
+// #[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+// #[test_case(POSTGRES; "PostgreSQL")] Fails on linux: Invalid string conversion occurred
+fn cursor_get_text_from_text(profile: &Profile) {
+    // Given a text column with a string larger than 255 characters. It also must contain non ASCII
+    // characters.
+    let table_name = table_name!();
+    let (conn, table) = Given::new(&table_name)
+        .column_types(&["TEXT"])
+        .build(profile)
+        .unwrap();
+    // For this test we want to run into a scenario, there we can not fetch everything in the first
+    // roundtrip, so we choose a text larger than 256 characters.
+    let text = "€".repeat(300);
+    let insert_sql = table.sql_insert();
+    conn.execute(&insert_sql, &text.into_parameter()).unwrap();
+
+    // When
+    let mut cursor = conn
+        .execute(&table.sql_all_ordered_by_id(), ())
+        .unwrap()
+        .unwrap();
+    let mut row = cursor.next_row().unwrap().unwrap();
+    let mut buffer = Vec::new();
+    row.get_text(1, &mut buffer).unwrap();
+
+    // Then
+    let actual = String::from_utf8(buffer).unwrap();
+    assert_eq!("€".repeat(300), actual);
+}
+
+// This triggers a bug in the ODBC driver at least version 17 and 18
+//
+// In addition this bug does not seem to occur then talking to an MSSQL server hosted in a windows
+// Enivornment. In our current CI test suite, if the client runs on windows so does the server, so
+// we do not execute this test on windows.
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[should_panic(
+    expected = "SQLGetData has been unable to fetch all data, even though the capacity of the \
+    target buffer has been adapted to hold the entire payload based on the indicator of the last \
+    part. You may consider filing a bug with the ODBC driver you are using."
+)]
+#[cfg(not(target_os = "windows"))]
+fn cursor_get_text_from_text_mssql(profile: &Profile) {
+    // Given a text column with a string larger than 255 characters. It also must contain non ASCII
+    // characters.
+    let table_name = table_name!();
+    let (conn, table) = Given::new(&table_name)
+        .column_types(&["TEXT"])
+        .build(profile)
+        .unwrap();
+    // For this test we want to run into a scenario, there we can not fetch everything in the first
+    // roundtrip, so we choose a text larger than 256 characters.
+    let text = "€".repeat(300);
+    let insert_sql = table.sql_insert();
+    conn.execute(&insert_sql, &text.into_parameter()).unwrap();
+
+    // When
+    let mut cursor = conn
+        .execute(&table.sql_all_ordered_by_id(), ())
+        .unwrap()
+        .unwrap();
+    let mut row = cursor.next_row().unwrap().unwrap();
+    let mut buffer = Vec::new();
+    row.get_text(1, &mut buffer).unwrap();
+
+    // Microsoft driver is buggy in this situation, as it does not use the indicator to report the
+    // true size of the string or the `NO_TOTAL`. We can at least test that a panic occurs and not
+    // some endless loop or buffer overflow.
+}
+
+/// If we want to use two buffers alternating to fetch data (like in the concurrent use case in
+/// the arrow-odbc downstream crate) we may want to generate a second row set buffer from an
+/// existing one. For this it is useful if we can infer the capacity of the block cursor, without
+/// unbinding it first.
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+#[test_case(POSTGRES; "PostgreSQL")]
+fn row_arrary_size_from_block_cursor(profile: &Profile) {
+    // Given a table
+    let table_name = table_name!();
+    let (conn, table) = Given::new(&table_name)
+        .column_types(&["INTEGER"])
+        .build(profile)
+        .unwrap();
+
+    // When
+    let capacity_used_to_create_buffer = 42;
+    let cursor = conn
+        .execute(&table.sql_all_ordered_by_id(), ())
+        .unwrap()
+        .unwrap();
+    let buffer = ColumnarAnyBuffer::from_descs(
+        capacity_used_to_create_buffer,
+        [BufferDesc::I32 { nullable: true }],
+    );
+    let block_cursor = cursor.bind_buffer(buffer).unwrap();
+    let capacity_reported_by_block_cursor = block_cursor.row_array_size();
+
+    // Then
+    assert_eq!(
+        capacity_used_to_create_buffer,
+        capacity_reported_by_block_cursor
+    );
+}
+
+/// Learning test what display size drivers report for JSON columns
+// #[test_case(MSSQL, "@json VARCHAR(max)", None; "Microsoft SQL Server")] // Unknown data type JSON
+// #[test_case(MARIADB, Some(1073741823); "Maria DB")] // Different size reported if MariaDB runs on windows
+#[test_case(SQLITE_3, Some(255); "SQLite 3")]
+#[test_case(POSTGRES, Some(255); "PostgreSQL")]
+fn json_column_display_size(profile: &Profile, expected_display_size: Option<usize>) {
+    // Given a table with a column type which will return `NO_TOTAL`
+    let table_name = table_name!();
+    let (conn, table) = Given::new(&table_name)
+        .column_types(&["JSON"])
+        .build(profile)
+        .unwrap();
+    let query = table.sql_all_ordered_by_id();
+
+    // When obtaining result set metadata
+    let mut result_set = conn.execute(&query, ()).unwrap().unwrap();
+    // First column is id with index `0`. Second index (`1`) referes to the JSON column
+    let size = result_set
+        .col_display_size(1)
+        .unwrap()
+        .map(NonZeroUsize::get);
+
+    // Then it is equal to the recorded display size
+    assert_eq!(expected_display_size, size);
+}
+
+/// Fetch decimal values in their text interpretation and transform them to their integer
+/// representation.
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+#[test_case(POSTGRES; "PostgreSQL")]
+fn fetch_decimals_to_int(profile: &Profile) {
+    // Given
+    let table_name = table_name!();
+    let (conn, table) = Given::new(&table_name)
+        .column_types(&["DECIMAL(5,3)"])
+        .build(profile)
+        .unwrap();
+    conn.execute(
+        &format!("INSERT INTO {table_name} (a) VALUES (12.345), (-12.345), (12), (12.3)"),
+        (),
+    )
+    .unwrap();
+
+    // When
+    let mut cursor = conn
+        .execute(&table.sql_all_ordered_by_id(), ())
+        .unwrap()
+        .unwrap();
+    let row_set_buffer = TextRowSet::for_cursor(4, &mut cursor, None).unwrap();
+    let mut block_cursor = cursor.bind_buffer(row_set_buffer).unwrap();
+    let batch = block_cursor.fetch().unwrap().unwrap();
+    let n = |i| batch.at_as_str(0, i).unwrap().unwrap().as_bytes();
+    let n1 = decimal_text_to_i128(n(0), 3);
+    let n2 = decimal_text_to_i128(n(1), 3);
+    let n3 = decimal_text_to_i128(n(2), 3);
+    let n4 = decimal_text_to_i128(n(3), 3);
+
+    // Then
+    assert_eq!(12345, n1);
+    assert_eq!(-12345, n2);
+    assert_eq!(12000, n3);
+    assert_eq!(12300, n4);
+}
+
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+#[test_case(POSTGRES; "PostgreSQL")]
+fn text_column_decimals_to_i128(profile: &Profile) {
+    // Given
+    let table_name = table_name!();
+    let (conn, table) = Given::new(&table_name)
+        .column_types(&["DECIMAL(5,3)"])
+        .build(profile)
+        .unwrap();
+    conn.execute(
+        &format!("INSERT INTO {table_name} (a) VALUES (12.345), (NULL), (-12), (12.3)"),
+        (),
+    )
+    .unwrap();
+
+    // When
+    let mut cursor = conn
+        .execute(&table.sql_all_ordered_by_id(), ())
+        .unwrap()
+        .unwrap();
+    let row_set_buffer = TextRowSet::for_cursor(10, &mut cursor, None).unwrap();
+    let mut block_cursor = cursor.bind_buffer(row_set_buffer).unwrap();
+    let batch = block_cursor.fetch().unwrap().unwrap();
+    let values: Vec<_> = batch.column(0).decimals_to_i128(3).collect();
+
+    // Then
+    assert_eq!(vec![Some(12345), None, Some(-12000), Some(12300)], values);
+}
+
+/// Bulf fetch in a dedicated system thread. Usually so the application can process the last batch
+/// while the next one is fetched.
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+#[test_case(POSTGRES; "PostgreSQL")]
+fn concurrent_bulk_fetch_double_buffered(profile: &Profile) {
+    // Given
+    let table_name = table_name!();
+    let (conn, table) = Given::new(&table_name)
+        .column_types(&["INT"])
+        .build(profile)
+        .unwrap();
+    conn.execute(&format!("INSERT INTO {table_name} (a) VALUES (1), (2)"), ())
+        .unwrap();
+
+    // When
+    let mut buffer_a = ColumnarAnyBuffer::from_descs(1, [BufferDesc::I32 { nullable: false }]);
+    let buffer_b = ColumnarAnyBuffer::from_descs(1, [BufferDesc::I32 { nullable: false }]);
+    let cursor = conn
+        .into_cursor(&table.sql_all_ordered_by_id(), ())
+        .unwrap()
+        .unwrap();
+    let block_cursor = cursor.bind_buffer(buffer_b).unwrap();
+    let mut concurrent_block_cursor = ConcurrentBlockCursor::from_block_cursor(block_cursor);
+
+    let has_another_batch = concurrent_block_cursor.fetch_into(&mut buffer_a).unwrap();
+    assert!(has_another_batch);
+    assert_eq!(1, buffer_a.num_rows());
+    assert_eq!(1i32, buffer_a.column(0).as_slice().unwrap()[0]);
+
+    let has_another_batch = concurrent_block_cursor.fetch_into(&mut buffer_a).unwrap();
+    assert!(has_another_batch);
+    assert_eq!(1, buffer_a.num_rows());
+    assert_eq!(2i32, buffer_a.column(0).as_slice().unwrap()[0]);
+
+    let has_another_batch = concurrent_block_cursor.fetch_into(&mut buffer_a).unwrap();
+    assert!(!has_another_batch);
+}
+
+/// Bulf fetch in a dedicated system thread. Usually so the application can process the last batch
+/// while the next one is fetched.
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+#[test_case(POSTGRES; "PostgreSQL")]
+fn concurrent_bulk_fetch_single_buffer(profile: &Profile) {
+    // Given
+    let table_name = table_name!();
+    let (conn, table) = Given::new(&table_name)
+        .column_types(&["INT"])
+        .build(profile)
+        .unwrap();
+    conn.execute(&format!("INSERT INTO {table_name} (a) VALUES (1), (2)"), ())
+        .unwrap();
+
+    // When
+    let buffer = ColumnarAnyBuffer::from_descs(1, [BufferDesc::I32 { nullable: false }]);
+    let cursor = conn
+        .into_cursor(&table.sql_all_ordered_by_id(), ())
+        .unwrap()
+        .unwrap();
+    let block_cursor = cursor.bind_buffer(buffer).unwrap();
+    let mut concurrent_block_cursor = ConcurrentBlockCursor::from_block_cursor(block_cursor);
+
+    let batch = concurrent_block_cursor.fetch().unwrap().unwrap();
+    assert_eq!(1, batch.num_rows());
+    assert_eq!(1i32, batch.column(0).as_slice().unwrap()[0]);
+    concurrent_block_cursor.fill(batch);
+
+    let batch = concurrent_block_cursor.fetch().unwrap().unwrap();
+    assert_eq!(1, batch.num_rows());
+    assert_eq!(2i32, batch.column(0).as_slice().unwrap()[0]);
+    concurrent_block_cursor.fill(batch);
+
+    let all_batches_consumed = concurrent_block_cursor.fetch().unwrap().is_none();
+    assert!(all_batches_consumed);
+}
+
+/// Catch edge cases, there we stop the thread, while there are still batches to consume
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+#[test_case(POSTGRES; "PostgreSQL")]
+fn concurrent_bulk_fetch_fetch_one_batch(profile: &Profile) {
+    // Given
+    let table_name = table_name!();
+    let (conn, table) = Given::new(&table_name)
+        .column_types(&["INT"])
+        .build(profile)
+        .unwrap();
+    conn.execute(&format!("INSERT INTO {table_name} (a) VALUES (1), (2)"), ())
+        .unwrap();
+
+    // When
+    let buffer = ColumnarAnyBuffer::from_descs(1, [BufferDesc::I32 { nullable: false }]);
+    let cursor = conn
+        .into_cursor(&table.sql_all_ordered_by_id(), ())
+        .unwrap()
+        .unwrap();
+    let block_cursor = cursor.bind_buffer(buffer).unwrap();
+    let mut concurrent_block_cursor = ConcurrentBlockCursor::from_block_cursor(block_cursor);
+    let _ = concurrent_block_cursor.fetch().unwrap().unwrap();
+    // Now instead of sending a buffer and fetching a next one, we interrupt the fetch thread while
+    // it does not own a buffer.
+    let cursor = concurrent_block_cursor.into_cursor().unwrap();
+    // Now we can deterministically fetch the second batch in the main thread again. Since the fetch
+    // thread has only ever seen one buffer, it could have only fetched one batch.
+
+    let actual = cursor_to_string(cursor);
+    assert_eq!("2", actual);
+}
+
+/// Bulk fetch in a dedicated system thread. Usually so the application can process the last batch
+/// while the next one is fetched.
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+// SQLite, actually does not create a diagonstic record or is creating any kind of error in this
+// case. It would just report the value to be zero. This has nothing todo with the fetch being
+// concurrent though. To test the error handling, the other DBMs have to suffice
+// #[test_case(SQLITE_3; "SQLite 3")]
+#[test_case(POSTGRES; "PostgreSQL")]
+fn concurrent_bulk_fetch_with_invalid_buffer_type(profile: &Profile) {
+    // Given an integer table with a NULL
+    let table_name = table_name!();
+    let (conn, table) = Given::new(&table_name)
+        .column_types(&["INT"])
+        .build(profile)
+        .unwrap();
+    conn.execute(&format!("INSERT INTO {table_name} (a) VALUES (NULL)"), ())
+        .unwrap();
+
+    // When fetching with a Columnar buffer not supporting nullable values
+    let mut buffer_a = ColumnarAnyBuffer::from_descs(1, [BufferDesc::I32 { nullable: false }]);
+    let buffer_b = ColumnarAnyBuffer::from_descs(1, [BufferDesc::I32 { nullable: false }]);
+    let cursor = conn
+        .into_cursor(&table.sql_all_ordered_by_id(), ())
+        .unwrap()
+        .unwrap();
+    let block_cursor = cursor.bind_buffer(buffer_b).unwrap();
+    let mut concurrent_block_cursor = ConcurrentBlockCursor::from_block_cursor(block_cursor);
+    // This line provokes the first error, due to the invalid buffer.
+    let result_one = concurrent_block_cursor.fetch_into(&mut buffer_a);
+    let result_two = concurrent_block_cursor.fetch_into(&mut buffer_a);
+
+    // Then
+    assert!(result_one.is_err());
+    // After the first error, we treat the stream same as we would treat a stream which is consumed.
+    assert!(!result_two.unwrap());
+}
+
+#[test_case(MSSQL; "Microsoft SQL Server")]
+fn concurrent_fetch_of_multiple_result_sets(profile: &Profile) {
+    // Given
+    let conn = profile.connection().unwrap();
+    let query = "SELECT 1 AS a; SELECT 2 AS b;";
+
+    // When
+    let mut buffer_a = ColumnarAnyBuffer::from_descs(1, [BufferDesc::I32 { nullable: false }]);
+    let buffer_b = ColumnarAnyBuffer::from_descs(1, [BufferDesc::I32 { nullable: false }]);
+    let cursor = conn.into_cursor(query, ()).unwrap().unwrap();
+    let block_cursor = cursor.bind_buffer(buffer_b).unwrap();
+    let mut concurrent_block_cursor = ConcurrentBlockCursor::from_block_cursor(block_cursor);
+    // Consume first result set.
+    concurrent_block_cursor.fetch_into(&mut buffer_a).unwrap();
+    concurrent_block_cursor.fetch_into(&mut buffer_a).unwrap();
+    // Now continue with the same cursor to fetch the second
+    let cursor = concurrent_block_cursor.into_cursor().unwrap();
+    let cursor = cursor.more_results().unwrap().unwrap();
+    let mut cursor = cursor.bind_buffer(buffer_a).unwrap();
+    let batch = cursor.fetch().unwrap().unwrap();
+
+    // Then
+    assert_eq!(2i32, batch.column(0).as_slice().unwrap()[0]);
+}
+
+/// This test covers a code path in which the thread dedicated to fething is not termintated by
+/// running out of batches.
+#[test_case(MSSQL; "Microsoft SQL Server")]
+fn concurrent_fetch_skip_first_result_set(profile: &Profile) {
+    // Given
+    let conn = profile.connection().unwrap();
+    let query = "SELECT 1 AS a; SELECT 2 AS b;";
+
+    // When
+    let buffer_a = ColumnarAnyBuffer::from_descs(1, [BufferDesc::I32 { nullable: false }]);
+    let buffer_b = ColumnarAnyBuffer::from_descs(1, [BufferDesc::I32 { nullable: false }]);
+    let cursor = conn.into_cursor(query, ()).unwrap().unwrap();
+    let block_cursor = cursor.bind_buffer(buffer_b).unwrap();
+    let concurrent_block_cursor = ConcurrentBlockCursor::from_block_cursor(block_cursor);
+    // Skip over first result set, without fetching any batches.
+    // Now continue with the same cursor to fetch the second
+    let cursor = concurrent_block_cursor.into_cursor().unwrap();
+    let cursor = cursor.more_results().unwrap().unwrap();
+    let mut cursor = cursor.bind_buffer(buffer_a).unwrap();
+    let batch = cursor.fetch().unwrap().unwrap();
+
+    // Then
+    assert_eq!(2i32, batch.column(0).as_slice().unwrap()[0]);
+}
+
+/// This tests checks if there is more than one attribute returned. We had a bug (see issue:
+/// <https://github.com/pacman82/odbc-api/issues/511>) in which we used a buffer intended for zero
+/// terminated strings to get the attributes. Yet the attributes use the `0` as a delimiter.
+#[test]
+fn list_all_driver_attributes() {
+    // Given an ODBC environment with drivers installed
+    let environment = &environment().unwrap();
+
+    // When fetching driver infors
+    let driver_infos = environment.drivers().unwrap();
+
+    // At least one driver is expecetd to have more than one attribute.
+    let maximum = driver_infos
+        .iter()
+        .map(|info| info.attributes.len())
+        .max()
+        .expect("At least one ODBC driver must be installed");
+    assert!(maximum > 1);
+}
+
+#[test_case(MSSQL, true; "Microsoft SQL Server")]
+#[test_case(MARIADB, false; "Maria DB")]
+#[test_case(SQLITE_3, false; "SQLite 3")]
+#[test_case(POSTGRES, false; "PostgreSQL")]
+#[tokio::test]
+async fn polling_preallocated_statement_execution(
+    profile: &Profile,
+    expected_to_support_polling: bool,
+) {
+    // Given a table
+    let table_name = table_name!();
+    let (conn, table) = Given::new(&table_name)
+        .column_types(&["VARCHAR(50)"])
+        .build(profile)
+        .unwrap();
+    let query = format!("INSERT INTO {table_name} (a) VALUES ('Hello, World!')");
+    // We use this counter to check if the sleep function is actually invoked and the driver does
+    // actually support asynchronous polling.
+    let mut sleep_counter_spy = 0;
+    let sleep = || {
+        sleep_counter_spy += 1;
+        tokio::time::sleep(Duration::from_millis(50))
+    };
+
+    // When
+    let mut statement = conn.preallocate().unwrap().into_polling().unwrap();
+    statement.execute(&query, (), sleep).await.unwrap();
+
+    // Then
+    let actual = table.content_as_string(&conn);
+    assert_eq!("Hello, World!", actual);
+    let used_polling = sleep_counter_spy != 0;
+    assert_eq!(expected_to_support_polling, used_polling);
+}
+
+#[test_case(MSSQL, true; "Microsoft SQL Server")]
+#[test_case(MARIADB, false; "Maria DB")]
+#[test_case(SQLITE_3, false; "SQLite 3")]
+#[test_case(POSTGRES, false; "PostgreSQL")]
+#[tokio::test]
+async fn async_bulk_fetch(profile: &Profile, expected_to_support_polling: bool) {
+    // Given a table with a thousand records
+    let table_name = table_name!();
+    let (conn, table) = Given::new(&table_name)
+        .column_types(&["VARCHAR(50)"])
+        .build(profile)
+        .unwrap();
+    let prepared = conn.prepare(&table.sql_insert()).unwrap();
+    let mut inserter = prepared.into_text_inserter(1000, [50]).unwrap();
+    for index in 0..1000 {
+        inserter
+            .append([Some(index.to_string().as_bytes())].iter().copied())
+            .unwrap();
+    }
+    inserter.execute().unwrap();
+    let query = table.sql_all_ordered_by_id();
+    // We use this counter to check if the sleep function is actually invoked and the driver does
+    // actually support asynchronous polling.
+    let mut sleep_counter_spy = 0;
+    let mut sleep = || {
+        sleep_counter_spy += 1;
+        tokio::time::sleep(Duration::from_millis(50))
+    };
+
+    // When
+    let mut sum_rows_fetched = 0;
+    let cursor = conn
+        .execute_polling(&query, (), &mut sleep)
+        .await
+        .unwrap()
+        .unwrap();
+    // Fetching results in ten batches
+    let buffer = TextRowSet::from_max_str_lens(100, [50usize]).unwrap();
+    let mut row_set_cursor = cursor.bind_buffer(buffer).unwrap();
+    let mut maybe_batch = row_set_cursor.fetch(&mut sleep).await.unwrap();
+    while let Some(batch) = maybe_batch {
+        sum_rows_fetched += batch.num_rows();
+        maybe_batch = row_set_cursor.fetch(&mut sleep).await.unwrap();
+    }
+
+    // Then
+    assert_eq!(1000, sum_rows_fetched);
+    let used_polling = sleep_counter_spy != 0;
+    assert_eq!(expected_to_support_polling, used_polling);
+}
+
+/// Long columns are completed using `SQLGetData` after the fetch, which would not be polled.
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[tokio::test]
+async fn binding_long_text_to_polling_cursor_fails(profile: &Profile) {
+    // Given
+    let table_name = table_name!();
+    let (conn, table) = Given::new(&table_name)
+        .column_types(&["VARCHAR(MAX)"])
+        .build(profile)
+        .unwrap();
+    let sleep = || tokio::time::sleep(Duration::from_millis(50));
+    let cursor = conn
+        .execute_polling(&table.sql_all_ordered_by_id(), (), sleep)
+        .await
+        .unwrap()
+        .unwrap();
+
+    // When
+    let buffer = ColumnarAnyBuffer::from_descs(10, [BufferDesc::LongText]);
+    let result = cursor.bind_buffer(buffer);
+
+    // Then
+    assert!(matches!(result, Err(Error::CompletingRowSetWhilePolling)));
+}
+
+/// Use the `async-io` timer, as used by `async-std` and `smol`, rather than `tokio` for polling.
+#[cfg(feature = "async-io")]
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+#[test_case(POSTGRES; "PostgreSQL")]
+fn execute_polling_with_async_io_timer(profile: &Profile) {
+    // Given a table
+    let table_name = table_name!();
+    let (conn, table) = Given::new(&table_name)
+        .column_types(&["VARCHAR(50)"])
+        .build(profile)
+        .unwrap();
+    let query = format!("INSERT INTO {table_name} (a) VALUES ('Hello, World!')");
+
+    // When
+    let sleep = odbc_api::sleep_with(Duration::from_millis(50));
+    async_io::block_on(conn.execute_polling(&query, (), sleep)).unwrap();
+
+    // Then
+    let actual = table.content_as_string(&conn);
+    assert_eq!("Hello, World!", actual);
+}
+
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+#[test_case(POSTGRES; "PostgreSQL")]
+fn row_wise_bulk_query_using_tuple(profile: &Profile) {
+    // Given a cursor
+    let table_name = table_name!();
+    let (conn, table) = Given::new(&table_name)
+        .column_types(&["INTEGER", "VARCHAR(50)"])
+        .values_by_column(&[
+            &[Some("42"), Some("5")],
+            &[Some("Hello, World!"), Some("Hallo, Welt!")],
+        ])
+        .build(profile)
+        .unwrap();
+    let cursor = conn
+        .execute(&table.sql_all_ordered_by_id(), ())
+        .unwrap()
+        .unwrap();
+
+    // When
+    let row_set_buffer = RowVec::<(i32, VarCharArray<50>)>::new(10);
+    let mut block_cursor = cursor.bind_buffer(row_set_buffer).unwrap();
+    let batch = block_cursor.fetch().unwrap().unwrap();
+
+    // Then
+    assert_eq!(2, batch.num_rows());
+    assert_eq!(42, batch[0].0);
+    assert_eq!("Hello, World!", batch[0].1.as_str().unwrap().unwrap());
+    assert_eq!(5, batch[1].0);
+    assert_eq!("Hallo, Welt!", batch[1].1.as_str().unwrap().unwrap());
+}
+
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+#[test_case(POSTGRES; "PostgreSQL")]
+fn row_wise_bulk_query_nullable(profile: &Profile) {
+    // Given a cursor
+    let table_name = table_name!();
+    let (conn, table) = Given::new(&table_name)
+        .column_types(&["INTEGER"])
+        .values_by_column(&[&[Some("42"), None]])
+        .build(profile)
+        .unwrap();
+    let cursor = conn
+        .execute(&table.sql_all_ordered_by_id(), ())
+        .unwrap()
+        .unwrap();
+
+    // When
+    let row_set_buffer = RowVec::<(Nullable<i32>,)>::new(10);
+    let mut block_cursor = cursor.bind_buffer(row_set_buffer).unwrap();
+    let batch = block_cursor.fetch().unwrap().unwrap();
+
+    // Then
+    assert_eq!(Some(&42), batch[0].0.as_opt());
+    assert_eq!(None, batch[1].0.as_opt());
+}
+
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+#[test_case(POSTGRES; "PostgreSQL")]
+fn fetch_one_row_into_tuple(profile: &Profile) {
+    // Given
+    let table_name = table_name!();
+    let (conn, table) = Given::new(&table_name)
+        .column_types(&["INTEGER", "VARCHAR(50)"])
+        .values_by_column(&[&[Some("42")], &[Some("Hello, World!")]])
+        .build(profile)
+        .unwrap();
+    let cursor = conn
+        .execute(&table.sql_all_ordered_by_id(), ())
+        .unwrap()
+        .unwrap();
+
+    // When
+    let row = cursor.fetch_one::<(i32, VarCharArray<50>)>().unwrap();
+
+    // Then
+    let (number, text) = row.unwrap();
+    assert_eq!(42, number);
+    assert_eq!(Some("Hello, World!"), text.as_str().unwrap());
+}
+
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+#[test_case(POSTGRES; "PostgreSQL")]
+fn fetch_one_rejects_more_than_one_row(profile: &Profile) {
+    // Given
+    let table_name = table_name!();
+    let (conn, table) = Given::new(&table_name)
+        .column_types(&["INTEGER"])
+        .values_by_column(&[&[Some("1"), Some("2")]])
+        .build(profile)
+        .unwrap();
+    let cursor = conn
+        .execute(&table.sql_all_ordered_by_id(), ())
+        .unwrap()
+        .unwrap();
+
+    // When
+    let result = cursor.fetch_one::<i32>();
+
+    // Then
+    assert!(matches!(result, Err(Error::MoreThanOneRow)));
+}
+
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+#[test_case(POSTGRES; "PostgreSQL")]
+fn next_row_as_tuple(profile: &Profile) {
+    // Given
+    let table_name = table_name!();
+    let (conn, table) = Given::new(&table_name)
+        .column_types(&["INTEGER", "VARCHAR(50)"])
+        .values_by_column(&[&[Some("1"), Some("2")], &[Some("one"), None]])
+        .build(profile)
+        .unwrap();
+    let mut cursor = conn
+        .execute(&table.sql_all_ordered_by_id(), ())
+        .unwrap()
+        .unwrap();
+
+    // When
+    let first = cursor.next_row_as::<(i32, VarCharArray<50>)>().unwrap();
+    let second = cursor.next_row_as::<(i32, VarCharArray<50>)>().unwrap();
+    let third = cursor.next_row_as::<(i32, VarCharArray<50>)>().unwrap();
+
+    // Then
+    let (number, text) = first.unwrap();
+    assert_eq!(1, number);
+    assert_eq!(Some("one"), text.as_str().unwrap());
+    let (number, text) = second.unwrap();
+    assert_eq!(2, number);
+    assert_eq!(None, text.as_str().unwrap());
+    assert!(third.is_none());
 }
 
 #[test_case(MSSQL; "Microsoft SQL Server")]
 // #[test_case(MARIADB; "Maria DB")] Only allows one SQL Statement
 // #[test_case(SQLITE_3; "SQLite 3")] Only allows one SQL Statement
 #[test_case(POSTGRES; "PostgreSQL")]
-fn execute_select_insert_select(profile: &Profile) {
+fn next_row_as_leaves_cursor_usable_for_more_results(profile: &Profile) {
+    // Given
+    let conn = profile.connection().unwrap();
+    let mut cursor = conn
+        .execute("SELECT 1 AS A; SELECT 2 AS B;", ())
+        .unwrap()
+        .unwrap();
+
+    // When
+    let first = cursor.next_row_as::<i32>().unwrap();
+    let end_of_first = cursor.next_row_as::<i32>().unwrap();
+    let mut cursor = cursor.more_results().unwrap().unwrap();
+    let second = cursor.next_row_as::<i32>().unwrap();
+
+    // Then
+    assert_eq!(Some(1), first);
+    assert_eq!(None, end_of_first);
+    assert_eq!(Some(2), second);
+}
+
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+#[test_case(POSTGRES; "PostgreSQL")]
+fn row_wise_bulk_query_single_nullable_without_tuple(profile: &Profile) {
+    // Given a cursor
     let table_name = table_name!();
-    let (conn, _table) = Given::new(&table_name)
+    let (conn, table) = Given::new(&table_name)
         .column_types(&["INTEGER"])
+        .values_by_column(&[&[Some("42"), None, Some("5")]])
         .build(profile)
         .unwrap();
+    let cursor = conn
+        .execute(&table.sql_all_ordered_by_id(), ())
+        .unwrap()
+        .unwrap();
 
-    let first_cursor = conn
-        .execute(
-            &format!("SELECT 1 AS A; INSERT INTO {table_name} (a) VALUES (2); SELECT 3 AS B;"),
-            (),
-        )
+    // When
+    let row_set_buffer = RowVec::<Nullable<i32>>::new(10);
+    let mut block_cursor = cursor.bind_buffer(row_set_buffer).unwrap();
+    let batch = block_cursor.fetch().unwrap().unwrap();
+
+    // Then
+    assert_eq!(3, batch.num_rows());
+    assert_eq!(Some(&42), batch[0].as_opt());
+    assert_eq!(None, batch[1].as_opt());
+    assert_eq!(Some(&5), batch[2].as_opt());
+}
+
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+#[test_case(POSTGRES; "PostgreSQL")]
+fn row_wise_bulk_query_binary(profile: &Profile) {
+    // Given a cursor
+    let table_name = table_name!();
+    let (conn, table) = Given::new(&table_name)
+        .column_types(&["VARCHAR(20)"])
+        .values_by_column(&[&[Some("Hello, World!"), None]])
+        .build(profile)
+        .unwrap();
+    let cursor = conn
+        .execute(&table.sql_all_ordered_by_id(), ())
+        .unwrap()
+        .unwrap();
+
+    // When
+    let row_set_buffer = RowVec::<(VarBinaryArray<20>,)>::new(2);
+    let mut block_cursor = cursor.bind_buffer(row_set_buffer).unwrap();
+    let batch = block_cursor.fetch().unwrap().unwrap();
+
+    // Then
+    assert_eq!(b"Hello, World!", batch[0].0.as_bytes().unwrap());
+}
+
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+#[test_case(POSTGRES; "PostgreSQL")]
+fn row_wise_bulk_query_wide_text(profile: &Profile) {
+    // Given a cursor
+    let table_name = table_name!();
+    let (conn, table) = Given::new(&table_name)
+        .column_types(&["VARCHAR(20)"])
+        .values_by_column(&[&[Some("Hello, World!"), None]])
+        .build(profile)
+        .unwrap();
+    let cursor = conn
+        .execute(&table.sql_all_ordered_by_id(), ())
+        .unwrap()
+        .unwrap();
+
+    // When
+    let row_set_buffer = RowVec::<(VarWCharArray<20>,)>::new(2);
+    let mut block_cursor = cursor.bind_buffer(row_set_buffer).unwrap();
+    let batch = block_cursor.fetch().unwrap().unwrap();
+
+    // Then
+    assert_eq!(
+        "Hello, World!",
+        batch[0].0.as_utf16().unwrap().to_string().unwrap()
+    );
+}
+
+#[cfg(feature = "derive")]
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+#[test_case(POSTGRES; "PostgreSQL")]
+fn row_wise_bulk_query_using_custom_row(profile: &Profile) {
+    // Given a cursor
+    let table_name = table_name!();
+    let (conn, table) = Given::new(&table_name)
+        .column_types(&["INTEGER", "VARCHAR(50)"])
+        .values_by_column(&[
+            &[Some("42"), Some("5")],
+            &[Some("Hello, World!"), Some("Hallo, Welt!")],
+        ])
+        .build(profile)
+        .unwrap();
+    let cursor = conn
+        .execute(&table.sql_all_ordered_by_id(), ())
+        .unwrap()
+        .unwrap();
+
+    // When
+    #[derive(Clone, Copy, Default, Fetch)]
+    struct MyRow {
+        a: i32,
+        b: VarCharArray<50>,
+    }
+    let row_set_buffer = RowVec::<MyRow>::new(10);
+    let mut block_cursor = cursor.bind_buffer(row_set_buffer).unwrap();
+    let batch = block_cursor.fetch().unwrap().unwrap();
+
+    // Then
+    assert_eq!(2, batch.num_rows());
+    assert_eq!(42, batch[0].a);
+    assert_eq!("Hello, World!", batch[0].b.as_str().unwrap().unwrap());
+    assert_eq!(5, batch[1].a);
+    assert_eq!("Hallo, Welt!", batch[1].b.as_str().unwrap().unwrap());
+}
+
+#[cfg(feature = "derive")]
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+#[test_case(POSTGRES; "PostgreSQL")]
+fn row_wise_bulk_query_numeric_and_bit_fields_using_custom_row(profile: &Profile) {
+    // Given a cursor
+    let table_name = table_name!();
+    let (conn, table) = Given::new(&table_name)
+        .column_types(&["INTEGER", "DOUBLE PRECISION", "BIT"])
+        .build(profile)
+        .unwrap();
+    let insert = table.sql_insert();
+    conn.execute(&insert, (&42i32, &1.5f64, &Bit::from_bool(true)))
+        .unwrap();
+    let cursor = conn
+        .execute(&table.sql_all_ordered_by_id(), ())
+        .unwrap()
+        .unwrap();
+
+    // When
+    #[derive(Clone, Copy, Default, Fetch)]
+    struct MyRow {
+        a: i32,
+        b: f64,
+        c: Bit,
+    }
+    let row_set_buffer = RowVec::<MyRow>::new(10);
+    let mut block_cursor = cursor.bind_buffer(row_set_buffer).unwrap();
+    let batch = block_cursor.fetch().unwrap().unwrap();
+
+    // Then
+    assert_eq!(1, batch.num_rows());
+    assert_eq!(42, batch[0].a);
+    assert_eq!(1.5, batch[0].b);
+    assert!(batch[0].c.as_bool());
+}
+
+#[cfg(feature = "derive")]
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+#[test_case(POSTGRES; "PostgreSQL")]
+fn row_wise_bulk_query_with_flattened_struct(profile: &Profile) {
+    // Given a cursor
+    let table_name = table_name!();
+    let (conn, table) = Given::new(&table_name)
+        .column_types(&[
+            "INTEGER",
+            "VARCHAR(50)",
+            "VARCHAR(50)",
+            "VARCHAR(10)",
+            "INTEGER",
+        ])
+        .values_by_column(&[
+            &[Some("1")],
+            &[Some("Main Street 1")],
+            &[Some("Springfield")],
+            &[Some("12345")],
+            &[Some("42")],
+        ])
+        .build(profile)
+        .unwrap();
+    let cursor = conn
+        .execute(&table.sql_all_ordered_by_id(), ())
         .unwrap()
         .unwrap();
 
-    // The statement is not actually in a cursor state here, because the second statement is an
-    // Insert statement. Everything works fine though, if we do not try to fetch any values. If we
-    // fetch it would trigger a sequence error.
-    let second_cursor = first_cursor.more_results().unwrap();
-    assert!(second_cursor.is_some());
-    let second_cursor = second_cursor.unwrap();
-
-    let third_cursor = second_cursor.more_results().unwrap();
-    assert!(third_cursor.is_some());
-    let third_cursor = third_cursor.unwrap();
+    // When
+    #[derive(Clone, Copy, Default, Fetch)]
+    struct Address {
+        street: VarCharArray<50>,
+        city: VarCharArray<50>,
+        zip: VarCharArray<10>,
+    }
+    #[derive(Clone, Copy, Default, Fetch)]
+    struct Customer {
+        id: i32,
+        #[odbc(flatten)]
+        address: Address,
+        visits: i32,
+    }
+    let row_set_buffer = RowVec::<Customer>::new(10);
+    let mut block_cursor = cursor.bind_buffer(row_set_buffer).unwrap();
+    let batch = block_cursor.fetch().unwrap().unwrap();
 
-    let fourth_cursor = third_cursor.more_results().unwrap();
-    assert!(fourth_cursor.is_none());
+    // Then
+    assert_eq!(1, batch.num_rows());
+    let customer = &batch[0];
+    assert_eq!(1, customer.id);
+    let address = &customer.address;
+    assert_eq!("Main Street 1", address.street.as_str().unwrap().unwrap());
+    assert_eq!("Springfield", address.city.as_str().unwrap().unwrap());
+    assert_eq!("12345", address.zip.as_str().unwrap().unwrap());
+    assert_eq!(42, customer.visits);
 }
 
-// #[test_case(MSSQL; "Microsoft SQL Server")] Without changing server configuration VARCHAR(50)
-// does not seem to store things in UTF-8, but rather use an ASCII encoding which can not represent
-// the chinese characters.
-// Window hosted MARIA DB seems to also have trouble storying utf-8 in a varchar(50). Runs fine
-// though with windows client and linux server
-// #[test_case(MARIADB; "Maria DB")]
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
 #[test_case(SQLITE_3; "SQLite 3")]
 #[test_case(POSTGRES; "PostgreSQL")]
-fn chinese_text_argument(profile: &Profile) {
-    // Given a table
+fn truncation_in_row_wise_bulk_buffer(profile: &Profile) {
+    // Given a cursor
     let table_name = table_name!();
     let (conn, table) = Given::new(&table_name)
         .column_types(&["VARCHAR(50)"])
+        .values_by_column(&[&[Some("Hello, World!")]])
         .build(profile)
         .unwrap();
-    let insert_sql = table.sql_insert();
-
-    // When
-    conn.execute(&insert_sql, &"您好".into_parameter()).unwrap();
-
-    // Then
     let cursor = conn
         .execute(&table.sql_all_ordered_by_id(), ())
         .unwrap()
         .unwrap();
-    #[cfg(any(feature = "wide", all(not(feature = "narrow"), target_os = "windows")))]
-    let actual = {
-        let buffer = ColumnarBuffer::<_>::new(vec![(1, TextColumn::<u16>::new(1, 50))]);
-        let mut cursor = cursor.bind_buffer(buffer).unwrap();
-        let batch = cursor.fetch().unwrap().unwrap();
-        let utf16 = batch.column(0).get(0).unwrap();
-        let utf16 = U16Str::from_slice(utf16);
-        utf16.to_string().unwrap()
-    };
 
-    // Fetching non narrow should always work, but does not for PostgreSQL with narrow compilation
-    // flag.
-    #[cfg(not(any(feature = "wide", all(not(feature = "narrow"), target_os = "windows"))))]
-    let actual = {
-        let buffer = ColumnarBuffer::<_>::new(vec![(1, TextColumn::<u8>::new(1, 50))]);
-        let mut cursor = cursor.bind_buffer(buffer).unwrap();
-        let batch = cursor.fetch().unwrap().unwrap();
-        batch.at_as_str(0, 0).unwrap().unwrap().to_string()
-    };
-    assert_eq!("您好", actual);
+    // When
+    let mut row_set_buffer = RowVec::<(VarCharArray<10>,)>::new(10);
+
+    let mut block_cursor = cursor.bind_buffer(&mut row_set_buffer).unwrap();
+    let batch = block_cursor.fetch().unwrap().unwrap();
+
+    // Then
+    assert_eq!("Hello, Wo", batch[0].0.as_str().unwrap().unwrap());
+    drop(block_cursor);
+    assert_eq!(
+        TruncationInfo {
+            indicator: Some(13),
+            buffer_index: 0
+        },
+        (&(&mut row_set_buffer)).find_truncation().unwrap()
+    )
 }
 
 #[test_case(MSSQL; "Microsoft SQL Server")]
 #[test_case(MARIADB; "Maria DB")]
 #[test_case(SQLITE_3; "SQLite 3")]
-// #[test_case(POSTGRES; "PostgreSQL")] Postgres does not recognize NVARCHAR as a type
-fn chinese_text_argument_nvarchar(profile: &Profile) {
-    // Given a table
+#[test_case(POSTGRES; "PostgreSQL")]
+fn fetch_fixed_type_row_wise(profile: &Profile) {
+    // Given a cursor
     let table_name = table_name!();
     let (conn, table) = Given::new(&table_name)
-        .column_types(&["NVARCHAR(50)"])
+        .column_types(&["INTEGER"])
         .build(profile)
         .unwrap();
-    let insert_sql = table.sql_insert();
-
-    // When
-    let arg = U16String::from_str("您好"); // Narrow build will fail for MSSQL without this line.
-    conn.execute(&insert_sql, &arg.into_parameter()).unwrap();
-
-    // Then
+    conn.execute(&table.sql_insert(), &42.into_parameter())
+        .unwrap();
     let cursor = conn
         .execute(&table.sql_all_ordered_by_id(), ())
         .unwrap()
         .unwrap();
-    let buffer = ColumnarBuffer::<_>::new(vec![(1, TextColumn::<u16>::new(1, 50))]);
-    let mut cursor = cursor.bind_buffer(buffer).unwrap();
-    let batch = cursor.fetch().unwrap().unwrap();
-    let utf16 = batch.column(0).get(0).unwrap();
-    let utf16 = U16Str::from_slice(utf16);
-    let actual = utf16.to_string().unwrap();
-    assert_eq!("您好", actual);
+
+    // When
+    type RowSample = (i32,);
+
+    let row_set_buffer = RowVec::<RowSample>::new(10);
+    let mut block_cursor = cursor.bind_buffer(row_set_buffer).unwrap();
+    let batch = block_cursor.fetch().unwrap().unwrap();
+
+    // Then
+    assert_eq!(42, batch[0].0);
 }
 
-/// Inspired by <https://github.com/pacman82/odbc-api/issues/428>
-/// Microsoft driver seems to report wrong sizes for the remaining strings if a text column can not
-/// be fetched on first try.
-// #[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MSSQL; "Microsoft SQL Server")]
 #[test_case(MARIADB; "Maria DB")]
-#[test_case(SQLITE_3; "SQLite 3")]
-// #[test_case(POSTGRES; "PostgreSQL")] Fails on linux: Invalid string conversion occurred
-fn cursor_get_text_from_text(profile: &Profile) {
-    // Given a text column with a string larger than 255 characters. It also must contain non ASCII
-    // characters.
+// #[test_case(SQLITE_3; "SQLite 3")] SQLite just returns a zeroed out numeric struct
+#[test_case(POSTGRES; "PostgreSQL")]
+fn fetch_decimal_as_numeric_struct_using_get_data(profile: &Profile) {
+    // Given a cursor over a result set with a decimal in its first column
     let table_name = table_name!();
     let (conn, table) = Given::new(&table_name)
-        .column_types(&["TEXT"])
+        .column_types(&["DECIMAL(5,3)"])
         .build(profile)
         .unwrap();
-    // For this test we want to run into a scenario, there we can not fetch everything in the first
-    // roundtrip, so we choose a text larger than 256 characters.
-    let text = "€".repeat(300);
-    let insert_sql = table.sql_insert();
-    conn.execute(&insert_sql, &text.into_parameter()).unwrap();
-
-    // When
-    let mut cursor = conn
+    conn.execute(&table.sql_insert(), &(25.212).into_parameter())
+        .unwrap();
+    let cursor = conn
         .execute(&table.sql_all_ordered_by_id(), ())
         .unwrap()
         .unwrap();
-    let mut row = cursor.next_row().unwrap().unwrap();
-    let mut buffer = Vec::new();
-    row.get_text(1, &mut buffer).unwrap();
+
+    // When
+    // Binding numeric withouth this wrapper would bind it without the App Row Descriptor
+    struct BindNumericAsAppRowDesc(Numeric);
+    let mut target = BindNumericAsAppRowDesc(Numeric {
+        precision: 0,
+        scale: 0,
+        sign: 0,
+        val: Default::default(),
+    });
+    unsafe impl CData for BindNumericAsAppRowDesc {
+        fn cdata_type(&self) -> CDataType {
+            CDataType::Ard
+        }
+        fn indicator_ptr(&self) -> *const isize {
+            std::ptr::null()
+        }
+        fn value_ptr(&self) -> *const std::ffi::c_void {
+            &self.0 as *const Numeric as *const std::ffi::c_void
+        }
+        fn buffer_length(&self) -> isize {
+            0
+        }
+    }
+    unsafe impl CDataMut for BindNumericAsAppRowDesc {
+        fn mut_indicator_ptr(&mut self) -> *mut isize {
+            null_mut()
+        }
+        fn mut_value_ptr(&mut self) -> Pointer {
+            &mut self.0 as *mut Numeric as *mut std::ffi::c_void
+        }
+    }
+    unsafe {
+        let mut stmt = cursor.into_stmt();
+
+        let mut ard = stmt.application_row_descriptor().unwrap();
+
+        ard.set_type(1, CDataType::Numeric).unwrap();
+        ard.set_precision(1, 5).unwrap();
+        ard.set_scale(1, 3).unwrap();
+
+        stmt.fetch();
+
+        stmt.get_data(1, &mut target);
+
+        stmt.close_cursor();
+    }
 
     // Then
-    let actual = String::from_utf8(buffer).unwrap();
-    assert_eq!("€".repeat(300), actual);
+    assert_eq!(5, target.0.precision);
+    assert_eq!(3, target.0.scale);
+    // 1 is positive, 0 is negative
+    assert_eq!(1, target.0.sign);
+    // Hex representation of 25212 is 627C
+    // First character contains '7C' -> C ~ 12, 7 ~ 7; 12 + 16 * 7 = 124
+    assert_eq!(124, target.0.val[0]);
+    // Second character encodes '62' -> 2 + 16 * 6 = 98
+    assert_eq!(98, target.0.val[1]);
+    assert_eq!(0, target.0.val[2]);
 }
 
-// This triggers a bug in the ODBC driver at least version 17 and 18
-//
-// In addition this bug does not seem to occur then talking to an MSSQL server hosted in a windows
-// Enivornment. In our current CI test suite, if the client runs on windows so does the server, so
-// we do not execute this test on windows.
 #[test_case(MSSQL; "Microsoft SQL Server")]
-#[should_panic(
-    expected = "SQLGetData has been unable to fetch all data, even though the capacity of the \
-    target buffer has been adapted to hold the entire payload based on the indicator of the last \
-    part. You may consider filing a bug with the ODBC driver you are using."
-)]
-#[cfg(not(target_os = "windows"))]
-fn cursor_get_text_from_text_mssql(profile: &Profile) {
-    // Given a text column with a string larger than 255 characters. It also must contain non ASCII
-    // characters.
+#[test_case(MARIADB; "Maria DB")]
+// #[test_case(SQLITE_3; "SQLite 3")] Always filled with zero
+#[test_case(POSTGRES; "PostgreSQL")]
+fn fetch_decimal_as_numeric_struct_using_bind_col(profile: &Profile) {
+    // Given a cursor over a result set with a decimal in its first column
     let table_name = table_name!();
     let (conn, table) = Given::new(&table_name)
-        .column_types(&["TEXT"])
+        .column_types(&["DECIMAL(5,3)"])
         .build(profile)
         .unwrap();
-    // For this test we want to run into a scenario, there we can not fetch everything in the first
-    // roundtrip, so we choose a text larger than 256 characters.
-    let text = "€".repeat(300);
-    let insert_sql = table.sql_insert();
-    conn.execute(&insert_sql, &text.into_parameter()).unwrap();
+    conn.execute(&table.sql_insert(), &(25.212).into_parameter())
+        .unwrap();
+    let cursor = conn
+        .execute(&table.sql_all_ordered_by_id(), ())
+        .unwrap()
+        .unwrap();
+
+    // When
+    // Binding numeric withouth this wrapper would bind it without the App Row Descriptor
+    let mut target = Numeric {
+        precision: 0,
+        scale: 0,
+        sign: 0,
+        val: Default::default(),
+    };
+    unsafe {
+        let mut stmt = cursor.into_stmt();
+
+        // stmt.bind_col(1, &mut target);
+        let mut ard = stmt.application_row_descriptor().unwrap();
+
+        // Setting Field descriptors always seems to yield structs field with zeroes
+        ard.set_type(1, CDataType::Numeric).unwrap();
+        ard.set_precision(1, 5).unwrap();
+        ard.set_scale(1, 3).unwrap();
+        // Setting the dataptr directly on the ARD is required to make it work for MSSQL which does
+        // seem to set the wrong pointer if just using bind col. Postgres and MariaDB would work as
+        // intended with bind_col.
+        // stmt.bind_col(1, &mut target);
+        ard.set_data_ptr(1, &mut target as *mut Numeric as Pointer)
+            .unwrap();
 
-    // When
-    let mut cursor = conn
-        .execute(&table.sql_all_ordered_by_id(), ())
-        .unwrap()
-        .unwrap();
-    let mut row = cursor.next_row().unwrap().unwrap();
-    let mut buffer = Vec::new();
-    row.get_text(1, &mut buffer).unwrap();
+        stmt.fetch();
 
-    // Microsoft driver is buggy in this situation, as it does not use the indicator to report the
-    // true size of the string or the `NO_TOTAL`. We can at least test that a panic occurs and not
-    // some endless loop or buffer overflow.
+        stmt.close_cursor();
+    }
+
+    // Then
+    assert_eq!(5, target.precision);
+    assert_eq!(3, target.scale);
+    // 1 is positive, 0 is negative
+    assert_eq!(1, target.sign);
+    // Hex representation of 25212 is 627C
+    // First character contains '7C' -> C ~ 12, 7 ~ 7; 12 + 16 * 7 = 124
+    assert_eq!(124, target.val[0]);
+    // Second character encodes '62' -> 2 + 16 * 6 = 98
+    assert_eq!(98, target.val[1]);
+    assert_eq!(0, target.val[2]);
 }
 
-/// If we want to use two buffers alternating to fetch data (like in the concurrent use case in
-/// the arrow-odbc downstream crate) we may want to generate a second row set buffer from an
-/// existing one. For this it is useful if we can infer the capacity of the block cursor, without
-/// unbinding it first.
 #[test_case(MSSQL; "Microsoft SQL Server")]
 #[test_case(MARIADB; "Maria DB")]
-#[test_case(SQLITE_3; "SQLite 3")]
+// #[test_case(SQLITE_3; "SQLite 3")] Always filled with zero
 #[test_case(POSTGRES; "PostgreSQL")]
-fn row_arrary_size_from_block_cursor(profile: &Profile) {
-    // Given a table
+fn fetch_decimal_into_numeric_column_buffer(profile: &Profile) {
+    // Given a cursor over a result set with a decimal in its first column
     let table_name = table_name!();
     let (conn, table) = Given::new(&table_name)
-        .column_types(&["INTEGER"])
+        .column_types(&["DECIMAL(5,3)"])
         .build(profile)
         .unwrap();
-
-    // When
-    let capacity_used_to_create_buffer = 42;
+    conn.execute(&table.sql_insert(), &(25.212).into_parameter())
+        .unwrap();
+    conn.execute(&table.sql_insert(), &None::<f64>.into_parameter())
+        .unwrap();
     let cursor = conn
         .execute(&table.sql_all_ordered_by_id(), ())
         .unwrap()
         .unwrap();
-    let buffer = ColumnarAnyBuffer::from_descs(
-        capacity_used_to_create_buffer,
-        [BufferDesc::I32 { nullable: true }],
-    );
-    let block_cursor = cursor.bind_buffer(buffer).unwrap();
-    let capacity_reported_by_block_cursor = block_cursor.row_array_size();
+
+    // When
+    let desc = BufferDesc::Numeric {
+        precision: 5,
+        scale: 3,
+        nullable: true,
+    };
+    let buffer = ColumnarAnyBuffer::from_descs(10, [desc]);
+    let mut cursor = cursor.bind_buffer(buffer).unwrap();
+    let batch = cursor.fetch().unwrap().unwrap();
 
     // Then
-    assert_eq!(
-        capacity_used_to_create_buffer,
-        capacity_reported_by_block_cursor
-    );
+    let mut values = batch.column(0).as_nullable_slice::<Numeric>().unwrap();
+    let first = values.next().unwrap().unwrap();
+    assert_eq!(5, first.precision);
+    assert_eq!(3, first.scale);
+    // 1 is positive, 0 is negative
+    assert_eq!(1, first.sign);
+    // Hex representation of 25212 is 627C
+    assert_eq!([124, 98, 0], first.val[..3]);
+    assert!(values.next().unwrap().is_none());
+    assert!(values.next().is_none());
 }
 
-/// Learning test what display size drivers report for JSON columns
-// #[test_case(MSSQL, "@json VARCHAR(max)", None; "Microsoft SQL Server")] // Unknown data type JSON
-// #[test_case(MARIADB, Some(1073741823); "Maria DB")] // Different size reported if MariaDB runs on windows
-#[test_case(SQLITE_3, Some(255); "SQLite 3")]
-#[test_case(POSTGRES, Some(255); "PostgreSQL")]
-fn json_column_display_size(profile: &Profile, expected_display_size: Option<usize>) {
-    // Given a table with a column type which will return `NO_TOTAL`
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+// #[test_case(SQLITE_3; "SQLite 3")] No decimal type
+#[test_case(POSTGRES; "PostgreSQL")]
+fn insert_numeric_with_fractional_digits(profile: &Profile) {
+    // Given
     let table_name = table_name!();
     let (conn, table) = Given::new(&table_name)
-        .column_types(&["JSON"])
+        .column_types(&["DECIMAL(5,3)"])
         .build(profile)
         .unwrap();
-    let query = table.sql_all_ordered_by_id();
+    let desc = BufferDesc::Numeric {
+        precision: 5,
+        scale: 3,
+        nullable: false,
+    };
+    let prepared = conn.prepare(&table.sql_insert()).unwrap();
+    let mut inserter = prepared.into_column_inserter(1, [desc]).unwrap();
 
-    // When obtaining result set metadata
-    let mut result_set = conn.execute(&query, ()).unwrap().unwrap();
-    // First column is id with index `0`. Second index (`1`) referes to the JSON column
-    let size = result_set
-        .col_display_size(1)
-        .unwrap()
-        .map(NonZeroUsize::get);
+    // When
+    inserter.set_num_rows(1);
+    let values = inserter.column_mut(0).as_slice::<Numeric>().unwrap();
+    // Hex representation of 25212 is 627C. Precision and scale of the struct are ignored, the
+    // declared ones of the description are used.
+    let mut val = [0; 16];
+    val[..2].copy_from_slice(&[124, 98]);
+    values[0] = Numeric {
+        precision: 0,
+        scale: 0,
+        sign: 1,
+        val,
+    };
+    inserter.execute().unwrap();
 
-    // Then it is equal to the recorded display size
-    assert_eq!(expected_display_size, size);
+    // Then
+    let actual = table.content_as_string(&conn);
+    assert_eq!("25.212", actual);
 }
 
-/// Fetch decimal values in their text interpretation and transform them to their integer
-/// representation.
 #[test_case(MSSQL; "Microsoft SQL Server")]
 #[test_case(MARIADB; "Maria DB")]
 #[test_case(SQLITE_3; "SQLite 3")]
 #[test_case(POSTGRES; "PostgreSQL")]
-fn fetch_decimals_to_int(profile: &Profile) {
+fn set_and_get_cursor_type_via_generic_statement_attributes(profile: &Profile) {
     // Given
+    let conn = profile.connection().unwrap();
+    let mut stmt = conn.preallocate().unwrap();
+    let cursor_type = sys::StatementAttribute::CursorType;
+    // SQL_CURSOR_STATIC
+    let cursor_static = 3;
+
+    // When
+    unsafe { stmt.set_stmt_attr_u32(cursor_type, cursor_static) }.unwrap();
+    let actual = unsafe { stmt.stmt_attr_u32(cursor_type) }.unwrap();
+
+    // Then
+    assert_eq!(cursor_static, actual);
+}
+
+/// Learning test to see how scrolling cursors behave
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+#[test_case(POSTGRES; "PostgreSQL")]
+fn scroll_cursor(profile: &Profile) {
+    // Given a table
     let table_name = table_name!();
     let (conn, table) = Given::new(&table_name)
-        .column_types(&["DECIMAL(5,3)"])
+        .column_types(&["VARCHAR(50)"])
         .build(profile)
         .unwrap();
-    conn.execute(
-        &format!("INSERT INTO {table_name} (a) VALUES (12.345), (-12.345), (12), (12.3)"),
-        (),
-    )
-    .unwrap();
+    conn.execute(&table.sql_insert(), &"one".into_parameter())
+        .unwrap();
+    conn.execute(&table.sql_insert(), &"two".into_parameter())
+        .unwrap();
+    conn.execute(&table.sql_insert(), &"three".into_parameter())
+        .unwrap();
+    let query = table.sql_all_ordered_by_id();
 
     // When
-    let mut cursor = conn
-        .execute(&table.sql_all_ordered_by_id(), ())
-        .unwrap()
-        .unwrap();
-    let row_set_buffer = TextRowSet::for_cursor(4, &mut cursor, None).unwrap();
-    let mut block_cursor = cursor.bind_buffer(row_set_buffer).unwrap();
-    let batch = block_cursor.fetch().unwrap().unwrap();
-    let n = |i| batch.at_as_str(0, i).unwrap().unwrap().as_bytes();
-    let n1 = decimal_text_to_i128(n(0), 3);
-    let n2 = decimal_text_to_i128(n(1), 3);
-    let n3 = decimal_text_to_i128(n(2), 3);
-    let n4 = decimal_text_to_i128(n(3), 3);
+    let stmt = conn.preallocate().unwrap();
+    let stmt = stmt.into_statement();
+    let stmt_ptr = stmt.as_sys();
+    let first;
+    let second;
+    let third;
+    unsafe {
+        // 3(UL) ~ SQL_CURSOR_STATIC
+        let _ = odbc_sys::SQLSetStmtAttr(
+            stmt_ptr,
+            odbc_sys::StatementAttribute::CursorType,
+            3 as Pointer,
+            0,
+        );
+        // Bind row set buffer
+        let mut stmt = Preallocated::new(stmt);
+        let mut cursor = stmt.execute(&query, ()).unwrap().unwrap();
+        let mut buffer = TextRowSet::for_cursor(1, &mut cursor, Some(10)).unwrap();
+        let mut block_cursor = cursor.bind_buffer(&mut buffer).unwrap();
+        // Move cursor two forward
+        first = block_cursor
+            .fetch()
+            .unwrap()
+            .unwrap()
+            .at_as_str(0, 0)
+            .unwrap()
+            .unwrap()
+            .to_owned();
+        second = block_cursor
+            .fetch()
+            .unwrap()
+            .unwrap()
+            .at_as_str(0, 0)
+            .unwrap()
+            .unwrap()
+            .to_owned();
+        let _ = odbc_sys::SQLFetchScroll(stmt_ptr, sys::FetchOrientation::Absolute, 1);
+        third = block_cursor
+            .fetch()
+            .unwrap()
+            .unwrap()
+            .at_as_str(0, 0)
+            .unwrap()
+            .unwrap()
+            .to_owned();
+    }
 
     // Then
-    assert_eq!(12345, n1);
-    assert_eq!(-12345, n2);
-    assert_eq!(12000, n3);
-    assert_eq!(12300, n4);
+    assert_eq!("one", first);
+    assert_eq!("two", second);
+    assert_eq!("two", third);
 }
 
-/// Bulf fetch in a dedicated system thread. Usually so the application can process the last batch
-/// while the next one is fetched.
+/// Learning test to see how downstream applications could recover from truncations during bulk
+/// fetches
 #[test_case(MSSQL; "Microsoft SQL Server")]
 #[test_case(MARIADB; "Maria DB")]
 #[test_case(SQLITE_3; "SQLite 3")]
 #[test_case(POSTGRES; "PostgreSQL")]
-fn concurrent_bulk_fetch_double_buffered(profile: &Profile) {
-    // Given
+fn recover_from_truncation(profile: &Profile) {
+    // Given a table
     let table_name = table_name!();
     let (conn, table) = Given::new(&table_name)
-        .column_types(&["INT"])
+        .column_types(&["VARCHAR(10)"])
         .build(profile)
         .unwrap();
-    conn.execute(&format!("INSERT INTO {table_name} (a) VALUES (1), (2)"), ())
+    conn.execute(&table.sql_insert(), &"1".into_parameter())
+        .unwrap();
+    conn.execute(&table.sql_insert(), &"123456789".into_parameter())
+        .unwrap();
+    conn.execute(&table.sql_insert(), &"1".into_parameter())
         .unwrap();
+    let query = table.sql_all_ordered_by_id();
 
     // When
-    let mut buffer_a = ColumnarAnyBuffer::from_descs(1, [BufferDesc::I32 { nullable: false }]);
-    let buffer_b = ColumnarAnyBuffer::from_descs(1, [BufferDesc::I32 { nullable: false }]);
-    let cursor = conn
-        .into_cursor(&table.sql_all_ordered_by_id(), ())
-        .unwrap()
-        .unwrap();
-    let block_cursor = cursor.bind_buffer(buffer_b).unwrap();
-    let mut concurrent_block_cursor = ConcurrentBlockCursor::from_block_cursor(block_cursor);
+    let stmt = conn.preallocate().unwrap();
+    let stmt = stmt.into_statement();
+    let stmt_ptr = stmt.as_sys();
+    let untruncated;
+    unsafe {
+        // 3(UL) ~ SQL_CURSOR_STATIC
+        let _ = odbc_sys::SQLSetStmtAttr(
+            stmt_ptr,
+            odbc_sys::StatementAttribute::CursorType,
+            3 as Pointer,
+            0,
+        );
+        // Bind row set buffer
+        let mut stmt = Preallocated::new(stmt);
+        let cursor = stmt.execute(&query, ()).unwrap().unwrap();
+        let buffer = TextRowSet::from_max_str_lens(1, [1]).unwrap();
+        let mut block_cursor = cursor.bind_buffer(buffer).unwrap();
+        // Fetch first row set (one row). This would be truncated
+        let _ = block_cursor.fetch().unwrap().unwrap();
+        // Fetch second rowset with truncation
+        let _ = block_cursor.fetch().unwrap().unwrap();
+        // In order to recover we set the position back and bind a large enough buffer.
 
-    let has_another_batch = concurrent_block_cursor.fetch_into(&mut buffer_a).unwrap();
-    assert!(has_another_batch);
-    assert_eq!(1, buffer_a.num_rows());
-    assert_eq!(1i32, buffer_a.column(0).as_slice().unwrap()[0]);
+        // Set position back
+        let _ = odbc_sys::SQLFetchScroll(stmt_ptr, sys::FetchOrientation::Prior, 0);
 
-    let has_another_batch = concurrent_block_cursor.fetch_into(&mut buffer_a).unwrap();
-    assert!(has_another_batch);
-    assert_eq!(1, buffer_a.num_rows());
-    assert_eq!(2i32, buffer_a.column(0).as_slice().unwrap()[0]);
+        // Bind large enough buffer
+        let (cursor, _) = block_cursor.unbind().unwrap();
+        let buffer = TextRowSet::from_max_str_lens(1, [10]).unwrap();
+        let mut block_cursor = cursor.bind_buffer(buffer).unwrap();
+
+        let batch = block_cursor.fetch().unwrap().unwrap();
+        untruncated = batch.at_as_str(0, 0).unwrap().unwrap().to_owned();
+    }
 
-    let has_another_batch = concurrent_block_cursor.fetch_into(&mut buffer_a).unwrap();
-    assert!(!has_another_batch);
+    // Then
+    assert_eq!("123456789", untruncated);
 }
 
-/// Bulf fetch in a dedicated system thread. Usually so the application can process the last batch
-/// while the next one is fetched.
+/// Fetch a larger result set using a static cursor, to verify the cursor type set via
+/// `StatementOptions` is accepted by the driver.
 #[test_case(MSSQL; "Microsoft SQL Server")]
 #[test_case(MARIADB; "Maria DB")]
 #[test_case(SQLITE_3; "SQLite 3")]
 #[test_case(POSTGRES; "PostgreSQL")]
-fn concurrent_bulk_fetch_single_buffer(profile: &Profile) {
-    // Given
+fn fetch_large_result_set_with_static_cursor(profile: &Profile) {
+    // Given a table with 1000 rows
     let table_name = table_name!();
     let (conn, table) = Given::new(&table_name)
-        .column_types(&["INT"])
+        .column_types(&["INTEGER"])
         .build(profile)
         .unwrap();
-    conn.execute(&format!("INSERT INTO {table_name} (a) VALUES (1), (2)"), ())
+    let desc = BufferDesc::I32 { nullable: false };
+    let mut inserter = conn
+        .prepare(&table.sql_insert())
+        .unwrap()
+        .into_column_inserter(1000, [desc])
         .unwrap();
+    inserter.set_num_rows(1000);
+    let values = inserter.column_mut(0).as_slice::<i32>().unwrap();
+    for (index, value) in values.iter_mut().enumerate() {
+        *value = index as i32;
+    }
+    inserter.execute().unwrap();
 
     // When
-    let buffer = ColumnarAnyBuffer::from_descs(1, [BufferDesc::I32 { nullable: false }]);
+    let options = StatementOptions {
+        cursor_type: Some(CursorType::Static),
+        ..StatementOptions::default()
+    };
     let cursor = conn
-        .into_cursor(&table.sql_all_ordered_by_id(), ())
+        .execute_with_options(&table.sql_all_ordered_by_id(), (), options)
         .unwrap()
         .unwrap();
-    let block_cursor = cursor.bind_buffer(buffer).unwrap();
-    let mut concurrent_block_cursor = ConcurrentBlockCursor::from_block_cursor(block_cursor);
+    let buffer = ColumnarAnyBuffer::from_descs(100, [desc]);
+    let mut block_cursor = cursor.bind_buffer(buffer).unwrap();
+    let mut num_rows = 0;
+    let mut sum = 0;
+    while let Some(batch) = block_cursor.fetch().unwrap() {
+        let values = batch.column(0).as_slice::<i32>().unwrap();
+        num_rows += values.len();
+        sum += values.iter().map(|&v| v as i64).sum::<i64>();
+    }
 
-    let batch = concurrent_block_cursor.fetch().unwrap().unwrap();
-    assert_eq!(1, batch.num_rows());
-    assert_eq!(1i32, batch.column(0).as_slice().unwrap()[0]);
-    concurrent_block_cursor.fill(batch);
+    // Then
+    assert_eq!(1000, num_rows);
+    assert_eq!(499_500, sum);
+}
 
-    let batch = concurrent_block_cursor.fetch().unwrap().unwrap();
-    assert_eq!(1, batch.num_rows());
-    assert_eq!(2i32, batch.column(0).as_slice().unwrap()[0]);
-    concurrent_block_cursor.fill(batch);
+/// Only scrollable cursors allow fetching rows by absolute position.
+#[test_case(MSSQL; "Microsoft SQL Server")]
+fn scroll_only_with_scrollable_cursor_type(profile: &Profile) {
+    // Given
+    let table_name = table_name!();
+    let (conn, table) = Given::new(&table_name)
+        .column_types(&["INTEGER"])
+        .values_by_column(&[&[Some("1"), Some("2")]])
+        .build(profile)
+        .unwrap();
+    let query = table.sql_all_ordered_by_id();
 
-    let all_batches_consumed = concurrent_block_cursor.fetch().unwrap().is_none();
-    assert!(all_batches_consumed);
+    // When
+    let fetch_absolute = |cursor_type| {
+        let options = StatementOptions {
+            cursor_type: Some(cursor_type),
+            ..StatementOptions::default()
+        };
+        let mut cursor = conn
+            .execute_with_options(&query, (), options)
+            .unwrap()
+            .unwrap();
+        unsafe {
+            sys::SQLFetchScroll(
+                cursor.as_stmt_ref().as_sys(),
+                sys::FetchOrientation::Absolute,
+                1,
+            )
+        }
+    };
+    let forward_only = fetch_absolute(CursorType::ForwardOnly);
+    let static_cursor = fetch_absolute(CursorType::Static);
+
+    // Then
+    assert_eq!(sys::SqlReturn::ERROR, forward_only);
+    assert_eq!(sys::SqlReturn::SUCCESS, static_cursor);
 }
 
-/// Catch edge cases, there we stop the thread, while there are still batches to consume
+/// Temporary tables created via the guard, must be dropped after the guard goes out of scope.
 #[test_case(MSSQL; "Microsoft SQL Server")]
 #[test_case(MARIADB; "Maria DB")]
 #[test_case(SQLITE_3; "SQLite 3")]
 #[test_case(POSTGRES; "PostgreSQL")]
-fn concurrent_bulk_fetch_fetch_one_batch(profile: &Profile) {
+fn temp_table_dropped_with_guard(profile: &Profile) {
     // Given
+    let conn = profile.connection().unwrap();
     let table_name = table_name!();
-    let (conn, table) = Given::new(&table_name)
-        .column_types(&["INT"])
-        .build(profile)
-        .unwrap();
-    conn.execute(&format!("INSERT INTO {table_name} (a) VALUES (1), (2)"), ())
-        .unwrap();
 
     // When
-    let buffer = ColumnarAnyBuffer::from_descs(1, [BufferDesc::I32 { nullable: false }]);
-    let cursor = conn
-        .into_cursor(&table.sql_all_ordered_by_id(), ())
-        .unwrap()
+    let name = {
+        let table = conn
+            .create_temp_table(&table_name, &[("id", "INTEGER"), ("text", "VARCHAR(10)")])
+            .unwrap();
+        conn.execute(
+            &format!("INSERT INTO {} VALUES (1, 'Hello')", table.name()),
+            (),
+        )
         .unwrap();
-    let block_cursor = cursor.bind_buffer(buffer).unwrap();
-    let mut concurrent_block_cursor = ConcurrentBlockCursor::from_block_cursor(block_cursor);
-    let _ = concurrent_block_cursor.fetch().unwrap().unwrap();
-    // Now instead of sending a buffer and fetching a next one, we interrupt the fetch thread while
-    // it does not own a buffer.
-    let cursor = concurrent_block_cursor.into_cursor().unwrap();
-    // Now we can deterministically fetch the second batch in the main thread again. Since the fetch
-    // thread has only ever seen one buffer, it could have only fetched one batch.
+        let mut cursor = conn
+            .execute(&format!("SELECT text FROM {}", table.name()), ())
+            .unwrap()
+            .unwrap();
+        let mut row = cursor.next_row().unwrap().unwrap();
+        let mut text = Vec::new();
+        row.get_text(1, &mut text).unwrap();
+        assert_eq!(b"Hello", text.as_slice());
+        table.name().to_owned()
+    };
 
-    let actual = cursor_to_string(cursor);
-    assert_eq!("2", actual);
+    // Then
+    let result = conn.execute(&format!("SELECT * FROM {name}"), ());
+    assert!(result.is_err());
 }
 
-/// Bulk fetch in a dedicated system thread. Usually so the application can process the last batch
-/// while the next one is fetched.
 #[test_case(MSSQL; "Microsoft SQL Server")]
 #[test_case(MARIADB; "Maria DB")]
-// SQLite, actually does not create a diagonstic record or is creating any kind of error in this
-// case. It would just report the value to be zero. This has nothing todo with the fetch being
-// concurrent though. To test the error handling, the other DBMs have to suffice
-// #[test_case(SQLITE_3; "SQLite 3")]
+#[test_case(SQLITE_3; "SQLite 3")]
 #[test_case(POSTGRES; "PostgreSQL")]
-fn concurrent_bulk_fetch_with_invalid_buffer_type(profile: &Profile) {
-    // Given an integer table with a NULL
+fn prepared_statement_produces_result_set(profile: &Profile) {
+    // Given
     let table_name = table_name!();
     let (conn, table) = Given::new(&table_name)
-        .column_types(&["INT"])
+        .column_types(&["INTEGER"])
         .build(profile)
         .unwrap();
-    conn.execute(&format!("INSERT INTO {table_name} (a) VALUES (NULL)"), ())
-        .unwrap();
 
-    // When fetching with a Columnar buffer not supporting nullable values
-    let mut buffer_a = ColumnarAnyBuffer::from_descs(1, [BufferDesc::I32 { nullable: false }]);
-    let buffer_b = ColumnarAnyBuffer::from_descs(1, [BufferDesc::I32 { nullable: false }]);
-    let cursor = conn
-        .into_cursor(&table.sql_all_ordered_by_id(), ())
-        .unwrap()
-        .unwrap();
-    let block_cursor = cursor.bind_buffer(buffer_b).unwrap();
-    let mut concurrent_block_cursor = ConcurrentBlockCursor::from_block_cursor(block_cursor);
-    // This line provokes the first error, due to the invalid buffer.
-    let result_one = concurrent_block_cursor.fetch_into(&mut buffer_a);
-    let result_two = concurrent_block_cursor.fetch_into(&mut buffer_a);
+    // When
+    let mut select = conn.prepare(&table.sql_all_ordered_by_id()).unwrap();
+    let mut insert = conn.prepare(&table.sql_insert()).unwrap();
 
     // Then
-    assert!(result_one.is_err());
-    // After the first error, we treat the stream same as we would treat a stream which is consumed.
-    assert!(!result_two.unwrap());
+    assert!(select.produces_result_set().unwrap());
+    assert!(!insert.produces_result_set().unwrap());
 }
 
 #[test_case(MSSQL; "Microsoft SQL Server")]
-fn concurrent_fetch_of_multiple_result_sets(profile: &Profile) {
+fn describe_query_without_executing_it(profile: &Profile) {
     // Given
-    let conn = profile.connection().unwrap();
-    let query = "SELECT 1 AS a; SELECT 2 AS b;";
+    let table_name = table_name!();
+    let (conn, table) = Given::new(&table_name)
+        .column_types(&["VARCHAR(255) NOT NULL", "INTEGER"])
+        .build(profile)
+        .unwrap();
 
     // When
-    let mut buffer_a = ColumnarAnyBuffer::from_descs(1, [BufferDesc::I32 { nullable: false }]);
-    let buffer_b = ColumnarAnyBuffer::from_descs(1, [BufferDesc::I32 { nullable: false }]);
-    let cursor = conn.into_cursor(query, ()).unwrap().unwrap();
-    let block_cursor = cursor.bind_buffer(buffer_b).unwrap();
-    let mut concurrent_block_cursor = ConcurrentBlockCursor::from_block_cursor(block_cursor);
-    // Consume first result set.
-    concurrent_block_cursor.fetch_into(&mut buffer_a).unwrap();
-    concurrent_block_cursor.fetch_into(&mut buffer_a).unwrap();
-    // Now continue with the same cursor to fetch the second
-    let cursor = concurrent_block_cursor.into_cursor().unwrap();
-    let cursor = cursor.more_results().unwrap().unwrap();
-    let mut cursor = cursor.bind_buffer(buffer_a).unwrap();
-    let batch = cursor.fetch().unwrap().unwrap();
+    let columns = conn
+        .describe_query(&format!("SELECT a, b FROM {table_name}"))
+        .unwrap();
 
     // Then
-    assert_eq!(2i32, batch.column(0).as_slice().unwrap()[0]);
+    let expected = vec![
+        ColumnDescription::new(
+            "a",
+            DataType::Varchar {
+                length: NonZeroUsize::new(255),
+            },
+            Nullability::NoNulls,
+        ),
+        ColumnDescription::new("b", DataType::Integer, Nullability::Nullable),
+    ];
+    assert_eq!(expected, columns);
+    assert!(conn.describe_query(&table.sql_insert()).unwrap().is_empty());
 }
 
-/// This test covers a code path in which the thread dedicated to fething is not termintated by
-/// running out of batches.
 #[test_case(MSSQL; "Microsoft SQL Server")]
-fn concurrent_fetch_skip_first_result_set(profile: &Profile) {
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+#[test_case(POSTGRES; "PostgreSQL")]
+fn failing_sql_text_is_part_of_error(profile: &Profile) {
     // Given
     let conn = profile.connection().unwrap();
-    let query = "SELECT 1 AS a; SELECT 2 AS b;";
+    let sql = "SELECT * FROM table_which_does_not_exist WHERE a = ?";
 
     // When
-    let buffer_a = ColumnarAnyBuffer::from_descs(1, [BufferDesc::I32 { nullable: false }]);
-    let buffer_b = ColumnarAnyBuffer::from_descs(1, [BufferDesc::I32 { nullable: false }]);
-    let cursor = conn.into_cursor(query, ()).unwrap().unwrap();
-    let block_cursor = cursor.bind_buffer(buffer_b).unwrap();
-    let concurrent_block_cursor = ConcurrentBlockCursor::from_block_cursor(block_cursor);
-    // Skip over first result set, without fetching any batches.
-    // Now continue with the same cursor to fetch the second
-    let cursor = concurrent_block_cursor.into_cursor().unwrap();
-    let cursor = cursor.more_results().unwrap().unwrap();
-    let mut cursor = cursor.bind_buffer(buffer_a).unwrap();
-    let batch = cursor.fetch().unwrap().unwrap();
+    let result = conn.execute_with_sql_context(sql, &"secret".into_parameter());
 
     // Then
-    assert_eq!(2i32, batch.column(0).as_slice().unwrap()[0]);
-}
-
-/// This tests checks if there is more than one attribute returned. We had a bug (see issue:
-/// <https://github.com/pacman82/odbc-api/issues/511>) in which we used a buffer intended for zero
-/// terminated strings to get the attributes. Yet the attributes use the `0` as a delimiter.
-#[test]
-fn list_all_driver_attributes() {
-    // Given an ODBC environment with drivers installed
-    let environment = &environment().unwrap();
-
-    // When fetching driver infors
-    let driver_infos = environment.drivers().unwrap();
-
-    // At least one driver is expecetd to have more than one attribute.
-    let maximum = driver_infos
-        .iter()
-        .map(|info| info.attributes.len())
-        .max()
-        .expect("At least one ODBC driver must be installed");
-    assert!(maximum > 1);
+    let error = result.err().unwrap();
+    assert!(matches!(&error, Error::ExecutionFailed { sql: text, .. } if text == sql));
+    let message = error.to_string();
+    assert!(message.contains(sql));
+    assert!(!message.contains("secret"));
 }
 
-#[test_case(MSSQL, true; "Microsoft SQL Server")]
-#[test_case(MARIADB, false; "Maria DB")]
-#[test_case(SQLITE_3, false; "SQLite 3")]
-#[test_case(POSTGRES, false; "PostgreSQL")]
-#[tokio::test]
-async fn polling_preallocated_statement_execution(
-    profile: &Profile,
-    expected_to_support_polling: bool,
-) {
-    // Given a table
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+#[test_case(POSTGRES; "PostgreSQL")]
+fn execute_and_prepare_accept_owned_and_borrowed_sql(profile: &Profile) {
+    // Given
     let table_name = table_name!();
     let (conn, table) = Given::new(&table_name)
-        .column_types(&["VARCHAR(50)"])
+        .column_types(&["INTEGER"])
         .build(profile)
         .unwrap();
-    let query = format!("INSERT INTO {table_name} (a) VALUES ('Hello, World!')");
-    // We use this counter to check if the sleep function is actually invoked and the driver does
-    // actually support asynchronous polling.
-    let mut sleep_counter_spy = 0;
-    let sleep = || {
-        sleep_counter_spy += 1;
-        tokio::time::sleep(Duration::from_millis(50))
-    };
+    let insert: String = format!("INSERT INTO {table_name} (a) VALUES (1)");
+    let select: &str = &table.sql_all_ordered_by_id();
 
     // When
-    let mut statement = conn.preallocate().unwrap().into_polling().unwrap();
-    statement.execute(&query, (), sleep).await.unwrap();
+    conn.execute(insert, ()).unwrap();
+    conn.prepare(table.sql_insert())
+        .unwrap()
+        .execute(&2)
+        .unwrap();
+    let cursor = conn.execute(select, ()).unwrap().unwrap();
 
     // Then
-    let actual = table.content_as_string(&conn);
-    assert_eq!("Hello, World!", actual);
-    let used_polling = sleep_counter_spy != 0;
-    assert_eq!(expected_to_support_polling, used_polling);
+    let actual = cursor_to_string(cursor);
+    assert_eq!("1\n2", actual);
 }
 
-#[test_case(MSSQL, true; "Microsoft SQL Server")]
-#[test_case(MARIADB, false; "Maria DB")]
-#[test_case(SQLITE_3, false; "SQLite 3")]
-#[test_case(POSTGRES, false; "PostgreSQL")]
-#[tokio::test]
-async fn async_bulk_fetch(profile: &Profile, expected_to_support_polling: bool) {
-    // Given a table with a thousand records
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+#[test_case(POSTGRES; "PostgreSQL")]
+fn fetch_varchar_into_owned_strings_without_truncation(profile: &Profile) {
+    // Given
     let table_name = table_name!();
+    let long_text = "x".repeat(300);
+    let column = [Some("a"), Some(long_text.as_str()), None, Some("abcdef")];
+    let values = [&column[..]];
     let (conn, table) = Given::new(&table_name)
-        .column_types(&["VARCHAR(50)"])
+        .column_types(&["VARCHAR(400)"])
+        .values_by_column(&values)
         .build(profile)
-        .unwrap();
-    let prepared = conn.prepare(&table.sql_insert()).unwrap();
-    let mut inserter = prepared.into_text_inserter(1000, [50]).unwrap();
-    for index in 0..1000 {
-        inserter
-            .append([Some(index.to_string().as_bytes())].iter().copied())
-            .unwrap();
-    }
-    inserter.execute().unwrap();
-    let query = table.sql_all_ordered_by_id();
-    // We use this counter to check if the sleep function is actually invoked and the driver does
-    // actually support asynchronous polling.
-    let mut sleep_counter_spy = 0;
-    let mut sleep = || {
-        sleep_counter_spy += 1;
-        tokio::time::sleep(Duration::from_millis(50))
-    };
-
-    // When
-    let mut sum_rows_fetched = 0;
-    let cursor = conn
-        .execute_polling(&query, (), &mut sleep)
-        .await
-        .unwrap()
-        .unwrap();
-    // Fetching results in ten batches
-    let buffer = TextRowSet::from_max_str_lens(100, [50usize]).unwrap();
-    let mut row_set_cursor = cursor.bind_buffer(buffer).unwrap();
-    let mut maybe_batch = row_set_cursor.fetch(&mut sleep).await.unwrap();
-    while let Some(batch) = maybe_batch {
-        sum_rows_fetched += batch.num_rows();
-        maybe_batch = row_set_cursor.fetch(&mut sleep).await.unwrap();
+        .unwrap();
+    let mut cursor = conn
+        .execute(table.sql_all_ordered_by_id(), ())
+        .unwrap()
+        .unwrap();
+
+    // When
+    let buffer = StringRowVec::for_cursor(3, &mut cursor, 4).unwrap();
+    let mut block_cursor = cursor.bind_buffer(buffer).unwrap();
+    let mut rows = Vec::new();
+    while let Some(batch) = block_cursor.fetch().unwrap() {
+        assert_eq!(1, batch.num_cols());
+        rows.extend(batch.rows().map(<[_]>::to_vec));
     }
 
     // Then
-    assert_eq!(1000, sum_rows_fetched);
-    let used_polling = sleep_counter_spy != 0;
-    assert_eq!(expected_to_support_polling, used_polling);
+    let expected = vec![
+        vec![Some("a".to_owned())],
+        vec![Some(long_text.clone())],
+        vec![None],
+        vec![Some("abcdef".to_owned())],
+    ];
+    assert_eq!(expected, rows);
 }
 
+/// Owned strings can be moved out of batches handed out by a concurrent block cursor
 #[test_case(MSSQL; "Microsoft SQL Server")]
 #[test_case(MARIADB; "Maria DB")]
 #[test_case(SQLITE_3; "SQLite 3")]
 #[test_case(POSTGRES; "PostgreSQL")]
-fn row_wise_bulk_query_using_tuple(profile: &Profile) {
-    // Given a cursor
+fn take_owned_strings_from_concurrent_block_cursor(profile: &Profile) {
+    // Given
     let table_name = table_name!();
+    let column = [Some("a"), None, Some("abcdef")];
+    let values = [&column[..]];
     let (conn, table) = Given::new(&table_name)
-        .column_types(&["INTEGER", "VARCHAR(50)"])
-        .values_by_column(&[
-            &[Some("42"), Some("5")],
-            &[Some("Hello, World!"), Some("Hallo, Welt!")],
-        ])
+        .column_types(&["VARCHAR(10)"])
+        .values_by_column(&values)
         .build(profile)
         .unwrap();
-    let cursor = conn
-        .execute(&table.sql_all_ordered_by_id(), ())
+    let mut cursor = conn
+        .into_cursor(&table.sql_all_ordered_by_id(), ())
         .unwrap()
         .unwrap();
 
     // When
-    let row_set_buffer = RowVec::<(i32, VarCharArray<50>)>::new(10);
-    let mut block_cursor = cursor.bind_buffer(row_set_buffer).unwrap();
-    let batch = block_cursor.fetch().unwrap().unwrap();
+    let buffer = StringRowVec::for_cursor(2, &mut cursor, 4).unwrap();
+    let block_cursor = cursor.bind_buffer(buffer).unwrap();
+    let mut concurrent_block_cursor = ConcurrentBlockCursor::from_block_cursor(block_cursor);
+    let mut rows = Vec::new();
+    while let Some(mut batch) = concurrent_block_cursor.fetch().unwrap() {
+        rows.extend(batch.take_rows());
+        assert_eq!(0, batch.num_rows());
+        concurrent_block_cursor.fill(batch);
+    }
 
     // Then
-    assert_eq!(2, batch.num_rows());
-    assert_eq!(42, batch[0].0);
-    assert_eq!("Hello, World!", batch[0].1.as_str().unwrap().unwrap());
-    assert_eq!(5, batch[1].0);
-    assert_eq!("Hallo, Welt!", batch[1].1.as_str().unwrap().unwrap());
+    let expected = vec![
+        vec![Some("a".to_owned())],
+        vec![None],
+        vec![Some("abcdef".to_owned())],
+    ];
+    assert_eq!(expected, rows);
 }
 
 #[test_case(MSSQL; "Microsoft SQL Server")]
 #[test_case(MARIADB; "Maria DB")]
 #[test_case(SQLITE_3; "SQLite 3")]
 #[test_case(POSTGRES; "PostgreSQL")]
-fn row_wise_bulk_query_nullable(profile: &Profile) {
-    // Given a cursor
+fn round_trip_cursor_through_statement_handle(profile: &Profile) {
+    // Given
     let table_name = table_name!();
     let (conn, table) = Given::new(&table_name)
         .column_types(&["INTEGER"])
-        .values_by_column(&[&[Some("42"), None]])
+        .values_by_column(&[&[Some("1"), Some("2"), Some("3")]])
         .build(profile)
         .unwrap();
     let cursor = conn
-        .execute(&table.sql_all_ordered_by_id(), ())
+        .execute(table.sql_all_ordered_by_id(), ())
         .unwrap()
         .unwrap();
 
     // When
-    let row_set_buffer = RowVec::<(Nullable<i32>,)>::new(10);
-    let mut block_cursor = cursor.bind_buffer(row_set_buffer).unwrap();
-    let batch = block_cursor.fetch().unwrap().unwrap();
+    let mut statement = cursor.into_stmt();
+    // Fetch the first row using the statement handle directly
+    let has_row = unsafe { statement.fetch() }
+        .into_result_bool(&statement)
+        .unwrap();
+    // Safety: Statement is still in cursor state.
+    let cursor = unsafe { CursorImpl::new(statement) };
 
     // Then
-    assert_eq!(Some(&42), batch[0].0.as_opt());
-    assert_eq!(None, batch[1].0.as_opt());
+    assert!(has_row);
+    assert_eq!("2\n3", cursor_to_string(cursor));
 }
 
 #[test_case(MSSQL; "Microsoft SQL Server")]
 #[test_case(MARIADB; "Maria DB")]
 #[test_case(SQLITE_3; "SQLite 3")]
 #[test_case(POSTGRES; "PostgreSQL")]
-fn row_wise_bulk_query_binary(profile: &Profile) {
-    // Given a cursor
+fn fetch_into_caller_provided_arena(profile: &Profile) {
+    // Given
     let table_name = table_name!();
     let (conn, table) = Given::new(&table_name)
-        .column_types(&["VARCHAR(20)"])
-        .values_by_column(&[&[Some("Hello, World!"), None]])
+        .column_types(&["INTEGER NOT NULL", "INTEGER NOT NULL"])
+        .values_by_column(&[
+            &[Some("1"), Some("2"), Some("3")],
+            &[Some("4"), Some("5"), Some("6")],
+        ])
         .build(profile)
         .unwrap();
     let cursor = conn
-        .execute(&table.sql_all_ordered_by_id(), ())
+        .execute(table.sql_all_ordered_by_id(), ())
         .unwrap()
         .unwrap();
+    // Storage for both columns, owned by the caller rather than the buffer.
+    let mut arena = [0i32; 10];
 
     // When
-    let row_set_buffer = RowVec::<(VarBinaryArray<20>,)>::new(2);
-    let mut block_cursor = cursor.bind_buffer(row_set_buffer).unwrap();
-    let batch = block_cursor.fetch().unwrap().unwrap();
+    {
+        let (first, second) = arena.split_at_mut(5);
+        let buffer = ColumnarBuffer::new(vec![(1, first), (2, second)]);
+        let mut block_cursor = cursor.bind_buffer(buffer).unwrap();
+        let batch = block_cursor.fetch().unwrap().unwrap();
+        assert_eq!(3, batch.num_rows());
+    }
 
     // Then
-    assert_eq!(b"Hello, World!", batch[0].0.as_bytes().unwrap());
+    assert_eq!([1, 2, 3, 0, 0, 4, 5, 6, 0, 0], arena);
 }
 
 #[test_case(MSSQL; "Microsoft SQL Server")]
 #[test_case(MARIADB; "Maria DB")]
 #[test_case(SQLITE_3; "SQLite 3")]
 #[test_case(POSTGRES; "PostgreSQL")]
-fn row_wise_bulk_query_wide_text(profile: &Profile) {
-    // Given a cursor
+fn fetch_nullable_column_into_caller_provided_storage(profile: &Profile) {
+    // Given
     let table_name = table_name!();
     let (conn, table) = Given::new(&table_name)
-        .column_types(&["VARCHAR(20)"])
-        .values_by_column(&[&[Some("Hello, World!"), None]])
+        .column_types(&["INTEGER"])
+        .values_by_column(&[&[Some("1"), None, Some("3")]])
         .build(profile)
         .unwrap();
     let cursor = conn
-        .execute(&table.sql_all_ordered_by_id(), ())
+        .execute(table.sql_all_ordered_by_id(), ())
         .unwrap()
         .unwrap();
+    let mut values = [0i32; 5];
+    let mut indicators = [0isize; 5];
 
     // When
-    let row_set_buffer = RowVec::<(VarWCharArray<20>,)>::new(2);
-    let mut block_cursor = cursor.bind_buffer(row_set_buffer).unwrap();
+    let column = NullableSliceMut::new(&mut values, &mut indicators);
+    let buffer = ColumnarBuffer::new(vec![(1, column)]);
+    let mut block_cursor = cursor.bind_buffer(buffer).unwrap();
     let batch = block_cursor.fetch().unwrap().unwrap();
+    let actual: Vec<_> = batch.column(0).map(|value| value.copied()).collect();
+
+    // Then
+    assert_eq!(vec![Some(1), None, Some(3)], actual);
+}
+
+#[test_case(MSSQL; "Microsoft SQL Server")]
+fn insert_null_and_long_binary_with_binary_param(profile: &Profile) {
+    // Given
+    let table_name = table_name!();
+    let (conn, table) = Given::new(&table_name)
+        .column_types(&["VARBINARY(MAX)"])
+        .build(profile)
+        .unwrap();
+    let large: Vec<u8> = (0..10_000).map(|i| (i % 256) as u8).collect();
+
+    // When
+    let mut prepared = conn.prepare(table.sql_insert()).unwrap();
+    prepared
+        .execute(&BinaryParam::new(None::<&[u8]>.into_parameter()))
+        .unwrap();
+    prepared
+        .execute(&BinaryParam::new(large.clone().into_parameter()))
+        .unwrap();
+
+    // Then
+    let mut cursor = conn
+        .execute(table.sql_all_ordered_by_id(), ())
+        .unwrap()
+        .unwrap();
+    let mut buf = Vec::new();
+    {
+        let mut row = cursor.next_row().unwrap().unwrap();
+        assert!(!row.get_binary(1, &mut buf).unwrap());
+    }
+    let mut row = cursor.next_row().unwrap().unwrap();
+    assert!(row.get_binary(1, &mut buf).unwrap());
+    assert_eq!(large, buf);
+}
+
+#[test_case(MSSQL; "Microsoft SQL Server")]
+fn restart_static_cursor(profile: &Profile) {
+    // Given
+    let table_name = table_name!();
+    let (conn, table) = Given::new(&table_name)
+        .column_types(&["VARCHAR(10)"])
+        .values_by_column(&[&[Some("one"), Some("two"), None]])
+        .build(profile)
+        .unwrap();
+    let options = StatementOptions {
+        cursor_type: Some(CursorType::Static),
+        ..StatementOptions::default()
+    };
+    let mut cursor = conn
+        .execute_with_options(&table.sql_all_ordered_by_id(), (), options)
+        .unwrap()
+        .unwrap();
+    fn collect_rows(cursor: &mut impl Cursor) -> Vec<Option<String>> {
+        let mut rows = Vec::new();
+        while let Some(mut row) = cursor.next_row().unwrap() {
+            let mut text = String::new();
+            let is_not_null = row.append_text(1, &mut text).unwrap();
+            rows.push(is_not_null.then_some(text));
+        }
+        rows
+    }
+
+    // When
+    let first_pass = collect_rows(&mut cursor);
+    cursor.restart().unwrap();
+    let second_pass = collect_rows(&mut cursor);
 
     // Then
     assert_eq!(
-        "Hello, World!",
-        batch[0].0.as_utf16().unwrap().to_string().unwrap()
+        vec![Some("one".to_owned()), Some("two".to_owned()), None],
+        first_pass
     );
+    assert_eq!(first_pass, second_pass);
 }
 
-#[cfg(feature = "derive")]
+/// Modify and delete rows in place, using a positioned update cursor.
 #[test_case(MSSQL; "Microsoft SQL Server")]
-#[test_case(MARIADB; "Maria DB")]
-#[test_case(SQLITE_3; "SQLite 3")]
-#[test_case(POSTGRES; "PostgreSQL")]
-fn row_wise_bulk_query_using_custom_row(profile: &Profile) {
-    // Given a cursor
+fn update_and_delete_rows_through_block_cursor(profile: &Profile) {
+    // Given
     let table_name = table_name!();
     let (conn, table) = Given::new(&table_name)
-        .column_types(&["INTEGER", "VARCHAR(50)"])
-        .values_by_column(&[
-            &[Some("42"), Some("5")],
-            &[Some("Hello, World!"), Some("Hallo, Welt!")],
-        ])
+        .column_types(&["INTEGER"])
+        .values_by_column(&[&[Some("1"), Some("2"), Some("3")]])
         .build(profile)
         .unwrap();
+    let options = StatementOptions {
+        cursor_type: Some(CursorType::KeysetDriven),
+        concurrency: Some(Concurrency::Lock),
+    };
     let cursor = conn
-        .execute(&table.sql_all_ordered_by_id(), ())
+        .execute_with_options(&table.sql_all_ordered_by_id(), (), options)
+        .unwrap()
+        .unwrap();
+    let buffer = RowVec::<(i32,)>::new(10);
+    let mut cursor = cursor
+        .bind_buffer(buffer)
         .unwrap()
+        .into_updatable()
         .unwrap();
 
     // When
-    #[derive(Clone, Copy, Default, Fetch)]
-    struct MyRow {
-        a: i32,
-        b: VarCharArray<50>,
-    }
-    let row_set_buffer = RowVec::<MyRow>::new(10);
-    let mut block_cursor = cursor.bind_buffer(row_set_buffer).unwrap();
-    let batch = block_cursor.fetch().unwrap().unwrap();
+    cursor.fetch().unwrap().unwrap();
+    let rows = unsafe { cursor.buffer_mut() };
+    rows[0].0 = 10;
+    cursor.update_row(0).unwrap();
+    cursor.delete_row(2).unwrap();
+    drop(cursor);
 
     // Then
-    assert_eq!(2, batch.num_rows());
-    assert_eq!(42, batch[0].a);
-    assert_eq!("Hello, World!", batch[0].b.as_str().unwrap().unwrap());
-    assert_eq!(5, batch[1].a);
-    assert_eq!("Hallo, Welt!", batch[1].b.as_str().unwrap().unwrap());
+    let actual = table.content_as_string(&conn);
+    assert_eq!("10\n2", actual);
 }
 
+/// Cursors are read-only by default, so they can not be used to update rows.
 #[test_case(MSSQL; "Microsoft SQL Server")]
 #[test_case(MARIADB; "Maria DB")]
 #[test_case(SQLITE_3; "SQLite 3")]
 #[test_case(POSTGRES; "PostgreSQL")]
-fn truncation_in_row_wise_bulk_buffer(profile: &Profile) {
-    // Given a cursor
+fn read_only_cursor_is_not_updatable(profile: &Profile) {
+    // Given
     let table_name = table_name!();
     let (conn, table) = Given::new(&table_name)
-        .column_types(&["VARCHAR(50)"])
-        .values_by_column(&[&[Some("Hello, World!")]])
+        .column_types(&["INTEGER"])
         .build(profile)
         .unwrap();
+    let options = StatementOptions {
+        cursor_type: Some(CursorType::ForwardOnly),
+        ..StatementOptions::default()
+    };
     let cursor = conn
-        .execute(&table.sql_all_ordered_by_id(), ())
+        .execute_with_options(&table.sql_all_ordered_by_id(), (), options)
         .unwrap()
         .unwrap();
+    let block_cursor = cursor.bind_buffer(RowVec::<(i32,)>::new(10)).unwrap();
 
     // When
-    let mut row_set_buffer = RowVec::<(VarCharArray<10>,)>::new(10);
+    let result = block_cursor.into_updatable();
 
-    let mut block_cursor = cursor.bind_buffer(&mut row_set_buffer).unwrap();
-    let batch = block_cursor.fetch().unwrap().unwrap();
+    // Then
+    assert!(matches!(result, Err(Error::ReadOnlyCursor)));
+}
+
+#[test_case(MSSQL; "Microsoft SQL Server")]
+fn insert_in_chunks_exceeding_parameter_limit(profile: &Profile) {
+    // Given
+    let table_name = table_name!();
+    let (conn, table) = Given::new(&table_name)
+        .column_types(&["INTEGER", "INTEGER", "INTEGER"])
+        .build(profile)
+        .unwrap();
+    // 3 columns times 1000 rows exceeds the 2100 parameter markers supported by Microsoft SQL
+    // Server in a single statement.
+    let values: Vec<i32> = (0..1000).flat_map(|index| [index; 3]).collect();
+    let columns = ["a", "b", "c"];
+    let unchunked = conn.insert_in_chunks(&table_name, &columns, &values, values.len());
+    assert!(unchunked.is_err());
+
+    // When
+    conn.insert_in_chunks(&table_name, &columns, &values, 2100)
+        .unwrap();
 
     // Then
-    assert_eq!("Hello, Wo", batch[0].0.as_str().unwrap().unwrap());
-    drop(block_cursor);
-    assert_eq!(
-        TruncationInfo {
-            indicator: Some(13),
-            buffer_index: 0
-        },
-        (&(&mut row_set_buffer)).find_truncation().unwrap()
-    )
+    let cursor = conn
+        .execute(
+            &format!("SELECT COUNT(*), SUM(a), SUM(c) FROM {}", table.name),
+            (),
+        )
+        .unwrap()
+        .unwrap();
+    assert_eq!("1000,499500,499500", cursor_to_string(cursor));
+    assert!(conn.is_autocommit().unwrap());
 }
 
 #[test_case(MSSQL; "Microsoft SQL Server")]
 #[test_case(MARIADB; "Maria DB")]
-#[test_case(SQLITE_3; "SQLite 3")]
 #[test_case(POSTGRES; "PostgreSQL")]
-fn fetch_fixed_type_row_wise(profile: &Profile) {
-    // Given a cursor
+fn insert_in_chunks_rolls_back_all_chunks_on_error(profile: &Profile) {
+    // Given
     let table_name = table_name!();
     let (conn, table) = Given::new(&table_name)
+        .column_types(&["INTEGER NOT NULL"])
+        .build(profile)
+        .unwrap();
+    let values = [Nullable::new(1), Nullable::new(2), Nullable::null()];
+
+    // When inserting one row per statement, with the last one violating the constraint
+    let result = conn.insert_in_chunks(&table_name, &["a"], &values, 1);
+
+    // Then
+    assert!(result.is_err());
+    assert_eq!("", table.content_as_string(&conn));
+    assert!(conn.is_autocommit().unwrap());
+}
+
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+#[test_case(POSTGRES; "PostgreSQL")]
+fn instrumentation_receives_execute_event(profile: &Profile) {
+    // Given
+    let table_name = table_name!();
+    let (mut conn, table) = Given::new(&table_name)
         .column_types(&["INTEGER"])
         .build(profile)
         .unwrap();
-    conn.execute(&table.sql_insert(), &42.into_parameter())
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let sink = events.clone();
+    conn.set_instrumentation(move |event| {
+        sink.lock()
+            .unwrap()
+            .push((event.phase, event.duration, event.sql.map(str::to_owned)))
+    });
+
+    // When
+    let insert = table.sql_insert();
+    conn.execute(&insert, &42).unwrap();
+
+    // Then
+    let events = events.lock().unwrap();
+    assert_eq!(1, events.len());
+    let (phase, duration, sql) = &events[0];
+    assert_eq!(ExecPhase::Execute, *phase);
+    assert!(*duration > Duration::ZERO);
+    assert!(*duration < Duration::from_secs(60));
+    assert_eq!(Some(&insert), sql.as_ref());
+}
+
+#[test_case(MSSQL; "Microsoft SQL Server")]
+#[test_case(MARIADB; "Maria DB")]
+#[test_case(SQLITE_3; "SQLite 3")]
+#[test_case(POSTGRES; "PostgreSQL")]
+fn instrumentation_receives_prepared_execute_and_fetch_events(profile: &Profile) {
+    // Given
+    let table_name = table_name!();
+    let (mut conn, table) = Given::new(&table_name)
+        .column_types(&["INTEGER"])
+        .values_by_column(&[&[Some("1"), Some("2")]])
+        .build(profile)
         .unwrap();
-    let cursor = conn
-        .execute(&table.sql_all_ordered_by_id(), ())
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let sink = events.clone();
+    conn.set_instrumentation(move |event| {
+        sink.lock()
+            .unwrap()
+            .push((event.phase, event.sql.map(str::to_owned)))
+    });
+
+    // When
+    let query = table.sql_all_ordered_by_id();
+    let mut prepared = conn.prepare(&query).unwrap();
+    {
+        let mut cursor = prepared.execute(()).unwrap().unwrap();
+        while cursor.next_row().unwrap().is_some() {}
+    }
+    let cursor = prepared.execute(()).unwrap().unwrap();
+    let buffer = ColumnarAnyBuffer::from_descs(10, [BufferDesc::I32 { nullable: true }]);
+    let mut block_cursor = cursor.bind_buffer(buffer).unwrap();
+    while block_cursor.fetch().unwrap().is_some() {}
+
+    // Then
+    let events = events.lock().unwrap();
+    let expected = [
+        (ExecPhase::Prepare, Some(query.clone())),
+        (ExecPhase::Execute, Some(query.clone())),
+        // Two rows and the end of the result set
+        (ExecPhase::Fetch, None),
+        (ExecPhase::Fetch, None),
+        (ExecPhase::Fetch, None),
+        (ExecPhase::Execute, Some(query.clone())),
+        // One row set and the end of the result set
+        (ExecPhase::Fetch, None),
+        (ExecPhase::Fetch, None),
+    ];
+    assert_eq!(&expected[..], &events[..]);
+}
+
+/// Sub-second precision of a chrono `NaiveDateTime` should survive a round trip through a
+/// `DATETIME2(7)` column.
+#[cfg(feature = "chrono")]
+#[test_case(MSSQL; "Microsoft SQL Server")]
+fn chrono_naive_date_time_round_trip(profile: &Profile) {
+    use chrono::NaiveDate;
+    use odbc_api::chrono::ChronoTimestamp;
+
+    // Given
+    let table_name = table_name!();
+    let (conn, table) = Given::new(&table_name)
+        .column_types(&["DATETIME2(7)"])
+        .build(profile)
+        .unwrap();
+    let input = NaiveDate::from_ymd_opt(2025, 1, 31)
         .unwrap()
+        .and_hms_nano_opt(13, 45, 8, 123_456_700)
         .unwrap();
 
     // When
-    type RowSample = (i32,);
-
-    let row_set_buffer = RowVec::<RowSample>::new(10);
-    let mut block_cursor = cursor.bind_buffer(row_set_buffer).unwrap();
-    let batch = block_cursor.fetch().unwrap().unwrap();
+    conn.execute(
+        &table.sql_insert(),
+        &ChronoTimestamp::try_from(input).unwrap(),
+    )
+    .unwrap();
+    let mut cursor = conn
+        .execute(&table.sql_all_ordered_by_id(), ())
+        .unwrap()
+        .unwrap();
+    let mut row = cursor.next_row().unwrap().unwrap();
+    let mut actual = ChronoTimestamp::default();
+    row.get_data(1, &mut actual).unwrap();
 
     // Then
-    assert_eq!(42, batch[0].0);
+    assert_eq!(Some(input), actual.to_naive());
 }
 
+/// Columns are assigned to the fields of a struct by position, with `NULL` mapped to `None`.
+#[cfg(feature = "serde")]
 #[test_case(MSSQL; "Microsoft SQL Server")]
 #[test_case(MARIADB; "Maria DB")]
-// #[test_case(SQLITE_3; "SQLite 3")] SQLite just returns a zeroed out numeric struct
+#[test_case(SQLITE_3; "SQLite 3")]
 #[test_case(POSTGRES; "PostgreSQL")]
-fn fetch_decimal_as_numeric_struct_using_get_data(profile: &Profile) {
-    // Given a cursor over a result set with a decimal in its first column
+fn deserialize_row_into_struct(profile: &Profile) {
+    #[derive(serde::Deserialize, Debug, PartialEq)]
+    struct Row {
+        small: i32,
+        big: i64,
+        text: Option<String>,
+    }
+
+    // Given
     let table_name = table_name!();
     let (conn, table) = Given::new(&table_name)
-        .column_types(&["DECIMAL(5,3)"])
+        .column_types(&["INTEGER", "BIGINT", "VARCHAR(50)"])
+        .values_by_column(&[
+            &[Some("1"), Some("2")],
+            &[Some("10000000000"), Some("-3")],
+            &[Some("Hello"), None],
+        ])
         .build(profile)
         .unwrap();
-    conn.execute(&table.sql_insert(), &(25.212).into_parameter())
-        .unwrap();
-    let cursor = conn
+    let mut cursor = conn
         .execute(&table.sql_all_ordered_by_id(), ())
         .unwrap()
         .unwrap();
 
     // When
-    // Binding numeric withouth this wrapper would bind it without the App Row Descriptor
-    struct BindNumericAsAppRowDesc(Numeric);
-    let mut target = BindNumericAsAppRowDesc(Numeric {
-        precision: 0,
-        scale: 0,
-        sign: 0,
-        val: Default::default(),
-    });
-    unsafe impl CData for BindNumericAsAppRowDesc {
-        fn cdata_type(&self) -> CDataType {
-            CDataType::Ard
-        }
-        fn indicator_ptr(&self) -> *const isize {
-            std::ptr::null()
-        }
-        fn value_ptr(&self) -> *const std::ffi::c_void {
-            &self.0 as *const Numeric as *const std::ffi::c_void
-        }
-        fn buffer_length(&self) -> isize {
-            0
-        }
-    }
-    unsafe impl CDataMut for BindNumericAsAppRowDesc {
-        fn mut_indicator_ptr(&mut self) -> *mut isize {
-            null_mut()
-        }
-        fn mut_value_ptr(&mut self) -> Pointer {
-            &mut self.0 as *mut Numeric as *mut std::ffi::c_void
-        }
+    let mut rows = Vec::new();
+    while let Some(mut row) = cursor.next_row().unwrap() {
+        rows.push(row.deserialize::<Row>().unwrap());
     }
-    unsafe {
-        let mut stmt = cursor.into_stmt();
-
-        let mut ard = stmt.application_row_descriptor().unwrap();
-
-        ard.set_type(1, CDataType::Numeric).unwrap();
-        ard.set_precision(1, 5).unwrap();
-        ard.set_scale(1, 3).unwrap();
 
-        stmt.fetch();
+    // Then
+    let expected = [
+        Row {
+            small: 1,
+            big: 10_000_000_000,
+            text: Some("Hello".to_owned()),
+        },
+        Row {
+            small: 2,
+            big: -3,
+            text: None,
+        },
+    ];
+    assert_eq!(&expected[..], &rows[..]);
+}
 
-        stmt.get_data(1, &mut target);
+#[cfg(feature = "serde")]
+#[test_case(MSSQL; "Microsoft SQL Server")]
+fn deserialize_bit_float_and_binary_columns(profile: &Profile) {
+    // Given
+    let conn = profile.connection().unwrap();
+    let mut cursor = conn
+        .execute(
+            "SELECT CAST(1 AS BIT), CAST(2.5 AS FLOAT), CAST(0x010203 AS VARBINARY(3))",
+            (),
+        )
+        .unwrap()
+        .unwrap();
 
-        stmt.close_cursor();
-    }
+    // When
+    let mut row = cursor.next_row().unwrap().unwrap();
+    let (flag, float, bytes) = row.deserialize::<(bool, f64, Vec<u8>)>().unwrap();
 
     // Then
-    assert_eq!(5, target.0.precision);
-    assert_eq!(3, target.0.scale);
-    // 1 is positive, 0 is negative
-    assert_eq!(1, target.0.sign);
-    // Hex representation of 25212 is 627C
-    // First character contains '7C' -> C ~ 12, 7 ~ 7; 12 + 16 * 7 = 124
-    assert_eq!(124, target.0.val[0]);
-    // Second character encodes '62' -> 2 + 16 * 6 = 98
-    assert_eq!(98, target.0.val[1]);
-    assert_eq!(0, target.0.val[2]);
+    assert!(flag);
+    assert_eq!(2.5, float);
+    assert_eq!(vec![1u8, 2, 3], bytes);
 }
 
+#[cfg(feature = "serde")]
 #[test_case(MSSQL; "Microsoft SQL Server")]
 #[test_case(MARIADB; "Maria DB")]
-// #[test_case(SQLITE_3; "SQLite 3")] Always filled with zero
+#[test_case(SQLITE_3; "SQLite 3")]
 #[test_case(POSTGRES; "PostgreSQL")]
-fn fetch_decimal_as_numeric_struct_using_bind_col(profile: &Profile) {
-    // Given a cursor over a result set with a decimal in its first column
+fn deserialize_row_with_wrong_number_of_fields(profile: &Profile) {
+    #[derive(serde::Deserialize, Debug)]
+    struct Row {
+        _a: i32,
+    }
+
+    // Given
     let table_name = table_name!();
     let (conn, table) = Given::new(&table_name)
-        .column_types(&["DECIMAL(5,3)"])
+        .column_types(&["INTEGER", "INTEGER"])
+        .values_by_column(&[&[Some("1")], &[Some("2")]])
         .build(profile)
         .unwrap();
-    conn.execute(&table.sql_insert(), &(25.212).into_parameter())
-        .unwrap();
-    let cursor = conn
+    let mut cursor = conn
         .execute(&table.sql_all_ordered_by_id(), ())
         .unwrap()
         .unwrap();
 
     // When
-    // Binding numeric withouth this wrapper would bind it without the App Row Descriptor
-    let mut target = Numeric {
-        precision: 0,
-        scale: 0,
-        sign: 0,
-        val: Default::default(),
-    };
-    unsafe {
-        let mut stmt = cursor.into_stmt();
-
-        // stmt.bind_col(1, &mut target);
-        let mut ard = stmt.application_row_descriptor().unwrap();
-
-        // Setting Field descriptors always seems to yield structs field with zeroes
-        ard.set_type(1, CDataType::Numeric).unwrap();
-        ard.set_precision(1, 5).unwrap();
-        ard.set_scale(1, 3).unwrap();
-        // Setting the dataptr directly on the ARD is required to make it work for MSSQL which does
-        // seem to set the wrong pointer if just using bind col. Postgres and MariaDB would work as
-        // intended with bind_col.
-        // stmt.bind_col(1, &mut target);
-        ard.set_data_ptr(1, &mut target as *mut Numeric as Pointer)
-            .unwrap();
-
-        stmt.fetch();
-
-        stmt.close_cursor();
-    }
+    let mut row = cursor.next_row().unwrap().unwrap();
+    let result = row.deserialize::<Row>();
 
     // Then
-    assert_eq!(5, target.precision);
-    assert_eq!(3, target.scale);
-    // 1 is positive, 0 is negative
-    assert_eq!(1, target.sign);
-    // Hex representation of 25212 is 627C
-    // First character contains '7C' -> C ~ 12, 7 ~ 7; 12 + 16 * 7 = 124
-    assert_eq!(124, target.val[0]);
-    // Second character encodes '62' -> 2 + 16 * 6 = 98
-    assert_eq!(98, target.val[1]);
-    assert_eq!(0, target.val[2]);
+    assert!(matches!(
+        result,
+        Err(Error::FieldCountMismatch {
+            num_columns: 2,
+            num_fields: 1
+        })
+    ));
 }
 
-/// Learning test to see how scrolling cursors behave
+#[cfg(feature = "ndarray")]
 #[test_case(MSSQL; "Microsoft SQL Server")]
 #[test_case(MARIADB; "Maria DB")]
 #[test_case(SQLITE_3; "SQLite 3")]
 #[test_case(POSTGRES; "PostgreSQL")]
-fn scroll_cursor(profile: &Profile) {
-    // Given a table
+fn fetch_numeric_columns_into_ndarray(profile: &Profile) {
+    use odbc_api::ndarray::fetch_f64_array;
+
+    // Given
     let table_name = table_name!();
     let (conn, table) = Given::new(&table_name)
-        .column_types(&["VARCHAR(50)"])
+        .column_types(&["INTEGER", "REAL"])
+        .values_by_column(&[
+            &[Some("1"), Some("2"), Some("3")],
+            &[Some("0.5"), Some("1.5"), None],
+        ])
         .build(profile)
         .unwrap();
-    conn.execute(&table.sql_insert(), &"one".into_parameter())
-        .unwrap();
-    conn.execute(&table.sql_insert(), &"two".into_parameter())
-        .unwrap();
-    conn.execute(&table.sql_insert(), &"three".into_parameter())
+    let cursor = conn
+        .execute(&table.sql_all_ordered_by_id(), ())
+        .unwrap()
         .unwrap();
-    let query = table.sql_all_ordered_by_id();
 
     // When
-    let stmt = conn.preallocate().unwrap();
-    let stmt = stmt.into_statement();
-    let stmt_ptr = stmt.as_sys();
-    let first;
-    let second;
-    let third;
-    unsafe {
-        // 3(UL) ~ SQL_CURSOR_STATIC
-        let _ = odbc_sys::SQLSetStmtAttr(
-            stmt_ptr,
-            odbc_sys::StatementAttribute::CursorType,
-            3 as Pointer,
-            0,
-        );
-        // Bind row set buffer
-        let mut stmt = Preallocated::new(stmt);
-        let mut cursor = stmt.execute(&query, ()).unwrap().unwrap();
-        let mut buffer = TextRowSet::for_cursor(1, &mut cursor, Some(10)).unwrap();
-        let mut block_cursor = cursor.bind_buffer(&mut buffer).unwrap();
-        // Move cursor two forward
-        first = block_cursor
-            .fetch()
-            .unwrap()
-            .unwrap()
-            .at_as_str(0, 0)
-            .unwrap()
-            .unwrap()
-            .to_owned();
-        second = block_cursor
-            .fetch()
-            .unwrap()
-            .unwrap()
-            .at_as_str(0, 0)
-            .unwrap()
-            .unwrap()
-            .to_owned();
-        let _ = odbc_sys::SQLFetchScroll(stmt_ptr, sys::FetchOrientation::Absolute, 1);
-        third = block_cursor
-            .fetch()
-            .unwrap()
-            .unwrap()
-            .at_as_str(0, 0)
-            .unwrap()
-            .unwrap()
-            .to_owned();
-    }
+    // Batch size smaller than the number of rows, to fetch more than one batch.
+    let array = fetch_f64_array(cursor, 2).unwrap();
 
     // Then
-    assert_eq!("one", first);
-    assert_eq!("two", second);
-    assert_eq!("two", third);
+    assert_eq!(&[3, 2], array.shape());
+    assert_eq!(
+        [1.0, 0.5, 2.0, 1.5, 3.0],
+        [
+            array[[0, 0]],
+            array[[0, 1]],
+            array[[1, 0]],
+            array[[1, 1]],
+            array[[2, 0]]
+        ]
+    );
+    assert!(array[[2, 1]].is_nan());
 }
 
-/// Learning test to see how downstream applications could recover from truncations during bulk
-/// fetches
+#[cfg(feature = "ndarray")]
 #[test_case(MSSQL; "Microsoft SQL Server")]
 #[test_case(MARIADB; "Maria DB")]
 #[test_case(SQLITE_3; "SQLite 3")]
 #[test_case(POSTGRES; "PostgreSQL")]
-fn recover_from_truncation(profile: &Profile) {
-    // Given a table
+fn fetch_numeric_columns_into_ndarray_with_validity(profile: &Profile) {
+    use odbc_api::ndarray::fetch_f64_array_with_validity;
+
+    // Given
     let table_name = table_name!();
     let (conn, table) = Given::new(&table_name)
-        .column_types(&["VARCHAR(10)"])
+        .column_types(&["INTEGER", "REAL"])
+        .values_by_column(&[&[Some("1"), None], &[None, Some("2.5")]])
         .build(profile)
         .unwrap();
-    conn.execute(&table.sql_insert(), &"1".into_parameter())
-        .unwrap();
-    conn.execute(&table.sql_insert(), &"123456789".into_parameter())
-        .unwrap();
-    conn.execute(&table.sql_insert(), &"1".into_parameter())
+    let cursor = conn
+        .execute(&table.sql_all_ordered_by_id(), ())
+        .unwrap()
         .unwrap();
-    let query = table.sql_all_ordered_by_id();
 
     // When
-    let stmt = conn.preallocate().unwrap();
-    let stmt = stmt.into_statement();
-    let stmt_ptr = stmt.as_sys();
-    let untruncated;
-    unsafe {
-        // 3(UL) ~ SQL_CURSOR_STATIC
-        let _ = odbc_sys::SQLSetStmtAttr(
-            stmt_ptr,
-            odbc_sys::StatementAttribute::CursorType,
-            3 as Pointer,
-            0,
-        );
-        // Bind row set buffer
-        let mut stmt = Preallocated::new(stmt);
-        let cursor = stmt.execute(&query, ()).unwrap().unwrap();
-        let buffer = TextRowSet::from_max_str_lens(1, [1]).unwrap();
-        let mut block_cursor = cursor.bind_buffer(buffer).unwrap();
-        // Fetch first row set (one row). This would be truncated
-        let _ = block_cursor.fetch().unwrap().unwrap();
-        // Fetch second rowset with truncation
-        let _ = block_cursor.fetch().unwrap().unwrap();
-        // In order to recover we set the position back and bind a large enough buffer.
-
-        // Set position back
-        let _ = odbc_sys::SQLFetchScroll(stmt_ptr, sys::FetchOrientation::Prior, 0);
-
-        // Bind large enough buffer
-        let (cursor, _) = block_cursor.unbind().unwrap();
-        let buffer = TextRowSet::from_max_str_lens(1, [10]).unwrap();
-        let mut block_cursor = cursor.bind_buffer(buffer).unwrap();
-
-        let batch = block_cursor.fetch().unwrap().unwrap();
-        untruncated = batch.at_as_str(0, 0).unwrap().unwrap().to_owned();
-    }
+    let (values, validity) = fetch_f64_array_with_validity(cursor, 10).unwrap();
 
     // Then
-    assert_eq!("123456789", untruncated);
+    assert_eq!(&[2, 2], validity.shape());
+    assert_eq!(
+        [true, false, false, true],
+        [
+            validity[[0, 0]],
+            validity[[0, 1]],
+            validity[[1, 0]],
+            validity[[1, 1]]
+        ]
+    );
+    assert_eq!(1.0, values[[0, 0]]);
+    assert_eq!(2.5, values[[1, 1]]);
 }
@@ -0,0 +1,61 @@
+//! Installs a logger capturing all log records. Since the logger is process global, these tests
+//! have to run in their own process.
+
+use std::sync::Mutex;
+
+use odbc_api::{ConnectionOptions, Environment, IntoParameter, Sensitive};
+use test_case::test_case;
+
+const MSSQL_CONNECTION: &str =
+    "Driver={ODBC Driver 18 for SQL Server};Server=localhost;UID=SA;PWD=My@Test@Password1;\
+    TrustServerCertificate=yes;";
+
+/// Captures the messages of all log records, so tests can assert on them.
+struct CapturingLogger;
+
+static CAPTURED_LOG_MESSAGES: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+impl log::Log for CapturingLogger {
+    fn enabled(&self, _metadata: &log::Metadata) -> bool {
+        true
+    }
+
+    fn log(&self, record: &log::Record) {
+        CAPTURED_LOG_MESSAGES
+            .lock()
+            .unwrap()
+            .push(record.args().to_string());
+    }
+
+    fn flush(&self) {}
+}
+
+#[test_case(MSSQL_CONNECTION; "Microsoft SQL Server")]
+fn trace_parameter_values(connection_string: &str) {
+    // Given
+    log::set_logger(&CapturingLogger).unwrap();
+    log::set_max_level(log::LevelFilter::Trace);
+    let env = Environment::new().unwrap();
+    let mut conn = env
+        .connect_with_connection_string(connection_string, ConnectionOptions::default())
+        .unwrap();
+    conn.enable_parameter_logging();
+
+    // When
+    let secret = "TopSecret1337".into_parameter();
+    let public = "Public".into_parameter();
+    let query = "SELECT ?, ?, ?";
+    conn.execute(query, (&4711, Sensitive(&secret), &public))
+        .unwrap();
+    // Sensitivity is a property of the parameter, not of its position
+    conn.execute(query, (&42, &public, Sensitive(&secret)))
+        .unwrap();
+
+    // Then
+    let messages = CAPTURED_LOG_MESSAGES.lock().unwrap();
+    assert!(messages.iter().any(|m| m == "Parameter 1: 4711"));
+    assert!(messages.iter().any(|m| m == "Parameter 2: <redacted>"));
+    assert!(messages.iter().any(|m| m == "Parameter 2: 'Public'"));
+    assert!(messages.iter().any(|m| m == "Parameter 3: <redacted>"));
+    assert!(!messages.iter().any(|m| m.contains("TopSecret1337")));
+}
@@ -0,0 +1,11 @@
+//! Compile tests for the `query!` macro. These do not require a database.
+
+#[cfg(feature = "query_macro")]
+#[test]
+fn query_macro() {
+    let cases = trybuild::TestCases::new();
+    cases.pass("tests/ui/query_matching_parameters.rs");
+    cases.compile_fail("tests/ui/query_too_few_parameters.rs");
+    cases.compile_fail("tests/ui/query_too_many_parameters.rs");
+    cases.compile_fail("tests/ui/query_not_a_literal.rs");
+}
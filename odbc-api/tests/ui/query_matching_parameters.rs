@@ -0,0 +1,21 @@
+use odbc_api::{query, Connection, Error, IntoParameter};
+
+#[allow(dead_code)]
+fn insert(conn: &Connection<'_>, name: &str, year: i16) -> Result<(), Error> {
+    query!(conn, "DELETE FROM Birthdays")?;
+    query!(
+        conn,
+        "INSERT INTO Birthdays (name, year) VALUES (?, ?)",
+        &name.into_parameter(),
+        &year,
+    )?;
+    // Question marks in literals, quoted identifiers and comments are not placeholders.
+    query!(
+        conn,
+        "INSERT INTO \"Why?\" (a, b) VALUES (?, '?') -- ?\n /* ? */",
+        &year
+    )?;
+    Ok(())
+}
+
+fn main() {}
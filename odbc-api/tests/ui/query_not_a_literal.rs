@@ -0,0 +1,8 @@
+use odbc_api::{query, Connection, Error};
+
+fn insert_year(conn: &Connection<'_>, sql: &str, year: i16) -> Result<(), Error> {
+    query!(conn, sql, &year)?;
+    Ok(())
+}
+
+fn main() {}
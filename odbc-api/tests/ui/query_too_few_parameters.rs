@@ -0,0 +1,8 @@
+use odbc_api::{query, Connection, Error};
+
+fn insert_year(conn: &Connection<'_>, year: i16) -> Result<(), Error> {
+    query!(conn, "INSERT INTO Birthdays (name, year) VALUES (?, ?)", &year)?;
+    Ok(())
+}
+
+fn main() {}